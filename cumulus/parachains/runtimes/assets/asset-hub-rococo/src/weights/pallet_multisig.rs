@@ -177,4 +177,68 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn create_stateful(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `7766`
+		// Minimum execution time: 37_250_000 picoseconds.
+		Weight::from_parts(38_242_706, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			// Standard Error: 1_427
+			.saturating_add(Weight::from_parts(119_359, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn reconfigure_stateful(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `387 + s * (36 ±0)`
+		//  Estimated: `7766`
+		// Minimum execution time: 40_316_000 picoseconds.
+		Weight::from_parts(41_957_308, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			// Standard Error: 1_304
+			.saturating_add(Weight::from_parts(132_947, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_call_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4491`
+		//  Estimated: `7766`
+		// Minimum execution time: 39_510_000 picoseconds.
+		Weight::from_parts(40_822_000, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn dissolve_stateful() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4285`
+		//  Estimated: `7766`
+		// Minimum execution time: 23_297_000 picoseconds.
+		Weight::from_parts(23_954_000, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -177,4 +177,68 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn create_stateful(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `7766`
+		// Minimum execution time: 35_937_000 picoseconds.
+		Weight::from_parts(37_178_177, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			// Standard Error: 1_371
+			.saturating_add(Weight::from_parts(120_645, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn reconfigure_stateful(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `387 + s * (36 ±0)`
+		//  Estimated: `7766`
+		// Minimum execution time: 39_759_000 picoseconds.
+		Weight::from_parts(41_362_436, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			// Standard Error: 1_564
+			.saturating_add(Weight::from_parts(131_145, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_call_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4491`
+		//  Estimated: `7766`
+		// Minimum execution time: 39_353_000 picoseconds.
+		Weight::from_parts(40_550_000, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::StatefulMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StatefulMultisigs` (`max_values`: None, `max_size`: Some(4301), added: 6776, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn dissolve_stateful() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4285`
+		//  Estimated: `7766`
+		// Minimum execution time: 22_841_000 picoseconds.
+		Weight::from_parts(23_639_000, 0)
+			.saturating_add(Weight::from_parts(0, 7766))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -17,7 +17,7 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner--ss9ysm1-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("westend-dev"), DB CACHE: 1024
@@ -50,216 +50,240 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_vesting`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// The range of component `s` is `[1, 100]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 35_225_000 picoseconds.
-		Weight::from_parts(34_420_748, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_341
-			.saturating_add(Weight::from_parts(41_794, 0).saturating_mul(l.into()))
-			// Standard Error: 4_166
-			.saturating_add(Weight::from_parts(114_507, 0).saturating_mul(s.into()))
+		//  Measured:  `348 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 37_257_000 picoseconds.
+		Weight::from_parts(36_406_395, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 2_528
+			.saturating_add(Weight::from_parts(42_913, 0).saturating_mul(l.into()))
+			// Standard Error: 4_001
+			.saturating_add(Weight::from_parts(126_132, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// The range of component `s` is `[1, 100]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 38_507_000 picoseconds.
-		Weight::from_parts(38_552_717, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_406
-			.saturating_add(Weight::from_parts(42_332, 0).saturating_mul(l.into()))
-			// Standard Error: 4_282
-			.saturating_add(Weight::from_parts(67_638, 0).saturating_mul(s.into()))
+		//  Measured:  `348 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 39_298_000 picoseconds.
+		Weight::from_parts(39_344_939, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 2_550
+			.saturating_add(Weight::from_parts(43_531, 0).saturating_mul(l.into()))
+			// Standard Error: 3_941
+			.saturating_add(Weight::from_parts(79_456, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// The range of component `s` is `[1, 100]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 36_919_000 picoseconds.
-		Weight::from_parts(35_087_984, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_435
-			.saturating_add(Weight::from_parts(66_131, 0).saturating_mul(l.into()))
-			// Standard Error: 4_333
-			.saturating_add(Weight::from_parts(125_178, 0).saturating_mul(s.into()))
+		//  Measured:  `451 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 38_712_000 picoseconds.
+		Weight::from_parts(36_791_968, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 2_526
+			.saturating_add(Weight::from_parts(62_881, 0).saturating_mul(l.into()))
+			// Standard Error: 4_220
+			.saturating_add(Weight::from_parts(138_710, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
-	/// The range of component `s` is `[1, 28]`.
+	/// The range of component `s` is `[1, 100]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_393_000 picoseconds.
-		Weight::from_parts(39_522_987, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_958
-			.saturating_add(Weight::from_parts(46_626, 0).saturating_mul(l.into()))
-			// Standard Error: 3_484
-			.saturating_add(Weight::from_parts(94_547, 0).saturating_mul(s.into()))
+		//  Measured:  `451 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 42_043_000 picoseconds.
+		Weight::from_parts(41_137_652, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 1_984
+			.saturating_add(Weight::from_parts(47_178, 0).saturating_mul(l.into()))
+			// Standard Error: 3_588
+			.saturating_add(Weight::from_parts(105_898, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `522 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 72_925_000 picoseconds.
-		Weight::from_parts(75_858_529, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 3_995
-			.saturating_add(Weight::from_parts(70_032, 0).saturating_mul(l.into()))
-			// Standard Error: 7_108
-			.saturating_add(Weight::from_parts(160_507, 0).saturating_mul(s.into()))
+		//  Measured:  `522 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 77_769_000 picoseconds.
+		Weight::from_parts(80_897_495, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 3_942
+			.saturating_add(Weight::from_parts(72_237, 0).saturating_mul(l.into()))
+			// Standard Error: 6_548
+			.saturating_add(Weight::from_parts(177_999, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: System Account (r:2 w:2)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `625 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
-		// Minimum execution time: 74_405_000 picoseconds.
-		Weight::from_parts(78_253_087, 0)
-			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_708
-			.saturating_add(Weight::from_parts(56_748, 0).saturating_mul(l.into()))
-			// Standard Error: 6_598
-			.saturating_add(Weight::from_parts(146_713, 0).saturating_mul(s.into()))
+		//  Measured:  `625 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 77_642_000 picoseconds.
+		Weight::from_parts(81_657_639, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 3_854
+			.saturating_add(Weight::from_parts(58_479, 0).saturating_mul(l.into()))
+			// Standard Error: 7_244
+			.saturating_add(Weight::from_parts(161_377, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 37_715_000 picoseconds.
-		Weight::from_parts(36_483_330, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_146
-			.saturating_add(Weight::from_parts(55_976, 0).saturating_mul(l.into()))
-			// Standard Error: 3_964
-			.saturating_add(Weight::from_parts(116_455, 0).saturating_mul(s.into()))
+		//  Measured:  `449 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 38_590_000 picoseconds.
+		Weight::from_parts(37_329_557, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 2_271
+			.saturating_add(Weight::from_parts(53_315, 0).saturating_mul(l.into()))
+			// Standard Error: 3_642
+			.saturating_add(Weight::from_parts(129_299, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
-	/// Storage: Balances Locks (r:1 w:1)
-	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	/// Storage: Balances Freezes (r:1 w:0)
-	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_102_000 picoseconds.
-		Weight::from_parts(41_671_515, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_743
-			.saturating_add(Weight::from_parts(47_496, 0).saturating_mul(l.into()))
-			// Standard Error: 5_065
-			.saturating_add(Weight::from_parts(95_785, 0).saturating_mul(s.into()))
+		//  Measured:  `449 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 44_875_000 picoseconds.
+		Weight::from_parts(44_416_514, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 2_700
+			.saturating_add(Weight::from_parts(46_556, 0).saturating_mul(l.into()))
+			// Standard Error: 4_563
+			.saturating_add(Weight::from_parts(110_658, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `555 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 41_497_000 picoseconds.
-		Weight::from_parts(38_763_834, 4764)
-			// Standard Error: 2_030
-			.saturating_add(Weight::from_parts(99_580, 0).saturating_mul(l.into()))
-			// Standard Error: 3_750
-			.saturating_add(Weight::from_parts(132_188, 0).saturating_mul(s.into()))
+		//  Measured:  `555 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 43_447_000 picoseconds.
+		Weight::from_parts(40_585_496, 10915)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(98_544, 0).saturating_mul(l.into()))
+			// Standard Error: 4_077
+			.saturating_add(Weight::from_parts(152_470, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(7450), added: 9925, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 100]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `487 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `10915`
+		// Minimum execution time: 56_169_000 picoseconds.
+		Weight::from_parts(55_377_543, 0)
+			.saturating_add(Weight::from_parts(0, 10915))
+			// Standard Error: 1_927
+			.saturating_add(Weight::from_parts(39_664, 0).saturating_mul(l.into()))
+			// Standard Error: 3_419
+			.saturating_add(Weight::from_parts(94_475, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -64,6 +64,224 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Parachains DB utilities.
+	#[command(subcommand)]
+	Db(DbSubcommand),
+
+	/// Post-incident analysis of disputes.
+	#[command(subcommand)]
+	Disputes(DisputesSubcommand),
+
+	/// Simulate approval voting to compare assignment parameters.
+	SimulateApprovals(SimulateApprovalsCmd),
+}
+
+/// Parachains DB utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum DbSubcommand {
+	/// Decode the parachains DB of a stopped node to JSON.
+	Inspect(InspectParachainsDbCmd),
+}
+
+/// The `db inspect` command.
+#[derive(Debug, Clone, Parser)]
+pub struct InspectParachainsDbCmd {
+	/// The columns to decode, separated by commas.
+	///
+	/// One of `availability-store`, `approval-voting`, `dispute-coordinator` or
+	/// `chain-selection`. All columns are decoded by default.
+	#[arg(long, value_delimiter = ',')]
+	pub column: Vec<polkadot_service::ParachainsDbColumn>,
+
+	/// Only include entries of the given session.
+	///
+	/// The availability store and chain selection don't record sessions, their entries are not
+	/// filtered.
+	#[arg(long)]
+	pub session: Option<polkadot_service::SessionIndex>,
+
+	/// Only include entries related to the given candidate hash.
+	#[arg(long)]
+	pub candidate_hash: Option<polkadot_service::Hash>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for InspectParachainsDbCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Post-incident analysis of disputes.
+#[derive(Debug, clap::Subcommand)]
+pub enum DisputesSubcommand {
+	/// Export the concluded disputes kept in the parachains DB of a stopped node to JSON.
+	Export(ExportDisputesCmd),
+	/// Re-validate the candidate of an exported dispute to reproduce its verdict.
+	Revalidate(RevalidateDisputedCandidateCmd),
+}
+
+/// The `disputes export` command.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportDisputesCmd {
+	/// Only export the disputes of the given session.
+	#[arg(long)]
+	pub session: Option<polkadot_service::SessionIndex>,
+
+	/// Only export the dispute of the given candidate hash.
+	#[arg(long)]
+	pub candidate_hash: Option<polkadot_service::Hash>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportDisputesCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// The `disputes revalidate` command.
+///
+/// The files holding the validation code, PoV, persisted validation data and executor parameters
+/// contain the SCALE-encoded values, either as raw bytes or as `0x`-prefixed hex.
+#[derive(Debug, Clone, Parser)]
+pub struct RevalidateDisputedCandidateCmd {
+	/// The disputes exported with `disputes export`.
+	#[arg(long, value_name = "PATH")]
+	pub disputes: PathBuf,
+
+	/// The hash of the disputed candidate.
+	#[arg(long)]
+	pub candidate_hash: polkadot_service::Hash,
+
+	/// The validation code of the candidate, as stored in `Paras::CodeByHash`.
+	#[arg(long, value_name = "PATH")]
+	pub validation_code: PathBuf,
+
+	/// The PoV of the candidate.
+	#[arg(long, value_name = "PATH")]
+	pub pov: PathBuf,
+
+	/// The persisted validation data of the candidate.
+	#[arg(long, value_name = "PATH")]
+	pub persisted_validation_data: PathBuf,
+
+	/// The executor parameters of the session of the candidate.
+	///
+	/// The default executor parameters are used if not given.
+	#[arg(long, value_name = "PATH")]
+	pub executor_params: Option<PathBuf>,
+
+	/// The limit for decompressing the validation code.
+	///
+	/// Defaults to the limit of a relay chain accepting validation code of the maximum size.
+	#[arg(long, default_value_t = 30 * 1024 * 1024)]
+	pub validation_code_bomb_limit: u32,
+
+	/// Path to the directory where the PVF worker binaries are located.
+	#[arg(long, value_name = "PATH")]
+	pub workers_path: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+}
+
+impl sc_cli::CliConfiguration for RevalidateDisputedCandidateCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+}
+
+/// The `simulate-approvals` command.
+///
+/// The parameters taking comma-separated values are simulated in all combinations, with the same
+/// seed, so their results can be compared. Durations are in milliseconds and their distributions
+/// are given as `<millis>`, `uniform:<min>-<max>` or `exp:<mean>`.
+#[derive(Debug, Clone, Parser)]
+pub struct SimulateApprovalsCmd {
+	/// The number of validators in the session.
+	#[arg(long, default_value_t = 300)]
+	pub validators: u32,
+
+	/// The number of availability cores, all of them occupied in every block.
+	#[arg(long, default_value_t = 50)]
+	pub cores: u32,
+
+	/// The values of `needed_approvals` to simulate, separated by commas.
+	#[arg(long, value_delimiter = ',', default_value = "30")]
+	pub needed_approvals: Vec<u32>,
+
+	/// The values of `n_delay_tranches` to simulate, separated by commas.
+	#[arg(long, value_delimiter = ',', default_value = "89")]
+	pub n_delay_tranches: Vec<u32>,
+
+	/// The values of `relay_vrf_modulo_samples` to simulate, separated by commas.
+	#[arg(long, value_delimiter = ',', default_value = "6")]
+	pub relay_vrf_modulo_samples: Vec<u32>,
+
+	/// The width of the zeroth delay tranche.
+	#[arg(long, default_value_t = 0)]
+	pub zeroth_delay_tranche_width: u32,
+
+	/// The number of slots after which an assignment without approval is a no-show.
+	#[arg(long, default_value_t = 2)]
+	pub no_show_slots: u32,
+
+	/// The duration of a relay chain slot.
+	#[arg(long, default_value_t = 6_000)]
+	pub slot_duration_millis: u64,
+
+	/// Simulate v1 assignments, each covering a single core.
+	#[arg(long)]
+	pub disable_v2_assignments: bool,
+
+	/// The probability of a triggered assignment never being followed by an approval.
+	#[arg(long, default_value_t = 0.0)]
+	pub no_show_rate: f64,
+
+	/// The time it takes for a message to reach all other validators.
+	#[arg(long, default_value = "uniform:50-500")]
+	pub network_latency: polkadot_service::approval_voting_simulation::Distribution,
+
+	/// The time it takes a validator to recover and check a candidate.
+	#[arg(long, default_value = "uniform:1000-3000")]
+	pub approval_check_duration: polkadot_service::approval_voting_simulation::Distribution,
+
+	/// The number of relay chain blocks to simulate for each parameter set.
+	#[arg(long, default_value_t = 10)]
+	pub blocks: u32,
+
+	/// Candidates not approved within this time are reported as unapproved.
+	#[arg(long, default_value_t = 120_000)]
+	pub max_lag_millis: u64,
+
+	/// The seed of the simulation.
+	#[arg(long, default_value_t = 0)]
+	pub seed: u64,
 }
 
 #[allow(missing_docs)]
@@ -159,6 +377,19 @@ pub struct RunCmd {
 	/// networks.
 	#[arg(long)]
 	pub keep_finalized_for: Option<u32>,
+
+	/// Record the message traffic of all subsystems to the given file.
+	///
+	/// The recording of a subsystem can be replayed against it in tests, to reproduce issues seen
+	/// on a live node. Only meant for debugging, the file grows without bounds. Only the
+	/// subsystems of validators are recorded.
+	#[arg(long, value_name = "PATH")]
+	pub record_subsystem_messages: Option<PathBuf>,
+
+	/// Recover the available data of large PoVs as content-addressed blocks, fetched over bitswap
+	/// from the backers and other validators in parallel, before falling back to erasure chunks.
+	#[arg(long)]
+	pub fetch_available_data_blocks: bool,
 }
 
 #[allow(missing_docs)]
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::cli::{Cli, Subcommand, NODE_VERSION};
+use crate::cli::{Cli, DbSubcommand, DisputesSubcommand, Subcommand, NODE_VERSION};
 use frame_benchmarking_cli::{
 	BenchmarkCmd, ExtrinsicFactory, SubstrateRemarkBuilder, SUBSTRATE_REFERENCE_HARDWARE,
 };
@@ -235,6 +235,8 @@ where
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
 				keep_finalized_for: cli.run.keep_finalized_for,
+				message_recording_path: cli.run.record_subsystem_messages,
+				fetch_available_data_blocks: cli.run.fetch_available_data_blocks,
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -468,6 +470,114 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::Db(DbSubcommand::Inspect(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			let columns = if cmd.column.is_empty() {
+				polkadot_service::ParachainsDbColumn::ALL.to_vec()
+			} else {
+				cmd.column.clone()
+			};
+			let filter = polkadot_service::ParachainsDbFilter {
+				session: cmd.session,
+				candidate_hash: cmd.candidate_hash.map(polkadot_service::CandidateHash),
+			};
+
+			Ok(runner.sync_run(|config| {
+				let output =
+					polkadot_service::inspect_parachains_db(&config.database, &columns, &filter)?;
+				println!(
+					"{}",
+					serde_json::to_string_pretty(&output).map_err(|err| err.to_string())?
+				);
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::Disputes(DisputesSubcommand::Export(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+			let filter = polkadot_service::ParachainsDbFilter {
+				session: cmd.session,
+				candidate_hash: cmd.candidate_hash.map(polkadot_service::CandidateHash),
+			};
+
+			Ok(runner.sync_run(|config| {
+				let output =
+					polkadot_service::export_concluded_disputes(&config.database, &filter)?;
+				println!(
+					"{}",
+					serde_json::to_string_pretty(&output).map_err(|err| err.to_string())?
+				);
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::Disputes(DisputesSubcommand::Revalidate(cmd))) => {
+			let runner = cli.create_runner(cmd)?;
+
+			Ok(runner.async_run(|config| {
+				let task_manager = sc_service::TaskManager::new(config.tokio_handle.clone(), None)
+					.map_err(sc_service::Error::from)?;
+				let params = polkadot_service::RevalidationParams {
+					disputes: cmd.disputes.clone(),
+					candidate_hash: polkadot_service::CandidateHash(cmd.candidate_hash),
+					validation_code: cmd.validation_code.clone(),
+					pov: cmd.pov.clone(),
+					persisted_validation_data: cmd.persisted_validation_data.clone(),
+					executor_params: cmd.executor_params.clone(),
+					validation_code_bomb_limit: cmd.validation_code_bomb_limit,
+					artifacts_cache_path: config.data_path.join("revalidation-artifacts"),
+					workers_path: cmd.workers_path.clone(),
+					node_version: Some(NODE_VERSION.to_string()),
+				};
+
+				Ok((
+					async move {
+						let output =
+							polkadot_service::revalidate_disputed_candidate(params).await?;
+						println!(
+							"{}",
+							serde_json::to_string_pretty(&output).map_err(|err| err.to_string())?
+						);
+						Ok::<_, Error>(())
+					},
+					task_manager,
+				))
+			})?)
+		},
+		Some(Subcommand::SimulateApprovals(cmd)) => {
+			use polkadot_service::approval_voting_simulation::{simulate, SimulationConfig};
+
+			for &needed_approvals in &cmd.needed_approvals {
+				for &n_delay_tranches in &cmd.n_delay_tranches {
+					for &relay_vrf_modulo_samples in &cmd.relay_vrf_modulo_samples {
+						let config = SimulationConfig {
+							n_validators: cmd.validators,
+							n_cores: cmd.cores,
+							needed_approvals,
+							n_delay_tranches,
+							relay_vrf_modulo_samples,
+							zeroth_delay_tranche_width: cmd.zeroth_delay_tranche_width,
+							no_show_slots: cmd.no_show_slots,
+							slot_duration_millis: cmd.slot_duration_millis,
+							enable_v2_assignments: !cmd.disable_v2_assignments,
+							no_show_rate: cmd.no_show_rate,
+							network_latency: cmd.network_latency,
+							approval_check_duration: cmd.approval_check_duration,
+							n_blocks: cmd.blocks,
+							max_lag_millis: cmd.max_lag_millis,
+							seed: cmd.seed,
+						};
+						let report = simulate(&config).map_err(|err| err.to_string())?;
+
+						println!(
+							"needed_approvals={} n_delay_tranches={} relay_vrf_modulo_samples={}",
+							needed_approvals, n_delay_tranches, relay_vrf_modulo_samples,
+						);
+						println!("{}\n", report);
+					}
+				}
+			}
+
+			Ok(())
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
@@ -354,6 +354,13 @@ async fn run_main_loop<Context>(
 								)
 							).await;
 						},
+						ApprovalVotingParallelMessage::GetApprovalDuties(_, _) => {
+							to_approval_voting_worker.send_message(
+								msg.try_into().expect(
+									"Message is GetApprovalDuties and that can be safely converted to ApprovalVotingMessage; qed"
+								)
+							).await;
+						},
 						// Now the message the approval distribution subsystem would've handled and need to
 						// be forwarded to the workers.
 						ApprovalVotingParallelMessage::NewBlocks(msg) => {
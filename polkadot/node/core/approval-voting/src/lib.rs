@@ -35,8 +35,8 @@ use polkadot_node_primitives::{
 use polkadot_node_subsystem::{
 	errors::RecoveryError,
 	messages::{
-		ApprovalCheckError, ApprovalCheckResult, ApprovalDistributionMessage,
-		ApprovalVotingMessage, AssignmentCheckError, AssignmentCheckResult,
+		ApprovalCheckError, ApprovalCheckResult, ApprovalDistributionMessage, ApprovalDuty,
+		ApprovalDutyOutcome, ApprovalVotingMessage, AssignmentCheckError, AssignmentCheckResult,
 		AvailabilityRecoveryMessage, BlockDescription, CandidateValidationMessage, ChainApiMessage,
 		ChainSelectionMessage, CheckedIndirectAssignment, CheckedIndirectSignedApprovalVote,
 		DisputeCoordinatorMessage, HighestApprovedAncestorBlock, PvfExecKind, RuntimeApiMessage,
@@ -50,7 +50,7 @@ use polkadot_node_subsystem_util::{
 	database::Database,
 	metrics::{self, prometheus},
 	runtime::{Config as RuntimeInfoConfig, ExtendedSessionInfo, RuntimeInfo},
-	TimeoutExt,
+	DutyRecords, TimeoutExt,
 };
 use polkadot_primitives::{
 	vstaging::CandidateReceiptV2 as CandidateReceipt, ApprovalVoteMultipleCandidates,
@@ -103,6 +103,7 @@ pub mod criteria;
 mod import;
 mod ops;
 mod persisted_entries;
+pub mod simulation;
 
 use crate::{
 	approval_checking::{Check, TranchesToApproveResult},
@@ -898,6 +899,8 @@ struct State {
 	per_block_assignments_gathering_times:
 		LruMap<BlockNumber, HashMap<(Hash, CandidateHash), AssignmentGatheringRecord>>,
 	no_show_stats: NoShowStats,
+	// The assignments taken by the local validator, kept for reporting its performance.
+	approval_duties: DutyRecords<(CandidateHash, Hash), (ValidatorIndex, ApprovalDuty)>,
 }
 
 // Regularly dump the no-show stats at this block number frequency.
@@ -1159,6 +1162,46 @@ impl State {
 		}
 	}
 
+	// Note an assignment taken by the local validator.
+	fn note_approval_duty(
+		&mut self,
+		session_index: SessionIndex,
+		validator_index: ValidatorIndex,
+		candidate_hash: CandidateHash,
+		block_hash: Hash,
+		tranche: DelayTranche,
+	) {
+		let duty = ApprovalDuty {
+			candidate_hash,
+			block_hash,
+			tranche,
+			outcome: ApprovalDutyOutcome::Pending,
+		};
+		self.approval_duties.insert(
+			session_index,
+			(candidate_hash, block_hash),
+			(validator_index, duty),
+		);
+	}
+
+	// Update the outcome of an assignment taken by the local validator, unless the outcome is
+	// already known.
+	fn note_approval_duty_outcome(
+		&mut self,
+		session_index: SessionIndex,
+		candidate_hash: CandidateHash,
+		block_hash: Hash,
+		outcome: impl FnOnce(ValidatorIndex) -> Option<ApprovalDutyOutcome>,
+	) {
+		if let Some((validator_index, duty)) =
+			self.approval_duties.get_mut(session_index, &(candidate_hash, block_hash))
+		{
+			if duty.outcome == ApprovalDutyOutcome::Pending {
+				duty.outcome = outcome(*validator_index).unwrap_or(duty.outcome);
+			}
+		}
+	}
+
 	fn record_no_shows(
 		&mut self,
 		session_index: SessionIndex,
@@ -1257,6 +1300,7 @@ where
 			MAX_BLOCKS_WITH_ASSIGNMENT_TIMESTAMPS,
 		)),
 		no_show_stats: NoShowStats::default(),
+		approval_duties: DutyRecords::default(),
 	};
 
 	let mut last_finalized_height: Option<BlockNumber> = {
@@ -1621,6 +1665,13 @@ async fn handle_actions<
 				metrics.on_assignment_produced(assignment_tranche);
 				let block_hash = indirect_cert.block_hash;
 				let validator_index = indirect_cert.validator;
+				state.note_approval_duty(
+					session,
+					validator_index,
+					candidate_hash,
+					block_hash,
+					assignment_tranche,
+				);
 
 				if distribute_assignment {
 					approval_voting_sender.send_unbounded_message(
@@ -2163,6 +2214,12 @@ async fn handle_from_overseer<
 				.await?;
 				Vec::new()
 			},
+			ApprovalVotingMessage::GetApprovalDuties(session, tx) => {
+				let duties =
+					state.approval_duties.session(session).map(|(_, (_, duty))| duty.clone());
+				let _ = tx.send(duties.collect());
+				Vec::new()
+			},
 		},
 	};
 
@@ -3163,6 +3220,17 @@ where
 		}
 		if newly_approved {
 			state.record_no_shows(session_index, para_id.into(), &status.no_show_validators);
+			state.note_approval_duty_outcome(
+				session_index,
+				candidate_hash,
+				block_hash,
+				|validator_index| {
+					status
+						.no_show_validators
+						.contains(&validator_index)
+						.then_some(ApprovalDutyOutcome::NoShow)
+				},
+			);
 		}
 		actions.extend(schedule_wakeup_action(
 			&approval_entry,
@@ -3238,27 +3306,44 @@ fn should_trigger_assignment(
 	match approval_entry.our_assignment() {
 		None => false,
 		Some(ref assignment) if assignment.triggered() => false,
-		Some(ref assignment) if assignment.tranche() == 0 => true,
-		Some(ref assignment) => {
-			match required_tranches {
-				RequiredTranches::All => !approval_checking::check_approval(
-					&candidate_entry,
-					&approval_entry,
-					RequiredTranches::All,
-				)
-				// when all are required, we are just waiting for the first 1/3+
-				.is_approved(Tick::max_value()),
-				RequiredTranches::Pending { maximum_broadcast, clock_drift, .. } => {
-					let drifted_tranche_now =
-						tranche_now.saturating_sub(clock_drift as DelayTranche);
-					assignment.tranche() <= maximum_broadcast &&
-						assignment.tranche() <= drifted_tranche_now
-				},
-				RequiredTranches::Exact { .. } => {
-					// indicates that no new assignments are needed at the moment.
-					false
-				},
-			}
+		Some(ref assignment) => should_trigger_tranche(
+			assignment.tranche(),
+			approval_entry,
+			candidate_entry,
+			required_tranches,
+			tranche_now,
+		),
+	}
+}
+
+// Whether an untriggered assignment in the given tranche should be triggered, given the
+// currently required tranches.
+pub(crate) fn should_trigger_tranche(
+	tranche: DelayTranche,
+	approval_entry: &ApprovalEntry,
+	candidate_entry: &CandidateEntry,
+	required_tranches: RequiredTranches,
+	tranche_now: DelayTranche,
+) -> bool {
+	if tranche == 0 {
+		return true
+	}
+
+	match required_tranches {
+		RequiredTranches::All => !approval_checking::check_approval(
+			&candidate_entry,
+			&approval_entry,
+			RequiredTranches::All,
+		)
+		// when all are required, we are just waiting for the first 1/3+
+		.is_approved(Tick::max_value()),
+		RequiredTranches::Pending { maximum_broadcast, clock_drift, .. } => {
+			let drifted_tranche_now = tranche_now.saturating_sub(clock_drift as DelayTranche);
+			tranche <= maximum_broadcast && tranche <= drifted_tranche_now
+		},
+		RequiredTranches::Exact { .. } => {
+			// indicates that no new assignments are needed at the moment.
+			false
 		},
 	}
 }
@@ -3785,6 +3870,10 @@ async fn issue_approval<
 		"Ready to issue approval vote",
 	);
 
+	state.note_approval_duty_outcome(block_entry.session(), candidate_hash, block_hash, |_| {
+		Some(ApprovalDutyOutcome::Approved)
+	});
+
 	let actions = advance_approval_state(
 		sender,
 		state,
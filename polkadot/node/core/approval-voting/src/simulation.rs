@@ -0,0 +1,632 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Simulation of approval voting, for tuning the assignment criteria.
+//!
+//! Runs the assignment criteria and the approval checking logic of this subsystem against a
+//! simulated network, to estimate how long it takes for relay chain blocks to be approved and how
+//! many messages are sent with a given set of session parameters. This allows comparing values of
+//! `needed_approvals`, `n_delay_tranches` and `relay_vrf_modulo_samples` before changing them.
+//!
+//! Every simulated block includes a candidate on every core, with the backing group of core `i`
+//! being group `i`. Validators trigger their assignments by the same rules as the subsystem, but
+//! all of them share a single view of the assignments and approvals sent so far: a message is
+//! seen by everyone once its network latency has passed. Time advances in ticks of
+//! [`TICK_DURATION_MILLIS`], so all durations are rounded up to a multiple of it.
+
+use crate::{
+	approval_checking::{self, TranchesToApproveResult},
+	criteria::{self, Config as AssignmentConfig, OurAssignment},
+	persisted_entries::{ApprovalEntry, CandidateEntry},
+	should_trigger_tranche, APPROVAL_DELAY,
+};
+
+use bitvec::{bitvec, order::Lsb0};
+use polkadot_node_primitives::approval::{
+	time::{slot_number_to_tick, Tick, TICK_DURATION_MILLIS},
+	v1::{DelayTranche, RelayVRFStory},
+};
+use polkadot_primitives::{
+	vstaging::{CandidateDescriptorV2, CandidateReceiptV2 as CandidateReceipt},
+	AssignmentId, CandidateHash, CoreIndex, GroupIndex, Hash, Id as ParaId, IndexedVec,
+	ValidatorIndex, ASSIGNMENT_KEY_TYPE_ID,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sc_keystore::{Keystore, LocalKeystore};
+use sp_consensus_slots::Slot;
+
+use std::{
+	cmp::Reverse,
+	collections::{BTreeMap, BinaryHeap},
+	fmt,
+	str::FromStr,
+};
+
+/// Errors of the simulation.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Invalid simulation config: {0}")]
+	InvalidConfig(&'static str),
+}
+
+/// A distribution of durations, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution {
+	/// Always the same duration.
+	Constant(u64),
+	/// Uniformly distributed between `min` and `max`, inclusive.
+	Uniform {
+		/// The shortest duration.
+		min: u64,
+		/// The longest duration.
+		max: u64,
+	},
+	/// Exponentially distributed with the given mean.
+	Exponential {
+		/// The mean duration.
+		mean: u64,
+	},
+}
+
+impl Distribution {
+	/// Sample a duration, in milliseconds.
+	pub fn sample_millis(&self, rng: &mut impl Rng) -> u64 {
+		match *self {
+			Distribution::Constant(millis) => millis,
+			Distribution::Uniform { min, max } => rng.gen_range(min..=max),
+			Distribution::Exponential { mean } => {
+				// Inverse transform sampling. `gen` yields values in `[0, 1)`.
+				let u: f64 = rng.gen();
+				(-(1.0 - u).ln() * mean as f64).round() as u64
+			},
+		}
+	}
+
+	// Sample a duration, rounded up to whole ticks.
+	fn sample_ticks(&self, rng: &mut impl Rng) -> Tick {
+		self.sample_millis(rng).div_ceil(TICK_DURATION_MILLIS)
+	}
+}
+
+impl FromStr for Distribution {
+	type Err = String;
+
+	/// Parse `<millis>`, `uniform:<min>-<max>` or `exp:<mean>`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let parse = |v: &str| {
+			v.trim().parse::<u64>().map_err(|e| format!("Invalid duration `{}`: {}", v, e))
+		};
+
+		match s.split_once(':') {
+			None => parse(s).map(Distribution::Constant),
+			Some(("uniform", range)) => {
+				let (min, max) = range
+					.split_once('-')
+					.ok_or_else(|| format!("Expected `uniform:<min>-<max>`, got `{}`", s))?;
+				let (min, max) = (parse(min)?, parse(max)?);
+				if min > max {
+					return Err(format!("Empty range `{}`", s))
+				}
+				Ok(Distribution::Uniform { min, max })
+			},
+			Some(("exp", mean)) => parse(mean).map(|mean| Distribution::Exponential { mean }),
+			Some(_) => Err(format!(
+				"Unknown distribution `{}`, expected `<millis>`, `uniform:<min>-<max>` or `exp:<mean>`",
+				s
+			)),
+		}
+	}
+}
+
+impl fmt::Display for Distribution {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Distribution::Constant(millis) => write!(f, "{}", millis),
+			Distribution::Uniform { min, max } => write!(f, "uniform:{}-{}", min, max),
+			Distribution::Exponential { mean } => write!(f, "exp:{}", mean),
+		}
+	}
+}
+
+/// The parameters of a simulation.
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+	/// The number of validators in the session.
+	pub n_validators: u32,
+	/// The number of availability cores, all of them occupied in every block.
+	pub n_cores: u32,
+	/// The number of approvals needed for a candidate, as in the session info.
+	pub needed_approvals: u32,
+	/// The number of delay tranches, as in the session info.
+	pub n_delay_tranches: u32,
+	/// The number of samples of `relay_vrf_modulo`, as in the session info.
+	pub relay_vrf_modulo_samples: u32,
+	/// The width of the zeroth delay tranche, as in the session info.
+	pub zeroth_delay_tranche_width: u32,
+	/// The number of slots after which an assignment without approval is a no-show.
+	pub no_show_slots: u32,
+	/// The duration of a relay chain slot.
+	pub slot_duration_millis: u64,
+	/// Whether validators produce v2 assignments, covering multiple cores.
+	pub enable_v2_assignments: bool,
+	/// The probability of a triggered assignment never being followed by an approval.
+	pub no_show_rate: f64,
+	/// The time it takes for a message to reach all other validators.
+	pub network_latency: Distribution,
+	/// The time it takes a validator to recover and check a candidate.
+	pub approval_check_duration: Distribution,
+	/// The number of blocks to simulate.
+	pub n_blocks: u32,
+	/// Candidates not approved within this time are reported as unapproved.
+	pub max_lag_millis: u64,
+	/// The seed of the simulation, which is fully determined by its config.
+	pub seed: u64,
+}
+
+impl Default for SimulationConfig {
+	fn default() -> Self {
+		SimulationConfig {
+			n_validators: 300,
+			n_cores: 50,
+			needed_approvals: 30,
+			n_delay_tranches: 89,
+			relay_vrf_modulo_samples: 6,
+			zeroth_delay_tranche_width: 0,
+			no_show_slots: 2,
+			slot_duration_millis: 6_000,
+			enable_v2_assignments: true,
+			no_show_rate: 0.0,
+			network_latency: Distribution::Uniform { min: 50, max: 500 },
+			approval_check_duration: Distribution::Uniform { min: 1_000, max: 3_000 },
+			n_blocks: 10,
+			max_lag_millis: 120_000,
+			seed: 0,
+		}
+	}
+}
+
+/// Statistics over a set of durations, in milliseconds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LagStats {
+	/// The mean duration.
+	pub mean: u64,
+	/// The median duration.
+	pub p50: u64,
+	/// The 95th percentile.
+	pub p95: u64,
+	/// The 99th percentile.
+	pub p99: u64,
+	/// The longest duration.
+	pub max: u64,
+}
+
+impl LagStats {
+	fn from_millis(mut samples: Vec<u64>) -> Self {
+		if samples.is_empty() {
+			return LagStats::default()
+		}
+
+		samples.sort_unstable();
+		let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).saturating_sub(1)];
+
+		LagStats {
+			mean: samples.iter().sum::<u64>() / samples.len() as u64,
+			p50: percentile(50),
+			p95: percentile(95),
+			p99: percentile(99),
+			max: samples[samples.len() - 1],
+		}
+	}
+}
+
+impl fmt::Display for LagStats {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"mean {}ms, p50 {}ms, p95 {}ms, p99 {}ms, max {}ms",
+			self.mean, self.p50, self.p95, self.p99, self.max
+		)
+	}
+}
+
+/// The outcome of a simulation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulationReport {
+	/// The number of simulated blocks.
+	pub n_blocks: usize,
+	/// The number of simulated candidates.
+	pub n_candidates: usize,
+	/// The number of candidates not approved within `max_lag_millis`.
+	pub unapproved_candidates: usize,
+	/// Time from the start of the block's slot until each candidate was approved.
+	pub candidate_lag: LagStats,
+	/// Time from the start of the block's slot until all of its candidates were approved. Blocks
+	/// with unapproved candidates are not included.
+	pub block_lag: LagStats,
+	/// The number of triggered assignments, which are all broadcast.
+	pub assignments: usize,
+	/// The number of broadcast approvals.
+	pub approvals: usize,
+	/// The number of triggered assignments never followed by an approval.
+	pub no_shows: usize,
+	/// The highest tranche of any triggered assignment.
+	pub max_triggered_tranche: DelayTranche,
+}
+
+impl fmt::Display for SimulationReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let per_candidate = |n: usize| n as f64 / self.n_candidates.max(1) as f64;
+
+		writeln!(
+			f,
+			"candidates: {} in {} blocks, {} unapproved",
+			self.n_candidates, self.n_blocks, self.unapproved_candidates
+		)?;
+		writeln!(f, "candidate approval lag: {}", self.candidate_lag)?;
+		writeln!(f, "block approval lag: {}", self.block_lag)?;
+		writeln!(
+			f,
+			"per candidate: {:.1} assignments, {:.1} approvals, {:.1} no-shows",
+			per_candidate(self.assignments),
+			per_candidate(self.approvals),
+			per_candidate(self.no_shows),
+		)?;
+		write!(f, "highest triggered tranche: {}", self.max_triggered_tranche)
+	}
+}
+
+// A message broadcast by a validator, ordered by the tick it is seen at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Message {
+	Assignment { core: u32, validator: ValidatorIndex, tranche: DelayTranche },
+	Approval { core: u32, validator: ValidatorIndex },
+}
+
+// The view of a single candidate shared by all validators.
+struct SimulatedCandidate {
+	approval_entry: ApprovalEntry,
+	candidate_entry: CandidateEntry,
+	// The assignments of all validators to the candidate's core, ordered by tranche, and whether
+	// they were triggered.
+	assignments: Vec<(OurAssignment, bool)>,
+	approved_at: Option<Tick>,
+}
+
+/// Simulate approval voting with the given config.
+///
+/// This is CPU intensive: every validator computes its assignments for every simulated block.
+pub fn simulate(config: &SimulationConfig) -> Result<SimulationReport, Error> {
+	if config.n_cores == 0 || config.n_cores > config.n_validators {
+		return Err(Error::InvalidConfig("there must be between one and `n_validators` cores"))
+	}
+	if config.needed_approvals == 0 || config.needed_approvals >= config.n_validators {
+		return Err(Error::InvalidConfig(
+			"`needed_approvals` must be between one and `n_validators`",
+		))
+	}
+	if !(0.0..=1.0).contains(&config.no_show_rate) {
+		return Err(Error::InvalidConfig("`no_show_rate` must be between zero and one"))
+	}
+	if config.slot_duration_millis < TICK_DURATION_MILLIS {
+		return Err(Error::InvalidConfig("slots must be at least one tick long"))
+	}
+
+	let (keystores, assignment_keys): (Vec<LocalKeystore>, Vec<AssignmentId>) = (0..config
+		.n_validators)
+		.map(|i| {
+			let keystore = LocalKeystore::in_memory();
+			let public = keystore
+				.sr25519_generate_new(
+					ASSIGNMENT_KEY_TYPE_ID,
+					Some(format!("//Validator//{}", i).as_str()),
+				)
+				.expect("generating a key in an in-memory keystore doesn't fail; qed");
+			(keystore, AssignmentId::from(public))
+		})
+		.unzip();
+
+	let assignment_config = AssignmentConfig {
+		assignment_keys,
+		validator_groups: validator_groups(config.n_validators, config.n_cores),
+		n_cores: config.n_cores,
+		zeroth_delay_tranche_width: config.zeroth_delay_tranche_width,
+		relay_vrf_modulo_samples: config.relay_vrf_modulo_samples,
+		n_delay_tranches: config.n_delay_tranches,
+	};
+
+	let mut rng = ChaCha20Rng::seed_from_u64(config.seed);
+	let mut report = SimulationReport::default();
+	let mut candidate_lags = Vec::new();
+	let mut block_lags = Vec::new();
+
+	for _ in 0..config.n_blocks {
+		let approved_at =
+			simulate_block(config, &keystores, &assignment_config, &mut rng, &mut report);
+
+		report.n_blocks += 1;
+		report.n_candidates += approved_at.len();
+		report.unapproved_candidates += approved_at.iter().filter(|t| t.is_none()).count();
+		candidate_lags.extend(approved_at.iter().flatten().map(|t| t * TICK_DURATION_MILLIS));
+		if let Some(block_approved_at) = approved_at
+			.iter()
+			.copied()
+			.collect::<Option<Vec<_>>>()
+			.and_then(|t| t.into_iter().max())
+		{
+			block_lags.push(block_approved_at * TICK_DURATION_MILLIS);
+		}
+	}
+
+	report.candidate_lag = LagStats::from_millis(candidate_lags);
+	report.block_lag = LagStats::from_millis(block_lags);
+
+	Ok(report)
+}
+
+// Simulate a single block, counting messages in the report. Returns the tick each candidate was
+// approved at, relative to the start of the block's slot.
+fn simulate_block(
+	config: &SimulationConfig,
+	keystores: &[LocalKeystore],
+	assignment_config: &AssignmentConfig,
+	rng: &mut ChaCha20Rng,
+	report: &mut SimulationReport,
+) -> Vec<Option<Tick>> {
+	let n_validators = config.n_validators as usize;
+	let relay_vrf_story = RelayVRFStory(rng.gen());
+
+	let mut candidates: Vec<SimulatedCandidate> = (0..config.n_cores)
+		.map(|core| SimulatedCandidate {
+			approval_entry: ApprovalEntry::new(
+				Vec::new(),
+				GroupIndex(core),
+				None,
+				None,
+				bitvec![u8, Lsb0; 0; n_validators],
+				false,
+			),
+			candidate_entry: CandidateEntry {
+				candidate: dummy_candidate_receipt(core, Hash::from(rng.gen::<[u8; 32]>())),
+				session: 0,
+				block_assignments: BTreeMap::new(),
+				approvals: bitvec![u8, Lsb0; 0; n_validators],
+			},
+			assignments: Vec::new(),
+			approved_at: None,
+		})
+		.collect();
+
+	let leaving_cores: Vec<(CandidateHash, CoreIndex, GroupIndex)> = candidates
+		.iter()
+		.enumerate()
+		.map(|(core, c)| {
+			(c.candidate_entry.candidate.hash(), CoreIndex(core as _), GroupIndex(core as _))
+		})
+		.collect();
+
+	for keystore in keystores {
+		let assignments = criteria::compute_assignments(
+			keystore,
+			relay_vrf_story.clone(),
+			assignment_config,
+			leaving_cores.iter().copied(),
+			config.enable_v2_assignments,
+		);
+
+		for (core, assignment) in assignments {
+			candidates[core.0 as usize].assignments.push((assignment, false));
+		}
+	}
+
+	for candidate in &mut candidates {
+		candidate.assignments.sort_by_key(|(assignment, _)| assignment.tranche());
+	}
+
+	// Simulated blocks are independent, so each one starts at slot zero.
+	let no_show_duration =
+		slot_number_to_tick(config.slot_duration_millis, Slot::from(config.no_show_slots as u64));
+	let max_tick = config.max_lag_millis / TICK_DURATION_MILLIS;
+	let mut messages = BinaryHeap::new();
+
+	for tick in 0..=max_tick {
+		while let Some(Reverse((seen_at, message))) = messages.peek().copied() {
+			if seen_at > tick {
+				break
+			}
+			messages.pop();
+
+			match message {
+				Message::Assignment { core, validator, tranche } => candidates[core as usize]
+					.approval_entry
+					.import_assignment(tranche, validator, seen_at, false),
+				Message::Approval { core, validator } => {
+					candidates[core as usize].candidate_entry.mark_approval(validator);
+				},
+			}
+		}
+
+		let tranche_now = tick as DelayTranche;
+		for (core, candidate) in candidates.iter_mut().enumerate() {
+			if candidate.approved_at.is_some() {
+				continue
+			}
+
+			let SimulatedCandidate { approval_entry, candidate_entry, assignments, approved_at } =
+				candidate;
+
+			let TranchesToApproveResult { required_tranches, .. } =
+				approval_checking::tranches_to_approve(
+					approval_entry,
+					candidate_entry.approvals(),
+					tranche_now,
+					0,
+					no_show_duration,
+					config.needed_approvals as _,
+				);
+
+			let check = approval_checking::check_approval(
+				candidate_entry,
+				approval_entry,
+				required_tranches.clone(),
+			);
+			if check.is_approved(tick.saturating_sub(APPROVAL_DELAY)) {
+				*approved_at = Some(tick);
+				continue
+			}
+
+			// The subsystem only considers triggering an assignment once its tranche has started.
+			for (assignment, triggered) in
+				assignments.iter_mut().take_while(|(a, _)| a.tranche() <= tranche_now)
+			{
+				if *triggered ||
+					!should_trigger_tranche(
+						assignment.tranche(),
+						approval_entry,
+						candidate_entry,
+						required_tranches.clone(),
+						tranche_now,
+					) {
+					continue
+				}
+
+				*triggered = true;
+				report.assignments += 1;
+				report.max_triggered_tranche =
+					report.max_triggered_tranche.max(assignment.tranche());
+
+				let core = core as u32;
+				let validator = assignment.validator_index();
+				messages.push(Reverse((
+					tick + config.network_latency.sample_ticks(rng),
+					Message::Assignment { core, validator, tranche: assignment.tranche() },
+				)));
+
+				if rng.gen_bool(config.no_show_rate) {
+					report.no_shows += 1;
+				} else {
+					report.approvals += 1;
+					let approval_sent_at = tick + config.approval_check_duration.sample_ticks(rng);
+					messages.push(Reverse((
+						approval_sent_at + config.network_latency.sample_ticks(rng),
+						Message::Approval { core, validator },
+					)));
+				}
+			}
+		}
+
+		if candidates.iter().all(|c| c.approved_at.is_some()) {
+			break
+		}
+	}
+
+	candidates.into_iter().map(|c| c.approved_at).collect()
+}
+
+// Split the validators into one group per core, the way the runtime does.
+fn validator_groups(
+	n_validators: u32,
+	n_groups: u32,
+) -> IndexedVec<GroupIndex, Vec<ValidatorIndex>> {
+	let size = n_validators / n_groups;
+	let big_groups = n_validators % n_groups;
+	let mut next = 0;
+
+	(0..n_groups)
+		.map(|i| {
+			let len = if i < big_groups { size + 1 } else { size };
+			let group = (next..next + len).map(ValidatorIndex).collect();
+			next += len;
+			group
+		})
+		.collect()
+}
+
+fn dummy_candidate_receipt(core: u32, relay_parent: Hash) -> CandidateReceipt {
+	CandidateReceipt {
+		descriptor: CandidateDescriptorV2::new(
+			ParaId::from(core),
+			relay_parent,
+			CoreIndex(core),
+			0,
+			Hash::zero(),
+			Hash::zero(),
+			Hash::zero(),
+			Hash::zero(),
+			Hash::zero().into(),
+		),
+		commitments_hash: Hash::zero(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn small_config() -> SimulationConfig {
+		SimulationConfig {
+			n_validators: 60,
+			n_cores: 6,
+			needed_approvals: 8,
+			n_delay_tranches: 40,
+			relay_vrf_modulo_samples: 1,
+			network_latency: Distribution::Constant(200),
+			approval_check_duration: Distribution::Constant(1_000),
+			n_blocks: 2,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn all_candidates_approved_without_no_shows() {
+		let report = simulate(&small_config()).unwrap();
+
+		assert_eq!(report.n_blocks, 2);
+		assert_eq!(report.n_candidates, 12);
+		assert_eq!(report.unapproved_candidates, 0);
+		assert_eq!(report.no_shows, 0);
+		assert_eq!(report.assignments, report.approvals);
+		assert!(report.approvals >= 12 * 8);
+		// Approvals of tranche zero assignments are seen after three ticks.
+		assert!(report.candidate_lag.p50 >= 3 * TICK_DURATION_MILLIS);
+		assert!(report.block_lag.max >= report.candidate_lag.max);
+	}
+
+	#[test]
+	fn no_shows_are_covered_by_later_tranches() {
+		let config = SimulationConfig { no_show_rate: 0.3, ..small_config() };
+		let report = simulate(&config).unwrap();
+		let reference = simulate(&small_config()).unwrap();
+
+		assert!(report.no_shows > 0);
+		assert!(report.assignments > reference.assignments);
+		assert!(report.candidate_lag.max > reference.candidate_lag.max);
+	}
+
+	#[test]
+	fn simulation_is_deterministic() {
+		assert_eq!(simulate(&small_config()).unwrap(), simulate(&small_config()).unwrap());
+	}
+
+	#[test]
+	fn distributions_are_parsed() {
+		assert_eq!("250".parse(), Ok(Distribution::Constant(250)));
+		assert_eq!("uniform:50-500".parse(), Ok(Distribution::Uniform { min: 50, max: 500 }));
+		assert_eq!("exp:300".parse(), Ok(Distribution::Exponential { mean: 300 }));
+		assert!("uniform:500-50".parse::<Distribution>().is_err());
+		assert!("normal:300".parse::<Distribution>().is_err());
+	}
+}
@@ -0,0 +1,113 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only access to the persisted availability store, used to inspect the database of a
+//! stopped node.
+
+use std::{sync::Arc, time::Duration};
+
+use codec::Decode;
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{BlockNumber, CandidateHash, Hash, ValidatorIndex};
+
+use super::{load_meta, CandidateMeta, Config, Error, State, META_PREFIX};
+
+/// The progress of a candidate, as persisted in its meta information.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandidateState {
+	/// The candidate is not included in any block. It was first observed at the given time.
+	Unavailable {
+		/// The time the candidate was first observed at, since the unix epoch.
+		first_seen: Duration,
+	},
+	/// The candidate is included in the given unfinalized blocks, sorted by block number.
+	Unfinalized {
+		/// The time the candidate was first observed at, since the unix epoch.
+		first_seen: Duration,
+		/// The unfinalized blocks including the candidate.
+		included_in: Vec<(BlockNumber, Hash)>,
+	},
+	/// The candidate is included in a finalized block.
+	Finalized {
+		/// The time the including block was finalized at, since the unix epoch.
+		finalized_at: Duration,
+	},
+}
+
+/// The state and chunk inventory the availability store holds for a candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateInventory {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The progress of the candidate.
+	pub state: CandidateState,
+	/// Whether the full available data of the candidate is stored.
+	pub data_available: bool,
+	/// The validator indices of the stored erasure chunks.
+	pub chunks_stored: Vec<ValidatorIndex>,
+}
+
+impl CandidateInventory {
+	fn new(candidate_hash: CandidateHash, meta: CandidateMeta) -> Self {
+		let state = match meta.state {
+			State::Unavailable(first_seen) =>
+				CandidateState::Unavailable { first_seen: first_seen.into() },
+			State::Unfinalized(first_seen, blocks) => CandidateState::Unfinalized {
+				first_seen: first_seen.into(),
+				included_in: blocks.into_iter().map(|(number, hash)| (number.0, hash)).collect(),
+			},
+			State::Finalized(finalized_at) =>
+				CandidateState::Finalized { finalized_at: finalized_at.into() },
+		};
+		let chunks_stored = meta
+			.chunks_stored
+			.iter_ones()
+			.map(|index| ValidatorIndex(index as u32))
+			.collect();
+
+		CandidateInventory {
+			candidate_hash,
+			state,
+			data_available: meta.data_available,
+			chunks_stored,
+		}
+	}
+}
+
+/// Load the inventory of all candidates known to the availability store.
+pub fn load_candidates(
+	db: &Arc<dyn Database>,
+	config: &Config,
+) -> Result<Vec<CandidateInventory>, Error> {
+	db.iter_with_prefix(config.col_meta, &META_PREFIX[..])
+		.map(|r| {
+			let (key, value) = r?;
+			let candidate_hash = CandidateHash::decode(&mut &key[META_PREFIX.len()..])?;
+			let meta = CandidateMeta::decode(&mut &value[..])?;
+			Ok(CandidateInventory::new(candidate_hash, meta))
+		})
+		.collect()
+}
+
+/// Load the inventory of a single candidate, if it is known to the availability store.
+pub fn load_candidate(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<CandidateInventory>, Error> {
+	Ok(load_meta(db, config, candidate_hash)?
+		.map(|meta| CandidateInventory::new(*candidate_hash, meta)))
+}
@@ -52,6 +52,7 @@ use polkadot_primitives::{
 };
 use util::availability_chunks::availability_chunk_indices;
 
+pub mod inspect;
 mod metrics;
 pub use self::metrics::*;
 
@@ -334,6 +334,49 @@ fn store_chunk_works() {
 	});
 }
 
+#[test]
+fn inspect_reports_candidate_inventory() {
+	let store = test_store();
+	let candidate_hash = CandidateHash(Hash::repeat_byte(33));
+	let block_hash = Hash::repeat_byte(2);
+	let mut chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 0; 10];
+	chunks_stored.set(2, true);
+	chunks_stored.set(7, true);
+
+	with_tx(&store, |tx| {
+		super::write_meta(
+			tx,
+			&TEST_CONFIG,
+			&candidate_hash,
+			&CandidateMeta {
+				data_available: true,
+				chunks_stored,
+				state: State::Unfinalized(BETimestamp(5), vec![(BEBlockNumber(3), block_hash)]),
+			},
+		);
+	});
+
+	let expected = inspect::CandidateInventory {
+		candidate_hash,
+		state: inspect::CandidateState::Unfinalized {
+			first_seen: Duration::from_secs(5),
+			included_in: vec![(3, block_hash)],
+		},
+		data_available: true,
+		chunks_stored: vec![ValidatorIndex(2), ValidatorIndex(7)],
+	};
+	assert_eq!(inspect::load_candidates(&store, &TEST_CONFIG).unwrap(), vec![expected.clone()]);
+	assert_eq!(
+		inspect::load_candidate(&store, &TEST_CONFIG, &candidate_hash).unwrap(),
+		Some(expected)
+	);
+	assert_eq!(
+		inspect::load_candidate(&store, &TEST_CONFIG, &CandidateHash(Hash::repeat_byte(1)))
+			.unwrap(),
+		None
+	);
+}
+
 #[test]
 fn store_chunk_does_nothing_if_no_entry_already() {
 	let store = test_store();
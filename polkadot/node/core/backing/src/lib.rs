@@ -86,7 +86,7 @@ use polkadot_node_primitives::{
 };
 use polkadot_node_subsystem::{
 	messages::{
-		AvailabilityDistributionMessage, AvailabilityStoreMessage, CanSecondRequest,
+		AvailabilityDistributionMessage, AvailabilityStoreMessage, BackingDuty, CanSecondRequest,
 		CandidateBackingMessage, CandidateValidationMessage, CollatorProtocolMessage,
 		HypotheticalCandidate, HypotheticalMembershipRequest, IntroduceSecondedCandidateRequest,
 		ProspectiveParachainsMessage, ProvisionableData, ProvisionerMessage, PvfExecKind,
@@ -103,7 +103,7 @@ use polkadot_node_subsystem_util::{
 	request_node_features, request_session_executor_params, request_session_index_for_child,
 	request_validator_groups, request_validators,
 	runtime::{self, ClaimQueueSnapshot},
-	Validator,
+	DutyRecords, Validator,
 };
 use polkadot_parachain_primitives::primitives::IsSystem;
 use polkadot_primitives::{
@@ -448,6 +448,8 @@ struct State {
 	background_validation_tx: mpsc::Sender<(Hash, ValidatedCandidateCommand)>,
 	/// The handle to the keystore used for signing.
 	keystore: KeystorePtr,
+	/// The statements issued by the local validator, kept for reporting its performance.
+	duties: DutyRecords<CandidateHash, BackingDuty>,
 }
 
 impl State {
@@ -462,6 +464,7 @@ impl State {
 			per_session_cache: PerSessionCache::default(),
 			background_validation_tx,
 			keystore,
+			duties: DutyRecords::default(),
 		}
 	}
 }
@@ -952,6 +955,9 @@ async fn handle_communication<Context>(
 			handle_get_backable_candidates_message(state, requested_candidates, tx, metrics)?,
 		CandidateBackingMessage::CanSecond(request, tx) =>
 			handle_can_second_request(ctx, state, request, tx).await,
+		CandidateBackingMessage::GetBackingDuties(session, tx) => {
+			let _ = tx.send(state.duties.session(session).map(|(_, duty)| duty.clone()).collect());
+		},
 	}
 
 	Ok(())
@@ -1419,6 +1425,7 @@ async fn handle_validated_candidate_command<Context>(
 							ctx,
 							rp_state,
 							&mut state.per_candidate,
+							&mut state.duties,
 							statement,
 							state.keystore.clone(),
 							metrics,
@@ -1486,6 +1493,7 @@ async fn handle_validated_candidate_command<Context>(
 								ctx,
 								rp_state,
 								&mut state.per_candidate,
+								&mut state.duties,
 								statement,
 								state.keystore.clone(),
 								metrics,
@@ -1725,12 +1733,14 @@ async fn sign_import_and_distribute_statement<Context>(
 	ctx: &mut Context,
 	rp_state: &mut PerRelayParentState,
 	per_candidate: &mut HashMap<CandidateHash, PerCandidateState>,
+	duties: &mut DutyRecords<CandidateHash, BackingDuty>,
 	statement: StatementWithPVD,
 	keystore: KeystorePtr,
 	metrics: &Metrics,
 ) -> Result<Option<SignedFullStatementWithPVD>, Error> {
 	if let Some(signed_statement) = sign_statement(&*rp_state, statement, keystore, metrics) {
 		let summary = import_statement(ctx, rp_state, per_candidate, &signed_statement).await?;
+		note_backing_duty(rp_state, duties, signed_statement.payload());
 
 		// `Share` must always be sent before `Backed`. We send the latter in
 		// `post_import_statement_action` below.
@@ -1745,6 +1755,35 @@ async fn sign_import_and_distribute_statement<Context>(
 	}
 }
 
+/// Record a statement issued by the local validator.
+fn note_backing_duty(
+	rp_state: &PerRelayParentState,
+	duties: &mut DutyRecords<CandidateHash, BackingDuty>,
+	statement: &StatementWithPVD,
+) {
+	let Some(session) = rp_state
+		.table_context
+		.validator
+		.as_ref()
+		.map(|v| v.signing_context().session_index)
+	else {
+		return
+	};
+	let candidate_hash = statement.candidate_hash();
+	let Some(candidate) = rp_state.table.get_candidate(&candidate_hash) else { return };
+
+	duties.insert(
+		session,
+		candidate_hash,
+		BackingDuty {
+			candidate_hash,
+			para_id: candidate.descriptor.para_id(),
+			relay_parent: rp_state.parent,
+			seconded: matches!(statement, StatementWithPVD::Seconded(..)),
+		},
+	);
+}
+
 #[overseer::contextbounds(CandidateBacking, prefix = self::overseer)]
 async fn background_validate_and_make_available<Context>(
 	ctx: &mut Context,
@@ -77,6 +77,9 @@ use async_trait::async_trait;
 mod metrics;
 use self::metrics::Metrics;
 
+mod offline;
+pub use offline::validate_candidate_offline;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,86 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Validation of candidates outside of a running node, used to reproduce the verdict on a
+//! disputed candidate from data fetched from an archive.
+
+use super::*;
+
+use futures::future::{self, Either};
+
+/// Validate a candidate the way it is validated when participating in a dispute.
+///
+/// A PVF host is started for this validation only, preparing the PVF into the artifacts cache of
+/// the given `config`.
+pub async fn validate_candidate_offline(
+	config: Config,
+	persisted_validation_data: PersistedValidationData,
+	validation_code: ValidationCode,
+	candidate_receipt: CandidateReceipt,
+	pov: PoV,
+	executor_params: ExecutorParams,
+	validation_code_bomb_limit: u32,
+) -> Result<ValidationResult, ValidationFailed> {
+	let Config {
+		artifacts_cache_path,
+		node_version,
+		secure_validator_mode,
+		prep_worker_path,
+		exec_worker_path,
+		pvf_execute_workers_max_num,
+		pvf_prepare_workers_soft_max_num,
+		pvf_prepare_workers_hard_max_num,
+	} = config;
+
+	let (validation_host, task) = polkadot_node_core_pvf::start(
+		polkadot_node_core_pvf::Config::new(
+			artifacts_cache_path,
+			node_version,
+			secure_validator_mode,
+			prep_worker_path,
+			exec_worker_path,
+			pvf_execute_workers_max_num,
+			pvf_prepare_workers_soft_max_num,
+			pvf_prepare_workers_hard_max_num,
+		),
+		Default::default(),
+	)
+	.await
+	.map_err(|e| ValidationFailed(e.to_string()))?;
+
+	// The session index is only checked for backing.
+	let expected_session_index = candidate_receipt.descriptor.session_index().unwrap_or_default();
+	let metrics = Metrics::default();
+	let validation = validate_candidate_exhaustive(
+		expected_session_index,
+		validation_host,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		Arc::new(pov),
+		executor_params,
+		PvfExecKind::Dispute,
+		&metrics,
+		None,
+		validation_code_bomb_limit,
+	);
+
+	futures::pin_mut!(task, validation);
+	match future::select(task, validation).await {
+		Either::Left(((), _)) => Err(ValidationFailed("The PVF host exited unexpectedly".into())),
+		Either::Right((result, _)) => result,
+	}
+}
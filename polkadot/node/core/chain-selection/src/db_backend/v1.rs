@@ -471,6 +471,65 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn inspect_loads_snapshot() {
+		let db = test_db();
+		let mut backend = DbBackend::new(db.clone(), Config { col_data: 0 });
+
+		let parent = BlockEntry {
+			block_hash: Hash::repeat_byte(1),
+			block_number: 1,
+			parent_hash: Hash::repeat_byte(0),
+			children: vec![Hash::repeat_byte(2)],
+			viability: ViabilityCriteria {
+				earliest_unviable_ancestor: None,
+				explicitly_reverted: false,
+				approval: Approval::Stagnant,
+			},
+			weight: 100,
+		};
+		let child = BlockEntry {
+			block_hash: Hash::repeat_byte(2),
+			block_number: 2,
+			parent_hash: Hash::repeat_byte(1),
+			children: vec![],
+			viability: ViabilityCriteria {
+				earliest_unviable_ancestor: Some(Hash::repeat_byte(1)),
+				explicitly_reverted: false,
+				approval: Approval::Approved,
+			},
+			weight: 200,
+		};
+
+		backend
+			.write(vec![
+				BackendWriteOp::WriteBlockEntry(parent.clone().into()),
+				BackendWriteOp::WriteBlockEntry(child.clone().into()),
+				BackendWriteOp::WriteBlocksByNumber(1, vec![parent.block_hash]),
+				BackendWriteOp::WriteBlocksByNumber(2, vec![child.block_hash]),
+				BackendWriteOp::WriteStagnantAt(10, vec![child.block_hash]),
+			])
+			.unwrap();
+
+		let config = crate::Config {
+			col_data: 0,
+			stagnant_check_interval: Default::default(),
+			stagnant_check_mode: Default::default(),
+		};
+		let snapshot = crate::inspect::load_snapshot(db, &config).unwrap();
+
+		assert!(snapshot.leaves.is_empty());
+		assert_eq!(snapshot.stagnant_at, vec![(10, vec![child.block_hash])]);
+		assert_eq!(
+			snapshot
+				.blocks
+				.iter()
+				.map(|block| (block.block_hash, block.approved, block.stagnant, block.viable))
+				.collect::<Vec<_>>(),
+			vec![(parent.block_hash, false, true, false), (child.block_hash, true, false, false)],
+		);
+	}
+
 	#[test]
 	fn delete_block_entry() {
 		let db = test_db();
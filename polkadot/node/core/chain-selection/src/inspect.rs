@@ -0,0 +1,110 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only access to the persisted chain selection data, used to inspect the database of a
+//! stopped node.
+
+use polkadot_node_primitives::BlockWeight;
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{BlockNumber, Hash};
+
+use std::sync::Arc;
+
+use crate::{
+	backend::Backend,
+	db_backend::v1::{Config as DbConfig, DbBackend},
+	Approval, Config, Error,
+};
+
+/// An unfinalized block tracked by chain selection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockRecord {
+	/// The hash of the block.
+	pub block_hash: Hash,
+	/// The number of the block.
+	pub block_number: BlockNumber,
+	/// The hash of the parent block.
+	pub parent_hash: Hash,
+	/// The hashes of the known children.
+	pub children: Vec<Hash>,
+	/// The weight of the block.
+	pub weight: BlockWeight,
+	/// Whether the block has been approved.
+	pub approved: bool,
+	/// Whether the block failed to get approved in time.
+	pub stagnant: bool,
+	/// Whether the block has been reverted by one of its descendants.
+	pub explicitly_reverted: bool,
+	/// The earliest unfinalized ancestor which is either reverted or stagnant.
+	pub earliest_unviable_ancestor: Option<Hash>,
+	/// Whether the block can be built upon.
+	pub viable: bool,
+}
+
+/// A snapshot of the chain selection data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainSelectionSnapshot {
+	/// The hashes of the viable leaves, best first.
+	pub leaves: Vec<Hash>,
+	/// All unfinalized blocks, ordered by block number.
+	pub blocks: Vec<BlockRecord>,
+	/// The blocks to be checked for stagnancy at the given unix timestamps, in seconds.
+	pub stagnant_at: Vec<(u64, Vec<Hash>)>,
+}
+
+/// Load all the data persisted by chain selection.
+pub fn load_snapshot(
+	db: Arc<dyn Database>,
+	config: &Config,
+) -> Result<ChainSelectionSnapshot, Error> {
+	let backend = DbBackend::new(db, DbConfig { col_data: config.col_data });
+
+	let leaves = backend.load_leaves()?.inner.into_iter().map(|leaf| leaf.block_hash).collect();
+
+	let mut blocks = Vec::new();
+	if let Some(mut number) = backend.load_first_block_number()? {
+		loop {
+			let hashes = backend.load_blocks_by_number(number)?;
+			if hashes.is_empty() {
+				break
+			}
+
+			for hash in hashes {
+				if let Some(entry) = backend.load_block_entry(&hash)? {
+					let viable = entry.viability.is_viable();
+					let viability = entry.viability;
+					blocks.push(BlockRecord {
+						block_hash: entry.block_hash,
+						block_number: entry.block_number,
+						parent_hash: entry.parent_hash,
+						children: entry.children,
+						weight: entry.weight,
+						approved: matches!(viability.approval, Approval::Approved),
+						stagnant: viability.approval.is_stagnant(),
+						explicitly_reverted: viability.explicitly_reverted,
+						earliest_unviable_ancestor: viability.earliest_unviable_ancestor,
+						viable,
+					});
+				}
+			}
+			number += 1;
+		}
+	}
+
+	let stagnant_at = backend.load_stagnant_at_up_to(u64::MAX, usize::MAX)?;
+
+	Ok(ChainSelectionSnapshot { leaves, blocks, stagnant_at })
+}
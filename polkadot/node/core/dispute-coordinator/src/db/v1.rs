@@ -473,6 +473,53 @@ mod tests {
 			.is_some());
 	}
 
+	#[test]
+	fn inspect_loads_recent_disputes_with_votes() {
+		let mut backend = make_db();
+		let config = crate::Config { col_dispute_data: 0 };
+		let disputed = CandidateHash(Hash::repeat_byte(1));
+		let without_votes = CandidateHash(Hash::repeat_byte(2));
+
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_earliest_session(3);
+		overlay_db.write_recent_disputes(
+			vec![
+				((4, disputed), DisputeStatus::Confirmed),
+				((3, without_votes), DisputeStatus::Active),
+			]
+			.into_iter()
+			.collect(),
+		);
+		overlay_db.write_candidate_votes(
+			4,
+			disputed,
+			CandidateVotes {
+				candidate_receipt: dummy_candidate_receipt_v2(dummy_hash()),
+				valid: Vec::new(),
+				invalid: Vec::new(),
+			},
+		);
+		let write_ops = overlay_db.into_write_ops();
+		backend.write(write_ops).unwrap();
+
+		assert_eq!(
+			crate::inspect::load_earliest_session(&*backend.inner, &config).unwrap(),
+			Some(3)
+		);
+
+		let disputes = crate::inspect::load_disputes(&*backend.inner, &config).unwrap();
+		assert_eq!(disputes.len(), 2);
+		assert_eq!((disputes[0].session, disputes[0].candidate_hash), (3, without_votes));
+		assert_eq!(disputes[0].status, DisputeStatus::Active);
+		assert!(disputes[0].votes.is_none());
+		assert_eq!((disputes[1].session, disputes[1].candidate_hash), (4, disputed));
+		assert_eq!(disputes[1].status, DisputeStatus::Confirmed);
+		assert_eq!(
+			disputes[1].votes.as_ref().map(|votes| votes.candidate_receipt.clone()),
+			Some(dummy_candidate_receipt_v2(dummy_hash()))
+		);
+	}
+
 	#[test]
 	fn overlay_pre_and_post_commit_consistency() {
 		let mut backend = make_db();
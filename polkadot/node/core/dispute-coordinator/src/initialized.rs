@@ -35,7 +35,7 @@ use polkadot_node_primitives::{
 use polkadot_node_subsystem::{
 	messages::{
 		ApprovalVotingParallelMessage, BlockDescription, ChainSelectionMessage,
-		DisputeCoordinatorMessage, DisputeDistributionMessage, ImportStatementsResult,
+		DisputeCoordinatorMessage, DisputeDistributionMessage, DisputeDuty, ImportStatementsResult,
 	},
 	overseer, ActivatedLeaf, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, RuntimeApiError,
 };
@@ -927,11 +927,61 @@ impl Initialized {
 
 				let _ = tx.send(undisputed_chain);
 			},
+			DisputeCoordinatorMessage::GetDisputeDuties(session, tx) => {
+				gum::trace!(target: LOG_TARGET, "DisputeCoordinatorMessage::GetDisputeDuties");
+				let duties = self.get_dispute_duties(ctx, overlay_db, session).await?;
+				let _ = tx.send(duties);
+			},
 		}
 
 		Ok(Box::new(|| Ok(())))
 	}
 
+	/// Collect the recent disputes of the given session along with our votes in them.
+	async fn get_dispute_duties<Context>(
+		&mut self,
+		ctx: &mut Context,
+		overlay_db: &mut OverlayedBackend<'_, impl Backend>,
+		session: SessionIndex,
+	) -> Result<Vec<DisputeDuty>> {
+		let recent_disputes = overlay_db.load_recent_disputes()?.unwrap_or_default();
+
+		let mut duties = Vec::new();
+		for ((_, candidate_hash), status) in
+			recent_disputes.into_iter().filter(|((s, _), _)| *s == session)
+		{
+			let Some(votes) = overlay_db.load_candidate_votes(session, &candidate_hash)? else {
+				continue
+			};
+			let votes = CandidateVotes::from(votes);
+
+			let env = CandidateEnvironment::new(
+				ctx,
+				&mut self.runtime_info,
+				session,
+				votes.candidate_receipt.descriptor.relay_parent(),
+				self.offchain_disabled_validators.iter(session),
+				&mut self.controlled_validator_indices,
+			)
+			.await;
+			let controlled_indices = env.as_ref().map(|env| env.controlled_indices());
+
+			let vote = controlled_indices.and_then(|indices| {
+				if indices.iter().any(|index| votes.valid.raw().contains_key(index)) {
+					Some(true)
+				} else if indices.iter().any(|index| votes.invalid.contains_key(index)) {
+					Some(false)
+				} else {
+					None
+				}
+			});
+
+			duties.push(DisputeDuty { candidate_hash, status, vote });
+		}
+
+		Ok(duties)
+	}
+
 	// We use fatal result rather than result here. Reason being, We for example increase
 	// spam slots in this function. If then the import fails for some non fatal and
 	// unrelated reason, we should likely actually decrement previously incremented spam
@@ -0,0 +1,70 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only access to the persisted dispute coordinator data, used to inspect the database of a
+//! stopped node.
+
+use polkadot_node_primitives::DisputeStatus;
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{CandidateHash, SessionIndex};
+
+use crate::{db::v1, Config};
+
+pub use crate::{db::v1::CandidateVotes, error::FatalError};
+
+/// A recent dispute, together with the votes imported for its candidate.
+#[derive(Debug, Clone)]
+pub struct DisputeRecord {
+	/// The session the disputed candidate belongs to.
+	pub session: SessionIndex,
+	/// The hash of the disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The status of the dispute.
+	pub status: DisputeStatus,
+	/// The votes on the candidate, if any are stored.
+	pub votes: Option<CandidateVotes>,
+}
+
+/// Load the earliest session the dispute coordinator keeps data for.
+pub fn load_earliest_session(
+	db: &dyn Database,
+	config: &Config,
+) -> Result<Option<SessionIndex>, FatalError> {
+	v1::load_earliest_session(db, &config.column_config())
+}
+
+/// Load all recent disputes with their votes, ordered by session and candidate hash.
+pub fn load_disputes(db: &dyn Database, config: &Config) -> Result<Vec<DisputeRecord>, FatalError> {
+	let config = config.column_config();
+	v1::load_recent_disputes(db, &config)?
+		.unwrap_or_default()
+		.into_iter()
+		.map(|((session, candidate_hash), status)| {
+			let votes = v1::load_candidate_votes(db, &config, session, &candidate_hash)?;
+			Ok(DisputeRecord { session, candidate_hash, status, votes })
+		})
+		.collect()
+}
+
+/// Load the votes on a candidate, disputed or not.
+pub fn load_candidate_votes(
+	db: &dyn Database,
+	config: &Config,
+	session: SessionIndex,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<CandidateVotes>, FatalError> {
+	v1::load_candidate_votes(db, &config.column_config(), session, candidate_hash)
+}
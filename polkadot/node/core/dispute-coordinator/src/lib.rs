@@ -69,6 +69,9 @@ pub(crate) mod backend;
 pub(crate) mod db;
 pub(crate) mod error;
 
+/// Read-only access to the persisted data.
+pub mod inspect;
+
 /// Subsystem after receiving the first active leaf.
 mod initialized;
 use initialized::{InitialData, Initialized};
@@ -127,7 +127,7 @@ use std::{
 };
 
 use super::LOG_TARGET;
-use polkadot_node_subsystem::messages::Ancestors;
+use polkadot_node_subsystem::messages::{Ancestors, FragmentChainCandidate};
 use polkadot_node_subsystem_util::inclusion_emulator::{
 	self, validate_commitments, ConstraintModifications, Constraints, Fragment,
 	HypotheticalOrConcreteCandidate, ProspectiveCandidate, RelayChainBlockInfo,
@@ -363,6 +363,17 @@ impl CandidateEntry {
 		self.candidate_hash
 	}
 
+	/// Describe the candidate for inspecting the fragment chain.
+	pub fn describe(&self) -> FragmentChainCandidate {
+		FragmentChainCandidate {
+			candidate_hash: self.candidate_hash,
+			relay_parent: self.relay_parent,
+			parent_head_data_hash: self.parent_head_data_hash,
+			output_head_data_hash: self.output_head_data_hash,
+			backed: self.state == CandidateState::Backed,
+		}
+	}
+
 	fn new(
 		candidate_hash: CandidateHash,
 		candidate: CommittedCandidateReceipt,
@@ -545,6 +556,17 @@ impl Scope {
 		&self.base_constraints
 	}
 
+	/// Get the maximum length of the best backable chain, including the candidates pending
+	/// availability.
+	pub fn max_backable_len(&self) -> usize {
+		self.max_backable_len
+	}
+
+	/// Get the hashes of the candidates pending availability in this scope.
+	pub fn pending_availability(&self) -> impl Iterator<Item = CandidateHash> + '_ {
+		self.pending_availability.iter().map(|c| c.candidate_hash)
+	}
+
 	/// Whether the candidate in question is one pending availability in this scope.
 	fn get_pending_availability(
 		&self,
@@ -755,6 +777,15 @@ impl FragmentChain {
 		self.unconnected.candidates()
 	}
 
+	/// Describe the candidates of the best backable chain, in-order.
+	pub fn describe_best_chain(&self) -> Vec<FragmentChainCandidate> {
+		self.best_chain
+			.chain
+			.iter()
+			.map(|node| CandidateEntry::from(node).describe())
+			.collect()
+	}
+
 	/// Return whether this candidate is backed in this chain or the unconnected storage.
 	pub fn is_candidate_backed(&self, hash: &CandidateHash) -> bool {
 		self.best_chain.candidates.contains(hash) ||
@@ -28,17 +28,17 @@
 
 #![deny(unused_crate_dependencies)]
 
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use fragment_chain::CandidateStorage;
 use futures::{channel::oneshot, prelude::*};
 
 use polkadot_node_subsystem::{
 	messages::{
-		Ancestors, ChainApiMessage, HypotheticalCandidate, HypotheticalMembership,
-		HypotheticalMembershipRequest, IntroduceSecondedCandidateRequest, ParentHeadData,
-		ProspectiveParachainsMessage, ProspectiveValidationDataRequest, RuntimeApiMessage,
-		RuntimeApiRequest,
+		Ancestors, ChainApiMessage, FragmentChainConstraints, FragmentChainState,
+		HypotheticalCandidate, HypotheticalMembership, HypotheticalMembershipRequest,
+		IntroduceSecondedCandidateRequest, ParentHeadData, ProspectiveParachainsMessage,
+		ProspectiveValidationDataRequest, RuntimeApiMessage, RuntimeApiRequest,
 	},
 	overseer, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, SpawnedSubsystem, SubsystemError,
 };
@@ -50,8 +50,11 @@ use polkadot_node_subsystem_util::{
 	runtime::{fetch_claim_queue, fetch_scheduling_lookahead},
 };
 use polkadot_primitives::{
-	vstaging::{transpose_claim_queue, CommittedCandidateReceiptV2 as CommittedCandidateReceipt},
-	BlockNumber, CandidateHash, Hash, Header, Id as ParaId, PersistedValidationData,
+	vstaging::{
+		transpose_claim_queue, CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+		TransposedClaimQueue,
+	},
+	BlockNumber, CandidateHash, CoreIndex, Hash, Header, Id as ParaId, PersistedValidationData,
 };
 
 use crate::{
@@ -74,6 +77,8 @@ const LOG_TARGET: &str = "parachain::prospective-parachains";
 struct RelayBlockViewData {
 	// The fragment chains for current and upcoming scheduled paras.
 	fragment_chains: HashMap<ParaId, FragmentChain>,
+	// The cores claimed by the scheduled paras at each depth of the claim queue.
+	claim_queue: TransposedClaimQueue,
 }
 
 struct View {
@@ -172,6 +177,8 @@ async fn run_iteration<Context>(
 					answer_minimum_relay_parents_request(&view, relay_parent, tx),
 				ProspectiveParachainsMessage::GetProspectiveValidationData(request, tx) =>
 					answer_prospective_validation_data_request(&view, request, tx),
+				ProspectiveParachainsMessage::GetFragmentChainsState(para, tx) =>
+					answer_fragment_chains_state_request(&view, para, tx),
 			},
 		}
 	}
@@ -378,10 +385,21 @@ async fn handle_active_leaves_update<Context>(
 				chain.unconnected().map(|candidate| candidate.hash()).collect::<Vec<_>>()
 			);
 
+			gum::debug!(
+				target: LOG_TARGET,
+				relay_parent = ?hash,
+				para_id = ?para,
+				state = ?fragment_chain_state(hash, *para, &chain, Some(claims_by_depth)),
+				"Fragment chain state",
+			);
+
 			fragment_chains.insert(*para, chain);
 		}
 
-		view.per_relay_parent.insert(hash, RelayBlockViewData { fragment_chains });
+		view.per_relay_parent.insert(
+			hash,
+			RelayBlockViewData { fragment_chains, claim_queue: transposed_claim_queue },
+		);
 
 		view.active_leaves.insert(hash);
 
@@ -875,6 +893,63 @@ fn answer_prospective_validation_data_request(
 	});
 }
 
+fn answer_fragment_chains_state_request(
+	view: &View,
+	para: Option<ParaId>,
+	tx: oneshot::Sender<Vec<FragmentChainState>>,
+) {
+	let mut response = Vec::new();
+	for active_leaf in &view.active_leaves {
+		let Some(leaf_data) = view.per_relay_parent.get(active_leaf) else { continue };
+		for (para_id, fragment_chain) in &leaf_data.fragment_chains {
+			if para.map_or(false, |para| &para != para_id) {
+				continue
+			}
+
+			response.push(fragment_chain_state(
+				*active_leaf,
+				*para_id,
+				fragment_chain,
+				leaf_data.claim_queue.get(para_id),
+			));
+		}
+	}
+
+	let _ = tx.send(response);
+}
+
+// Describe the fragment chain of a para under an active leaf.
+fn fragment_chain_state(
+	leaf: Hash,
+	para_id: ParaId,
+	fragment_chain: &FragmentChain,
+	claims_by_depth: Option<&BTreeMap<u8, BTreeSet<CoreIndex>>>,
+) -> FragmentChainState {
+	let scope = fragment_chain.scope();
+	let constraints = scope.base_constraints();
+
+	FragmentChainState {
+		leaf,
+		para_id,
+		earliest_relay_parent: scope.earliest_relay_parent().number,
+		max_backable_len: scope.max_backable_len() as u32,
+		constraints: FragmentChainConstraints {
+			min_relay_parent_number: constraints.min_relay_parent_number,
+			max_pov_size: constraints.max_pov_size as u32,
+			max_code_size: constraints.max_code_size as u32,
+			max_head_data_size: constraints.max_head_data_size as u32,
+			required_parent: constraints.required_parent.hash(),
+			validation_code_hash: constraints.validation_code_hash,
+			upgrade_restriction: constraints.upgrade_restriction,
+			future_validation_code: constraints.future_validation_code,
+		},
+		pending_availability: scope.pending_availability().collect(),
+		best_chain: fragment_chain.describe_best_chain(),
+		unconnected: fragment_chain.unconnected().map(CandidateEntry::describe).collect(),
+		claim_queue: claims_by_depth.cloned().unwrap_or_default(),
+	}
+}
+
 #[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
 async fn fetch_backing_state<Context>(
 	ctx: &mut Context,
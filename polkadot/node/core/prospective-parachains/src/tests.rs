@@ -18,9 +18,10 @@ use super::*;
 use assert_matches::assert_matches;
 use polkadot_node_subsystem::{
 	messages::{
-		AllMessages, HypotheticalMembershipRequest, ParentHeadData, ProspectiveParachainsMessage,
-		ProspectiveValidationDataRequest,
+		AllMessages, FragmentChainCandidate, HypotheticalMembershipRequest, ParentHeadData,
+		ProspectiveParachainsMessage, ProspectiveValidationDataRequest,
 	},
+	overseer::recorder::{read_recording, Recorder, RecordingContext},
 	RuntimeApiError,
 };
 use polkadot_node_subsystem_test_helpers as test_helpers;
@@ -173,6 +174,43 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 	view
 }
 
+// Like `test_harness`, but records the message traffic of the subsystem to `recorder`.
+fn recording_test_harness<T: Future<Output = VirtualOverseer>>(
+	recorder: Recorder,
+	test: impl FnOnce(VirtualOverseer) -> T,
+) -> View {
+	sp_tracing::init_for_tests();
+
+	let pool = sp_core::testing::TaskExecutor::new();
+
+	let (context, virtual_overseer) =
+		polkadot_node_subsystem_test_helpers::make_subsystem_context(pool.clone());
+	let mut context = RecordingContext::new(context, "prospective-parachains", recorder);
+
+	let mut view = View::new();
+	let subsystem = async move {
+		if let Err(e) = run_iteration(&mut context, &mut view, &Metrics(None)).await {
+			panic!("{:?}", e);
+		}
+
+		view
+	};
+
+	let test_fut = test(virtual_overseer);
+
+	futures::pin_mut!(test_fut);
+	futures::pin_mut!(subsystem);
+	let (_, view) = futures::executor::block_on(future::join(
+		async move {
+			let mut virtual_overseer = test_fut.await;
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+		},
+		subsystem,
+	));
+
+	view
+}
+
 #[derive(Debug, Clone)]
 struct PerParaData {
 	min_relay_parent: BlockNumber,
@@ -2208,6 +2246,107 @@ fn check_pvd_query(#[case] runtime_api_version: u32) {
 	assert_eq!(view.active_leaves.len(), 1);
 }
 
+#[test]
+fn check_fragment_chains_state_query() {
+	let test_state = TestState::default();
+	let view = test_harness(|mut virtual_overseer| async move {
+		// Leaf A
+		let leaf_a = TestLeaf {
+			number: 100,
+			hash: Hash::from_low_u64_be(130),
+			para_data: vec![
+				(1.into(), PerParaData::new(98, HeadData(vec![1, 2, 3]))),
+				(2.into(), PerParaData::new(100, HeadData(vec![2, 3, 4]))),
+			],
+		};
+
+		// Activate leaves.
+		activate_leaf(&mut virtual_overseer, &leaf_a, &test_state).await;
+
+		// Candidate A builds on the included head and is backed, candidate C is only seconded and
+		// misses its parent.
+		let (candidate_a, pvd_a) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![1, 2, 3]),
+			HeadData(vec![1]),
+			test_state.validation_code_hash,
+		);
+		let (candidate_c, pvd_c) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![2]),
+			HeadData(vec![3]),
+			test_state.validation_code_hash,
+		);
+		let candidate_hash_a = candidate_a.hash();
+		let candidate_hash_c = candidate_c.hash();
+		introduce_seconded_candidate(&mut virtual_overseer, candidate_a.clone(), pvd_a).await;
+		back_candidate(&mut virtual_overseer, &candidate_a, candidate_hash_a).await;
+		introduce_seconded_candidate(&mut virtual_overseer, candidate_c, pvd_c).await;
+
+		// All the paras are reported without a filter.
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(overseer::FromOrchestra::Communication {
+				msg: ProspectiveParachainsMessage::GetFragmentChainsState(None, tx),
+			})
+			.await;
+		assert_eq!(rx.await.unwrap().len(), 2);
+
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(overseer::FromOrchestra::Communication {
+				msg: ProspectiveParachainsMessage::GetFragmentChainsState(Some(1.into()), tx),
+			})
+			.await;
+		let mut resp = rx.await.unwrap();
+		assert_eq!(resp.len(), 1);
+		let state = resp.remove(0);
+
+		assert_eq!(state.leaf, leaf_a.hash);
+		assert_eq!(state.para_id, 1.into());
+		assert_eq!(state.earliest_relay_parent, 98);
+		assert_eq!(state.max_backable_len, 1);
+		assert_eq!(state.constraints.min_relay_parent_number, 98);
+		assert_eq!(state.constraints.required_parent, HeadData(vec![1, 2, 3]).hash());
+		assert_eq!(state.constraints.validation_code_hash, test_state.validation_code_hash);
+		assert!(state.pending_availability.is_empty());
+		assert_eq!(
+			state.best_chain,
+			vec![FragmentChainCandidate {
+				candidate_hash: candidate_hash_a,
+				relay_parent: leaf_a.hash,
+				parent_head_data_hash: HeadData(vec![1, 2, 3]).hash(),
+				output_head_data_hash: HeadData(vec![1]).hash(),
+				backed: true,
+			}]
+		);
+		assert_eq!(
+			state.unconnected,
+			vec![FragmentChainCandidate {
+				candidate_hash: candidate_hash_c,
+				relay_parent: leaf_a.hash,
+				parent_head_data_hash: HeadData(vec![2]).hash(),
+				output_head_data_hash: HeadData(vec![3]).hash(),
+				backed: false,
+			}]
+		);
+		assert_eq!(
+			state.claim_queue,
+			(0..DEFAULT_SCHEDULING_LOOKAHEAD as u8)
+				.map(|depth| (depth, [CoreIndex(0)].into_iter().collect()))
+				.collect()
+		);
+
+		virtual_overseer
+	});
+
+	assert_eq!(view.active_leaves.len(), 1);
+}
+
 // Test simultaneously activating and deactivating leaves, and simultaneously deactivating
 // multiple leaves.
 #[test]
@@ -2817,3 +2956,61 @@ fn uses_ancestry_only_within_session() {
 		virtual_overseer
 	});
 }
+
+// Records the traffic of the subsystem and replays it against a fresh one.
+#[test]
+fn replays_recorded_traffic() {
+	let test_state = TestState::default();
+	let dir = tempfile::tempdir().unwrap();
+	let path = dir.path().join("recording");
+
+	let leaf_a = TestLeaf {
+		number: 100,
+		hash: Hash::from_low_u64_be(130),
+		para_data: vec![
+			(1.into(), PerParaData::new(97, HeadData(vec![1, 2, 3]))),
+			(2.into(), PerParaData::new(100, HeadData(vec![2, 3, 4]))),
+		],
+	};
+	let (candidate_a, pvd_a) = make_candidate(
+		leaf_a.hash,
+		leaf_a.number,
+		1.into(),
+		HeadData(vec![1, 2, 3]),
+		HeadData(vec![1]),
+		test_state.validation_code_hash,
+	);
+	let candidate_hash_a = candidate_a.hash();
+
+	let recorder = Recorder::create(&path).unwrap();
+	let recorded_view =
+		recording_test_harness(recorder.clone(), |mut virtual_overseer| async move {
+			activate_leaf(&mut virtual_overseer, &leaf_a, &test_state).await;
+
+			introduce_seconded_candidate(&mut virtual_overseer, candidate_a.clone(), pvd_a).await;
+			back_candidate(&mut virtual_overseer, &candidate_a, candidate_hash_a).await;
+			get_backable_candidates(
+				&mut virtual_overseer,
+				&leaf_a,
+				1.into(),
+				Ancestors::new(),
+				1,
+				vec![(candidate_hash_a, leaf_a.hash)],
+			)
+			.await;
+
+			virtual_overseer
+		});
+
+	recorder.flush();
+	let recording = read_recording(&path).unwrap();
+	let replayed_view = test_harness(|mut virtual_overseer| async move {
+		test_helpers::replay::replay(&mut virtual_overseer, "prospective-parachains", &recording)
+			.await;
+
+		virtual_overseer
+	});
+
+	assert_eq!(replayed_view.active_leaves, recorded_view.active_leaves);
+	assert_eq!(replayed_view.per_relay_parent.len(), recorded_view.per_relay_parent.len());
+}
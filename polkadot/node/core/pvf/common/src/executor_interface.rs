@@ -24,12 +24,15 @@ use polkadot_primitives::{
 use sc_executor_common::{
 	error::WasmError,
 	runtime_blob::RuntimeBlob,
-	wasm_runtime::{HeapAllocStrategy, WasmModule as _},
+	wasm_runtime::{HeapAllocStrategy, WasmModule},
 };
-use sc_executor_wasmtime::{Config, DeterministicStackLimit, Semantics, WasmtimeRuntime};
+use sc_executor_wasmtime::{Config, DeterministicStackLimit, Semantics};
 use sp_core::storage::{ChildInfo, TrackedStorageKey};
 use sp_externalities::MultiRemovalResults;
-use std::any::{Any, TypeId};
+use std::{
+	any::{Any, TypeId},
+	sync::OnceLock,
+};
 
 // Memory configuration
 //
@@ -46,6 +49,17 @@ use std::any::{Any, TypeId};
 const DEFAULT_HEAP_PAGES_ESTIMATE: u32 = 32;
 const EXTRA_HEAP_PAGES: u32 = 2048;
 
+/// The size of a WASM page, which is also the unit of [`ExecutorParam::MaxMemoryPages`].
+const WASM_PAGE_SIZE: u64 = 65536;
+
+/// The magic bytes every PolkaVM program blob starts with.
+const POLKAVM_BLOB_MAGIC: &[u8] = b"PVM\0";
+
+/// The magic bytes prefixing the artifacts prepared from PolkaVM program blobs.
+///
+/// Artifacts prepared by wasmtime are ELF files, so they never start with these bytes.
+const POLKAVM_ARTIFACT_MAGIC: &[u8] = b"PVF-PVM\0";
+
 // VALUES OF THE DEFAULT CONFIGURATION SHOULD NEVER BE CHANGED
 // They are used as base values for the execution environment parametrization.
 // To overwrite them, add new ones to `EXECUTOR_PARAMS` in the `session_info` pallet and perform
@@ -138,14 +152,20 @@ pub unsafe fn execute_artifact(
 pub unsafe fn create_runtime_from_artifact_bytes(
 	compiled_artifact_blob: &[u8],
 	executor_params: &ExecutorParams,
-) -> Result<WasmtimeRuntime, WasmError> {
+) -> Result<Box<dyn WasmModule>, WasmError> {
+	if let Some(program) = compiled_artifact_blob.strip_prefix(POLKAVM_ARTIFACT_MAGIC) {
+		let blob = polkavm::ProgramBlob::parse(polkavm::ArcBytes::from(program))?;
+		return create_polkavm_runtime(blob, executor_params)
+	}
+
 	let mut config = DEFAULT_CONFIG.clone();
 	config.semantics = params_to_wasmtime_semantics(executor_params).0;
 
-	sc_executor_wasmtime::create_runtime_from_artifact_bytes::<HostFunctions>(
+	let runtime = sc_executor_wasmtime::create_runtime_from_artifact_bytes::<HostFunctions>(
 		compiled_artifact_blob,
 		config,
-	)
+	)?;
+	Ok(Box::new(runtime))
 }
 
 /// Takes the default config and overwrites any settings with existing executor parameters.
@@ -167,6 +187,7 @@ pub fn params_to_wasmtime_semantics(par: &ExecutorParams) -> (Semantics, Determi
 			ExecutorParam::StackLogicalMax(slm) => stack_limit.logical_max = *slm,
 			ExecutorParam::StackNativeMax(snm) => stack_limit.native_stack_max = *snm,
 			ExecutorParam::WasmExtBulkMemory => sem.wasm_bulk_memory = true,
+			ExecutorParam::PolkaVm |
 			ExecutorParam::PrecheckingMaxMemory(_) |
 			ExecutorParam::PvfPrepTimeout(_, _) |
 			ExecutorParam::PvfExecTimeout(_, _) => (), /* Not used here */
@@ -176,22 +197,114 @@ pub fn params_to_wasmtime_semantics(par: &ExecutorParams) -> (Semantics, Determi
 	(sem, stack_limit)
 }
 
-/// Runs the prevalidation on the given code. Returns a [`RuntimeBlob`] if it succeeds.
-pub fn prevalidate(code: &[u8]) -> Result<RuntimeBlob, sc_executor_common::error::WasmError> {
+/// Validation code which passed prevalidation.
+pub enum PvfBlob {
+	/// A WASM module, executed by wasmtime.
+	Wasm(RuntimeBlob),
+	/// A PolkaVM (RISC-V) program.
+	PolkaVm {
+		/// The parsed program.
+		blob: polkavm::ProgramBlob,
+		/// The raw program, which the prepared artifact is made of.
+		code: Vec<u8>,
+	},
+}
+
+/// Runs the prevalidation on the given code. Returns a [`PvfBlob`] if it succeeds.
+pub fn prevalidate(code: &[u8]) -> Result<PvfBlob, sc_executor_common::error::WasmError> {
+	// PolkaVM programs are detected here rather than by `RuntimeBlob`, which only accepts them if
+	// enabled through the environment. Whether they may be executed is decided by the executor
+	// parameters instead.
+	if code.starts_with(POLKAVM_BLOB_MAGIC) {
+		let blob = polkavm::ProgramBlob::parse(polkavm::ArcBytes::from(code))?;
+		return Ok(PvfBlob::PolkaVm { blob, code: code.to_vec() })
+	}
+
 	// Construct the runtime blob and do some basic checks for consistency.
 	let blob = RuntimeBlob::new(code)?;
 	// In the future this function should take care of any further prevalidation logic.
-	Ok(blob)
+	Ok(PvfBlob::Wasm(blob))
 }
 
 /// Runs preparation on the given runtime blob. If successful, it returns a serialized compiled
 /// artifact which can then be used to pass into `Executor::execute` after writing it to the disk.
+///
+/// PolkaVM programs are compiled when the runtime is constructed, so their artifact is the program
+/// itself, once it's checked that a runtime can be constructed from it.
 pub fn prepare(
-	blob: RuntimeBlob,
+	blob: PvfBlob,
 	executor_params: &ExecutorParams,
 ) -> Result<Vec<u8>, sc_executor_common::error::WasmError> {
-	let (semantics, _) = params_to_wasmtime_semantics(executor_params);
-	sc_executor_wasmtime::prepare_runtime_artifact(blob, &semantics)
+	match blob {
+		PvfBlob::Wasm(blob) => {
+			let (semantics, _) = params_to_wasmtime_semantics(executor_params);
+			sc_executor_wasmtime::prepare_runtime_artifact(blob, &semantics)
+		},
+		PvfBlob::PolkaVm { blob, code } => {
+			create_polkavm_runtime(blob, executor_params)?;
+			Ok([POLKAVM_ARTIFACT_MAGIC, &code[..]].concat())
+		},
+	}
+}
+
+/// Constructs the runtime for a PolkaVM program.
+///
+/// Programs are run by the interpreter, which is deterministic and doesn't need any other
+/// process or system call than the ones the execute worker already allows.
+fn create_polkavm_runtime(
+	blob: polkavm::ProgramBlob,
+	executor_params: &ExecutorParams,
+) -> Result<Box<dyn WasmModule>, WasmError> {
+	if !executor_params.polkavm_enabled() {
+		return Err(WasmError::Other(
+			"PolkaVM validation code is not enabled by the executor parameters".into(),
+		))
+	}
+
+	static ENGINE: OnceLock<Result<polkavm::Engine, String>> = OnceLock::new();
+	let engine = ENGINE
+		.get_or_init(|| {
+			let mut config = polkavm::Config::default();
+			config.set_backend(Some(polkavm::BackendKind::Interpreter));
+			config.set_cache_enabled(false);
+			polkavm::Engine::new(&config).map_err(|err| err.to_string())
+		})
+		.as_ref()
+		.map_err(|err| WasmError::Other(err.clone()))?;
+
+	let module = polkavm::Module::from_blob(engine, &polkavm::ModuleConfig::new(), blob)?;
+
+	// The memory of a program is laid out by the program itself. What it reserves upfront must stay
+	// within the memory limit of the session, and the rest of the limit is left to its heap.
+	let memory_map = module.memory_map();
+	let reserved_memory = u64::from(memory_map.ro_data_size()) +
+		u64::from(memory_map.rw_data_size()) +
+		u64::from(memory_map.stack_size());
+	let max_heap_size =
+		max_memory_size(executor_params).checked_sub(reserved_memory).ok_or_else(|| {
+			WasmError::Other(format!(
+				"PolkaVM program reserves {} bytes of memory, more than allowed by the executor parameters",
+				reserved_memory,
+			))
+		})?;
+
+	sc_executor_polkavm::create_runtime_from_module::<HostFunctions>(
+		&module,
+		Some(u32::try_from(max_heap_size).unwrap_or(u32::MAX)),
+	)
+}
+
+/// The maximum amount of memory, in bytes, a PVF may use according to the executor parameters.
+fn max_memory_size(executor_params: &ExecutorParams) -> u64 {
+	let max_pages = executor_params
+		.iter()
+		.find_map(|param| match param {
+			ExecutorParam::MaxMemoryPages(max_pages) => Some(*max_pages),
+			_ => None,
+		})
+		.unwrap_or(EXTRA_HEAP_PAGES);
+
+	u64::from(max_pages.saturating_add(DEFAULT_HEAP_PAGES_ESTIMATE)) * WASM_PAGE_SIZE
 }
 
 /// Available host functions. We leave out:
@@ -379,3 +492,28 @@ impl sp_core::traits::ReadRuntimeVersion for ReadRuntimeVersion {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn polkavm_code_is_detected_regardless_of_the_environment() {
+		let code = [POLKAVM_BLOB_MAGIC, b"not a program"].concat();
+
+		let err = prevalidate(&code).err().expect("the program is malformed");
+		assert!(!err.to_string().contains("SUBSTRATE_ENABLE_POLKAVM"), "{}", err);
+	}
+
+	#[test]
+	fn max_memory_size_honours_executor_params() {
+		assert_eq!(
+			max_memory_size(&ExecutorParams::default()),
+			u64::from(DEFAULT_HEAP_PAGES_ESTIMATE + EXTRA_HEAP_PAGES) * WASM_PAGE_SIZE,
+		);
+		assert_eq!(
+			max_memory_size(&ExecutorParams::from(&[ExecutorParam::MaxMemoryPages(8)][..])),
+			u64::from(DEFAULT_HEAP_PAGES_ESTIMATE + 8) * WASM_PAGE_SIZE,
+		);
+	}
+}
@@ -0,0 +1,93 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! PVF host integration tests executing PolkaVM validation code.
+
+use super::TestHost;
+use assert_matches::assert_matches;
+use polkadot_node_core_pvf::{InvalidCandidate, ValidationError};
+use polkadot_node_primitives::PoV;
+use polkadot_parachain_primitives::primitives::{BlockData, HeadData};
+use polkadot_primitives::{ExecutorParam, ExecutorParams, PersistedValidationData};
+use sp_core::H256;
+
+/// A PVF returning the empty `ValidationResult`, which encodes to zeroes, read from its zeroed
+/// stack.
+fn empty_result_pvf() -> Vec<u8> {
+	polkavm_common::assembler::assemble(
+		"
+		%stack_size = 4096
+		pub @validate_block:
+			a1 = 16
+			a0 = sp - a1
+			a1 = 8
+			ret
+		",
+	)
+	.unwrap()
+}
+
+fn pvd() -> PersistedValidationData {
+	PersistedValidationData {
+		parent_head: HeadData(vec![]),
+		relay_parent_number: 1u32,
+		relay_parent_storage_root: H256::default(),
+		max_pov_size: 4096 * 1024,
+	}
+}
+
+#[tokio::test]
+async fn execute_polkavm_pvf() {
+	let host = TestHost::new().await;
+
+	let result = host
+		.validate_candidate(
+			&empty_result_pvf(),
+			pvd(),
+			PoV { block_data: BlockData(vec![1, 2, 3]) },
+			ExecutorParams::from(&[ExecutorParam::PolkaVm][..]),
+			H256::default(),
+		)
+		.await
+		.unwrap();
+
+	assert!(result.head_data.0.is_empty());
+	assert!(result.new_validation_code.is_none());
+	assert_eq!(result.processed_downward_messages, 0);
+	assert_eq!(result.hrmp_watermark, 0);
+}
+
+#[tokio::test]
+async fn polkavm_pvf_heap_is_limited() {
+	let host = TestHost::new().await;
+
+	// The input payload alone takes more heap than the memory limit leaves to the program.
+	let result = host
+		.validate_candidate(
+			&empty_result_pvf(),
+			pvd(),
+			PoV { block_data: BlockData(vec![0; 3 * 1024 * 1024]) },
+			ExecutorParams::from(&[ExecutorParam::PolkaVm, ExecutorParam::MaxMemoryPages(1)][..]),
+			H256::default(),
+		)
+		.await;
+
+	assert_matches!(
+		result,
+		Err(ValidationError::Invalid(InvalidCandidate::WorkerReportedInvalid(err)))
+			if err.contains("maximum heap size")
+	);
+}
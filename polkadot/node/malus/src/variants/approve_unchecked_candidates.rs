@@ -0,0 +1,242 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that issues approval votes for the candidates it is assigned to
+//! without ever checking them.
+//!
+//! The available data of the candidate is not recovered and the candidate is not validated,
+//! approval voting is simply told that the candidate is valid. Disputes are participated in
+//! honestly, so invalid candidates approved by this node should still be disputed by the
+//! honest checkers.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use futures::channel::oneshot;
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_primitives::{AvailableData, BlockData, PoV, ValidationResult};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::{CandidateHash, PersistedValidationData};
+use sp_core::traits::SpawnNamed;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS, variants::create_fake_candidate_commitments};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Candidates approval voting was told are available without recovering their data.
+type Unchecked = Arc<Mutex<HashSet<CandidateHash>>>;
+
+/// Wraps around availability recovery and skips the recovery requested for approval checks.
+#[derive(Clone)]
+struct SkipApprovalRecovery {
+	distribution: Bernoulli,
+	unchecked: Unchecked,
+}
+
+impl<Sender> MessageInterceptor<Sender> for SkipApprovalRecovery
+where
+	Sender: overseer::AvailabilityRecoverySenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityRecoveryMessage;
+
+	/// Answer recovery requests of approval voting with empty available data.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			// Approval voting is the only one asking to recover from the backing group first,
+			// dispute participation recovers the data honestly.
+			FromOrchestra::Communication {
+				msg:
+					AvailabilityRecoveryMessage::RecoverAvailableData(
+						candidate_receipt,
+						session_index,
+						backing_group @ Some(_),
+						core_index,
+						tx,
+					),
+			} => {
+				if !self.distribution.sample(&mut rand::thread_rng()) {
+					return Some(FromOrchestra::Communication {
+						msg: AvailabilityRecoveryMessage::RecoverAvailableData(
+							candidate_receipt,
+							session_index,
+							backing_group,
+							core_index,
+							tx,
+						),
+					})
+				}
+
+				let candidate_hash = candidate_receipt.hash();
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					"😈 Skipping the recovery of the available data for an approval check.",
+				);
+
+				self.unchecked.lock().expect("poisoned lock").insert(candidate_hash);
+				let _ = tx.send(Ok(AvailableData {
+					pov: Arc::new(PoV { block_data: BlockData(Vec::new()) }),
+					validation_data: Default::default(),
+				}));
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+/// Wraps around candidate validation and reports the unchecked candidates as valid.
+#[derive(Clone)]
+struct SkipApprovalValidation {
+	unchecked: Unchecked,
+}
+
+impl<Sender> MessageInterceptor<Sender> for SkipApprovalValidation
+where
+	Sender: overseer::CandidateValidationSenderTrait + Clone + Send + 'static,
+{
+	type Message = CandidateValidationMessage;
+
+	/// Answer the approval validation requests of unchecked candidates without validating them.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::ValidateFromExhaustive {
+						validation_data,
+						validation_code,
+						candidate_receipt,
+						pov,
+						executor_params,
+						exec_kind: PvfExecKind::Approval,
+						response_sender,
+					},
+			} => {
+				let candidate_hash = candidate_receipt.hash();
+				if !self.unchecked.lock().expect("poisoned lock").remove(&candidate_hash) {
+					return Some(FromOrchestra::Communication {
+						msg: CandidateValidationMessage::ValidateFromExhaustive {
+							validation_data,
+							validation_code,
+							candidate_receipt,
+							pov,
+							executor_params,
+							exec_kind: PvfExecKind::Approval,
+							response_sender,
+						},
+					})
+				}
+
+				gum::info!(
+					target: MALUS,
+					?candidate_hash,
+					"😈 Approving a candidate without validating it.",
+				);
+
+				send_valid(validation_data, response_sender);
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+// Approval voting doesn't look at the commitments of a valid result.
+fn send_valid(
+	validation_data: PersistedValidationData,
+	response_sender: oneshot::Sender<Result<ValidationResult, ValidationFailed>>,
+) {
+	let commitments = create_fake_candidate_commitments(&validation_data);
+	let _ = response_sender.send(Ok(ValidationResult::Valid(commitments, validation_data)));
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct ApproveUncheckedCandidatesOptions {
+	/// Determines the percentage of assigned candidates which are approved without being
+	/// checked. Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// Generates an overseer that wraps availability recovery and candidate validation with our
+/// malicious interceptors.
+pub(crate) struct ApproveUncheckedCandidates {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for ApproveUncheckedCandidates {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node approving {:?} percent of the assigned candidates without checking them.",
+			&self.percentage,
+		);
+
+		let unchecked = Unchecked::default();
+		let skip_recovery = SkipApprovalRecovery {
+			distribution: Bernoulli::new(f64::from(self.percentage) / 100.0)
+				.expect("Invalid probability! Percentage must be in range [0..=100]."),
+			unchecked: unchecked.clone(),
+		};
+		let skip_validation = SkipApprovalValidation { unchecked };
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_recovery(move |ar| InterceptedSubsystem::new(ar, skip_recovery))
+		.replace_candidate_validation(move |cv| InterceptedSubsystem::new(cv, skip_validation))
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
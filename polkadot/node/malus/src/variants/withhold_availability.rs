@@ -0,0 +1,169 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that backs candidates honestly, but then withholds the erasure chunks
+//! and the available data of the candidates it backed.
+//!
+//! Other validators have to fetch their chunks from the remaining backers and availability
+//! recovery has to fall back from fetching the full data from backers to chunk recovery.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::CandidateHash;
+use sp_core::traits::SpawnNamed;
+
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashSet,
+	sync::{Arc, Mutex},
+};
+
+/// Wraps around the availability store and refuses to serve the data of backed candidates.
+#[derive(Clone)]
+struct WithholdChunks {
+	distribution: Bernoulli,
+	/// Candidates stored after backing them, whose data is withheld.
+	withheld: Arc<Mutex<HashSet<CandidateHash>>>,
+}
+
+impl WithholdChunks {
+	fn is_withheld(&self, candidate_hash: &CandidateHash) -> bool {
+		self.withheld.lock().expect("poisoned lock").contains(candidate_hash)
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for WithholdChunks
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	/// Note the candidates stored by `candidate-backing` and answer queries for their chunks or
+	/// available data as if nothing was stored.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			// Only backers store the full available data.
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::StoreAvailableData { candidate_hash, .. },
+			} => {
+				if self.distribution.sample(&mut rand::thread_rng()) {
+					gum::info!(
+						target: MALUS,
+						?candidate_hash,
+						"😈 Storing the available data of a backed candidate, but withholding it.",
+					);
+					self.withheld.lock().expect("poisoned lock").insert(candidate_hash);
+				}
+				Some(msg)
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryChunk(candidate_hash, validator_index, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(target: MALUS, ?candidate_hash, ?validator_index, "😈 Withholding chunk.");
+				let _ = tx.send(None);
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAllChunks(candidate_hash, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(target: MALUS, ?candidate_hash, "😈 Withholding all chunks.");
+				let _ = tx.send(Vec::new());
+				None
+			},
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::QueryAvailableData(candidate_hash, tx),
+			} if self.is_withheld(&candidate_hash) => {
+				gum::debug!(target: MALUS, ?candidate_hash, "😈 Withholding available data.");
+				let _ = tx.send(None);
+				None
+			},
+			msg => Some(msg),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdAvailabilityOptions {
+	/// Determines the percentage of backed candidates whose availability is withheld.
+	/// Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// Generates an overseer that wraps the availability store with our malicious interceptor.
+pub(crate) struct WithholdAvailability {
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for WithholdAvailability {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node withholding the availability of {:?} percent of the backed candidates.",
+			&self.percentage,
+		);
+
+		let withhold_chunks = WithholdChunks {
+			distribution: Bernoulli::new(f64::from(self.percentage) / 100.0)
+				.expect("Invalid probability! Percentage must be in range [0..=100]."),
+			withheld: Default::default(),
+		};
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_store(move |av_store| {
+			InterceptedSubsystem::new(av_store, withhold_chunks)
+		})
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
@@ -18,8 +18,9 @@ use futures::{future::Either, FutureExt, StreamExt, TryFutureExt};
 
 use sp_keystore::KeystorePtr;
 
-use polkadot_node_network_protocol::request_response::{
-	v1, v2, IncomingRequestReceiver, ReqProtocolNames,
+use polkadot_node_network_protocol::{
+	available_data_blocks::AvailableDataBlockStore,
+	request_response::{v1, v2, IncomingRequestReceiver, ReqProtocolNames},
 };
 use polkadot_node_subsystem::{
 	messages::AvailabilityDistributionMessage, overseer, FromOrchestra, OverseerSignal,
@@ -41,7 +42,7 @@ mod pov_requester;
 
 /// Responding to erasure chunk requests:
 mod responder;
-use responder::{run_chunk_receivers, run_pov_receiver};
+use responder::{run_available_data_blocks_receivers, run_chunk_receivers, run_pov_receiver};
 
 mod metrics;
 /// Prometheus `Metrics` for availability distribution.
@@ -58,6 +59,8 @@ pub struct AvailabilityDistributionSubsystem {
 	runtime: RuntimeInfo,
 	/// Receivers to receive messages from.
 	recvs: IncomingRequestReceivers,
+	/// Blocks of available data served over bitswap.
+	available_data_block_store: AvailableDataBlockStore,
 	/// Mapping of the req-response protocols to the full protocol names.
 	req_protocol_names: ReqProtocolNames,
 	/// Prometheus metrics.
@@ -72,6 +75,12 @@ pub struct IncomingRequestReceivers {
 	pub chunk_req_v1_receiver: IncomingRequestReceiver<v1::ChunkFetchingRequest>,
 	/// Receiver for incoming v2 availability chunk requests.
 	pub chunk_req_v2_receiver: IncomingRequestReceiver<v2::ChunkFetchingRequest>,
+	/// Receiver for incoming available data manifest requests.
+	pub available_data_manifest_req_receiver:
+		IncomingRequestReceiver<v1::AvailableDataManifestRequest>,
+	/// Receiver for incoming available data bitswap requests.
+	pub available_data_bitswap_req_receiver:
+		IncomingRequestReceiver<v1::AvailableDataBitswapRequest>,
 }
 
 #[overseer::subsystem(AvailabilityDistribution, error=SubsystemError, prefix=self::overseer)]
@@ -96,17 +105,36 @@ impl AvailabilityDistributionSubsystem {
 		metrics: Metrics,
 	) -> Self {
 		let runtime = RuntimeInfo::new(Some(keystore));
-		Self { runtime, recvs, req_protocol_names, metrics }
+		Self {
+			runtime,
+			recvs,
+			available_data_block_store: Default::default(),
+			req_protocol_names,
+			metrics,
+		}
+	}
+
+	/// Serve the blocks of available data from the given store, which may be shared with
+	/// availability recovery.
+	pub fn with_available_data_block_store(
+		mut self,
+		available_data_block_store: AvailableDataBlockStore,
+	) -> Self {
+		self.available_data_block_store = available_data_block_store;
+		self
 	}
 
 	/// Start processing work as passed on from the Overseer.
 	async fn run<Context>(self, mut ctx: Context) -> std::result::Result<(), FatalError> {
-		let Self { mut runtime, recvs, metrics, req_protocol_names } = self;
+		let Self { mut runtime, recvs, available_data_block_store, metrics, req_protocol_names } =
+			self;
 
 		let IncomingRequestReceivers {
 			pov_req_receiver,
 			chunk_req_v1_receiver,
 			chunk_req_v2_receiver,
+			available_data_manifest_req_receiver,
+			available_data_bitswap_req_receiver,
 		} = recvs;
 		let mut requester = Requester::new(req_protocol_names, metrics.clone()).fuse();
 		let mut warn_freq = gum::Freq::new();
@@ -119,6 +147,19 @@ impl AvailabilityDistributionSubsystem {
 			)
 			.map_err(FatalError::SpawnTask)?;
 
+			ctx.spawn(
+				"available-data-blocks-receiver",
+				run_available_data_blocks_receivers(
+					sender.clone(),
+					available_data_manifest_req_receiver,
+					available_data_bitswap_req_receiver,
+					available_data_block_store,
+					metrics.clone(),
+				)
+				.boxed(),
+			)
+			.map_err(FatalError::SpawnTask)?;
+
 			ctx.spawn(
 				"chunk-receiver",
 				run_chunk_receivers(
@@ -53,6 +53,12 @@ struct MetricsInner {
 	/// Number of PoVs served.
 	served_povs: CounterVec<U64>,
 
+	/// Number of available data manifests served.
+	served_available_data_manifests: CounterVec<U64>,
+
+	/// Number of available data bitswap requests served.
+	served_available_data_blocks: CounterVec<U64>,
+
 	/// Number of times our first set of validators did not provide the needed chunk and we had to
 	/// query further validators.
 	retries: Counter<U64>,
@@ -92,6 +98,20 @@ impl Metrics {
 		}
 	}
 
+	/// Increment counter on served available data manifests.
+	pub fn on_served_available_data_manifest(&self, label: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.served_available_data_manifests.with_label_values(&[label]).inc()
+		}
+	}
+
+	/// Increment counter on served available data bitswap requests.
+	pub fn on_served_available_data_blocks(&self, label: &'static str) {
+		if let Some(metrics) = &self.0 {
+			metrics.served_available_data_blocks.with_label_values(&[label]).inc()
+		}
+	}
+
 	/// Increment retry counter.
 	pub fn on_retry(&self) {
 		if let Some(metrics) = &self.0 {
@@ -143,6 +163,26 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			served_available_data_manifests: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_served_available_data_manifests_total",
+						"Total number of available data manifests served by this node.",
+					),
+					&["success"]
+				)?,
+				registry,
+			)?,
+			served_available_data_blocks: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_served_available_data_blocks_total",
+						"Total number of available data bitswap requests served by this node.",
+					),
+					&["success"]
+				)?,
+				registry,
+			)?,
 			retries: prometheus::register(
 				Counter::new(
 					"polkadot_parachain_fetch_retries_total",
@@ -23,12 +23,20 @@ use futures::{channel::oneshot, select, FutureExt};
 use codec::{Decode, Encode};
 use fatality::Nested;
 use polkadot_node_network_protocol::{
-	request_response::{v1, v2, IncomingRequest, IncomingRequestReceiver, IsRequest},
+	available_data_blocks::AvailableDataBlockStore,
+	request_response::{
+		incoming::OutgoingResponse, v1, v2, IncomingRequest, IncomingRequestReceiver, IsRequest,
+	},
 	UnifiedReputationChange as Rep,
 };
-use polkadot_node_primitives::{AvailableData, ErasureChunk};
+use polkadot_node_primitives::{
+	available_data_blocks::{split_available_data, AvailableDataManifest},
+	AvailableData, ErasureChunk,
+};
 use polkadot_node_subsystem::{messages::AvailabilityStoreMessage, SubsystemSender};
 use polkadot_primitives::{CandidateHash, ValidatorIndex};
+use sc_network::bitswap;
+use schnellru::{ByLength, LruMap};
 
 use crate::{
 	error::{JfyiError, Result},
@@ -37,6 +45,18 @@ use crate::{
 };
 
 const COST_INVALID_REQUEST: Rep = Rep::CostMajor("Received message could not be decoded.");
+const COST_INVALID_BITSWAP_REQUEST: Rep =
+	Rep::CostMajor("Received an invalid or too large bitswap wantlist.");
+
+/// The number of candidates whose manifest is cached.
+///
+/// Peers recovering a candidate request its manifest at about the same time, so the available
+/// data is only fetched from the av-store and split once for them, as long as its blocks are kept
+/// in the block store.
+const MANIFEST_CACHE_SIZE: u32 = 16;
+
+/// Manifests of the available data of recently requested candidates.
+pub type ManifestCache = LruMap<CandidateHash, AvailableDataManifest>;
 
 /// Receiver task to be forked as a separate task to handle PoV requests.
 pub async fn run_pov_receiver<Sender>(
@@ -131,6 +151,71 @@ pub async fn run_chunk_receivers<Sender>(
 	}
 }
 
+/// Receiver task to be forked as a separate task to handle requests for the manifest and blocks
+/// of available data.
+pub async fn run_available_data_blocks_receivers<Sender>(
+	mut sender: Sender,
+	mut manifest_receiver: IncomingRequestReceiver<v1::AvailableDataManifestRequest>,
+	mut bitswap_receiver: IncomingRequestReceiver<v1::AvailableDataBitswapRequest>,
+	block_store: AvailableDataBlockStore,
+	metrics: Metrics,
+) where
+	Sender: SubsystemSender<AvailabilityStoreMessage>,
+{
+	let mut cache = LruMap::new(ByLength::new(MANIFEST_CACHE_SIZE));
+	loop {
+		select! {
+			res = manifest_receiver.recv(|| vec![COST_INVALID_REQUEST]).fuse() => match res.into_nested() {
+				Ok(Ok(msg)) => {
+					answer_available_data_manifest_request_log(
+						&mut sender,
+						&mut cache,
+						&block_store,
+						msg,
+						&metrics,
+					)
+					.await;
+				},
+				Err(fatal) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						error = ?fatal,
+						"Shutting down available data manifest receiver."
+					);
+					return
+				},
+				Ok(Err(jfyi)) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						error = ?jfyi,
+						"Error decoding incoming available data manifest request."
+					);
+				}
+			},
+			res = bitswap_receiver.recv(|| vec![COST_INVALID_REQUEST]).fuse() => match res.into_nested() {
+				Ok(Ok(msg)) => {
+					answer_available_data_bitswap_request_log(&block_store, msg, &metrics);
+				},
+				Err(fatal) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						error = ?fatal,
+						"Shutting down available data bitswap receiver."
+					);
+					return
+				},
+				Ok(Err(jfyi)) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						error = ?jfyi,
+						"Error decoding incoming available data bitswap request."
+					);
+				}
+			}
+		}
+	}
+}
+
 /// Variant of `answer_pov_request` that does Prometheus metric and logging on errors.
 ///
 /// Any errors of `answer_pov_request` will simply be logged.
@@ -183,6 +268,58 @@ pub async fn answer_chunk_request_log<Sender, Req, MakeResp>(
 	}
 }
 
+/// Variant of `answer_available_data_manifest_request` that does Prometheus metric and logging on
+/// errors.
+///
+/// Any errors of `answer_available_data_manifest_request` will simply be logged.
+pub async fn answer_available_data_manifest_request_log<Sender>(
+	sender: &mut Sender,
+	cache: &mut ManifestCache,
+	block_store: &AvailableDataBlockStore,
+	req: IncomingRequest<v1::AvailableDataManifestRequest>,
+	metrics: &Metrics,
+) where
+	Sender: SubsystemSender<AvailabilityStoreMessage>,
+{
+	let res = answer_available_data_manifest_request(sender, cache, block_store, req).await;
+	match res {
+		Ok(result) =>
+			metrics.on_served_available_data_manifest(if result { SUCCEEDED } else { NOT_FOUND }),
+		Err(err) => {
+			gum::warn!(
+				target: LOG_TARGET,
+				err= ?err,
+				"Serving available data manifest failed with error"
+			);
+			metrics.on_served_available_data_manifest(FAILED);
+		},
+	}
+}
+
+/// Variant of `answer_available_data_bitswap_request` that does Prometheus metric and logging on
+/// errors.
+///
+/// Any errors of `answer_available_data_bitswap_request` will simply be logged.
+pub fn answer_available_data_bitswap_request_log(
+	block_store: &AvailableDataBlockStore,
+	req: IncomingRequest<v1::AvailableDataBitswapRequest>,
+	metrics: &Metrics,
+) {
+	let res = answer_available_data_bitswap_request(block_store, req);
+	match res {
+		Ok(result) =>
+			metrics.on_served_available_data_blocks(if result { SUCCEEDED } else { FAILED }),
+		Err(err) => {
+			gum::warn!(
+				target: LOG_TARGET,
+				err= ?err,
+				"Serving available data blocks failed with error"
+			);
+			metrics.on_served_available_data_blocks(FAILED);
+		},
+	}
+}
+
 /// Answer an incoming PoV fetch request by querying the av store.
 ///
 /// Returns: `Ok(true)` if chunk was found and served.
@@ -209,6 +346,90 @@ where
 	Ok(result)
 }
 
+/// Answer an incoming request for the manifest of the available data by querying the av store,
+/// unless the manifest is cached and its blocks are still in the block store.
+///
+/// The blocks of the available data are added to the block store, from where they are served
+/// with bitswap.
+///
+/// Returns: `Ok(true)` if the available data was found and its manifest served.
+pub async fn answer_available_data_manifest_request<Sender>(
+	sender: &mut Sender,
+	cache: &mut ManifestCache,
+	block_store: &AvailableDataBlockStore,
+	req: IncomingRequest<v1::AvailableDataManifestRequest>,
+) -> Result<bool>
+where
+	Sender: SubsystemSender<AvailabilityStoreMessage>,
+{
+	let candidate_hash = req.payload.candidate_hash;
+	let cached = cache
+		.get(&candidate_hash)
+		.filter(|manifest| block_store.contains_all(&manifest.blocks))
+		.cloned();
+	let manifest = match cached {
+		Some(manifest) => Some(manifest),
+		// Missing data isn't cached, the av-store may still receive it.
+		None => query_available_data(sender, candidate_hash).await?.map(|av_data| {
+			let (manifest, blocks) = split_available_data(&av_data);
+			block_store.insert(manifest.blocks.iter().copied().zip(blocks));
+			cache.insert(candidate_hash, manifest.clone());
+			manifest
+		}),
+	};
+
+	let result = manifest.is_some();
+
+	gum::trace!(
+		target: LOG_TARGET,
+		hash = ?candidate_hash,
+		peer = ?req.peer,
+		has_data = result,
+		"Serving available data manifest",
+	);
+
+	let response = match manifest {
+		None => v1::AvailableDataManifestResponse::NoSuchData,
+		Some(manifest) => v1::AvailableDataManifestResponse::Manifest(manifest),
+	};
+
+	req.send_response(response).map_err(|_| JfyiError::SendResponse)?;
+	Ok(result)
+}
+
+/// Answer an incoming bitswap request with the blocks of the block store.
+///
+/// Blocks which are not in the store are reported as missing.
+///
+/// Returns: `Ok(true)` if the request was valid and answered.
+pub fn answer_available_data_bitswap_request(
+	block_store: &AvailableDataBlockStore,
+	req: IncomingRequest<v1::AvailableDataBitswapRequest>,
+) -> Result<bool> {
+	match bitswap::handle_request(block_store, &req.peer, &req.payload.message) {
+		Ok(message) => {
+			req.send_response(v1::AvailableDataBitswapResponse { message })
+				.map_err(|_| JfyiError::SendResponse)?;
+			Ok(true)
+		},
+		Err(err) => {
+			gum::debug!(
+				target: LOG_TARGET,
+				peer = ?req.peer,
+				?err,
+				"Invalid available data bitswap request",
+			);
+			req.send_outgoing_response(OutgoingResponse {
+				result: Err(()),
+				reputation_changes: vec![COST_INVALID_BITSWAP_REQUEST],
+				sent_feedback: None,
+			})
+			.map_err(|_| JfyiError::SendResponse)?;
+			Ok(false)
+		},
+	}
+}
+
 /// Answer an incoming chunk request by querying the av store.
 ///
 /// Returns: `Ok(true)` if chunk was found and served.
@@ -57,9 +57,23 @@ fn test_harness<T: Future<Output = ()>>(
 		Block,
 		sc_network::NetworkWorker<Block, Hash>,
 	>(&req_protocol_names);
+	let (available_data_manifest_req_receiver, _available_data_manifest_req_cfg) =
+		IncomingRequest::get_config_receiver::<Block, sc_network::NetworkWorker<Block, Hash>>(
+			&req_protocol_names,
+		);
+	let (available_data_bitswap_req_receiver, _available_data_bitswap_req_cfg) =
+		IncomingRequest::get_config_receiver::<Block, sc_network::NetworkWorker<Block, Hash>>(
+			&req_protocol_names,
+		);
 	let subsystem = AvailabilityDistributionSubsystem::new(
 		keystore,
-		IncomingRequestReceivers { pov_req_receiver, chunk_req_v1_receiver, chunk_req_v2_receiver },
+		IncomingRequestReceivers {
+			pov_req_receiver,
+			chunk_req_v1_receiver,
+			chunk_req_v2_receiver,
+			available_data_manifest_req_receiver,
+			available_data_bitswap_req_receiver,
+		},
 		req_protocol_names,
 		Default::default(),
 	);
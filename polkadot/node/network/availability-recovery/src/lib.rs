@@ -34,11 +34,12 @@ use futures::{
 	stream::{FuturesUnordered, StreamExt},
 	task::{Context, Poll},
 };
+use rand::seq::IteratorRandom;
 use sc_network::ProtocolName;
 use schnellru::{ByLength, LruMap};
 use task::{
-	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
-	FetchSystematicChunksParams,
+	FetchBlocks, FetchBlocksParams, FetchChunks, FetchChunksParams, FetchFull, FetchFullParams,
+	FetchSystematicChunks, FetchSystematicChunksParams,
 };
 
 use polkadot_erasure_coding::{
@@ -49,6 +50,7 @@ use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
 use error::{log_error, Error, FatalError, Result};
 use polkadot_node_network_protocol::{
+	available_data_blocks::AvailableDataBlockStore,
 	request_response::{
 		v1 as request_v1, v2 as request_v2, IncomingRequestReceiver, IsRequest, ReqProtocolNames,
 	},
@@ -88,6 +90,9 @@ const LRU_SIZE: u32 = 16;
 
 const COST_INVALID_REQUEST: Rep = Rep::CostMajor("Peer sent unparsable request");
 
+/// The number of validators besides the backers which are asked for available data blocks.
+const MAX_NON_BACKER_BLOCK_SOURCES: usize = 8;
+
 /// PoV size limit in bytes for which prefer fetching from backers. (conservative, Polkadot for now)
 pub(crate) const CONSERVATIVE_FETCH_CHUNKS_THRESHOLD: usize = 1 * 1024 * 1024;
 /// PoV size limit in bytes for which prefer fetching from backers. (Kusama and all testnets)
@@ -123,6 +128,9 @@ pub enum RecoveryStrategyKind {
 pub struct AvailabilityRecoverySubsystem {
 	/// PoV recovery strategy to use.
 	recovery_strategy_kind: RecoveryStrategyKind,
+	/// If set, the available data of PoVs too large to be fetched from the backing group in one
+	/// piece is fetched as content-addressed blocks over bitswap, using and filling this store.
+	available_data_block_store: Option<AvailableDataBlockStore>,
 	// If this is true, do not request data from the availability store.
 	/// This is the useful for nodes where the
 	/// availability-store subsystem is not expected to run,
@@ -378,6 +386,7 @@ async fn handle_recover<Context>(
 	metrics: &Metrics,
 	erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
 	recovery_strategy_kind: RecoveryStrategyKind,
+	available_data_block_store: Option<AvailableDataBlockStore>,
 	bypass_availability_store: bool,
 	post_recovery_check: PostRecoveryCheck,
 	maybe_core_index: Option<CoreIndex>,
@@ -458,7 +467,25 @@ async fn handle_recover<Context>(
 							recovery_strategies.push_back(Box::new(FetchFull::new(
 								FetchFullParams { validators: backing_validators.to_vec() },
 							))),
-						_ => {},
+						_ =>
+							if let Some(block_store) = &available_data_block_store {
+								// Besides the backers, ask a few other validators, which may have
+								// recovered the blocks already.
+								let validators = (0..n_validators as u32)
+									.map(ValidatorIndex)
+									.filter(|index| !backing_validators.contains(index))
+									.choose_multiple(
+										&mut rand::thread_rng(),
+										MAX_NON_BACKER_BLOCK_SOURCES,
+									);
+								recovery_strategies.push_back(Box::new(FetchBlocks::new(
+									FetchBlocksParams {
+										backers: backing_validators.to_vec(),
+										validators,
+										block_store: block_store.clone(),
+									},
+								)))
+							},
 					};
 
 					backer_group = Some(backing_validators);
@@ -604,6 +631,7 @@ impl AvailabilityRecoverySubsystem {
 			recovery_strategy_kind: RecoveryStrategyKind::BackersFirstIfSizeLower(
 				fetch_chunks_threshold.unwrap_or(CONSERVATIVE_FETCH_CHUNKS_THRESHOLD),
 			),
+			available_data_block_store: None,
 			bypass_availability_store: true,
 			post_recovery_check: PostRecoveryCheck::PovHash,
 			req_receiver,
@@ -634,6 +662,7 @@ impl AvailabilityRecoverySubsystem {
 				RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(
 					fetch_chunks_threshold.unwrap_or(CONSERVATIVE_FETCH_CHUNKS_THRESHOLD),
 				),
+			available_data_block_store: None,
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
@@ -645,6 +674,19 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Fetch the available data of large PoVs as content-addressed blocks over bitswap, before
+	/// falling back to chunk recovery. Blocks in the given store aren't fetched, and the recovered
+	/// blocks are added to it.
+	///
+	/// Only applies to PoVs which are not fetched from the backing group in one piece.
+	pub fn with_available_data_blocks(
+		mut self,
+		block_store: Option<AvailableDataBlockStore>,
+	) -> Self {
+		self.available_data_block_store = block_store;
+		self
+	}
+
 	/// Customise the recovery strategy kind
 	/// Currently only useful for tests.
 	#[cfg(any(test, feature = "subsystem-benchmarks"))]
@@ -656,6 +698,7 @@ impl AvailabilityRecoverySubsystem {
 	) -> Self {
 		Self {
 			recovery_strategy_kind,
+			available_data_block_store: None,
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
@@ -674,6 +717,7 @@ impl AvailabilityRecoverySubsystem {
 			mut req_receiver,
 			metrics,
 			recovery_strategy_kind,
+			available_data_block_store,
 			bypass_availability_store,
 			post_recovery_check,
 			req_v1_protocol_name,
@@ -760,6 +804,7 @@ impl AvailabilityRecoverySubsystem {
 										&metrics,
 										erasure_task_tx.clone(),
 										recovery_strategy_kind.clone(),
+										available_data_block_store.clone(),
 										bypass_availability_store,
 										post_recovery_check.clone(),
 										maybe_core_index,
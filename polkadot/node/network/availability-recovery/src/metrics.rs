@@ -72,6 +72,20 @@ struct MetricsInner {
 	/// - `success`
 	full_data_requests_finished: CounterVec<U64>,
 
+	/// Number of sent available data block requests, including manifest requests.
+	blocks_requests_issued: Counter<U64>,
+
+	/// Counter for finished available data block requests.
+	///
+	/// Split by the result type:
+	///
+	/// - `no_such_data` ... peer did not have the requested data
+	/// - `timeout` ... request timed out.
+	/// - `error` ... Some networking issue except timeout
+	/// - `invalid` ... response was received, but not valid.
+	/// - `success`
+	blocks_requests_finished: CounterVec<U64>,
+
 	/// The duration of request to response.
 	///
 	/// Split by chunk type (`regular_chunks` or `systematic_chunks`).
@@ -226,6 +240,20 @@ impl Metrics {
 		}
 	}
 
+	/// Increment counter for available data block requests.
+	pub fn on_blocks_request_issued(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.blocks_requests_issued.inc()
+		}
+	}
+
+	/// An available data block request finished with the given result.
+	pub fn on_blocks_request_finished(&self, result: &str) {
+		if let Some(metrics) = &self.0 {
+			metrics.blocks_requests_finished.with_label_values(&[result]).inc()
+		}
+	}
+
 	/// Get a timer to time request/response duration.
 	pub fn time_chunk_request(&self, chunk_type: &str) -> Option<HistogramTimer> {
 		self.0.as_ref().map(|metrics| {
@@ -351,6 +379,23 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			blocks_requests_issued: prometheus::register(
+				Counter::new(
+					"polkadot_parachain_availability_recovery_blocks_requests_issued",
+					"Total number of issued available data block requests.",
+				)?,
+				registry,
+			)?,
+			blocks_requests_finished: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_availability_recovery_blocks_requests_finished",
+						"Total number of available data block requests finished.",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
 			time_chunk_request: prometheus::register(
 				prometheus::HistogramVec::new(prometheus::HistogramOpts::new(
 					"polkadot_parachain_availability_recovery_time_chunk_request",
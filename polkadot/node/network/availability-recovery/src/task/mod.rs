@@ -21,8 +21,8 @@
 mod strategy;
 
 pub use self::strategy::{
-	FetchChunks, FetchChunksParams, FetchFull, FetchFullParams, FetchSystematicChunks,
-	FetchSystematicChunksParams, RecoveryStrategy, State,
+	FetchBlocks, FetchBlocksParams, FetchChunks, FetchChunksParams, FetchFull, FetchFullParams,
+	FetchSystematicChunks, FetchSystematicChunksParams, RecoveryStrategy, State,
 };
 
 #[cfg(test)]
@@ -0,0 +1,339 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	task::{strategy::do_post_recovery_check, RecoveryParams, RecoveryStrategy, State},
+	LOG_TARGET,
+};
+
+use polkadot_node_network_protocol::{
+	available_data_blocks::AvailableDataBlockStore,
+	request_response::{
+		self as req_res, outgoing::RequestError, OutgoingRequest, OutgoingResult, Recipient,
+		Requests,
+	},
+};
+use polkadot_node_primitives::{
+	available_data_blocks::{hash_block, join_available_data, AvailableDataManifest},
+	AvailableData,
+};
+use polkadot_node_subsystem::{messages::NetworkBridgeTxMessage, overseer, RecoveryError};
+use polkadot_primitives::{Hash, ValidatorIndex};
+use sc_network::{bitswap, IfDisconnected, OutboundFailure, RequestFailure};
+
+use futures::{future::BoxFuture, stream::FuturesUnordered, FutureExt, StreamExt};
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Parameters specific to the `FetchBlocks` strategy.
+pub struct FetchBlocksParams {
+	/// Backers that will be used for fetching the manifest and the blocks.
+	pub backers: Vec<ValidatorIndex>,
+	/// Other validators that will be asked for blocks, in case they recovered the same or similar
+	/// available data before.
+	pub validators: Vec<ValidatorIndex>,
+	/// Blocks we already have, to which the recovered blocks are added.
+	pub block_store: AvailableDataBlockStore,
+}
+
+/// `RecoveryStrategy` that fetches the `AvailableData` as content-addressed blocks over bitswap.
+///
+/// The manifest listing the blocks is fetched from a single backer. Blocks which are not in the
+/// block store are requested in batches from the backers and the other validators in parallel.
+/// Identical blocks are only requested once.
+pub struct FetchBlocks {
+	params: FetchBlocksParams,
+}
+
+impl FetchBlocks {
+	/// Create a new `FetchBlocks` recovery strategy.
+	pub fn new(mut params: FetchBlocksParams) -> Self {
+		params.backers.shuffle(&mut rand::thread_rng());
+		params.validators.shuffle(&mut rand::thread_rng());
+		Self { params }
+	}
+
+	/// Fetch the manifest of the available data from the first backer which has it.
+	///
+	/// Backers which don't provide a plausible manifest are removed from the list.
+	async fn fetch_manifest<Sender: overseer::AvailabilityRecoverySenderTrait>(
+		&mut self,
+		sender: &mut Sender,
+		common_params: &RecoveryParams,
+	) -> Option<AvailableDataManifest> {
+		while let Some(validator_index) = self.params.backers.pop() {
+			let (req, response) = OutgoingRequest::new(
+				recipient(common_params, validator_index),
+				req_res::v1::AvailableDataManifestRequest {
+					candidate_hash: common_params.candidate_hash,
+				},
+			);
+			send_request(sender, common_params, Requests::AvailableDataManifestV1(req)).await;
+
+			match response.await {
+				Ok(req_res::v1::AvailableDataManifestResponse::Manifest(manifest))
+					if manifest.is_plausible() =>
+				{
+					common_params.metrics.on_blocks_request_finished("success");
+					// The backer has the data, so it can serve blocks as well.
+					self.params.backers.push(validator_index);
+					return Some(manifest)
+				},
+				Ok(req_res::v1::AvailableDataManifestResponse::NoSuchData) => {
+					common_params.metrics.on_blocks_request_finished("no_such_data");
+				},
+				Ok(_) => {
+					common_params.metrics.on_blocks_request_finished("invalid");
+					gum::debug!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						?validator_index,
+						"Implausible available data manifest",
+					);
+				},
+				Err(e) => {
+					common_params.metrics.on_blocks_request_finished(request_error_label(&e));
+					gum::debug!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						?validator_index,
+						err = ?e,
+						"Error fetching available data manifest."
+					);
+				},
+			}
+		}
+
+		None
+	}
+}
+
+#[async_trait::async_trait]
+impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender> for FetchBlocks {
+	fn display_name(&self) -> &'static str {
+		"Block-wise recovery over bitswap"
+	}
+
+	fn strategy_type(&self) -> &'static str {
+		"blocks_from_backers"
+	}
+
+	async fn run(
+		mut self: Box<Self>,
+		_: &mut State,
+		sender: &mut Sender,
+		common_params: &RecoveryParams,
+	) -> Result<AvailableData, RecoveryError> {
+		let strategy_type = RecoveryStrategy::<Sender>::strategy_type(&*self);
+
+		let manifest = self
+			.fetch_manifest(sender, common_params)
+			.await
+			.ok_or(RecoveryError::Unavailable)?;
+
+		let mut received_blocks: HashMap<Hash, Vec<u8>> = HashMap::new();
+		let mut seen = HashSet::new();
+		let mut wanted_blocks = Vec::new();
+		for hash in manifest.blocks.iter().copied().filter(|hash| seen.insert(*hash)) {
+			match self.params.block_store.get(&hash) {
+				Some(block) => {
+					received_blocks.insert(hash, block);
+				},
+				None => wanted_blocks.push(hash),
+			}
+		}
+
+		gum::trace!(
+			target: LOG_TARGET,
+			candidate_hash = ?common_params.candidate_hash,
+			size = manifest.size,
+			n_blocks = manifest.blocks.len(),
+			n_unique_blocks = seen.len(),
+			n_wanted_blocks = wanted_blocks.len(),
+			"Received available data manifest",
+		);
+
+		let mut pending_batches: VecDeque<Vec<Hash>> = wanted_blocks
+			.chunks(bitswap::MAX_WANTED_BLOCKS)
+			.map(|batch| batch.to_vec())
+			.collect();
+		let mut idle_sources: VecDeque<ValidatorIndex> = std::mem::take(&mut self.params.backers)
+			.into_iter()
+			.chain(std::mem::take(&mut self.params.validators))
+			.collect();
+		let mut ongoing_requests = FuturesUnordered::new();
+
+		loop {
+			// Every source works on at most one batch at a time. Batches of failed requests are
+			// retried with the next idle source.
+			while !pending_batches.is_empty() {
+				let Some(validator_index) = idle_sources.pop_front() else { break };
+				let batch =
+					pending_batches.pop_front().expect("checked to be non-empty above; qed");
+
+				let response =
+					send_bitswap_request(sender, common_params, validator_index, &batch).await;
+				ongoing_requests.push(async move { (validator_index, batch, response.await) });
+			}
+
+			let Some((validator_index, batch, response)) = ongoing_requests.next().await else {
+				break
+			};
+
+			match response.map(|response| bitswap::received_blocks(&response.message)) {
+				Ok(Ok(blocks)) => {
+					for block in blocks {
+						let hash = hash_block(&block);
+						if batch.contains(&hash) {
+							received_blocks.insert(hash, block);
+						}
+					}
+
+					let missing: Vec<Hash> = batch
+						.into_iter()
+						.filter(|hash| !received_blocks.contains_key(hash))
+						.collect();
+
+					if missing.is_empty() {
+						common_params.metrics.on_blocks_request_finished("success");
+						idle_sources.push_back(validator_index);
+					} else {
+						// The source doesn't have all the blocks we asked for, which is expected
+						// from validators which didn't back the candidate. Don't bother it again.
+						common_params.metrics.on_blocks_request_finished("no_such_data");
+						gum::trace!(
+							target: LOG_TARGET,
+							candidate_hash = ?common_params.candidate_hash,
+							?validator_index,
+							n_missing = missing.len(),
+							"Source doesn't have all wanted available data blocks",
+						);
+						pending_batches.push_back(missing);
+					}
+				},
+				Ok(Err(e)) => {
+					common_params.metrics.on_blocks_request_finished("invalid");
+					gum::debug!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						?validator_index,
+						err = ?e,
+						"Invalid bitswap response",
+					);
+					pending_batches.push_back(batch);
+				},
+				Err(e) => {
+					common_params.metrics.on_blocks_request_finished(request_error_label(&e));
+					gum::debug!(
+						target: LOG_TARGET,
+						candidate_hash = ?common_params.candidate_hash,
+						?validator_index,
+						err = ?e,
+						"Error fetching available data blocks."
+					);
+					pending_batches.push_back(batch);
+				},
+			}
+		}
+
+		if !pending_batches.is_empty() {
+			gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?common_params.candidate_hash,
+				n_missing_batches = pending_batches.len(),
+				"Ran out of validators to fetch available data blocks from",
+			);
+			return Err(RecoveryError::Unavailable)
+		}
+
+		let recovery_duration = common_params.metrics.time_erasure_recovery(strategy_type);
+
+		// The blocks were checked against the manifest, but the manifest itself was provided by a
+		// single backer. If the data turns out to be wrong, leave it to the following strategies
+		// to decide on its validity.
+		let Some(data) = join_available_data(&manifest, &received_blocks) else {
+			recovery_duration.map(|rd| rd.stop_and_discard());
+			gum::debug!(
+				target: LOG_TARGET,
+				candidate_hash = ?common_params.candidate_hash,
+				"Available data blocks don't decode",
+			);
+			return Err(RecoveryError::Unavailable)
+		};
+
+		match do_post_recovery_check(common_params, data).await {
+			Ok(data) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					candidate_hash = ?common_params.candidate_hash,
+					"Received available data blocks",
+				);
+				// Serve the blocks to other validators recovering the same data.
+				self.params.block_store.insert(received_blocks);
+				Ok(data)
+			},
+			Err(RecoveryError::Invalid) => {
+				recovery_duration.map(|rd| rd.stop_and_discard());
+				Err(RecoveryError::Unavailable)
+			},
+			Err(err) => Err(err),
+		}
+	}
+}
+
+fn recipient(common_params: &RecoveryParams, validator_index: ValidatorIndex) -> Recipient {
+	Recipient::Authority(common_params.validator_authority_keys[validator_index.0 as usize].clone())
+}
+
+async fn send_request<Sender: overseer::AvailabilityRecoverySenderTrait>(
+	sender: &mut Sender,
+	common_params: &RecoveryParams,
+	request: Requests,
+) {
+	sender
+		.send_message(NetworkBridgeTxMessage::SendRequests(
+			vec![request],
+			IfDisconnected::ImmediateError,
+		))
+		.await;
+
+	common_params.metrics.on_blocks_request_issued();
+}
+
+/// Send a bitswap request for the given blocks to the validator, returning the pending response.
+async fn send_bitswap_request<Sender: overseer::AvailabilityRecoverySenderTrait>(
+	sender: &mut Sender,
+	common_params: &RecoveryParams,
+	validator_index: ValidatorIndex,
+	wanted: &[Hash],
+) -> BoxFuture<'static, OutgoingResult<req_res::v1::AvailableDataBitswapResponse>> {
+	let wanted: Vec<[u8; 32]> = wanted.iter().map(|hash| hash.to_fixed_bytes()).collect();
+	let (req, response) = OutgoingRequest::new(
+		recipient(common_params, validator_index),
+		req_res::v1::AvailableDataBitswapRequest { message: bitswap::want_blocks(&wanted) },
+	);
+	send_request(sender, common_params, Requests::AvailableDataBitswapV1(req)).await;
+
+	response.boxed()
+}
+
+fn request_error_label(err: &RequestError) -> &'static str {
+	match err {
+		RequestError::NetworkError(RequestFailure::Network(OutboundFailure::Timeout)) => "timeout",
+		RequestError::InvalidResponse(_) => "invalid",
+		RequestError::NetworkError(_) | RequestError::Canceled(_) => "error",
+	}
+}
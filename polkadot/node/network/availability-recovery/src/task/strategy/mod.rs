@@ -16,11 +16,13 @@
 
 //! Recovery strategies.
 
+mod blocks;
 mod chunks;
 mod full;
 mod systematic;
 
 pub use self::{
+	blocks::{FetchBlocks, FetchBlocksParams},
 	chunks::{FetchChunks, FetchChunksParams},
 	full::{FetchFull, FetchFullParams},
 	systematic::{FetchSystematicChunks, FetchSystematicChunksParams},
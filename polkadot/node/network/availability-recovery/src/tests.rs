@@ -17,7 +17,12 @@
 use crate::task::{REGULAR_CHUNKS_REQ_RETRY_LIMIT, SYSTEMATIC_CHUNKS_REQ_RETRY_LIMIT};
 
 use super::*;
-use std::{result::Result, sync::Arc, time::Duration};
+use std::{
+	collections::{HashMap, HashSet},
+	result::Result,
+	sync::Arc,
+	time::Duration,
+};
 
 use assert_matches::assert_matches;
 use futures::{executor, future};
@@ -25,13 +30,19 @@ use futures_timer::Delay;
 use rstest::rstest;
 
 use codec::Encode;
-use polkadot_node_network_protocol::request_response::{
-	self as req_res,
-	v1::{AvailableDataFetchingRequest, ChunkResponse},
-	IncomingRequest, Protocol, Recipient, ReqProtocolNames, Requests,
+use polkadot_node_network_protocol::{
+	available_data_blocks::AvailableDataBlockStore,
+	request_response::{
+		self as req_res,
+		v1::{AvailableDataFetchingRequest, ChunkResponse},
+		IncomingRequest, Protocol, Recipient, ReqProtocolNames, Requests,
+	},
 };
 
-use polkadot_node_primitives::{BlockData, ErasureChunk, PoV, Proof};
+use polkadot_node_primitives::{
+	available_data_blocks::{split_available_data, AVAILABLE_DATA_BLOCK_SIZE},
+	BlockData, ErasureChunk, PoV, Proof,
+};
 use polkadot_node_subsystem::messages::{
 	AllMessages, NetworkBridgeTxMessage, RuntimeApiMessage, RuntimeApiRequest,
 };
@@ -45,7 +56,7 @@ use polkadot_primitives::{
 	HeadData, IndexedVec, NodeFeatures, PersistedValidationData, SessionInfo, ValidatorId,
 };
 use polkadot_primitives_test_helpers::{dummy_candidate_receipt, dummy_hash};
-use sc_network::{IfDisconnected, OutboundFailure, ProtocolName, RequestFailure};
+use sc_network::{bitswap, IfDisconnected, OutboundFailure, PeerId, ProtocolName, RequestFailure};
 use sp_keyring::Sr25519Keyring;
 
 type VirtualOverseer = TestSubsystemContextHandle<AvailabilityRecoveryMessage>;
@@ -1572,6 +1583,177 @@ fn recovers_from_only_chunks_if_pov_large(
 	});
 }
 
+#[test]
+fn large_pov_is_recovered_from_available_data_blocks() {
+	let mut test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let block_store = AvailableDataBlockStore::default();
+	let subsystem = with_chunks_if_pov_large(
+		request_receiver(&req_protocol_names),
+		&req_protocol_names,
+		Metrics::new_dummy(),
+	)
+	.with_available_data_blocks(Some(block_store.clone()));
+
+	// Spans several blocks, with the same content repeated.
+	let mut seed = 1u64;
+	let content: Vec<u8> = (0..AVAILABLE_DATA_BLOCK_SIZE)
+		.map(|_| {
+			seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+			(seed >> 56) as u8
+		})
+		.collect();
+	let pov = PoV { block_data: BlockData(content.repeat(3)) };
+	test_state.available_data = AvailableData {
+		validation_data: test_state.persisted_validation_data.clone(),
+		pov: Arc::new(pov),
+	};
+	let (_, erasure_root) = derive_erasure_chunks_with_proofs_and_root(
+		test_state.validators.len(),
+		&test_state.available_data,
+		|_, _| {},
+	);
+	test_state.candidate.descriptor.set_erasure_root(erasure_root);
+
+	let (manifest, blocks) = split_available_data(&test_state.available_data);
+	let all_blocks: HashMap<Hash, Vec<u8>> = manifest.blocks.iter().copied().zip(blocks).collect();
+	assert!(all_blocks.len() > 1);
+
+	// We have one of the blocks already, the other ones are served by the validators.
+	let (known_hash, known_block) = all_blocks.iter().next().unwrap();
+	block_store.insert([(*known_hash, known_block.clone())]);
+	let served_blocks = AvailableDataBlockStore::default();
+	served_blocks.insert(
+		all_blocks
+			.iter()
+			.filter(|(hash, _)| *hash != known_hash)
+			.map(|(h, b)| (*h, b.clone())),
+	);
+	let n_batches = (all_blocks.len() - 1).div_ceil(bitswap::MAX_WANTED_BLOCKS);
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				Some(GroupIndex(0)),
+				Some(test_state.core_index),
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api_session_info(&mut virtual_overseer).await;
+		test_state.test_runtime_api_node_features(&mut virtual_overseer).await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::AvailabilityStore(
+				AvailabilityStoreMessage::QueryChunkSize(_, tx)
+			) => {
+				let _ = tx.send(Some(crate::FETCH_CHUNKS_THRESHOLD + 1));
+			}
+		);
+
+		test_state.respond_to_available_data_query(&mut virtual_overseer, false).await;
+
+		let candidate_hash = test_state.candidate.hash();
+		let expected_validators = test_state.validator_groups.get(GroupIndex(0)).unwrap();
+
+		// The first backer doesn't have the data, the second one provides the manifest.
+		let responses = [
+			req_res::v1::AvailableDataManifestResponse::NoSuchData,
+			req_res::v1::AvailableDataManifestResponse::Manifest(manifest.clone()),
+		];
+		for response in responses {
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::NetworkBridgeTx(
+					NetworkBridgeTxMessage::SendRequests(
+						mut requests,
+						IfDisconnected::ImmediateError,
+					)
+				) => {
+					assert_eq!(requests.len(), 1);
+
+					assert_matches!(
+						requests.pop().unwrap(),
+						Requests::AvailableDataManifestV1(req) => {
+							assert_eq!(req.payload.candidate_hash, candidate_hash);
+							let validator_index = test_state.validator_authority_id
+								.iter()
+								.position(|a| Recipient::Authority(a.clone()) == req.peer)
+								.unwrap();
+							assert!(expected_validators.contains(&ValidatorIndex(validator_index as u32)));
+
+							let _ = req.pending_response.send(Ok((
+								response.encode(),
+								req_protocol_names.get_name(Protocol::AvailableDataManifestV1),
+							)));
+						}
+					)
+				}
+			);
+		}
+
+		// The blocks we don't have are requested over bitswap, every batch from another
+		// validator.
+		let mut recipients = HashSet::new();
+		for _ in 0..n_batches {
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::NetworkBridgeTx(
+					NetworkBridgeTxMessage::SendRequests(
+						mut requests,
+						IfDisconnected::ImmediateError,
+					)
+				) => {
+					assert_eq!(requests.len(), 1);
+
+					assert_matches!(
+						requests.pop().unwrap(),
+						Requests::AvailableDataBitswapV1(req) => {
+							let Recipient::Authority(authority) = req.peer.clone() else {
+								panic!("Blocks are requested from validators")
+							};
+							assert!(recipients.insert(authority));
+
+							let message = bitswap::handle_request(
+								&served_blocks,
+								&PeerId::random(),
+								&req.payload.message,
+							)
+							.unwrap();
+							let _ = req.pending_response.send(Ok((
+								req_res::v1::AvailableDataBitswapResponse { message }.encode(),
+								req_protocol_names.get_name(Protocol::AvailableDataBitswapV1),
+							)));
+						}
+					)
+				}
+			);
+		}
+
+		// Recovered data should match the original one.
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+		// And its blocks can be served to other validators.
+		assert!(block_store.contains_all(&manifest.blocks));
+		virtual_overseer
+	});
+}
+
 #[rstest]
 #[case(true, false)]
 #[case(false, true)]
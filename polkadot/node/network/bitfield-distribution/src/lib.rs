@@ -40,6 +40,7 @@ use polkadot_node_subsystem::{
 use polkadot_node_subsystem_util::{
 	self as util,
 	reputation::{ReputationAggregator, REPUTATION_CHANGE_INTERVAL},
+	DutyRecords,
 };
 
 use futures::select;
@@ -140,6 +141,9 @@ struct ProtocolState {
 
 	/// Aggregated reputation change
 	reputation: ReputationAggregator,
+
+	/// The bitfields distributed on behalf of the local validator, by relay parent.
+	duties: DutyRecords<Hash, BitfieldDuty>,
 }
 
 /// Data for a particular relay parent.
@@ -272,6 +276,13 @@ impl BitfieldDistribution {
 							// a network message was received
 							handle_network_msg(&mut ctx, state, &self.metrics, event, rng).await;
 						},
+						FromOrchestra::Communication {
+							msg: BitfieldDistributionMessage::GetBitfieldDuties(session, tx),
+						} => {
+							let duties =
+								state.duties.session(session).map(|(_, duty)| duty.clone()).collect();
+							let _ = tx.send(duties);
+						},
 						FromOrchestra::Signal(OverseerSignal::ActiveLeaves(ActiveLeavesUpdate {
 							activated,
 							..
@@ -372,6 +383,17 @@ async fn handle_bitfield_distribution<Context>(
 		return
 	};
 
+	let bits = &signed_availability.payload().0;
+	state.duties.insert(
+		session_idx,
+		relay_parent,
+		BitfieldDuty {
+			relay_parent,
+			available_cores: bits.count_ones() as u32,
+			cores: bits.len() as u32,
+		},
+	);
+
 	let msg = BitfieldGossipMessage { relay_parent, signed_availability };
 	let topology = state.topologies.get_topology_or_fallback(session_idx).local_grid_neighbors();
 	let required_routing = topology.required_routing_by_index(validator_index, true);
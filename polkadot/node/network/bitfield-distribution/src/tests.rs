@@ -92,6 +92,7 @@ fn prewarmed_state(
 		topologies,
 		view: our_view!(relay_parent),
 		reputation: ReputationAggregator::new(|_| true),
+		duties: Default::default(),
 	}
 }
 
@@ -295,6 +295,10 @@ where
 					Requests::PoVFetchingV1(_) => metrics.on_message("pov_fetching_v1"),
 					Requests::DisputeSendingV1(_) => metrics.on_message("dispute_sending_v1"),
 					Requests::AttestedCandidateV2(_) => metrics.on_message("attested_candidate_v2"),
+					Requests::AvailableDataManifestV1(_) =>
+						metrics.on_message("available_data_manifest_v1"),
+					Requests::AvailableDataBitswapV1(_) =>
+						metrics.on_message("available_data_bitswap_v1"),
 				}
 
 				network_service
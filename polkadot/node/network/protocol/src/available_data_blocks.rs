@@ -0,0 +1,77 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Store of content-addressed blocks of available data, served to other nodes over bitswap.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polkadot_primitives::Hash;
+use sc_network::bitswap::{BitswapBlockProvider, BitswapError};
+use schnellru::{ByLength, LruMap};
+
+/// The number of blocks kept in an [`AvailableDataBlockStore`].
+///
+/// Blocks are about 80 KiB on average and at most 256 KiB, enough for the blocks of several
+/// candidates with large PoVs.
+pub const AVAILABLE_DATA_BLOCK_STORE_SIZE: u32 = 512;
+
+/// Recently split or recovered blocks of available data, keyed by the hash of their content.
+///
+/// Shared between availability distribution, which serves the blocks to other nodes, and
+/// availability recovery, which doesn't fetch blocks it already has. As blocks are addressed by
+/// their content, blocks shared by the available data of different candidates are only kept once.
+#[derive(Clone)]
+pub struct AvailableDataBlockStore {
+	blocks: Arc<Mutex<LruMap<Hash, Vec<u8>>>>,
+}
+
+impl Default for AvailableDataBlockStore {
+	fn default() -> Self {
+		Self {
+			blocks: Arc::new(Mutex::new(LruMap::new(ByLength::new(
+				AVAILABLE_DATA_BLOCK_STORE_SIZE,
+			)))),
+		}
+	}
+}
+
+impl AvailableDataBlockStore {
+	/// Add blocks to the store. Every block has to be keyed by the hash of its content.
+	pub fn insert(&self, blocks: impl IntoIterator<Item = (Hash, Vec<u8>)>) {
+		let mut store = self.blocks.lock();
+		for (hash, block) in blocks {
+			store.insert(hash, block);
+		}
+	}
+
+	/// Get the block with the given hash.
+	pub fn get(&self, hash: &Hash) -> Option<Vec<u8>> {
+		self.blocks.lock().get(hash).cloned()
+	}
+
+	/// Whether the store has all the blocks with the given hashes.
+	pub fn contains_all<'a>(&self, hashes: impl IntoIterator<Item = &'a Hash>) -> bool {
+		let store = self.blocks.lock();
+		hashes.into_iter().all(|hash| store.peek(hash).is_some())
+	}
+}
+
+impl BitswapBlockProvider for AvailableDataBlockStore {
+	fn block(&self, hash: [u8; 32]) -> Result<Option<Vec<u8>>, BitswapError> {
+		Ok(self.get(&Hash::from(hash)))
+	}
+}
@@ -38,6 +38,9 @@ pub mod peer_set;
 /// Request/response protocols used in Polkadot.
 pub mod request_response;
 
+/// Blocks of available data served over bitswap.
+pub mod available_data_blocks;
+
 /// Accessing authority discovery service
 pub mod authority_discovery;
 /// Grid topology support module
@@ -100,6 +100,14 @@ pub enum Protocol {
 	/// Protocol for chunk fetching version 2, used by availability distribution and availability
 	/// recovery.
 	ChunkFetchingV2,
+
+	/// Protocol for fetching the manifest of the blocks the available data is split into, used by
+	/// availability distribution and availability recovery.
+	AvailableDataManifestV1,
+
+	/// Protocol for fetching content-addressed blocks of available data with bitswap messages,
+	/// used by availability distribution and availability recovery.
+	AvailableDataBitswapV1,
 }
 
 /// Minimum bandwidth we expect for validators - 500Mbit/s is the recommendation, so approximately
@@ -230,6 +238,26 @@ impl Protocol {
 				POV_REQUEST_TIMEOUT_CONNECTED,
 				tx,
 			),
+			Protocol::AvailableDataManifestV1 => N::request_response_config(
+				name,
+				legacy_names,
+				1_000,
+				// The manifest lists the hashes of at most a few thousand blocks.
+				100_000,
+				DEFAULT_REQUEST_TIMEOUT_CONNECTED,
+				tx,
+			),
+			Protocol::AvailableDataBitswapV1 => N::request_response_config(
+				name,
+				legacy_names,
+				// A wantlist of `MAX_WANTED_BLOCKS` CIDs.
+				4_096,
+				// Responses are bounded by the number of wanted blocks, which are way smaller than
+				// a full PoV.
+				POV_RESPONSE_SIZE,
+				POV_REQUEST_TIMEOUT_CONNECTED,
+				tx,
+			),
 			Protocol::DisputeSendingV1 => N::request_response_config(
 				name,
 				legacy_names,
@@ -267,6 +295,11 @@ impl Protocol {
 			// Validators are constantly self-selecting to request available data which may lead
 			// to constant load and occasional burstiness.
 			Protocol::AvailableDataFetchingV1 => 100,
+			// Every requester fetches the manifest of a candidate once, from a single backer.
+			Protocol::AvailableDataManifestV1 => 100,
+			// Requesters fetch blocks of the same candidate from several validators in parallel,
+			// resulting in more but smaller requests than for the full available data.
+			Protocol::AvailableDataBitswapV1 => 200,
 			// Incoming requests can get bursty, we should also be able to handle them fast on
 			// average, so something in the ballpark of 100 should be fine. Nodes will retry on
 			// failure, so having a good value here is mostly about performance tuning.
@@ -308,6 +341,8 @@ impl Protocol {
 			Protocol::AttestedCandidateV2 => None,
 			Protocol::CollationFetchingV2 => None,
 			Protocol::ChunkFetchingV2 => None,
+			Protocol::AvailableDataManifestV1 => None,
+			Protocol::AvailableDataBitswapV1 => None,
 		}
 	}
 }
@@ -364,6 +399,8 @@ impl ReqProtocolNames {
 			Protocol::PoVFetchingV1 => "/req_pov/1",
 			Protocol::AvailableDataFetchingV1 => "/req_available_data/1",
 			Protocol::DisputeSendingV1 => "/send_dispute/1",
+			Protocol::AvailableDataManifestV1 => "/req_available_data_manifest/1",
+			Protocol::AvailableDataBitswapV1 => "/req_available_data_bitswap/1",
 
 			// V2:
 			Protocol::CollationFetchingV2 => "/req_collation/2",
@@ -45,6 +45,10 @@ pub enum Requests {
 	/// Fetch a collation from a collator which previously announced it.
 	/// Compared to V1 it requires specifying which candidate is requested by its hash.
 	CollationFetchingV2(OutgoingRequest<v2::CollationFetchingRequest>),
+	/// Request the manifest of the blocks of the available data from a node.
+	AvailableDataManifestV1(OutgoingRequest<v1::AvailableDataManifestRequest>),
+	/// Request blocks of available data from a node.
+	AvailableDataBitswapV1(OutgoingRequest<v1::AvailableDataBitswapRequest>),
 }
 
 impl Requests {
@@ -64,6 +68,8 @@ impl Requests {
 			Self::AvailableDataFetchingV1(r) => r.encode_request(),
 			Self::DisputeSendingV1(r) => r.encode_request(),
 			Self::AttestedCandidateV2(r) => r.encode_request(),
+			Self::AvailableDataManifestV1(r) => r.encode_request(),
+			Self::AvailableDataBitswapV1(r) => r.encode_request(),
 		}
 	}
 }
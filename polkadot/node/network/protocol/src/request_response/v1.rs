@@ -19,7 +19,8 @@
 use codec::{Decode, Encode};
 
 use polkadot_node_primitives::{
-	AvailableData, DisputeMessage, ErasureChunk, PoV, Proof, UncheckedDisputeMessage,
+	available_data_blocks::AvailableDataManifest, AvailableData, DisputeMessage, ErasureChunk, PoV,
+	Proof, UncheckedDisputeMessage,
 };
 use polkadot_primitives::{
 	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, Hash, HeadData, Id as ParaId,
@@ -188,6 +189,52 @@ impl IsRequest for AvailableDataFetchingRequest {
 	const PROTOCOL: Protocol = Protocol::AvailableDataFetchingV1;
 }
 
+/// Request the manifest of the blocks the available data of a candidate is split into.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct AvailableDataManifestRequest {
+	/// The candidate hash to get the manifest for.
+	pub candidate_hash: CandidateHash,
+}
+
+/// Receive the requested manifest of the available data.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum AvailableDataManifestResponse {
+	/// The manifest of the available data.
+	#[codec(index = 0)]
+	Manifest(AvailableDataManifest),
+	/// Node was not in possession of the requested data.
+	#[codec(index = 1)]
+	NoSuchData,
+}
+
+impl IsRequest for AvailableDataManifestRequest {
+	type Response = AvailableDataManifestResponse;
+	const PROTOCOL: Protocol = Protocol::AvailableDataManifestV1;
+}
+
+/// Request blocks of available data by the hash of their content.
+///
+/// The blocks can be of any candidate, they are listed in the [`AvailableDataManifest`] of its
+/// available data.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct AvailableDataBitswapRequest {
+	/// A bitswap 1.2.0 message with the wantlist, see [`sc_network::bitswap::want_blocks`].
+	pub message: Vec<u8>,
+}
+
+/// Receive the requested blocks of available data.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct AvailableDataBitswapResponse {
+	/// A bitswap 1.2.0 message with the blocks the node has, see
+	/// [`sc_network::bitswap::received_blocks`].
+	pub message: Vec<u8>,
+}
+
+impl IsRequest for AvailableDataBitswapRequest {
+	type Response = AvailableDataBitswapResponse;
+	const PROTOCOL: Protocol = Protocol::AvailableDataBitswapV1;
+}
+
 /// A dispute request.
 ///
 /// Contains an invalid vote a valid one for a particular candidate in a given session.
@@ -0,0 +1,661 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Recording of the message traffic of subsystems.
+//!
+//! A [`RecordingSubsystem`] wraps a subsystem and records the signals and messages it receives,
+//! the messages it sends and the responses to all of them, including runtime API responses, to a
+//! [`Recorder`]. The recording can be replayed against the subsystem alone, see the `replay`
+//! module of `polkadot-node-subsystem-test-helpers`.
+//!
+//! Messages and responses are recorded in an encoding they can be restored from, see
+//! [`Recordable`]. Response channels are replaced by channels which record the response before
+//! forwarding it, see [`ResponseChannel`]. Signed and checked data is restored without checking it
+//! again, and collation generation configs can't be restored as their collator function can't be
+//! recorded.
+//!
+//! Events are written to the file by a dedicated thread, so recording them never blocks the
+//! recorded subsystems on I/O. If the thread falls more than [`RECORDING_QUEUE_SIZE`] events
+//! behind, further events are dropped until it catches up. The indices of the dropped events are
+//! missing from the recording, which can't be replayed then.
+
+use std::{
+	fmt::Debug,
+	fs,
+	io::{self, Write},
+	iter,
+	path::Path,
+	sync::{mpsc as std_mpsc, Arc, Mutex, OnceLock},
+	thread,
+};
+
+use codec::{Decode, Encode};
+use futures::{
+	channel::{mpsc, oneshot},
+	future::BoxFuture,
+	stream::FuturesUnordered,
+	FutureExt, StreamExt,
+};
+
+use polkadot_primitives::{BlockNumber, Hash};
+
+use crate::{
+	gen::SpawnedSubsystem, AllMessages, FromOrchestra, NormalPriority, OverseerSignal, Priority,
+	Subsystem, SubsystemContext, SubsystemError, SubsystemResult, SubsystemSender, TrySendError,
+};
+
+mod messages;
+mod recordable;
+
+pub use self::{messages::ResponseChannel, recordable::Recordable};
+
+const LOG_TARGET: &str = "parachain::overseer-recorder";
+
+/// Identifies a recording file.
+const RECORDING_MAGIC: [u8; 4] = *b"ovrc";
+
+/// The version of the recording format.
+const RECORDING_VERSION: u32 = 2;
+
+/// The number of events which may be queued for writing before further events are dropped.
+pub const RECORDING_QUEUE_SIZE: usize = 16 * 1024;
+
+/// A future resolving to the recorded response to a message, once it was sent.
+pub type ResponseFuture = BoxFuture<'static, Option<Vec<u8>>>;
+
+/// Records the encoded response to a message.
+pub type RecordResponse = Box<dyn FnOnce(Vec<u8>) + Send>;
+
+/// A signal, as recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum RecordedSignal {
+	/// Active leaves were updated.
+	ActiveLeaves {
+		/// The hash and number of the activated leaf.
+		activated: Option<(Hash, BlockNumber)>,
+		/// The hashes of the deactivated leaves.
+		deactivated: Vec<Hash>,
+	},
+	/// A block was finalized.
+	BlockFinalized(Hash, BlockNumber),
+	/// The subsystem was asked to conclude.
+	Conclude,
+}
+
+impl From<&OverseerSignal> for RecordedSignal {
+	fn from(signal: &OverseerSignal) -> Self {
+		match signal {
+			OverseerSignal::ActiveLeaves(update) => RecordedSignal::ActiveLeaves {
+				activated: update.activated.as_ref().map(|leaf| (leaf.hash, leaf.number)),
+				deactivated: update.deactivated.to_vec(),
+			},
+			OverseerSignal::BlockFinalized(hash, number) =>
+				RecordedSignal::BlockFinalized(*hash, *number),
+			OverseerSignal::Conclude => RecordedSignal::Conclude,
+		}
+	}
+}
+
+/// An event in the message traffic of a subsystem.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum RecordedEvent {
+	/// A signal was received by the subsystem.
+	Signal(RecordedSignal),
+	/// A message was received by the subsystem, see [`RecordableMessage::encode_message`].
+	Incoming(Vec<u8>),
+	/// A message was sent by the subsystem, see [`RecordableMessage::encode_message`].
+	Outgoing(Vec<u8>),
+	/// A message was responded to.
+	Response {
+		/// The index of the event of the message in the recording.
+		request: u64,
+		/// The encoded response.
+		response: Vec<u8>,
+	},
+}
+
+/// An entry of a recording.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct RecordEntry {
+	/// The index of the event in the recording.
+	pub index: u64,
+	/// The name of the subsystem the event was recorded for.
+	pub subsystem: String,
+	/// The recorded event.
+	pub event: RecordedEvent,
+}
+
+/// Writes the message traffic of subsystems to a file.
+///
+/// The recorder can be shared between subsystems, the events of all of them are written to the
+/// same recording in the order they are recorded.
+#[derive(Clone)]
+pub struct Recorder(Arc<Mutex<RecordQueue>>);
+
+/// The queue of events to be written, in the order of their indices.
+struct RecordQueue {
+	next_index: u64,
+	/// The number of events dropped since the writer fell behind.
+	dropped: u64,
+	writer: std_mpsc::SyncSender<WriterCommand>,
+}
+
+enum WriterCommand {
+	/// Write an entry to the recording.
+	Write(RecordEntry),
+	/// Acknowledge once all entries queued before are written.
+	Flush(std_mpsc::SyncSender<()>),
+}
+
+impl Recorder {
+	/// Create a recording at the given path, truncating an existing file.
+	///
+	/// Spawns the thread writing the recording, which exits once all clones of the recorder are
+	/// dropped.
+	pub fn create(path: &Path) -> io::Result<Self> {
+		let mut file = io::BufWriter::new(fs::File::create(path)?);
+		file.write_all(&RECORDING_MAGIC)?;
+		file.write_all(&RECORDING_VERSION.encode())?;
+		file.flush()?;
+
+		let (writer, commands) = std_mpsc::sync_channel(RECORDING_QUEUE_SIZE);
+		thread::Builder::new()
+			.name("overseer-recorder".into())
+			.spawn(move || write_recording(file, commands))?;
+
+		Ok(Recorder(Arc::new(Mutex::new(RecordQueue { next_index: 0, dropped: 0, writer }))))
+	}
+
+	/// Record an event of the given subsystem, returning the index of the event in the recording.
+	///
+	/// The event is dropped if the writer fell too far behind, so recording never blocks.
+	pub fn record(&self, subsystem: &str, event: RecordedEvent) -> u64 {
+		// Only the index is assigned and the entry queued under the lock, so the entries are
+		// written in the order of their indices.
+		let mut queue = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let index = queue.next_index;
+		queue.next_index += 1;
+
+		let entry = RecordEntry { index, subsystem: subsystem.into(), event };
+		match queue.writer.try_send(WriterCommand::Write(entry)) {
+			Ok(()) =>
+				if queue.dropped > 0 {
+					gum::warn!(
+						target: LOG_TARGET,
+						dropped = queue.dropped,
+						"Recording caught up, recorded events were dropped",
+					);
+					queue.dropped = 0;
+				},
+			Err(std_mpsc::TrySendError::Full(_)) => {
+				if queue.dropped == 0 {
+					gum::warn!(
+						target: LOG_TARGET,
+						subsystem,
+						"Recording fell behind, dropping recorded events",
+					);
+				}
+				queue.dropped += 1;
+			},
+			Err(std_mpsc::TrySendError::Disconnected(_)) => {
+				gum::warn!(target: LOG_TARGET, subsystem, "Recording stopped, dropping recorded event");
+			},
+		}
+
+		index
+	}
+
+	/// Wait until all events recorded so far are written to the file.
+	pub fn flush(&self) {
+		let (done, wait) = std_mpsc::sync_channel(1);
+		// Waiting for room in the queue must not block recording.
+		let writer = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).writer.clone();
+
+		if writer.send(WriterCommand::Flush(done)).is_ok() {
+			let _ = wait.recv();
+		}
+	}
+}
+
+/// Write the queued entries to the recording, until all recorders are dropped.
+fn write_recording(mut file: io::BufWriter<fs::File>, commands: std_mpsc::Receiver<WriterCommand>) {
+	while let Ok(command) = commands.recv() {
+		// Write everything queued in the meantime before flushing, so the recording survives the
+		// node being killed without a flush per entry.
+		let mut flushed = Vec::new();
+		for command in iter::once(command).chain(commands.try_iter()) {
+			match command {
+				WriterCommand::Write(entry) =>
+					if let Err(err) = file.write_all(&entry.encode()) {
+						gum::warn!(
+							target: LOG_TARGET,
+							?err,
+							subsystem = %entry.subsystem,
+							"Failed to write a recorded event",
+						);
+					},
+				WriterCommand::Flush(done) => flushed.push(done),
+			}
+		}
+
+		if let Err(err) = file.flush() {
+			gum::warn!(target: LOG_TARGET, ?err, "Failed to flush the recording");
+		}
+		for done in flushed {
+			let _ = done.send(());
+		}
+	}
+}
+
+/// Read a recording, with the entries in the order they were recorded.
+pub fn read_recording(path: &Path) -> io::Result<Vec<RecordEntry>> {
+	decode_recording(&fs::read(path)?)
+		.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+}
+
+fn decode_recording(mut input: &[u8]) -> Result<Vec<RecordEntry>, codec::Error> {
+	if !input.starts_with(&RECORDING_MAGIC) {
+		return Err("Not a recording of subsystem messages".into())
+	}
+	input = &input[RECORDING_MAGIC.len()..];
+	if u32::decode(&mut input)? != RECORDING_VERSION {
+		return Err("Unsupported recording version".into())
+	}
+
+	let mut entries = Vec::new();
+	while !input.is_empty() {
+		entries.push(RecordEntry::decode(&mut input)?);
+	}
+
+	Ok(entries)
+}
+
+/// A message type whose traffic can be recorded and replayed.
+pub trait RecordableMessage: Sized + Send + 'static {
+	/// Encode the message, recording only what is needed to restore its response channel.
+	fn encode_message(&self) -> Vec<u8>;
+
+	/// Tap the response channel of the message.
+	///
+	/// The response is passed to `record` in its encoded form before it is forwarded to the sender
+	/// of the message. The returned future needs to be polled for the response to be delivered.
+	fn tap_response(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>);
+
+	/// Decode a message encoded with [`RecordableMessage::encode_message`].
+	///
+	/// The returned future resolves to the encoded response, once the message is responded to.
+	fn decode_message(encoded: &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error>;
+
+	/// Respond to the message with an encoded response.
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error>;
+}
+
+/// Tap a response channel, see [`RecordableMessage::tap_response`].
+pub fn tap_response<T: Send + 'static>(
+	tx: oneshot::Sender<T>,
+	encode: impl FnOnce(&T) -> Vec<u8> + Send + 'static,
+	record: RecordResponse,
+) -> (oneshot::Sender<T>, BoxFuture<'static, ()>) {
+	let (tapped_tx, rx) = oneshot::channel();
+	let forward = async move {
+		if let Ok(response) = rx.await {
+			record(encode(&response));
+			let _ = tx.send(response);
+		}
+	};
+
+	(tapped_tx, forward.boxed())
+}
+
+/// Create a response channel for a decoded message, see [`RecordableMessage::decode_message`].
+pub fn capture_response<T: Send + 'static>(
+	encode: impl FnOnce(&T) -> Vec<u8> + Send + 'static,
+) -> (oneshot::Sender<T>, ResponseFuture) {
+	let (tx, rx) = oneshot::channel();
+	let response = async move { rx.await.ok().map(|response| encode(&response)) };
+
+	(tx, response.boxed())
+}
+
+/// The recording of the traffic of a single subsystem.
+#[derive(Clone)]
+struct Recording {
+	subsystem: &'static str,
+	recorder: Recorder,
+	responses: mpsc::UnboundedSender<BoxFuture<'static, ()>>,
+}
+
+/// The tapped response channel of a message.
+struct TappedResponse {
+	/// The index of the message in the recording, set once it is recorded.
+	request: Arc<OnceLock<u64>>,
+	/// Records the response and forwards it to the sender of the message.
+	forward: BoxFuture<'static, ()>,
+}
+
+impl Recording {
+	/// Encode a message for recording, tapping its response channel.
+	fn tap<M: RecordableMessage>(&self, msg: M) -> (M, Vec<u8>, Option<TappedResponse>) {
+		let encoded = msg.encode_message();
+		let request = Arc::new(OnceLock::new());
+		let record: RecordResponse = {
+			let request = request.clone();
+			let recorder = self.recorder.clone();
+			let subsystem = self.subsystem;
+			Box::new(move |response: Vec<u8>| {
+				// Responses to messages which weren't sent aren't recorded.
+				if let Some(request) = request.get() {
+					recorder
+						.record(subsystem, RecordedEvent::Response { request: *request, response });
+				}
+			})
+		};
+		let (msg, forward) = msg.tap_response(record);
+		let response = forward.map(|forward| TappedResponse { request, forward });
+
+		(msg, encoded, response)
+	}
+
+	fn tap_outgoing<OutgoingMessage>(
+		&self,
+		msg: OutgoingMessage,
+	) -> (OutgoingMessage, Vec<u8>, Option<TappedResponse>)
+	where
+		OutgoingMessage: TryFrom<AllMessages>,
+		<OutgoingMessage as TryFrom<AllMessages>>::Error: Debug,
+		AllMessages: From<OutgoingMessage>,
+	{
+		let (msg, recorded, response) = self.tap(AllMessages::from(msg));
+		let msg = OutgoingMessage::try_from(msg)
+			.expect("Tapping the response channel preserves the message type; qed");
+
+		(msg, recorded, response)
+	}
+
+	/// Record an event, and the response to it once it is sent.
+	fn record(&self, event: RecordedEvent, response: Option<TappedResponse>) {
+		let request = self.recorder.record(self.subsystem, event);
+		if let Some(response) = response {
+			let _ = response.request.set(request);
+			self.forward(response.forward);
+		}
+	}
+
+	/// Forward the response to a message which wasn't sent and thus isn't recorded.
+	fn forward_unrecorded(&self, response: Option<TappedResponse>) {
+		if let Some(response) = response {
+			self.forward(response.forward);
+		}
+	}
+
+	fn forward(&self, response: BoxFuture<'static, ()>) {
+		if self.responses.unbounded_send(response).is_err() {
+			gum::debug!(
+				target: LOG_TARGET,
+				subsystem = self.subsystem,
+				"Response forwarding stopped, dropping response",
+			);
+		}
+	}
+}
+
+/// Forward and record the responses to recorded messages.
+async fn forward_responses(mut responses: mpsc::UnboundedReceiver<BoxFuture<'static, ()>>) {
+	let mut pending = FuturesUnordered::new();
+	loop {
+		futures::select! {
+			response = responses.next() => match response {
+				Some(response) => pending.push(response),
+				None => break,
+			},
+			_ = pending.select_next_some() => {},
+		}
+	}
+
+	while pending.next().await.is_some() {}
+}
+
+/// A sender recording the messages sent by a subsystem.
+#[derive(Clone)]
+pub struct RecordingSender<Sender> {
+	inner: Sender,
+	recording: Recording,
+}
+
+#[async_trait::async_trait]
+impl<OutgoingMessage, Sender> SubsystemSender<OutgoingMessage> for RecordingSender<Sender>
+where
+	OutgoingMessage: TryFrom<AllMessages> + Send + 'static,
+	<OutgoingMessage as TryFrom<AllMessages>>::Error: Debug,
+	AllMessages: From<OutgoingMessage>,
+	Sender: SubsystemSender<OutgoingMessage>,
+{
+	async fn send_message(&mut self, msg: OutgoingMessage) {
+		self.send_message_with_priority::<NormalPriority>(msg).await
+	}
+
+	async fn send_message_with_priority<P: Priority>(&mut self, msg: OutgoingMessage) {
+		let (msg, recorded, response) = self.recording.tap_outgoing(msg);
+		self.inner.send_message_with_priority::<P>(msg).await;
+		self.recording.record(RecordedEvent::Outgoing(recorded), response);
+	}
+
+	fn try_send_message(
+		&mut self,
+		msg: OutgoingMessage,
+	) -> Result<(), TrySendError<OutgoingMessage>> {
+		self.try_send_message_with_priority::<NormalPriority>(msg)
+	}
+
+	fn try_send_message_with_priority<P: Priority>(
+		&mut self,
+		msg: OutgoingMessage,
+	) -> Result<(), TrySendError<OutgoingMessage>> {
+		let (msg, recorded, response) = self.recording.tap_outgoing(msg);
+		match self.inner.try_send_message_with_priority::<P>(msg) {
+			Ok(()) => {
+				self.recording.record(RecordedEvent::Outgoing(recorded), response);
+				Ok(())
+			},
+			Err(err) => {
+				// The message may be sent again, its response still needs to be forwarded.
+				self.recording.forward_unrecorded(response);
+				Err(err)
+			},
+		}
+	}
+
+	async fn send_messages<I>(&mut self, msgs: I)
+	where
+		I: IntoIterator<Item = OutgoingMessage> + Send,
+		I::IntoIter: Send,
+	{
+		for msg in msgs {
+			self.send_message(msg).await;
+		}
+	}
+
+	fn send_unbounded_message(&mut self, msg: OutgoingMessage) {
+		let (msg, recorded, response) = self.recording.tap_outgoing(msg);
+		self.inner.send_unbounded_message(msg);
+		self.recording.record(RecordedEvent::Outgoing(recorded), response);
+	}
+}
+
+/// A subsystem context recording the message traffic of the subsystem.
+pub struct RecordingContext<Context: SubsystemContext> {
+	inner: Context,
+	sender: RecordingSender<Context::Sender>,
+}
+
+impl<Context> RecordingContext<Context>
+where
+	Context: SubsystemContext<Signal = OverseerSignal, Error = SubsystemError>,
+	Context::Message: RecordableMessage,
+{
+	/// Wrap the context of the subsystem with the given name, recording its traffic to `recorder`.
+	pub fn new(mut inner: Context, subsystem: &'static str, recorder: Recorder) -> Self {
+		let (responses, pending) = mpsc::unbounded();
+		if let Err(err) = inner.spawn("message-recorder", forward_responses(pending).boxed()) {
+			gum::warn!(target: LOG_TARGET, ?err, subsystem, "Failed to spawn response forwarding");
+		}
+
+		let recording = Recording { subsystem, recorder, responses };
+		let sender = RecordingSender { inner: inner.sender().clone(), recording };
+
+		RecordingContext { inner, sender }
+	}
+
+	fn record_incoming(
+		&self,
+		msg: FromOrchestra<Context::Message, OverseerSignal>,
+	) -> FromOrchestra<Context::Message, OverseerSignal> {
+		let recording = &self.sender.recording;
+		match msg {
+			FromOrchestra::Signal(signal) => {
+				recording.record(RecordedEvent::Signal((&signal).into()), None);
+				FromOrchestra::Signal(signal)
+			},
+			FromOrchestra::Communication { msg } => {
+				let (msg, recorded, response) = recording.tap(msg);
+				recording.record(RecordedEvent::Incoming(recorded), response);
+				FromOrchestra::Communication { msg }
+			},
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl<Context> SubsystemContext for RecordingContext<Context>
+where
+	Context: SubsystemContext<Signal = OverseerSignal, Error = SubsystemError>,
+	Context::Message: RecordableMessage,
+	RecordingSender<Context::Sender>: SubsystemSender<Context::OutgoingMessages>,
+{
+	type Message = Context::Message;
+	type Signal = OverseerSignal;
+	type OutgoingMessages = Context::OutgoingMessages;
+	type Sender = RecordingSender<Context::Sender>;
+	type Error = SubsystemError;
+
+	async fn try_recv(
+		&mut self,
+	) -> Result<Option<FromOrchestra<Self::Message, OverseerSignal>>, ()> {
+		let msg = self.inner.try_recv().await?;
+		Ok(msg.map(|msg| self.record_incoming(msg)))
+	}
+
+	async fn recv(&mut self) -> SubsystemResult<FromOrchestra<Self::Message, OverseerSignal>> {
+		let msg = self.inner.recv().await?;
+		Ok(self.record_incoming(msg))
+	}
+
+	async fn recv_signal(&mut self) -> SubsystemResult<OverseerSignal> {
+		let signal = self.inner.recv_signal().await?;
+		self.sender.recording.record(RecordedEvent::Signal((&signal).into()), None);
+		Ok(signal)
+	}
+
+	fn spawn(
+		&mut self,
+		name: &'static str,
+		s: std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>,
+	) -> SubsystemResult<()> {
+		self.inner.spawn(name, s)
+	}
+
+	fn spawn_blocking(
+		&mut self,
+		name: &'static str,
+		s: std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>,
+	) -> SubsystemResult<()> {
+		self.inner.spawn_blocking(name, s)
+	}
+
+	fn sender(&mut self) -> &mut Self::Sender {
+		&mut self.sender
+	}
+}
+
+/// A subsystem whose message traffic is recorded, if a [`Recorder`] is given.
+pub struct RecordingSubsystem<Sub> {
+	subsystem: Sub,
+	name: &'static str,
+	recorder: Option<Recorder>,
+}
+
+impl<Sub> RecordingSubsystem<Sub> {
+	/// Wrap the subsystem with the given name. Its traffic is only recorded if `recorder` is set.
+	pub fn new(subsystem: Sub, name: &'static str, recorder: Option<Recorder>) -> Self {
+		RecordingSubsystem { subsystem, name, recorder }
+	}
+}
+
+impl<Context, Sub> Subsystem<Context, SubsystemError> for RecordingSubsystem<Sub>
+where
+	Context: SubsystemContext<Signal = OverseerSignal, Error = SubsystemError>,
+	Context::Message: RecordableMessage,
+	RecordingContext<Context>: SubsystemContext<Signal = OverseerSignal, Error = SubsystemError>,
+	Sub: Subsystem<Context, SubsystemError> + Subsystem<RecordingContext<Context>, SubsystemError>,
+{
+	fn start(self, ctx: Context) -> SpawnedSubsystem<SubsystemError> {
+		match self.recorder {
+			Some(recorder) => Subsystem::<RecordingContext<Context>, SubsystemError>::start(
+				self.subsystem,
+				RecordingContext::new(ctx, self.name, recorder),
+			),
+			None => Subsystem::<Context, SubsystemError>::start(self.subsystem, ctx),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn recording_round_trip() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("recording");
+		let recorder = Recorder::create(&path).unwrap();
+
+		let events = vec![
+			RecordedEvent::Signal(RecordedSignal::ActiveLeaves {
+				activated: Some((Hash::repeat_byte(1), 1)),
+				deactivated: vec![Hash::repeat_byte(2)],
+			}),
+			RecordedEvent::Incoming(vec![1, 2, 3]),
+			RecordedEvent::Response { request: 1, response: vec![1, 2, 3] },
+		];
+		for (index, event) in events.iter().enumerate() {
+			assert_eq!(recorder.record("subsystem", event.clone()), index as u64);
+		}
+		recorder.flush();
+
+		let entries = read_recording(&path).unwrap();
+		assert_eq!(entries.iter().map(|entry| entry.index).collect::<Vec<_>>(), vec![0, 1, 2]);
+		assert_eq!(entries.into_iter().map(|entry| entry.event).collect::<Vec<_>>(), events);
+	}
+
+	#[test]
+	fn rejects_unknown_files() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("recording");
+		fs::write(&path, b"not a recording").unwrap();
+
+		assert_eq!(read_recording(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+}
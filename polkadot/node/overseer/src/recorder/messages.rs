@@ -0,0 +1,1260 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The recording of the messages of all subsystems, and of their response channels.
+
+use std::{
+	fmt, mem,
+	sync::{Arc, Mutex},
+};
+
+use codec::{Decode, Encode};
+use futures::{
+	channel::oneshot,
+	future::{self, BoxFuture},
+	FutureExt,
+};
+
+use polkadot_node_network_protocol::request_response::{
+	IsRequest, OutgoingRequest, Recipient, Requests,
+};
+use polkadot_node_primitives::{BabeEpoch, SubmitCollationParams};
+use polkadot_node_subsystem_types::{
+	errors::{ChainApiError, RecoveryError, RuntimeApiError},
+	messages::{
+		network_bridge_event::NewGossipTopology, ApprovalCheckError, ApprovalCheckResult,
+		ApprovalDistributionMessage, ApprovalDuty, ApprovalDutyOutcome, ApprovalVotingMessage,
+		ApprovalVotingParallelMessage, AssignmentCheckError, AssignmentCheckResult,
+		AvailabilityDistributionMessage, AvailabilityRecoveryMessage, AvailabilityStoreMessage,
+		BackingDuty, BitfieldDistributionMessage, BitfieldDuty, BlockDescription, CanSecondRequest,
+		CandidateBackingMessage, CandidateValidationMessage, ChainApiMessage,
+		ChainSelectionMessage, CheckedIndirectAssignment, CheckedIndirectSignedApprovalVote,
+		CollationGenerationMessage, CollatorProtocolMessage, DisputeCoordinatorMessage,
+		DisputeDistributionMessage, DisputeDuty, FragmentChainCandidate, FragmentChainConstraints,
+		FragmentChainState, GossipSupportMessage, HighestApprovedAncestorBlock,
+		HypotheticalCandidate, HypotheticalMembershipRequest, ImportStatementsResult,
+		IntroduceSecondedCandidateRequest, NetworkBridgeEvent, NetworkBridgeRxMessage,
+		NetworkBridgeTxMessage, PreCheckOutcome, ProspectiveParachainsMessage,
+		ProspectiveValidationDataRequest, ProvisionableData, ProvisionerInherentData,
+		ProvisionerMessage, PvfExecKind, ReportPeerMessage, RuntimeApiMessage, RuntimeApiRequest,
+		StatementDistributionMessage, StoreAvailableDataError, ValidationFailed,
+	},
+};
+use polkadot_primitives::{
+	async_backing, slashing,
+	vstaging::{
+		self, async_backing::Constraints, CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+		CoreState,
+	},
+	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
+	CoreIndex, DisputeState, ExecutorParams, GroupRotationInfo, Hash, Id as ParaId,
+	InboundDownwardMessage, InboundHrmpMessage, NodeFeatures, OccupiedCoreAssumption,
+	PersistedValidationData, PvfCheckStatement, SessionIndex, SessionInfo, ValidationCode,
+	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
+};
+
+use super::{
+	capture_response,
+	recordable::{recordable_as_scale, recordable_enum, recordable_struct},
+	tap_response, RecordResponse, Recordable, RecordableMessage, ResponseFuture,
+};
+use crate::{
+	messages::{BitfieldSigningMessage, PvfCheckerMessage},
+	AllMessages,
+};
+
+/// The response channels of a message, or a part of a message holding response channels.
+pub trait ResponseChannel: Sized {
+	/// Append what is needed to restore the channel to `dest`, like whether it is set at all.
+	fn record_to(&self, _dest: &mut Vec<u8>) {}
+
+	/// Tap the channel, see [`RecordableMessage::tap_response`].
+	fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>);
+
+	/// Restore a channel recorded with [`ResponseChannel::record_to`], see
+	/// [`RecordableMessage::decode_message`].
+	fn capture(input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error>;
+
+	/// Send a recorded response through the channel.
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error>;
+}
+
+impl<T: Recordable + Send + 'static> ResponseChannel for oneshot::Sender<T> {
+	fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+		let (tx, forward) = tap_response(self, T::record, record);
+		(tx, Some(forward))
+	}
+
+	fn capture(_input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+		let (tx, response) = capture_response(T::record);
+		Ok((tx, Some(response)))
+	}
+
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+		let _ = self.send(T::restore(&mut &response[..])?);
+		Ok(())
+	}
+}
+
+impl<C: ResponseChannel> ResponseChannel for Option<C> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.is_some().record_to(dest);
+		if let Some(channel) = self {
+			channel.record_to(dest);
+		}
+	}
+
+	fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+		match self {
+			Some(channel) => {
+				let (channel, forward) = channel.tap(record);
+				(Some(channel), forward)
+			},
+			None => (None, None),
+		}
+	}
+
+	fn capture(input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+		if !bool::restore(input)? {
+			return Ok((None, None))
+		}
+
+		let (channel, response) = C::capture(input)?;
+		Ok((Some(channel), response))
+	}
+
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+		self.map_or(Ok(()), |channel| channel.respond(response))
+	}
+}
+
+/// The responses to the channels are recorded together, once all of them were responded to or
+/// dropped.
+impl<C: ResponseChannel> ResponseChannel for Vec<C> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.len().record_to(dest);
+		self.iter().for_each(|channel| channel.record_to(dest));
+	}
+
+	fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+		let responses = Arc::new(Mutex::new(vec![None; self.len()]));
+		let mut forwards = Vec::new();
+		let channels = self
+			.into_iter()
+			.enumerate()
+			.map(|(index, channel)| {
+				let responses = responses.clone();
+				let (channel, forward) = channel.tap(Box::new(move |response| {
+					responses.lock().unwrap_or_else(|poisoned| poisoned.into_inner())[index] =
+						Some(response);
+				}));
+				forwards.extend(forward);
+				channel
+			})
+			.collect();
+
+		let forward = async move {
+			future::join_all(forwards).await;
+			let responses: Vec<Option<Vec<u8>>> =
+				mem::take(&mut *responses.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+			record(responses.record());
+		};
+
+		(channels, Some(forward.boxed()))
+	}
+
+	fn capture(input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+		let len = usize::restore(input)?;
+		let mut responses = Vec::new();
+		let channels = (0..len)
+			.map(|_| {
+				let (channel, response) = C::capture(input)?;
+				responses.push(response);
+				Ok(channel)
+			})
+			.collect::<Result<Vec<_>, codec::Error>>()?;
+
+		let response = async move {
+			let responses = responses.into_iter().map(|response| async move {
+				match response {
+					Some(response) => response.await,
+					None => None,
+				}
+			});
+			Some(future::join_all(responses).await.record())
+		};
+
+		Ok((channels, Some(response.boxed())))
+	}
+
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+		let responses = Vec::<Option<Vec<u8>>>::restore(&mut &response[..])?;
+		if responses.len() != self.len() {
+			return Err("Recorded responses don't match the channels".into())
+		}
+
+		// Channels which weren't responded to when recording are dropped.
+		for (channel, response) in self.into_iter().zip(responses) {
+			if let Some(response) = response {
+				channel.respond(&response)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// The protocol of the fallback request is given by its type, so it isn't recorded.
+impl<Req, FallbackReq> ResponseChannel for OutgoingRequest<Req, FallbackReq>
+where
+	Req: Recordable,
+	FallbackReq: Recordable + IsRequest,
+{
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.peer.record_to(dest);
+		self.payload.record_to(dest);
+		match &self.fallback_request {
+			Some((request, _)) => {
+				true.record_to(dest);
+				request.record_to(dest);
+			},
+			None => false.record_to(dest),
+		}
+	}
+
+	fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+		let OutgoingRequest { peer, payload, fallback_request, pending_response } = self;
+		let (pending_response, forward) = pending_response.tap(record);
+		(OutgoingRequest { peer, payload, fallback_request, pending_response }, forward)
+	}
+
+	fn capture(input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+		let peer = Recipient::restore(input)?;
+		let payload = Req::restore(input)?;
+		let fallback_request =
+			Option::<FallbackReq>::restore(input)?.map(|request| (request, FallbackReq::PROTOCOL));
+		let (pending_response, response) = ResponseChannel::capture(input)?;
+		Ok((OutgoingRequest { peer, payload, fallback_request, pending_response }, response))
+	}
+
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+		self.pending_response.respond(response)
+	}
+}
+
+macro_rules! recordable_requests {
+	($($index:literal => $variant:ident,)*) => {
+		impl ResponseChannel for Requests {
+			fn record_to(&self, dest: &mut Vec<u8>) {
+				match self {
+					$(Requests::$variant(request) => {
+						let index: u8 = $index;
+						index.record_to(dest);
+						ResponseChannel::record_to(request, dest);
+					},)*
+				}
+			}
+
+			fn tap(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+				match self {
+					$(Requests::$variant(request) => {
+						let (request, forward) = request.tap(record);
+						(Requests::$variant(request), forward)
+					},)*
+				}
+			}
+
+			fn capture(
+				input: &mut &[u8],
+			) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+				match u8::restore(input)? {
+					$($index => {
+						let (request, response) = ResponseChannel::capture(input)?;
+						Ok((Requests::$variant(request), response))
+					},)*
+					_ => Err("Unknown request".into()),
+				}
+			}
+
+			fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+				match self {
+					$(Requests::$variant(request) => request.respond(response),)*
+				}
+			}
+		}
+	};
+}
+
+recordable_requests! {
+	0 => ChunkFetching,
+	1 => CollationFetchingV1,
+	2 => PoVFetchingV1,
+	3 => AvailableDataFetchingV1,
+	4 => DisputeSendingV1,
+	5 => AttestedCandidateV2,
+	6 => CollationFetchingV2,
+	7 => AvailableDataManifestV1,
+	8 => AvailableDataBitswapV1,
+}
+
+impl ResponseChannel for SubmitCollationParams {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.relay_parent.record_to(dest);
+		self.collation.record_to(dest);
+		self.parent_head.record_to(dest);
+		self.validation_code_hash.record_to(dest);
+		self.core_index.record_to(dest);
+		ResponseChannel::record_to(&self.result_sender, dest);
+	}
+
+	fn tap(mut self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+		let (result_sender, forward) = self.result_sender.take().tap(record);
+		self.result_sender = result_sender;
+		(self, forward)
+	}
+
+	fn capture(input: &mut &[u8]) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+		let relay_parent = Recordable::restore(input)?;
+		let collation = Recordable::restore(input)?;
+		let parent_head = Recordable::restore(input)?;
+		let validation_code_hash = Recordable::restore(input)?;
+		let core_index = Recordable::restore(input)?;
+		let (result_sender, response) = ResponseChannel::capture(input)?;
+		let params = SubmitCollationParams {
+			relay_parent,
+			collation,
+			parent_head,
+			validation_code_hash,
+			result_sender,
+			core_index,
+		};
+
+		Ok((params, response))
+	}
+
+	fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+		self.result_sender.respond(response)
+	}
+}
+
+recordable_as_scale! {
+	IntroduceSecondedCandidateRequest,
+	HypotheticalCandidate,
+	HypotheticalMembershipRequest,
+	ProspectiveValidationDataRequest,
+}
+
+recordable_struct!(CanSecondRequest {
+	candidate_para_id,
+	candidate_relay_parent,
+	candidate_hash,
+	parent_head_data_hash,
+});
+
+recordable_struct!(BackingDuty { candidate_hash, para_id, relay_parent, seconded });
+
+impl Recordable for ValidationFailed {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.0.record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		String::restore(input).map(ValidationFailed)
+	}
+}
+
+recordable_enum!(PreCheckOutcome {
+	0 => Valid,
+	1 => Invalid,
+	2 => Failed,
+});
+
+recordable_enum!(PvfExecKind {
+	0 => Dispute,
+	1 => Approval,
+	2 => BackingSystemParas(relay_parent),
+	3 => Backing(relay_parent),
+});
+
+recordable_struct!(DisputeDuty { candidate_hash, status, vote });
+
+recordable_enum!(ImportStatementsResult {
+	0 => InvalidImport,
+	1 => ValidImport,
+});
+
+recordable_struct!(BlockDescription { block_hash, session, candidates });
+
+recordable_enum!(ReportPeerMessage {
+	0 => Single(peer, rep),
+	1 => Batch(changes),
+});
+
+recordable_struct!(BitfieldDuty { relay_parent, available_cores, cores });
+
+recordable_enum!(StoreAvailableDataError {
+	0 => InvalidErasureRoot,
+});
+
+impl Recordable for ChainApiError {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.to_string().record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		String::restore(input).map(ChainApiError::from)
+	}
+}
+
+recordable_enum!(RecoveryError {
+	0 => Invalid,
+	1 => Unavailable,
+	2 => ChannelClosed,
+});
+
+recordable_enum!(ProvisionableData {
+	0 => Bitfield(relay_parent, bitfield),
+	1 => MisbehaviorReport(relay_parent, validator, misbehavior),
+	2 => Dispute(relay_parent, signature),
+});
+
+recordable_struct!(ProvisionerInherentData { bitfields, backed_candidates, disputes });
+
+recordable_enum!(AssignmentCheckResult {
+	0 => Accepted,
+	1 => AcceptedDuplicate,
+	2 => TooFarInFuture,
+	3 => Bad(err),
+});
+
+recordable_enum!(AssignmentCheckError {
+	0 => UnknownBlock(hash),
+	1 => UnknownSessionIndex(session),
+	2 => InvalidCandidateIndex(index),
+	3 => InvalidCandidate(index, candidate_hash),
+	4 => InvalidCert(validator, reason),
+	5 => Internal(hash, candidate_hash),
+	6 => InvalidBitfield(size),
+});
+
+recordable_enum!(ApprovalCheckResult {
+	0 => Accepted,
+	1 => Bad(err),
+});
+
+recordable_enum!(ApprovalCheckError {
+	0 => UnknownBlock(hash),
+	1 => UnknownSessionIndex(session),
+	2 => InvalidCandidateIndex(index),
+	3 => InvalidValidatorIndex(validator),
+	4 => InvalidCandidate(index, candidate_hash),
+	5 => InvalidSignature(validator),
+	6 => NoAssignment(validator),
+	7 => Internal(hash, candidate_hash),
+});
+
+recordable_struct!(HighestApprovedAncestorBlock { hash, number, descriptions });
+
+/// Checked assignments are restored without checking them again, like signed data.
+impl Recordable for CheckedIndirectAssignment {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.assignment().record_to(dest);
+		self.candidate_indices().record_to(dest);
+		self.tranche().record_to(dest);
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Ok(CheckedIndirectAssignment::from_checked(
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+		))
+	}
+}
+
+impl Recordable for CheckedIndirectSignedApprovalVote {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		(**self).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Recordable::restore(input).map(CheckedIndirectSignedApprovalVote::from_checked)
+	}
+}
+
+recordable_enum!(ApprovalDutyOutcome {
+	0 => Pending,
+	1 => Approved,
+	2 => NoShow,
+});
+
+recordable_struct!(ApprovalDuty { candidate_hash, block_hash, tranche, outcome });
+
+recordable_struct!(FragmentChainState {
+	leaf,
+	para_id,
+	earliest_relay_parent,
+	max_backable_len,
+	constraints,
+	pending_availability,
+	best_chain,
+	unconnected,
+	claim_queue,
+});
+
+recordable_struct!(FragmentChainConstraints {
+	min_relay_parent_number,
+	max_pov_size,
+	max_code_size,
+	max_head_data_size,
+	required_parent,
+	validation_code_hash,
+	upgrade_restriction,
+	future_validation_code,
+});
+
+recordable_struct!(FragmentChainCandidate {
+	candidate_hash,
+	relay_parent,
+	parent_head_data_hash,
+	output_head_data_hash,
+	backed,
+});
+
+recordable_struct!(NewGossipTopology { session, topology, local_index });
+
+impl<M: Recordable> Recordable for NetworkBridgeEvent<M> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			NetworkBridgeEvent::PeerConnected(peer, role, version, authority_ids) => {
+				0u8.record_to(dest);
+				(*peer, *role, *version).record_to(dest);
+				authority_ids.record_to(dest);
+			},
+			NetworkBridgeEvent::PeerDisconnected(peer) => {
+				1u8.record_to(dest);
+				peer.record_to(dest);
+			},
+			NetworkBridgeEvent::NewGossipTopology(topology) => {
+				2u8.record_to(dest);
+				topology.record_to(dest);
+			},
+			NetworkBridgeEvent::PeerMessage(peer, msg) => {
+				3u8.record_to(dest);
+				peer.record_to(dest);
+				msg.record_to(dest);
+			},
+			NetworkBridgeEvent::PeerViewChange(peer, view) => {
+				4u8.record_to(dest);
+				peer.record_to(dest);
+				view.record_to(dest);
+			},
+			NetworkBridgeEvent::OurViewChange(view) => {
+				5u8.record_to(dest);
+				view.record_to(dest);
+			},
+			NetworkBridgeEvent::UpdatedAuthorityIds(peer, authority_ids) => {
+				6u8.record_to(dest);
+				peer.record_to(dest);
+				authority_ids.record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Ok(match u8::restore(input)? {
+			0 => {
+				let (peer, role, version) = Recordable::restore(input)?;
+				NetworkBridgeEvent::PeerConnected(peer, role, version, Recordable::restore(input)?)
+			},
+			1 => NetworkBridgeEvent::PeerDisconnected(Recordable::restore(input)?),
+			2 => NetworkBridgeEvent::NewGossipTopology(Recordable::restore(input)?),
+			3 => NetworkBridgeEvent::PeerMessage(
+				Recordable::restore(input)?,
+				Recordable::restore(input)?,
+			),
+			4 => NetworkBridgeEvent::PeerViewChange(
+				Recordable::restore(input)?,
+				Recordable::restore(input)?,
+			),
+			5 => NetworkBridgeEvent::OurViewChange(Recordable::restore(input)?),
+			6 => NetworkBridgeEvent::UpdatedAuthorityIds(
+				Recordable::restore(input)?,
+				Recordable::restore(input)?,
+			),
+			_ => return Err("Unknown network bridge event".into()),
+		})
+	}
+}
+
+/// Tap the response channel of a variant, if it has one.
+macro_rules! tap_variant {
+	(
+		$record:ident, $msg:ident, $variant:ident { $($field:tt: $binding:ident),* }
+		-> $channel_field:tt: $channel:ident
+	) => {{
+		let ($channel, forward) = ResponseChannel::tap($channel, $record);
+		($msg::$variant { $($field: $binding,)* $channel_field: $channel }, forward)
+	}};
+	($record:ident, $msg:ident, $variant:ident { $($field:tt: $binding:ident),* }) => {{
+		drop($record);
+		($msg::$variant { $($field: $binding,)* }, None)
+	}};
+}
+
+/// Restore a variant from its restored fields, capturing its response channel if it has one.
+macro_rules! restore_variant {
+	(
+		$input:ident, $msg:ident, $variant:ident { $($field:tt: $binding:ident),* }
+		-> $channel_field:tt: $channel:ident
+	) => {{
+		let ($channel, response) = ResponseChannel::capture($input)?;
+		Ok(($msg::$variant { $($field: $binding,)* $channel_field: $channel }, response))
+	}};
+	($input:ident, $msg:ident, $variant:ident { $($field:tt: $binding:ident),* }) => {
+		Ok(($msg::$variant { $($field: $binding,)* }, None))
+	};
+}
+
+/// Respond through the response channel of a variant, if it has one.
+macro_rules! respond_variant {
+	($response:ident, $channel:ident) => {
+		ResponseChannel::respond($channel, $response)
+	};
+	($response:ident) => {{
+		let _ = $response;
+		Ok(())
+	}};
+}
+
+/// Record a message as the index of its variant, followed by the fields of the variant and the
+/// response channel, if any, which is given after the arrow.
+macro_rules! recordable_messages {
+	($msg:ident {
+		$(
+			$index:literal => $variant:ident { $($field:tt: $binding:ident),* $(,)? }
+			$(-> $channel_field:tt: $channel:ident)?,
+		)*
+	}) => {
+		impl RecordableMessage for $msg {
+			fn encode_message(&self) -> Vec<u8> {
+				let mut dest = Vec::new();
+				match self {
+					$($msg::$variant { $($field: $binding,)* $($channel_field: $channel,)? } => {
+						let index: u8 = $index;
+						index.record_to(&mut dest);
+						$(Recordable::record_to($binding, &mut dest);)*
+						$(ResponseChannel::record_to($channel, &mut dest);)?
+					},)*
+				}
+
+				dest
+			}
+
+			fn tap_response(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+				match self {
+					$($msg::$variant { $($field: $binding,)* $($channel_field: $channel,)? } =>
+						tap_variant!(
+							record, $msg, $variant { $($field: $binding),* }
+							$(-> $channel_field: $channel)?
+						),)*
+				}
+			}
+
+			fn decode_message(
+				encoded: &[u8],
+			) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+				let input = &mut &encoded[..];
+				match u8::restore(input)? {
+					$($index => {
+						$(let $binding = Recordable::restore(input)?;)*
+						restore_variant!(
+							input, $msg, $variant { $($field: $binding),* }
+							$(-> $channel_field: $channel)?
+						)
+					},)*
+					_ => Err(concat!("Unknown variant of ", stringify!($msg)).into()),
+				}
+			}
+
+			fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+				match self {
+					$($msg::$variant { $($field: _,)* $($channel_field: $channel,)? } =>
+						respond_variant!(response $(, $channel)?),)*
+				}
+			}
+		}
+	};
+}
+
+recordable_messages!(CandidateValidationMessage {
+	0 => ValidateFromExhaustive {
+		validation_data: validation_data,
+		validation_code: validation_code,
+		candidate_receipt: candidate_receipt,
+		pov: pov,
+		executor_params: executor_params,
+		exec_kind: exec_kind,
+	} -> response_sender: response_sender,
+	1 => PreCheck {
+		relay_parent: relay_parent,
+		validation_code_hash: validation_code_hash,
+	} -> response_sender: response_sender,
+});
+
+recordable_messages!(CandidateBackingMessage {
+	0 => GetBackableCandidates { 0: candidates } -> 1: tx,
+	1 => CanSecond { 0: request } -> 1: tx,
+	2 => Second { 0: relay_parent, 1: receipt, 2: persisted_validation_data, 3: pov },
+	3 => Statement { 0: relay_parent, 1: statement },
+	4 => GetBackingDuties { 0: session } -> 1: tx,
+});
+
+recordable_messages!(StatementDistributionMessage {
+	0 => Share { 0: relay_parent, 1: statement },
+	1 => Backed { 0: candidate_hash },
+	2 => NetworkBridgeUpdate { 0: event },
+});
+
+recordable_messages!(AvailabilityDistributionMessage {
+	0 => FetchPoV {
+		relay_parent: relay_parent,
+		from_validator: from_validator,
+		para_id: para_id,
+		candidate_hash: candidate_hash,
+		pov_hash: pov_hash,
+	} -> tx: tx,
+});
+
+recordable_messages!(AvailabilityRecoveryMessage {
+	0 => RecoverAvailableData { 0: receipt, 1: session, 2: backing_group, 3: core_index } -> 4: tx,
+});
+
+recordable_messages!(BitfieldDistributionMessage {
+	0 => DistributeBitfield { 0: relay_parent, 1: bitfield },
+	1 => GetBitfieldDuties { 0: session } -> 1: tx,
+	2 => NetworkBridgeUpdate { 0: event },
+});
+
+recordable_messages!(ProvisionerMessage {
+	0 => RequestInherentData { 0: relay_parent } -> 1: tx,
+	1 => ProvisionableData { 0: relay_parent, 1: data },
+});
+
+recordable_messages!(AvailabilityStoreMessage {
+	0 => QueryAvailableData { 0: candidate_hash } -> 1: tx,
+	1 => QueryDataAvailability { 0: candidate_hash } -> 1: tx,
+	2 => QueryChunk { 0: candidate_hash, 1: validator_index } -> 2: tx,
+	3 => QueryChunkSize { 0: candidate_hash } -> 1: tx,
+	4 => QueryAllChunks { 0: candidate_hash } -> 1: tx,
+	5 => QueryChunkAvailability { 0: candidate_hash, 1: validator_index } -> 2: tx,
+	6 => StoreChunk {
+		candidate_hash: candidate_hash,
+		validator_index: validator_index,
+		chunk: chunk,
+	} -> tx: tx,
+	7 => StoreAvailableData {
+		candidate_hash: candidate_hash,
+		n_validators: n_validators,
+		available_data: available_data,
+		expected_erasure_root: expected_erasure_root,
+		core_index: core_index,
+		node_features: node_features,
+	} -> tx: tx,
+});
+
+recordable_messages!(NetworkBridgeRxMessage {
+	0 => NewGossipTopology {
+		session: session,
+		local_index: local_index,
+		canonical_shuffling: canonical_shuffling,
+		shuffled_indices: shuffled_indices,
+	},
+	1 => UpdatedAuthorityIds { peer_id: peer_id, authority_ids: authority_ids },
+});
+
+recordable_messages!(NetworkBridgeTxMessage {
+	0 => ReportPeer { 0: report },
+	1 => DisconnectPeers { 0: peers, 1: peer_set },
+	2 => SendValidationMessage { 0: peers, 1: msg },
+	3 => SendCollationMessage { 0: peers, 1: msg },
+	4 => SendValidationMessages { 0: msgs },
+	5 => SendCollationMessages { 0: msgs },
+	6 => SendRequests { 1: if_disconnected } -> 0: requests,
+	7 => ConnectToValidators { validator_ids: validator_ids, peer_set: peer_set } -> failed: failed,
+	8 => ConnectToResolvedValidators { validator_addrs: validator_addrs, peer_set: peer_set },
+	9 => AddToResolvedValidators { validator_addrs: validator_addrs, peer_set: peer_set },
+});
+
+recordable_messages!(ChainApiMessage {
+	0 => BlockNumber { 0: hash } -> 1: tx,
+	1 => BlockHeader { 0: hash } -> 1: tx,
+	2 => BlockWeight { 0: hash } -> 1: tx,
+	3 => FinalizedBlockHash { 0: number } -> 1: tx,
+	4 => FinalizedBlockNumber {} -> 0: tx,
+	5 => Ancestors { hash: hash, k: k } -> response_channel: response_channel,
+});
+
+recordable_messages!(CollationGenerationMessage {
+	0 => Initialize { 0: config },
+	1 => Reinitialize { 0: config },
+	2 => SubmitCollation {} -> 0: params,
+});
+
+recordable_messages!(CollatorProtocolMessage {
+	0 => CollateOn { 0: para_id },
+	1 => DistributeCollation {
+		candidate_receipt: candidate_receipt,
+		parent_head_data_hash: parent_head_data_hash,
+		pov: pov,
+		parent_head_data: parent_head_data,
+		core_index: core_index,
+	} -> result_sender: result_sender,
+	2 => NetworkBridgeUpdate { 0: event },
+	3 => Invalid { 0: relay_parent, 1: receipt },
+	4 => Seconded { 0: relay_parent, 1: statement },
+});
+
+recordable_messages!(ApprovalDistributionMessage {
+	0 => NewBlocks { 0: blocks },
+	1 => DistributeAssignment { 0: assignment, 1: claimed_candidates },
+	2 => DistributeApproval { 0: vote },
+	3 => NetworkBridgeUpdate { 0: event },
+	4 => GetApprovalSignatures { 0: candidates } -> 1: tx,
+	5 => ApprovalCheckingLagUpdate { 0: lag },
+});
+
+recordable_messages!(ApprovalVotingMessage {
+	0 => ImportAssignment { 0: assignment } -> 1: tx,
+	1 => ImportApproval { 0: vote } -> 1: tx,
+	2 => ApprovedAncestor { 0: hash, 1: number } -> 2: tx,
+	3 => GetApprovalSignaturesForCandidate { 0: candidate_hash } -> 1: tx,
+	4 => GetApprovalDuties { 0: session } -> 1: tx,
+});
+
+recordable_messages!(ApprovalVotingParallelMessage {
+	0 => ApprovedAncestor { 0: hash, 1: number } -> 2: tx,
+	1 => GetApprovalSignaturesForCandidate { 0: candidate_hash } -> 1: tx,
+	2 => NewBlocks { 0: blocks },
+	3 => DistributeAssignment { 0: assignment, 1: claimed_candidates },
+	4 => DistributeApproval { 0: vote },
+	5 => NetworkBridgeUpdate { 0: event },
+	6 => GetApprovalSignatures { 0: candidates } -> 1: tx,
+	7 => ApprovalCheckingLagUpdate { 0: lag },
+	8 => GetApprovalDuties { 0: session } -> 1: tx,
+});
+
+recordable_messages!(GossipSupportMessage {
+	0 => NetworkBridgeUpdate { 0: event },
+});
+
+recordable_messages!(DisputeCoordinatorMessage {
+	0 => ImportStatements {
+		candidate_receipt: candidate_receipt,
+		session: session,
+		statements: statements,
+	} -> pending_confirmation: pending_confirmation,
+	1 => RecentDisputes {} -> 0: tx,
+	2 => ActiveDisputes {} -> 0: tx,
+	3 => QueryCandidateVotes { 0: queries } -> 1: tx,
+	4 => IssueLocalStatement { 0: session, 1: candidate_hash, 2: receipt, 3: valid },
+	5 => DetermineUndisputedChain {
+		base: base,
+		block_descriptions: block_descriptions,
+	} -> tx: tx,
+	6 => GetDisputeDuties { 0: session } -> 1: tx,
+});
+
+recordable_messages!(DisputeDistributionMessage {
+	0 => SendDispute { 0: msg },
+});
+
+recordable_messages!(ChainSelectionMessage {
+	0 => Approved { 0: hash },
+	1 => Leaves {} -> 0: tx,
+	2 => BestLeafContaining { 0: hash } -> 1: tx,
+	3 => RevertBlocks { 0: blocks },
+});
+
+recordable_messages!(ProspectiveParachainsMessage {
+	0 => IntroduceSecondedCandidate { 0: request } -> 1: tx,
+	1 => CandidateBacked { 0: para_id, 1: candidate_hash },
+	2 => GetBackableCandidates { 0: relay_parent, 1: para_id, 2: count, 3: ancestors } -> 4: tx,
+	3 => GetHypotheticalMembership { 0: request } -> 1: tx,
+	4 => GetMinimumRelayParents { 0: relay_parent } -> 1: tx,
+	5 => GetProspectiveValidationData { 0: request } -> 1: tx,
+	6 => GetFragmentChainsState { 0: para_id } -> 1: tx,
+});
+
+/// Messages of subsystems which don't receive any.
+macro_rules! recordable_void_messages {
+	($($msg:ident),* $(,)?) => {
+		$(
+			impl RecordableMessage for $msg {
+				fn encode_message(&self) -> Vec<u8> {
+					match *self {}
+				}
+
+				fn tap_response(
+					self,
+					_record: RecordResponse,
+				) -> (Self, Option<BoxFuture<'static, ()>>) {
+					match self {}
+				}
+
+				fn decode_message(
+					_encoded: &[u8],
+				) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+					Err(concat!(stringify!($msg), " has no variants").into())
+				}
+
+				fn respond(self, _response: &[u8]) -> Result<(), codec::Error> {
+					match self {}
+				}
+			}
+		)*
+	};
+}
+
+recordable_void_messages!(PvfCheckerMessage, BitfieldSigningMessage);
+
+/// An error restored from a recording, of which only the message is known.
+#[derive(Debug)]
+struct RecordedError(String);
+
+impl fmt::Display for RecordedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl std::error::Error for RecordedError {}
+
+/// A [`RuntimeApiError`], as recorded.
+#[derive(Encode, Decode)]
+enum RecordedRuntimeApiError {
+	Execution(String),
+	NotSupported,
+}
+
+impl RecordedRuntimeApiError {
+	fn new(err: &RuntimeApiError) -> Self {
+		match err {
+			RuntimeApiError::Execution { source, .. } =>
+				RecordedRuntimeApiError::Execution(source.to_string()),
+			RuntimeApiError::NotSupported { .. } => RecordedRuntimeApiError::NotSupported,
+		}
+	}
+
+	fn into_runtime_api_error(self, runtime_api_name: &'static str) -> RuntimeApiError {
+		match self {
+			RecordedRuntimeApiError::Execution(err) => RuntimeApiError::Execution {
+				runtime_api_name,
+				source: Arc::new(RecordedError(err)),
+			},
+			RecordedRuntimeApiError::NotSupported =>
+				RuntimeApiError::NotSupported { runtime_api_name },
+		}
+	}
+}
+
+fn encode_runtime_api_response<T: Encode>(response: &Result<T, RuntimeApiError>) -> Vec<u8> {
+	response.as_ref().map_err(RecordedRuntimeApiError::new).encode()
+}
+
+/// Runtime API requests and their responses come from the runtime and are recorded in their SCALE
+/// encoding.
+macro_rules! recordable_runtime_api_requests {
+	($($index:literal => $request:ident($($arg:ident: $arg_ty:ty),*) -> $response:ty,)*) => {
+		impl RecordableMessage for RuntimeApiMessage {
+			fn encode_message(&self) -> Vec<u8> {
+				let RuntimeApiMessage::Request(relay_parent, request) = self;
+				match request {
+					$(RuntimeApiRequest::$request($($arg,)* _) => {
+						let index: u8 = $index;
+						(relay_parent, index, $($arg,)*).encode()
+					},)*
+				}
+			}
+
+			fn tap_response(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+				let RuntimeApiMessage::Request(relay_parent, request) = self;
+				let (request, response) = match request {
+					$(RuntimeApiRequest::$request($($arg,)* tx) => {
+						let (tx, response) = tap_response(tx, encode_runtime_api_response, record);
+						(RuntimeApiRequest::$request($($arg,)* tx), response)
+					},)*
+				};
+
+				(RuntimeApiMessage::Request(relay_parent, request), Some(response))
+			}
+
+			fn decode_message(
+				encoded: &[u8],
+			) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+				let input = &mut &encoded[..];
+				let relay_parent = Hash::decode(input)?;
+				let (request, response) = match u8::decode(input)? {
+					$($index => {
+						$(let $arg = <$arg_ty>::decode(input)?;)*
+						let (tx, response) = capture_response(encode_runtime_api_response);
+						(RuntimeApiRequest::$request($($arg,)* tx), response)
+					},)*
+					_ => return Err("Unknown runtime API request".into()),
+				};
+
+				Ok((RuntimeApiMessage::Request(relay_parent, request), Some(response)))
+			}
+
+			fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+				let RuntimeApiMessage::Request(_, request) = self;
+				match request {
+					$(RuntimeApiRequest::$request(.., tx) => {
+						let response =
+							Result::<$response, RecordedRuntimeApiError>::decode(&mut &response[..])?;
+						let _ = tx.send(
+							response.map_err(|err| err.into_runtime_api_error(stringify!($request))),
+						);
+					},)*
+				}
+
+				Ok(())
+			}
+		}
+	};
+}
+
+recordable_runtime_api_requests! {
+	0 => Version() -> u32,
+	1 => Authorities() -> Vec<AuthorityDiscoveryId>,
+	2 => Validators() -> Vec<ValidatorId>,
+	3 => ValidatorGroups() -> (Vec<Vec<ValidatorIndex>>, GroupRotationInfo),
+	4 => AvailabilityCores() -> Vec<CoreState>,
+	5 => PersistedValidationData(para_id: ParaId, assumption: OccupiedCoreAssumption)
+		-> Option<PersistedValidationData>,
+	6 => AssumedValidationData(para_id: ParaId, parent_head_hash: Hash)
+		-> Option<(PersistedValidationData, ValidationCodeHash)>,
+	7 => CheckValidationOutputs(para_id: ParaId, commitments: CandidateCommitments) -> bool,
+	8 => SessionIndexForChild() -> SessionIndex,
+	9 => ValidationCode(para_id: ParaId, assumption: OccupiedCoreAssumption)
+		-> Option<ValidationCode>,
+	10 => ValidationCodeByHash(code_hash: ValidationCodeHash) -> Option<ValidationCode>,
+	11 => CandidatePendingAvailability(para_id: ParaId) -> Option<CommittedCandidateReceipt>,
+	12 => CandidateEvents() -> Vec<vstaging::CandidateEvent>,
+	13 => SessionExecutorParams(session: SessionIndex) -> Option<ExecutorParams>,
+	14 => SessionInfo(session: SessionIndex) -> Option<SessionInfo>,
+	15 => DmqContents(para_id: ParaId) -> Vec<InboundDownwardMessage<BlockNumber>>,
+	16 => InboundHrmpChannelsContents(para_id: ParaId)
+		-> std::collections::BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>,
+	17 => CurrentBabeEpoch() -> BabeEpoch,
+	18 => FetchOnChainVotes() -> Option<vstaging::ScrapedOnChainVotes>,
+	19 => SubmitPvfCheckStatement(statement: PvfCheckStatement, signature: ValidatorSignature)
+		-> (),
+	20 => PvfsRequirePrecheck() -> Vec<ValidationCodeHash>,
+	21 => ValidationCodeHash(para_id: ParaId, assumption: OccupiedCoreAssumption)
+		-> Option<ValidationCodeHash>,
+	22 => Disputes() -> Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>,
+	23 => UnappliedSlashes() -> Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>,
+	24 => KeyOwnershipProof(validator_id: ValidatorId)
+		-> Option<slashing::OpaqueKeyOwnershipProof>,
+	25 => SubmitReportDisputeLost(
+		dispute_proof: slashing::DisputeProof,
+		key_ownership_proof: slashing::OpaqueKeyOwnershipProof
+	) -> Option<()>,
+	26 => MinimumBackingVotes(session: SessionIndex) -> u32,
+	27 => DisabledValidators() -> Vec<ValidatorIndex>,
+	28 => ParaBackingState(para_id: ParaId) -> Option<vstaging::async_backing::BackingState>,
+	29 => AsyncBackingParams() -> async_backing::AsyncBackingParams,
+	30 => NodeFeatures(session: SessionIndex) -> NodeFeatures,
+	31 => ApprovalVotingParams(session: SessionIndex) -> ApprovalVotingParams,
+	32 => ClaimQueue()
+		-> std::collections::BTreeMap<CoreIndex, std::collections::VecDeque<ParaId>>,
+	33 => CandidatesPendingAvailability(para_id: ParaId) -> Vec<CommittedCandidateReceipt>,
+	34 => BackingConstraints(para_id: ParaId) -> Option<Constraints>,
+	35 => SchedulingLookahead(session: SessionIndex) -> u32,
+	36 => ValidationCodeBombLimit(session: SessionIndex) -> u32,
+	37 => ParaIds(session: SessionIndex) -> Vec<ParaId>,
+}
+
+/// The index under which messages of subsystems unknown to the recording are recorded.
+const UNKNOWN_TARGET: u8 = u8::MAX;
+
+/// Messages to other subsystems are recorded as the index of the receiving subsystem, followed by
+/// the message.
+macro_rules! recordable_all_messages {
+	($($index:literal => $variant:ident($msg:ident),)*) => {
+		impl RecordableMessage for AllMessages {
+			fn encode_message(&self) -> Vec<u8> {
+				match self {
+					$(AllMessages::$variant(msg) => {
+						let target: u8 = $index;
+						let mut dest = vec![target];
+						dest.extend(msg.encode_message());
+						dest
+					},)*
+					#[allow(unreachable_patterns)]
+					_ => vec![UNKNOWN_TARGET],
+				}
+			}
+
+			fn tap_response(self, record: RecordResponse) -> (Self, Option<BoxFuture<'static, ()>>) {
+				match self {
+					$(AllMessages::$variant(msg) => {
+						let (msg, forward) = msg.tap_response(record);
+						(AllMessages::$variant(msg), forward)
+					},)*
+					#[allow(unreachable_patterns)]
+					msg => (msg, None),
+				}
+			}
+
+			fn decode_message(
+				encoded: &[u8],
+			) -> Result<(Self, Option<ResponseFuture>), codec::Error> {
+				let (target, encoded) = encoded.split_first().ok_or("Empty recorded message")?;
+				match *target {
+					$($index => $msg::decode_message(encoded)
+						.map(|(msg, response)| (AllMessages::$variant(msg), response)),)*
+					_ => Err("Unknown message target".into()),
+				}
+			}
+
+			fn respond(self, response: &[u8]) -> Result<(), codec::Error> {
+				match self {
+					$(AllMessages::$variant(msg) => msg.respond(response),)*
+					#[allow(unreachable_patterns)]
+					_ => Err("Responding to the message is not supported".into()),
+				}
+			}
+		}
+	};
+}
+
+recordable_all_messages! {
+	0 => RuntimeApi(RuntimeApiMessage),
+	1 => ChainApi(ChainApiMessage),
+	2 => ProspectiveParachains(ProspectiveParachainsMessage),
+	3 => CandidateValidation(CandidateValidationMessage),
+	4 => CandidateBacking(CandidateBackingMessage),
+	5 => StatementDistribution(StatementDistributionMessage),
+	6 => AvailabilityDistribution(AvailabilityDistributionMessage),
+	7 => AvailabilityRecovery(AvailabilityRecoveryMessage),
+	8 => BitfieldDistribution(BitfieldDistributionMessage),
+	9 => Provisioner(ProvisionerMessage),
+	10 => AvailabilityStore(AvailabilityStoreMessage),
+	11 => NetworkBridgeRx(NetworkBridgeRxMessage),
+	12 => NetworkBridgeTx(NetworkBridgeTxMessage),
+	13 => CollationGeneration(CollationGenerationMessage),
+	14 => CollatorProtocol(CollatorProtocolMessage),
+	15 => ApprovalDistribution(ApprovalDistributionMessage),
+	16 => ApprovalVoting(ApprovalVotingMessage),
+	17 => ApprovalVotingParallel(ApprovalVotingParallelMessage),
+	18 => GossipSupport(GossipSupportMessage),
+	19 => DisputeCoordinator(DisputeCoordinatorMessage),
+	20 => DisputeDistribution(DisputeDistributionMessage),
+	21 => ChainSelection(ChainSelectionMessage),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_network_protocol::request_response::v1;
+	use polkadot_node_subsystem_types::messages::IfDisconnected;
+	use sc_network::{PeerId, ProtocolName};
+
+	/// Tap the response channels of `msg`, respond to it through the decoded copy and check that
+	/// the original one receives the response, as it was recorded.
+	fn respond_through_replay<M: RecordableMessage>(msg: M, respond: impl FnOnce(M)) {
+		let encoded = msg.encode_message();
+		let (decoded, response) = M::decode_message(&encoded).unwrap();
+		assert_eq!(decoded.encode_message(), encoded);
+
+		respond(decoded);
+		let response = futures::executor::block_on(response.unwrap()).unwrap();
+
+		let recorded = Arc::new(Mutex::new(None));
+		let record = {
+			let recorded = recorded.clone();
+			Box::new(move |response: Vec<u8>| *recorded.lock().unwrap() = Some(response))
+		};
+		let (msg, forward) = msg.tap_response(record);
+		msg.respond(&response).unwrap();
+		futures::executor::block_on(forward.unwrap());
+
+		assert_eq!(recorded.lock().unwrap().as_ref(), Some(&response));
+	}
+
+	#[test]
+	fn runtime_api_requests_round_trip() {
+		let relay_parent = Hash::repeat_byte(1);
+		let (tx, rx) = oneshot::channel();
+		let msg = RuntimeApiMessage::Request(
+			relay_parent,
+			RuntimeApiRequest::ParaBackingState(ParaId::from(100), tx),
+		);
+		respond_through_replay(msg, |decoded| {
+			let RuntimeApiMessage::Request(_, RuntimeApiRequest::ParaBackingState(_, tx)) = decoded
+			else {
+				panic!("The request is preserved")
+			};
+			tx.send(Err(RuntimeApiError::NotSupported { runtime_api_name: "test" }))
+				.unwrap();
+		});
+
+		assert!(matches!(
+			futures::executor::block_on(rx).unwrap(),
+			Err(RuntimeApiError::NotSupported { .. })
+		));
+	}
+
+	#[test]
+	fn requests_round_trip() {
+		let request = |candidate_hash| {
+			let (tx, rx) = oneshot::channel();
+			let request = OutgoingRequest {
+				peer: Recipient::Peer(PeerId::random()),
+				payload: v1::AvailableDataFetchingRequest { candidate_hash },
+				fallback_request: None,
+				pending_response: tx,
+			};
+			(Requests::AvailableDataFetchingV1(request), rx)
+		};
+		let (first, first_rx) = request(CandidateHash(Hash::repeat_byte(1)));
+		let (second, second_rx) = request(CandidateHash(Hash::repeat_byte(2)));
+		let msg = NetworkBridgeTxMessage::SendRequests(
+			vec![first, second],
+			IfDisconnected::ImmediateError,
+		);
+
+		respond_through_replay(msg, |decoded| {
+			let NetworkBridgeTxMessage::SendRequests(mut requests, _) = decoded else {
+				panic!("The message is preserved")
+			};
+			// The second request is never responded to.
+			requests.pop();
+			let Some(Requests::AvailableDataFetchingV1(request)) = requests.pop() else {
+				panic!("The requests are preserved")
+			};
+			let response = (vec![1, 2, 3], ProtocolName::from("/test/1"));
+			request.pending_response.send(Ok(response)).unwrap();
+		});
+
+		let (response, protocol) = futures::executor::block_on(first_rx).unwrap().unwrap();
+		assert_eq!(response, vec![1, 2, 3]);
+		assert_eq!(protocol, ProtocolName::from("/test/1"));
+		assert!(futures::executor::block_on(second_rx).is_err());
+	}
+}
@@ -0,0 +1,783 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The encoding of the parts of messages and responses in a recording.
+//!
+//! Types which are sent over the wire or stored on disk are recorded in their SCALE encoding.
+//! Types which only live in the node are recorded field by field, in a way which allows restoring
+//! them even if they can't be encoded, like signed data whose signature is only checked once.
+
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+	hash::Hash as StdHash,
+	io,
+	sync::Arc,
+};
+
+use codec::{Compact, Decode, Encode};
+use sc_network::{
+	IfDisconnected, Multiaddr, OutboundFailure, PeerId, ProtocolName, ReputationChange,
+	RequestFailure,
+};
+
+use polkadot_node_network_protocol::{
+	grid_topology::{SessionGridTopology, TopologyPeerInfo},
+	peer_set::{CollationVersion, PeerSet, ProtocolVersion, ValidationVersion},
+	request_response::{v1 as req_v1, v2 as req_v2, Recipient},
+	v1 as protocol_v1, v2 as protocol_v2, v3 as protocol_v3, CollationProtocols, ObservedRole,
+	OurView, ValidationProtocols, View,
+};
+use polkadot_node_primitives::{
+	approval::{
+		v1::{BlockApprovalMeta, RelayVRFStory, Slot},
+		v2::{CandidateBitfield, IndirectAssignmentCertV2, IndirectSignedApprovalVoteV2},
+	},
+	disputes::ValidCandidateVotes,
+	AvailableData, CandidateVotes, Collation, CollationGenerationConfig, CollationSecondedSignal,
+	DisputeMessage, DisputeStatus, ErasureChunk, InvalidCandidate, PoV, SignedDisputeStatement,
+	Statement, StatementWithPVD, UncheckedDisputeMessage, ValidationResult,
+};
+use polkadot_primitives::{
+	vstaging::{
+		BackedCandidate, CandidateReceiptV2 as CandidateReceipt, CommittedCandidateReceiptError,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+	},
+	AuthorityDiscoveryId, AvailabilityBitfield, CandidateCommitments, CandidateHash, CoreIndex,
+	DisputeStatement, DisputeStatementSet, EncodeAs, ExecutorParams, GroupIndex, Hash, HeadData,
+	Header, Id as ParaId, InvalidDisputeStatementKind, NodeFeatures, PersistedValidationData,
+	Signed, UncheckedSigned, UpgradeRestriction, ValidDisputeStatementKind, ValidationCode,
+	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
+};
+use polkadot_statement_table::{generic, v2 as table_v2};
+
+/// A type which can be recorded as part of a message or a response and restored from the
+/// recording.
+pub trait Recordable: Sized {
+	/// Append the recorded form of the value to `dest`.
+	fn record_to(&self, dest: &mut Vec<u8>);
+
+	/// Restore a value recorded with [`Recordable::record_to`].
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error>;
+
+	/// The recorded form of the value.
+	fn record(&self) -> Vec<u8> {
+		let mut dest = Vec::new();
+		self.record_to(&mut dest);
+		dest
+	}
+}
+
+/// Record types in their SCALE encoding.
+macro_rules! recordable_as_scale {
+	($($ty:ty),* $(,)?) => {
+		$(
+			impl Recordable for $ty {
+				fn record_to(&self, dest: &mut Vec<u8>) {
+					Encode::encode_to(self, dest)
+				}
+
+				fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+					Decode::decode(input)
+				}
+			}
+		)*
+	};
+}
+
+/// Record a struct field by field.
+macro_rules! recordable_struct {
+	($ty:ty { $($field:ident),* $(,)? }) => {
+		impl Recordable for $ty {
+			fn record_to(&self, dest: &mut Vec<u8>) {
+				$(Recordable::record_to(&self.$field, dest);)*
+			}
+
+			fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+				Ok(Self { $($field: Recordable::restore(input)?,)* })
+			}
+		}
+	};
+}
+
+/// Record an enum as the index of its variant, followed by the fields of the variant.
+macro_rules! recordable_enum {
+	($ty:ty { $($index:literal => $variant:ident $(($($field:ident),*))?,)* }) => {
+		impl Recordable for $ty {
+			fn record_to(&self, dest: &mut Vec<u8>) {
+				match self {
+					$(Self::$variant $(($($field),*))? => {
+						let index: u8 = $index;
+						index.record_to(dest);
+						$($(Recordable::record_to($field, dest);)*)?
+					},)*
+				}
+			}
+
+			fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+				match u8::restore(input)? {
+					$($index => {
+						$($(let $field = Recordable::restore(input)?;)*)?
+						Ok(Self::$variant $(($($field),*))?)
+					},)*
+					_ => Err(concat!("Unknown variant of ", stringify!($ty)).into()),
+				}
+			}
+		}
+	};
+}
+
+pub(crate) use recordable_as_scale;
+pub(crate) use recordable_enum;
+pub(crate) use recordable_struct;
+
+fn record_len(len: usize, dest: &mut Vec<u8>) {
+	Compact(len as u32).encode_to(dest)
+}
+
+fn restore_items<T: Recordable, C: FromIterator<T>>(input: &mut &[u8]) -> Result<C, codec::Error> {
+	let len = Compact::<u32>::decode(input)?.0;
+	(0..len).map(|_| T::restore(input)).collect()
+}
+
+/// Record the items of an unordered collection, sorted by their recorded form so the recording
+/// doesn't depend on the iteration order.
+fn record_unordered(items: impl Iterator<Item = Vec<u8>>, dest: &mut Vec<u8>) {
+	let mut items = items.collect::<Vec<_>>();
+	items.sort();
+	record_len(items.len(), dest);
+	items.into_iter().for_each(|item| dest.extend(item));
+}
+
+recordable_as_scale! {
+	bool,
+	u8,
+	u32,
+	u64,
+	i32,
+	String,
+	// Primitives.
+	Hash,
+	CandidateHash,
+	ParaId,
+	ValidatorIndex,
+	CoreIndex,
+	GroupIndex,
+	CandidateReceipt,
+	CommittedCandidateReceipt,
+	CommittedCandidateReceiptError,
+	CandidateCommitments,
+	PersistedValidationData,
+	HeadData,
+	Header,
+	ExecutorParams,
+	ValidationCode,
+	ValidationCodeHash,
+	AuthorityDiscoveryId,
+	ValidatorId,
+	ValidatorSignature,
+	BackedCandidate,
+	AvailabilityBitfield,
+	DisputeStatement,
+	DisputeStatementSet,
+	ValidDisputeStatementKind,
+	InvalidDisputeStatementKind,
+	NodeFeatures,
+	UpgradeRestriction,
+	// Node primitives.
+	PoV,
+	AvailableData,
+	ErasureChunk,
+	DisputeStatus,
+	UncheckedDisputeMessage,
+	Statement,
+	Collation,
+	IndirectAssignmentCertV2,
+	IndirectSignedApprovalVoteV2,
+	CandidateBitfield,
+	RelayVRFStory,
+	Slot,
+	// Network messages.
+	View,
+	protocol_v1::CollatorProtocolMessage,
+	protocol_v1::CollationProtocol,
+	protocol_v2::CollatorProtocolMessage,
+	protocol_v2::CollationProtocol,
+	protocol_v3::BitfieldDistributionMessage,
+	protocol_v3::StatementDistributionMessage,
+	protocol_v3::ApprovalDistributionMessage,
+	protocol_v3::ValidationProtocol,
+	// Requests.
+	req_v1::ChunkFetchingRequest,
+	req_v1::CollationFetchingRequest,
+	req_v1::PoVFetchingRequest,
+	req_v1::AvailableDataFetchingRequest,
+	req_v1::AvailableDataManifestRequest,
+	req_v1::AvailableDataBitswapRequest,
+	req_v1::DisputeRequest,
+	req_v2::ChunkFetchingRequest,
+	req_v2::AttestedCandidateRequest,
+	req_v2::CollationFetchingRequest,
+	// Statement table.
+	table_v2::SignedStatement,
+}
+
+impl Recordable for () {
+	fn record_to(&self, _dest: &mut Vec<u8>) {}
+
+	fn restore(_input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Ok(())
+	}
+}
+
+impl Recordable for usize {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		(*self as u64).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		u64::restore(input)?.try_into().map_err(|_| "Recorded size out of range".into())
+	}
+}
+
+macro_rules! recordable_tuple {
+	($($item:ident),+) => {
+		impl<$($item: Recordable),+> Recordable for ($($item,)+) {
+			#[allow(non_snake_case)]
+			fn record_to(&self, dest: &mut Vec<u8>) {
+				let ($($item,)+) = self;
+				$($item.record_to(dest);)+
+			}
+
+			fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+				Ok(($($item::restore(input)?,)+))
+			}
+		}
+	};
+}
+
+recordable_tuple!(A, B);
+recordable_tuple!(A, B, C);
+recordable_tuple!(A, B, C, D);
+
+impl<T: Recordable> Recordable for Option<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			None => 0u8.record_to(dest),
+			Some(value) => {
+				1u8.record_to(dest);
+				value.record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		match u8::restore(input)? {
+			0 => Ok(None),
+			1 => Ok(Some(T::restore(input)?)),
+			_ => Err("Invalid recorded option".into()),
+		}
+	}
+}
+
+impl<T: Recordable, E: Recordable> Recordable for Result<T, E> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			Ok(value) => {
+				0u8.record_to(dest);
+				value.record_to(dest);
+			},
+			Err(err) => {
+				1u8.record_to(dest);
+				err.record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		match u8::restore(input)? {
+			0 => Ok(Ok(T::restore(input)?)),
+			1 => Ok(Err(E::restore(input)?)),
+			_ => Err("Invalid recorded result".into()),
+		}
+	}
+}
+
+impl<T: Recordable> Recordable for Box<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		(**self).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		T::restore(input).map(Box::new)
+	}
+}
+
+impl<T: Recordable> Recordable for Arc<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		(**self).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		T::restore(input).map(Arc::new)
+	}
+}
+
+impl<T: Recordable> Recordable for Vec<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		record_len(self.len(), dest);
+		self.iter().for_each(|item| item.record_to(dest));
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items(input)
+	}
+}
+
+impl<T: Recordable> Recordable for VecDeque<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		record_len(self.len(), dest);
+		self.iter().for_each(|item| item.record_to(dest));
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items(input)
+	}
+}
+
+impl<T: Recordable + Ord> Recordable for BTreeSet<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		record_len(self.len(), dest);
+		self.iter().for_each(|item| item.record_to(dest));
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items(input)
+	}
+}
+
+impl<K: Recordable + Ord, V: Recordable> Recordable for BTreeMap<K, V> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		record_len(self.len(), dest);
+		self.iter().for_each(|(key, value)| {
+			key.record_to(dest);
+			value.record_to(dest);
+		});
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items::<(K, V), _>(input)
+	}
+}
+
+impl<T: Recordable + Eq + StdHash> Recordable for HashSet<T> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		record_unordered(self.iter().map(Recordable::record), dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items(input)
+	}
+}
+
+impl<K: Recordable + Eq + StdHash, V: Recordable> Recordable for HashMap<K, V> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		let entries = self.iter().map(|(key, value)| {
+			let mut entry = key.record();
+			value.record_to(&mut entry);
+			entry
+		});
+		record_unordered(entries, dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		restore_items::<(K, V), _>(input)
+	}
+}
+
+impl Recordable for PeerId {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.to_bytes().record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		PeerId::from_bytes(&Vec::<u8>::restore(input)?).map_err(|_| "Invalid peer id".into())
+	}
+}
+
+impl Recordable for Multiaddr {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.to_vec().record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Multiaddr::try_from(Vec::<u8>::restore(input)?).map_err(|_| "Invalid multiaddress".into())
+	}
+}
+
+impl Recordable for ReputationChange {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.value.record_to(dest);
+		self.reason.to_owned().record_to(dest);
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		let value = i32::restore(input)?;
+		// The reasons are static strings, restoring them leaks them. This only happens when
+		// replaying a recording in tests.
+		let reason = Box::leak(String::restore(input)?.into_boxed_str());
+		Ok(ReputationChange { value, reason })
+	}
+}
+
+impl Recordable for ProtocolName {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.to_string().record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		String::restore(input).map(ProtocolName::from)
+	}
+}
+
+recordable_enum!(RequestFailure {
+	0 => NotConnected,
+	1 => UnknownProtocol,
+	2 => Refused,
+	3 => Obsolete,
+	4 => Network(failure),
+});
+
+impl Recordable for OutboundFailure {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			OutboundFailure::DialFailure => 0u8.record_to(dest),
+			OutboundFailure::Timeout => 1u8.record_to(dest),
+			OutboundFailure::ConnectionClosed => 2u8.record_to(dest),
+			OutboundFailure::UnsupportedProtocols => 3u8.record_to(dest),
+			OutboundFailure::Io(err) => {
+				4u8.record_to(dest);
+				err.to_string().record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Ok(match u8::restore(input)? {
+			0 => OutboundFailure::DialFailure,
+			1 => OutboundFailure::Timeout,
+			2 => OutboundFailure::ConnectionClosed,
+			3 => OutboundFailure::UnsupportedProtocols,
+			4 => OutboundFailure::Io(Arc::new(io::Error::other(String::restore(input)?))),
+			_ => return Err("Unknown outbound failure".into()),
+		})
+	}
+}
+
+recordable_enum!(IfDisconnected {
+	0 => TryConnect,
+	1 => ImmediateError,
+});
+
+recordable_enum!(ObservedRole {
+	0 => Light,
+	1 => Full,
+	2 => Authority,
+});
+
+recordable_enum!(PeerSet {
+	0 => Validation,
+	1 => Collation,
+});
+
+impl Recordable for ProtocolVersion {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		u32::from(*self).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		match u32::restore(input)? {
+			1 => Ok(CollationVersion::V1.into()),
+			2 => Ok(CollationVersion::V2.into()),
+			3 => Ok(ValidationVersion::V3.into()),
+			_ => Err("Unknown protocol version".into()),
+		}
+	}
+}
+
+impl Recordable for OurView {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		let view: &View = self;
+		view.record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		let view = View::restore(input)?;
+		Ok(OurView::new(view.iter().copied(), view.finalized_number))
+	}
+}
+
+recordable_struct!(TopologyPeerInfo { peer_ids, validator_index, discovery_id });
+
+impl Recordable for SessionGridTopology {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.shuffled_indices().to_vec().record_to(dest);
+		self.canonical_shuffling().to_vec().record_to(dest);
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		let shuffled_indices = Recordable::restore(input)?;
+		let canonical_shuffling = Recordable::restore(input)?;
+		Ok(SessionGridTopology::new(shuffled_indices, canonical_shuffling))
+	}
+}
+
+impl<V3: Recordable> Recordable for ValidationProtocols<V3> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			ValidationProtocols::V3(msg) => {
+				3u8.record_to(dest);
+				msg.record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		match u8::restore(input)? {
+			3 => Ok(ValidationProtocols::V3(V3::restore(input)?)),
+			_ => Err("Unknown validation protocol version".into()),
+		}
+	}
+}
+
+impl<V1: Recordable, V2: Recordable> Recordable for CollationProtocols<V1, V2> {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		match self {
+			CollationProtocols::V1(msg) => {
+				1u8.record_to(dest);
+				msg.record_to(dest);
+			},
+			CollationProtocols::V2(msg) => {
+				2u8.record_to(dest);
+				msg.record_to(dest);
+			},
+		}
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		match u8::restore(input)? {
+			1 => Ok(CollationProtocols::V1(V1::restore(input)?)),
+			2 => Ok(CollationProtocols::V2(V2::restore(input)?)),
+			_ => Err("Unknown collation protocol version".into()),
+		}
+	}
+}
+
+impl Recordable for protocol_v3::GossipSupportNetworkMessage {
+	fn record_to(&self, _dest: &mut Vec<u8>) {
+		match *self {}
+	}
+
+	fn restore(_input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Err("Gossip support network messages don't exist".into())
+	}
+}
+
+recordable_enum!(Recipient {
+	0 => Peer(peer),
+	1 => Authority(authority),
+});
+
+/// Signed data is restored without checking its signature again, it was checked before it was
+/// recorded.
+impl<Payload, RealPayload> Recordable for Signed<Payload, RealPayload>
+where
+	Payload: Recordable + EncodeAs<RealPayload>,
+	RealPayload: Encode,
+{
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.payload().record_to(dest);
+		self.validator_index().record_to(dest);
+		self.signature().record_to(dest);
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		let payload = Payload::restore(input)?;
+		let validator_index = ValidatorIndex::restore(input)?;
+		let signature = ValidatorSignature::restore(input)?;
+		Ok(Signed::new_unchecked_from_trusted_source(UncheckedSigned::new(
+			payload,
+			validator_index,
+			signature,
+		)))
+	}
+}
+
+recordable_enum!(StatementWithPVD {
+	0 => Seconded(receipt, persisted_validation_data),
+	1 => Valid(candidate_hash),
+});
+
+impl Recordable for SignedDisputeStatement {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.statement().record_to(dest);
+		self.candidate_hash().record_to(dest);
+		self.session_index().record_to(dest);
+		self.validator_public().record_to(dest);
+		self.validator_signature().record_to(dest);
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Ok(SignedDisputeStatement::new_unchecked_from_trusted_source(
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+			Recordable::restore(input)?,
+		))
+	}
+}
+
+impl Recordable for DisputeMessage {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		UncheckedDisputeMessage::from(self.clone()).record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		UncheckedDisputeMessage::restore(input)
+			.map(DisputeMessage::new_unchecked_from_trusted_source)
+	}
+}
+
+impl Recordable for ValidCandidateVotes {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.raw().record_to(dest)
+	}
+
+	fn restore(input: &mut &[u8]) -> Result<Self, codec::Error> {
+		let votes: BTreeMap<ValidatorIndex, (ValidDisputeStatementKind, ValidatorSignature)> =
+			Recordable::restore(input)?;
+		Ok(votes.into_iter().collect())
+	}
+}
+
+recordable_struct!(CandidateVotes { candidate_receipt, valid, invalid });
+
+recordable_enum!(ValidationResult {
+	0 => Valid(commitments, persisted_validation_data),
+	1 => Invalid(invalid),
+});
+
+recordable_enum!(InvalidCandidate {
+	0 => ExecutionError(err),
+	1 => InvalidOutputs,
+	2 => Timeout,
+	3 => ParamsTooLarge(size),
+	4 => CodeTooLarge(size),
+	5 => PoVDecompressionFailure,
+	6 => BadReturn,
+	7 => BadParent,
+	8 => PoVHashMismatch,
+	9 => BadSignature,
+	10 => ParaHeadHashMismatch,
+	11 => CodeHashMismatch,
+	12 => CommitmentsHashMismatch,
+	13 => InvalidSessionIndex,
+	14 => InvalidUMPSignals(err),
+});
+
+recordable_struct!(CollationSecondedSignal { relay_parent, statement });
+
+/// The key and the collator function of the configuration can't be recorded, only the para the
+/// node collates for is. Restoring a configuration fails.
+impl Recordable for CollationGenerationConfig {
+	fn record_to(&self, dest: &mut Vec<u8>) {
+		self.para_id.record_to(dest);
+		self.collator.is_some().record_to(dest);
+	}
+
+	fn restore(_input: &mut &[u8]) -> Result<Self, codec::Error> {
+		Err("Collation generation configs can't be restored".into())
+	}
+}
+
+recordable_struct!(BlockApprovalMeta {
+	hash,
+	number,
+	parent_hash,
+	candidates,
+	slot,
+	session,
+	vrf_story,
+});
+
+type ValidityDoubleVote =
+	generic::ValidityDoubleVote<CommittedCandidateReceipt, CandidateHash, ValidatorSignature>;
+type DoubleSign = generic::DoubleSign<CommittedCandidateReceipt, CandidateHash, ValidatorSignature>;
+type UnauthorizedStatement = generic::UnauthorizedStatement<
+	CommittedCandidateReceipt,
+	CandidateHash,
+	ValidatorIndex,
+	ValidatorSignature,
+>;
+
+recordable_enum!(table_v2::Misbehavior {
+	0 => ValidityDoubleVote(double_vote),
+	1 => UnauthorizedStatement(statement),
+	2 => DoubleSign(double_sign),
+});
+
+recordable_enum!(ValidityDoubleVote {
+	0 => IssuedAndValidity(issued, validity),
+});
+
+recordable_enum!(DoubleSign {
+	0 => Seconded(candidate, first, second),
+	1 => Validity(digest, first, second),
+});
+
+recordable_struct!(UnauthorizedStatement { statement });
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_primitives_test_helpers::dummy_signature;
+
+	#[test]
+	fn unordered_collections_are_recorded_deterministically() {
+		let peers = (0..16).map(|_| PeerId::random()).collect::<Vec<_>>();
+		let forward = peers.iter().enumerate().map(|(i, peer)| (*peer, i as i32));
+		let backward = peers.iter().enumerate().rev().map(|(i, peer)| (*peer, i as i32));
+
+		let forward = forward.collect::<HashMap<_, _>>();
+		let backward = backward.collect::<HashMap<_, _>>();
+		assert_eq!(forward.record(), backward.record());
+
+		let restored = HashMap::<PeerId, i32>::restore(&mut &forward.record()[..]).unwrap();
+		assert_eq!(restored, forward);
+	}
+
+	#[test]
+	fn signed_data_round_trips() {
+		let statement = StatementWithPVD::Valid(CandidateHash(Hash::repeat_byte(1)));
+		let signed =
+			Signed::<_, polkadot_primitives::CompactStatement>::new_unchecked_from_trusted_source(
+				UncheckedSigned::new(statement, ValidatorIndex(3), dummy_signature()),
+			);
+
+		let restored = Signed::restore(&mut &signed.record()[..]).unwrap();
+		assert_eq!(restored, signed);
+	}
+}
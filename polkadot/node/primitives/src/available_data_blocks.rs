@@ -0,0 +1,244 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Splitting of the available data into content-addressed blocks.
+//!
+//! The encoded [`AvailableData`] of a candidate is split into blocks of
+//! [`MIN_AVAILABLE_DATA_BLOCK_SIZE`] to [`AVAILABLE_DATA_BLOCK_SIZE`] bytes, each addressed by
+//! its hash. An [`AvailableDataManifest`] lists the hashes of the blocks in order, so that the
+//! blocks can be fetched from different peers in parallel.
+//!
+//! Block boundaries are chosen by the content preceding them rather than by their offset. Data
+//! repeated at different offsets, e.g. a code blob included in the PoVs of several candidates, is
+//! therefore mostly split into the same blocks, which only need to be fetched once.
+
+use std::collections::HashMap;
+
+use codec::{Decode, DecodeAll, Encode};
+
+use polkadot_primitives::{BlakeTwo256, Hash, HashT, MAX_POV_SIZE};
+
+use crate::AvailableData;
+
+/// The minimum size of a block of available data, in bytes. Only the last block can be smaller.
+pub const MIN_AVAILABLE_DATA_BLOCK_SIZE: usize = 16 * 1024;
+
+/// The maximum size of a block of available data, in bytes.
+pub const AVAILABLE_DATA_BLOCK_SIZE: usize = 256 * 1024;
+
+// The persisted validation data is dominated by the parent head, which is far smaller than the
+// margin left here.
+const MAX_AVAILABLE_DATA_SIZE: usize = MAX_POV_SIZE as usize + 1024 * 1024;
+
+// After the minimum size, a block ends where the top 16 bits of the rolling hash of the preceding
+// `ROLLING_HASH_WINDOW` bytes are all zero, which makes blocks about 80 KiB on average.
+const BOUNDARY_MASK: u64 = !(u64::MAX >> 16);
+
+// Every byte shifts the rolling hash by one bit, so its top bit depends on the last 64 bytes.
+const ROLLING_HASH_WINDOW: usize = 64;
+
+// Random values mixed into the rolling hash for every byte value, generated with splitmix64.
+const GEAR: [u64; 256] = {
+	let mut table = [0u64; 256];
+	let mut state = 0u64;
+	let mut i = 0;
+	while i < table.len() {
+		state = state.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		table[i] = z ^ (z >> 31);
+		i += 1;
+	}
+	table
+};
+
+/// Lists the blocks the encoded available data of a candidate is split into.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct AvailableDataManifest {
+	/// The size of the encoded available data, in bytes.
+	pub size: u32,
+	/// The hashes of the blocks, in order.
+	pub blocks: Vec<Hash>,
+}
+
+impl AvailableDataManifest {
+	/// Whether the manifest could describe valid available data: its size is bounded and the
+	/// number of blocks fits the size.
+	pub fn is_plausible(&self) -> bool {
+		let size = self.size as usize;
+		size > 0 &&
+			size <= MAX_AVAILABLE_DATA_SIZE &&
+			self.blocks.len() >= size.div_ceil(AVAILABLE_DATA_BLOCK_SIZE) &&
+			self.blocks.len() <= size.div_ceil(MIN_AVAILABLE_DATA_BLOCK_SIZE)
+	}
+}
+
+/// Hash a block of available data.
+pub fn hash_block(block: &[u8]) -> Hash {
+	BlakeTwo256::hash(block)
+}
+
+/// The length of the block at the start of `data`.
+fn next_block_len(data: &[u8]) -> usize {
+	if data.len() <= MIN_AVAILABLE_DATA_BLOCK_SIZE {
+		return data.len()
+	}
+
+	let end = data.len().min(AVAILABLE_DATA_BLOCK_SIZE);
+	let mut hash = 0u64;
+	for (i, byte) in data
+		.iter()
+		.enumerate()
+		.take(end)
+		.skip(MIN_AVAILABLE_DATA_BLOCK_SIZE - ROLLING_HASH_WINDOW)
+	{
+		hash = (hash << 1).wrapping_add(GEAR[*byte as usize]);
+		if i >= MIN_AVAILABLE_DATA_BLOCK_SIZE && hash & BOUNDARY_MASK == 0 {
+			return i + 1
+		}
+	}
+	end
+}
+
+/// Split the available data into blocks, returning the manifest describing them and the blocks
+/// in order.
+pub fn split_available_data(data: &AvailableData) -> (AvailableDataManifest, Vec<Vec<u8>>) {
+	let encoded = data.encode();
+	let mut blocks = Vec::new();
+	let mut rest = &encoded[..];
+	while !rest.is_empty() {
+		let (block, remaining) = rest.split_at(next_block_len(rest));
+		blocks.push(block.to_vec());
+		rest = remaining;
+	}
+
+	let manifest = AvailableDataManifest {
+		size: encoded.len() as u32,
+		blocks: blocks.iter().map(|block| hash_block(block)).collect(),
+	};
+
+	(manifest, blocks)
+}
+
+/// Join the blocks listed in the manifest back into the available data.
+///
+/// `blocks` is expected to be keyed by the hash of each block. Returns `None` if any block is
+/// missing or doesn't match its hash, or if the joined blocks don't decode.
+pub fn join_available_data(
+	manifest: &AvailableDataManifest,
+	blocks: &HashMap<Hash, Vec<u8>>,
+) -> Option<AvailableData> {
+	if !manifest.is_plausible() {
+		return None
+	}
+
+	let mut encoded = Vec::with_capacity(manifest.size as usize);
+	for hash in &manifest.blocks {
+		let block = blocks.get(hash)?;
+		if block.len() > AVAILABLE_DATA_BLOCK_SIZE || hash_block(block) != *hash {
+			return None
+		}
+		encoded.extend_from_slice(block);
+		if encoded.len() > manifest.size as usize {
+			return None
+		}
+	}
+
+	if encoded.len() != manifest.size as usize {
+		return None
+	}
+
+	AvailableData::decode_all(&mut &encoded[..]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BlockData, PoV};
+	use std::{collections::HashSet, sync::Arc};
+
+	fn pseudo_random_bytes(seed: u64, len: usize) -> Vec<u8> {
+		let mut state = seed;
+		(0..len)
+			.map(|_| {
+				state ^= state << 13;
+				state ^= state >> 7;
+				state ^= state << 17;
+				state as u8
+			})
+			.collect()
+	}
+
+	fn available_data(block_data: Vec<u8>) -> AvailableData {
+		AvailableData {
+			pov: Arc::new(PoV { block_data: BlockData(block_data) }),
+			validation_data: Default::default(),
+		}
+	}
+
+	#[test]
+	fn split_and_join_round_trip() {
+		let data = available_data(pseudo_random_bytes(1, 3 * 1024 * 1024));
+		let (manifest, blocks) = split_available_data(&data);
+
+		assert!(manifest.is_plausible());
+		assert!(blocks[..blocks.len() - 1].iter().all(|block| {
+			block.len() >= MIN_AVAILABLE_DATA_BLOCK_SIZE && block.len() <= AVAILABLE_DATA_BLOCK_SIZE
+		}));
+
+		let mut by_hash: HashMap<_, _> = manifest.blocks.iter().copied().zip(blocks).collect();
+		assert_eq!(join_available_data(&manifest, &by_hash), Some(data));
+
+		let last = *manifest.blocks.last().unwrap();
+		by_hash.get_mut(&last).unwrap().push(0);
+		assert_eq!(join_available_data(&manifest, &by_hash), None);
+		by_hash.remove(&last);
+		assert_eq!(join_available_data(&manifest, &by_hash), None);
+	}
+
+	#[test]
+	fn repeated_content_at_different_offsets_shares_blocks() {
+		let code = pseudo_random_bytes(2, 2 * 1024 * 1024);
+		let with_prefix = |seed, prefix_len| {
+			let mut block_data = pseudo_random_bytes(seed, prefix_len);
+			block_data.extend_from_slice(&code);
+			split_available_data(&available_data(block_data)).0
+		};
+
+		let first = with_prefix(3, 1000);
+		let second = with_prefix(4, 123_456);
+		let first_blocks: HashSet<_> = first.blocks.iter().collect();
+		let shared = second.blocks.iter().filter(|hash| first_blocks.contains(hash)).count();
+
+		// Only the blocks around the start of the code differ.
+		assert!(shared + 6 >= second.blocks.len(), "{shared} of {}", second.blocks.len());
+	}
+
+	#[test]
+	fn implausible_manifests_are_rejected() {
+		let manifest = |size, n_blocks| AvailableDataManifest {
+			size,
+			blocks: vec![Hash::repeat_byte(1); n_blocks],
+		};
+
+		assert!(manifest(1, 1).is_plausible());
+		assert!(!manifest(0, 0).is_plausible());
+		assert!(!manifest(1, 2).is_plausible());
+		assert!(!manifest(AVAILABLE_DATA_BLOCK_SIZE as u32 + 1, 1).is_plausible());
+		assert!(!manifest(MAX_AVAILABLE_DATA_SIZE as u32 + 1, 100).is_plausible());
+	}
+}
@@ -188,6 +188,14 @@ impl DisputeMessage {
 		}))
 	}
 
+	/// Create a `DisputeMessage` from an `UncheckedDisputeMessage` without checking it.
+	///
+	/// Attention: Only to be used with messages which were checked before, like messages restored
+	/// from a recording of subsystem traffic.
+	pub fn new_unchecked_from_trusted_source(message: UncheckedDisputeMessage) -> Self {
+		DisputeMessage(message)
+	}
+
 	/// Read only access to the candidate receipt.
 	pub fn candidate_receipt(&self) -> &CandidateReceipt {
 		&self.0.candidate_receipt
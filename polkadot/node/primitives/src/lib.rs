@@ -49,6 +49,9 @@ pub use polkadot_parachain_primitives::primitives::{
 
 pub mod approval;
 
+/// Content-addressed blocks of the available data.
+pub mod available_data_blocks;
+
 /// Disputes related types.
 pub mod disputes;
 pub use disputes::{
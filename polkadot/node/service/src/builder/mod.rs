@@ -24,7 +24,7 @@ pub(crate) use partial::{new_partial, new_partial_basics};
 
 use crate::{
 	grandpa_support, open_database,
-	overseer::{ExtendedOverseerGenArgs, OverseerGen, OverseerGenArgs},
+	overseer::{ExtendedOverseerGenArgs, OverseerGen, OverseerGenArgs, Recorder},
 	parachains_db,
 	relay_chain_selection::SelectRelayChain,
 	workers, Chain, Error, FullBackend, FullClient, IdentifyVariant, IsParachainNode,
@@ -85,6 +85,11 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_hard_max_num: Option<usize>,
 	/// How long finalized data should be kept in the availability store (in hours)
 	pub keep_finalized_for: Option<u32>,
+	/// An optional file to record the message traffic of all subsystems to.
+	pub message_recording_path: Option<std::path::PathBuf>,
+	/// Whether to fetch the available data of large PoVs as content-addressed blocks over
+	/// bitswap.
+	pub fetch_available_data_blocks: bool,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -147,8 +152,16 @@ where
 			SelectRelayChain::new_longest_chain(basics.backend.clone())
 		};
 
-		let partial_components =
-			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+		// An overseer is only run by validators and nodes running alongside a parachain node.
+		let runs_overseer = config.role.is_authority() ||
+			params.is_parachain_node.is_running_alongside_parachain_node();
+		let rpc_overseer_handle = runs_overseer.then(|| overseer_handle.clone());
+		let partial_components = new_partial::<SelectRelayChain<_>>(
+			&mut config,
+			basics,
+			select_chain,
+			rpc_overseer_handle,
+		)?;
 
 		let net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 			&config.network,
@@ -199,6 +212,8 @@ where
 					prepare_workers_soft_max_num,
 					prepare_workers_hard_max_num,
 					keep_finalized_for,
+					message_recording_path,
+					fetch_available_data_blocks,
 				},
 			overseer_connector,
 			partial_components:
@@ -348,6 +363,12 @@ where
 		let (chunk_req_v2_receiver, cfg) =
 			IncomingRequest::get_config_receiver::<_, Network>(&req_protocol_names);
 		net_config.add_request_response_protocol(cfg);
+		let (available_data_manifest_req_receiver, cfg) =
+			IncomingRequest::get_config_receiver::<_, Network>(&req_protocol_names);
+		net_config.add_request_response_protocol(cfg);
+		let (available_data_bitswap_req_receiver, cfg) =
+			IncomingRequest::get_config_receiver::<_, Network>(&req_protocol_names);
+		net_config.add_request_response_protocol(cfg);
 
 		let grandpa_hard_forks = if config.chain_spec.is_kusama() {
 			grandpa_support::kusama_hard_forks()
@@ -434,12 +455,19 @@ where
 				pov_req_receiver,
 				chunk_req_v1_receiver,
 				chunk_req_v2_receiver,
+				available_data_manifest_req_receiver,
+				available_data_bitswap_req_receiver,
 				candidate_req_v2_receiver,
 				approval_voting_config,
 				dispute_req_receiver,
 				dispute_coordinator_config,
 				chain_selection_config,
 				fetch_chunks_threshold,
+				fetch_available_data_blocks,
+				message_recorder: message_recording_path
+					.as_deref()
+					.map(Recorder::create)
+					.transpose()?,
 			})
 		};
 
@@ -146,6 +146,7 @@ pub(crate) fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	overseer_handle: Option<polkadot_overseer::Handle>,
 ) -> Result<PolkadotPartialComponents<ChainSelection>, Error>
 where
 	ChainSelection: 'static + SelectChain<Block>,
@@ -234,6 +235,8 @@ where
 		let select_chain = select_chain.clone();
 		let chain_spec = config.chain_spec.cloned_box();
 		let backend = backend.clone();
+		let overseer_handle = overseer_handle.clone();
+		let is_validator = config.role.is_authority();
 
 		move |subscription_executor: polkadot_rpc::SubscriptionTaskExecutor|
 		      -> Result<polkadot_rpc::RpcExtension, sc_service::Error> {
@@ -259,6 +262,8 @@ where
 					subscription_executor,
 				},
 				backend: backend.clone(),
+				overseer_handle: overseer_handle.clone(),
+				is_validator,
 			};
 
 			polkadot_rpc::create_full(deps).map_err(Into::into)
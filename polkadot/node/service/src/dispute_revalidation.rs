@@ -0,0 +1,171 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline re-validation of the candidates of concluded disputes, reproducing the verdict of the
+//! dispute from the validation code and PoV of the candidate.
+
+use crate::{workers, Error};
+use codec::Decode;
+use polkadot_node_core_candidate_validation::{validate_candidate_offline, Config};
+use polkadot_node_primitives::{PoV, ValidationResult};
+use polkadot_primitives::{
+	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, ExecutorParams,
+	PersistedValidationData, ValidationCode,
+};
+use serde_json::{json, Value};
+use sp_core::Bytes;
+use std::path::{Path, PathBuf};
+
+/// The inputs of the re-validation of a disputed candidate.
+#[derive(Debug, Clone)]
+pub struct RevalidationParams {
+	/// The disputes exported with [`crate::export_concluded_disputes`].
+	pub disputes: PathBuf,
+	/// The candidate to re-validate, which has to be part of the exported disputes.
+	pub candidate_hash: CandidateHash,
+	/// The SCALE-encoded validation code of the candidate, as stored in `Paras::CodeByHash`.
+	pub validation_code: PathBuf,
+	/// The SCALE-encoded PoV of the candidate.
+	pub pov: PathBuf,
+	/// The SCALE-encoded persisted validation data of the candidate.
+	pub persisted_validation_data: PathBuf,
+	/// The SCALE-encoded executor parameters of the session, the defaults are used if not given.
+	pub executor_params: Option<PathBuf>,
+	/// The limit for decompressing the validation code.
+	pub validation_code_bomb_limit: u32,
+	/// The directory to prepare the validation code into.
+	pub artifacts_cache_path: PathBuf,
+	/// The directory to look for the PVF worker binaries in.
+	pub workers_path: Option<PathBuf>,
+	/// The version of the node, the worker binaries have to match it.
+	pub node_version: Option<String>,
+}
+
+/// Re-validate a candidate of a concluded dispute through the PVF host and compare the result
+/// with the outcome of the dispute.
+pub async fn revalidate_disputed_candidate(params: RevalidationParams) -> Result<Value, Error> {
+	let (outcome, candidate_receipt) = load_disputed_candidate(&params)?;
+
+	let validation_code: ValidationCode = read_scale_file(&params.validation_code)?;
+	if validation_code.hash() != candidate_receipt.descriptor.validation_code_hash() {
+		return Err(Error::Revalidation(
+			"The validation code does not match the code hash of the candidate".into(),
+		))
+	}
+	let pov: PoV = read_scale_file(&params.pov)?;
+	if pov.hash() != candidate_receipt.descriptor.pov_hash() {
+		return Err(Error::Revalidation(
+			"The PoV does not match the PoV hash of the candidate".into(),
+		))
+	}
+	let persisted_validation_data: PersistedValidationData =
+		read_scale_file(&params.persisted_validation_data)?;
+	let executor_params = match &params.executor_params {
+		Some(path) => read_scale_file(path)?,
+		None => ExecutorParams::default(),
+	};
+
+	let (prep_worker_path, exec_worker_path) =
+		workers::determine_workers_paths(params.workers_path, None, params.node_version.clone())?;
+	let config = Config {
+		artifacts_cache_path: params.artifacts_cache_path,
+		node_version: params.node_version,
+		secure_validator_mode: false,
+		prep_worker_path,
+		exec_worker_path,
+		pvf_execute_workers_max_num: 1,
+		pvf_prepare_workers_soft_max_num: 1,
+		pvf_prepare_workers_hard_max_num: 1,
+	};
+
+	let result = validate_candidate_offline(
+		config,
+		persisted_validation_data,
+		validation_code,
+		candidate_receipt,
+		pov,
+		executor_params,
+		params.validation_code_bomb_limit,
+	)
+	.await
+	.map_err(|err| Error::Revalidation(err.0))?;
+
+	let (revalidation, reproduced) = match result {
+		ValidationResult::Valid(_, _) => (json!("valid"), outcome == "valid"),
+		ValidationResult::Invalid(reason) =>
+			(json!({ "invalid": format!("{:?}", reason) }), outcome == "invalid"),
+	};
+
+	Ok(json!({
+		"candidate_hash": params.candidate_hash.0,
+		"outcome": outcome,
+		"revalidation": revalidation,
+		"reproduced": reproduced,
+	}))
+}
+
+// Find the candidate in the exported disputes, returning the outcome of its dispute and its
+// receipt.
+fn load_disputed_candidate(
+	params: &RevalidationParams,
+) -> Result<(String, CandidateReceipt), Error> {
+	let file = std::fs::File::open(&params.disputes)
+		.map_err(|err| Error::Revalidation(format!("{:?}: {}", params.disputes, err)))?;
+	let disputes: Value = serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|err| Error::Revalidation(format!("{:?}: {}", params.disputes, err)))?;
+
+	let candidate_hash = json!(params.candidate_hash.0);
+	let dispute = disputes
+		.as_array()
+		.and_then(|disputes| {
+			disputes.iter().find(|dispute| dispute["candidate_hash"] == candidate_hash)
+		})
+		.ok_or_else(|| {
+			Error::Revalidation(format!(
+				"No concluded dispute of candidate {:?} in {:?}",
+				params.candidate_hash, params.disputes
+			))
+		})?;
+
+	let outcome = dispute["outcome"].as_str().unwrap_or_default().to_owned();
+	let receipt: Bytes =
+		serde_json::from_value(dispute["candidate"]["receipt"].clone()).map_err(|_| {
+			Error::Revalidation(format!(
+				"The receipt of candidate {:?} was not exported",
+				params.candidate_hash
+			))
+		})?;
+	let receipt = CandidateReceipt::decode(&mut &receipt[..])
+		.map_err(|err| Error::Revalidation(format!("Invalid candidate receipt: {}", err)))?;
+
+	Ok((outcome, receipt))
+}
+
+// Read a file holding a SCALE-encoded value, either as raw bytes or `0x`-prefixed hex.
+fn read_scale_file<T: Decode>(path: &Path) -> Result<T, Error> {
+	let bytes =
+		std::fs::read(path).map_err(|err| Error::Revalidation(format!("{:?}: {}", path, err)))?;
+	let decoded_hex = std::str::from_utf8(&bytes)
+		.ok()
+		.map(str::trim)
+		.filter(|hex| hex.starts_with("0x"))
+		.map(sp_core::bytes::from_hex)
+		.transpose()
+		.map_err(|err| Error::Revalidation(format!("{:?}: {}", path, err)))?;
+	let bytes = decoded_hex.unwrap_or(bytes);
+
+	T::decode(&mut &bytes[..]).map_err(|err| Error::Revalidation(format!("{:?}: {}", path, err)))
+}
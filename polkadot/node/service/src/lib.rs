@@ -28,6 +28,8 @@ mod relay_chain_selection;
 #[cfg(feature = "full-node")]
 pub mod builder;
 #[cfg(feature = "full-node")]
+mod dispute_revalidation;
+#[cfg(feature = "full-node")]
 pub mod overseer;
 #[cfg(feature = "full-node")]
 pub mod workers;
@@ -41,6 +43,13 @@ pub use self::overseer::{
 	ValidatorOverseerGen,
 };
 
+#[cfg(feature = "full-node")]
+pub use dispute_revalidation::{revalidate_disputed_candidate, RevalidationParams};
+#[cfg(feature = "full-node")]
+pub use parachains_db::{ParachainsDbColumn, ParachainsDbFilter};
+#[cfg(feature = "full-node")]
+pub use polkadot_node_core_approval_voting::simulation as approval_voting_simulation;
+
 #[cfg(test)]
 mod tests;
 
@@ -74,7 +83,9 @@ use prometheus_endpoint::Registry;
 use sc_service::SpawnTaskHandle;
 
 pub use chain_spec::{GenericChainSpec, RococoChainSpec, WestendChainSpec};
-pub use polkadot_primitives::{Block, BlockId, BlockNumber, CollatorPair, Hash, Id as ParaId};
+pub use polkadot_primitives::{
+	Block, BlockId, BlockNumber, CandidateHash, CollatorPair, Hash, Id as ParaId, SessionIndex,
+};
 pub use sc_client_api::{Backend, CallExecutor};
 pub use sc_consensus::{BlockImport, LongestChain};
 pub use sc_executor::NativeExecutionDispatch;
@@ -216,6 +227,14 @@ pub enum Error {
 	#[error("Creating a custom database is required for validators")]
 	DatabasePathRequired,
 
+	#[cfg(feature = "full-node")]
+	#[error("Failed to inspect the parachains database: {0}")]
+	ParachainsDbInspection(String),
+
+	#[cfg(feature = "full-node")]
+	#[error("Failed to re-validate the candidate: {0}")]
+	Revalidation(String),
+
 	#[cfg(feature = "full-node")]
 	#[error("Expected at least one of polkadot, kusama, westend or rococo runtime feature")]
 	NoRuntime,
@@ -345,6 +364,57 @@ pub fn open_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Er
 	Ok(parachains_db)
 }
 
+/// Decode the given `columns` of the parachains database to JSON.
+///
+/// The database is neither created nor migrated, the node owning it must be stopped.
+#[cfg(feature = "full-node")]
+pub fn inspect_parachains_db(
+	db_source: &DatabaseSource,
+	columns: &[ParachainsDbColumn],
+	filter: &ParachainsDbFilter,
+) -> Result<serde_json::Value, Error> {
+	let parachains_db = open_existing_database(db_source)?;
+
+	parachains_db::inspect(parachains_db, columns, filter).map_err(Error::ParachainsDbInspection)
+}
+
+/// Export the concluded disputes still kept in the parachains database to JSON.
+///
+/// Disputes are pruned from the database once they leave the dispute window, the export has to
+/// be taken before. The node owning the database must be stopped.
+#[cfg(feature = "full-node")]
+pub fn export_concluded_disputes(
+	db_source: &DatabaseSource,
+	filter: &ParachainsDbFilter,
+) -> Result<serde_json::Value, Error> {
+	let parachains_db = open_existing_database(db_source)?;
+
+	parachains_db::export_concluded_disputes(&*parachains_db, filter)
+		.map_err(Error::ParachainsDbInspection)
+}
+
+#[cfg(feature = "full-node")]
+fn open_existing_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Error> {
+	let parachains_db = match db_source {
+		DatabaseSource::RocksDb { path, .. } => parachains_db::open_existing_rocksdb(path.clone())?,
+		DatabaseSource::ParityDb { path, .. } => parachains_db::open_existing_paritydb(
+			path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+		)?,
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } => {
+			if paritydb_path.is_dir() && paritydb_path.exists() {
+				parachains_db::open_existing_paritydb(
+					paritydb_path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+				)?
+			} else {
+				parachains_db::open_existing_rocksdb(rocksdb_path.clone())?
+			}
+		},
+		DatabaseSource::Custom { .. } => return Err(Error::DatabasePathRequired),
+	};
+
+	Ok(parachains_db)
+}
+
 /// Is this node running as in-process node for a parachain node?
 #[cfg(feature = "full-node")]
 #[derive(Clone)]
@@ -400,7 +470,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let sc_service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
@@ -26,11 +26,13 @@ use polkadot_node_core_candidate_validation::Config as CandidateValidationConfig
 use polkadot_node_core_chain_selection::Config as ChainSelectionConfig;
 use polkadot_node_core_dispute_coordinator::Config as DisputeCoordinatorConfig;
 use polkadot_node_network_protocol::{
+	available_data_blocks::AvailableDataBlockStore,
 	peer_set::{PeerSet, PeerSetProtocolNames},
 	request_response::{
 		v1 as request_v1, v2 as request_v2, IncomingRequestReceiver, ReqProtocolNames,
 	},
 };
+pub use polkadot_overseer::recorder::{Recorder, RecordingSubsystem};
 #[cfg(any(feature = "malus", test))]
 pub use polkadot_overseer::{dummy::dummy_overseer_builder, HeadSupportsParachains};
 use polkadot_overseer::{
@@ -125,6 +127,12 @@ pub struct ExtendedOverseerGenArgs {
 	pub chunk_req_v1_receiver: IncomingRequestReceiver<request_v1::ChunkFetchingRequest>,
 	/// Erasure chunk request v2 receiver.
 	pub chunk_req_v2_receiver: IncomingRequestReceiver<request_v2::ChunkFetchingRequest>,
+	/// Available data manifest request receiver.
+	pub available_data_manifest_req_receiver:
+		IncomingRequestReceiver<request_v1::AvailableDataManifestRequest>,
+	/// Available data bitswap request receiver.
+	pub available_data_bitswap_req_receiver:
+		IncomingRequestReceiver<request_v1::AvailableDataBitswapRequest>,
 	/// Receiver for incoming candidate requests.
 	pub candidate_req_v2_receiver: IncomingRequestReceiver<request_v2::AttestedCandidateRequest>,
 	/// Configuration for the approval voting subsystem.
@@ -139,6 +147,11 @@ pub struct ExtendedOverseerGenArgs {
 	/// than the value put in here we always try to recovery availability from backers.
 	/// The presence of this parameter here is needed to have different values per chain.
 	pub fetch_chunks_threshold: Option<usize>,
+	/// Whether to fetch the available data of large PoVs as content-addressed blocks from the
+	/// backing group.
+	pub fetch_available_data_blocks: bool,
+	/// Records the message traffic of all subsystems, if set.
+	pub message_recorder: Option<Recorder>,
 }
 
 /// Obtain a prepared validator `Overseer`, that is initialized with all default values.
@@ -167,47 +180,55 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		pov_req_receiver,
 		chunk_req_v1_receiver,
 		chunk_req_v2_receiver,
+		available_data_manifest_req_receiver,
+		available_data_bitswap_req_receiver,
 		candidate_req_v2_receiver,
 		approval_voting_config,
 		dispute_req_receiver,
 		dispute_coordinator_config,
 		chain_selection_config,
 		fetch_chunks_threshold,
+		fetch_available_data_blocks,
+		message_recorder,
 	}: ExtendedOverseerGenArgs,
 ) -> Result<
 	InitializedOverseerBuilder<
 		SpawnGlue<Spawner>,
 		Arc<RuntimeClient>,
-		CandidateValidationSubsystem,
-		PvfCheckerSubsystem,
-		CandidateBackingSubsystem,
-		StatementDistributionSubsystem,
-		AvailabilityDistributionSubsystem,
-		AvailabilityRecoverySubsystem,
-		BitfieldSigningSubsystem,
-		BitfieldDistributionSubsystem,
-		ProvisionerSubsystem,
-		RuntimeApiSubsystem<RuntimeClient>,
-		AvailabilityStoreSubsystem,
-		NetworkBridgeRxSubsystem<
-			Arc<dyn sc_network::service::traits::NetworkService>,
-			AuthorityDiscoveryService,
+		RecordingSubsystem<CandidateValidationSubsystem>,
+		RecordingSubsystem<PvfCheckerSubsystem>,
+		RecordingSubsystem<CandidateBackingSubsystem>,
+		RecordingSubsystem<StatementDistributionSubsystem>,
+		RecordingSubsystem<AvailabilityDistributionSubsystem>,
+		RecordingSubsystem<AvailabilityRecoverySubsystem>,
+		RecordingSubsystem<BitfieldSigningSubsystem>,
+		RecordingSubsystem<BitfieldDistributionSubsystem>,
+		RecordingSubsystem<ProvisionerSubsystem>,
+		RecordingSubsystem<RuntimeApiSubsystem<RuntimeClient>>,
+		RecordingSubsystem<AvailabilityStoreSubsystem>,
+		RecordingSubsystem<
+			NetworkBridgeRxSubsystem<
+				Arc<dyn sc_network::service::traits::NetworkService>,
+				AuthorityDiscoveryService,
+			>,
 		>,
-		NetworkBridgeTxSubsystem<
-			Arc<dyn sc_network::service::traits::NetworkService>,
-			AuthorityDiscoveryService,
+		RecordingSubsystem<
+			NetworkBridgeTxSubsystem<
+				Arc<dyn sc_network::service::traits::NetworkService>,
+				AuthorityDiscoveryService,
+			>,
 		>,
-		ChainApiSubsystem<RuntimeClient>,
-		DummySubsystem,
-		CollatorProtocolSubsystem,
-		DummySubsystem,
-		DummySubsystem,
-		ApprovalVotingParallelSubsystem,
-		GossipSupportSubsystem<AuthorityDiscoveryService>,
-		DisputeCoordinatorSubsystem,
-		DisputeDistributionSubsystem<AuthorityDiscoveryService>,
-		ChainSelectionSubsystem,
-		ProspectiveParachainsSubsystem,
+		RecordingSubsystem<ChainApiSubsystem<RuntimeClient>>,
+		RecordingSubsystem<DummySubsystem>,
+		RecordingSubsystem<CollatorProtocolSubsystem>,
+		RecordingSubsystem<DummySubsystem>,
+		RecordingSubsystem<DummySubsystem>,
+		RecordingSubsystem<ApprovalVotingParallelSubsystem>,
+		RecordingSubsystem<GossipSupportSubsystem<AuthorityDiscoveryService>>,
+		RecordingSubsystem<DisputeCoordinatorSubsystem>,
+		RecordingSubsystem<DisputeDistributionSubsystem<AuthorityDiscoveryService>>,
+		RecordingSubsystem<ChainSelectionSubsystem>,
+		RecordingSubsystem<ProspectiveParachainsSubsystem>,
 	>,
 	Error,
 >
@@ -225,119 +246,217 @@ where
 	let network_bridge_metrics: NetworkBridgeMetrics = Metrics::register(registry)?;
 	let approval_voting_parallel_metrics: ApprovalVotingParallelMetrics =
 		Metrics::register(registry)?;
+	// Blocks of available data served over bitswap, including the recovered ones.
+	let available_data_block_store = AvailableDataBlockStore::default();
 	let builder = Overseer::builder()
-		.network_bridge_tx(NetworkBridgeTxSubsystem::new(
-			network_service.clone(),
-			authority_discovery_service.clone(),
-			network_bridge_metrics.clone(),
-			req_protocol_names.clone(),
-			peerset_protocol_names.clone(),
-			notification_sinks.clone(),
+		.network_bridge_tx(RecordingSubsystem::new(
+			NetworkBridgeTxSubsystem::new(
+				network_service.clone(),
+				authority_discovery_service.clone(),
+				network_bridge_metrics.clone(),
+				req_protocol_names.clone(),
+				peerset_protocol_names.clone(),
+				notification_sinks.clone(),
+			),
+			"network-bridge-tx",
+			message_recorder.clone(),
 		))
-		.network_bridge_rx(NetworkBridgeRxSubsystem::new(
-			network_service.clone(),
-			authority_discovery_service.clone(),
-			Box::new(sync_service.clone()),
-			network_bridge_metrics,
-			peerset_protocol_names,
-			notification_services,
-			notification_sinks,
+		.network_bridge_rx(RecordingSubsystem::new(
+			NetworkBridgeRxSubsystem::new(
+				network_service.clone(),
+				authority_discovery_service.clone(),
+				Box::new(sync_service.clone()),
+				network_bridge_metrics,
+				peerset_protocol_names,
+				notification_services,
+				notification_sinks,
+			),
+			"network-bridge-rx",
+			message_recorder.clone(),
+		))
+		.availability_distribution(RecordingSubsystem::new(
+			AvailabilityDistributionSubsystem::new(
+				keystore.clone(),
+				IncomingRequestReceivers {
+					pov_req_receiver,
+					chunk_req_v1_receiver,
+					chunk_req_v2_receiver,
+					available_data_manifest_req_receiver,
+					available_data_bitswap_req_receiver,
+				},
+				req_protocol_names.clone(),
+				Metrics::register(registry)?,
+			)
+			.with_available_data_block_store(available_data_block_store.clone()),
+			"availability-distribution",
+			message_recorder.clone(),
+		))
+		.availability_recovery(RecordingSubsystem::new(
+			AvailabilityRecoverySubsystem::for_validator(
+				fetch_chunks_threshold,
+				available_data_req_receiver,
+				&req_protocol_names,
+				Metrics::register(registry)?,
+			)
+			.with_available_data_blocks(
+				fetch_available_data_blocks.then_some(available_data_block_store),
+			),
+			"availability-recovery",
+			message_recorder.clone(),
+		))
+		.availability_store(RecordingSubsystem::new(
+			AvailabilityStoreSubsystem::new(
+				parachains_db.clone(),
+				availability_config,
+				Box::new(sync_service.clone()),
+				Metrics::register(registry)?,
+			),
+			"availability-store",
+			message_recorder.clone(),
+		))
+		.bitfield_distribution(RecordingSubsystem::new(
+			BitfieldDistributionSubsystem::new(Metrics::register(registry)?),
+			"bitfield-distribution",
+			message_recorder.clone(),
+		))
+		.bitfield_signing(RecordingSubsystem::new(
+			BitfieldSigningSubsystem::new(keystore.clone(), Metrics::register(registry)?),
+			"bitfield-signing",
+			message_recorder.clone(),
+		))
+		.candidate_backing(RecordingSubsystem::new(
+			CandidateBackingSubsystem::new(keystore.clone(), Metrics::register(registry)?),
+			"candidate-backing",
+			message_recorder.clone(),
+		))
+		.candidate_validation(RecordingSubsystem::new(
+			CandidateValidationSubsystem::with_config(
+				candidate_validation_config,
+				keystore.clone(),
+				Metrics::register(registry)?, // candidate-validation metrics
+				Metrics::register(registry)?, // validation host metrics
+			),
+			"candidate-validation",
+			message_recorder.clone(),
 		))
-		.availability_distribution(AvailabilityDistributionSubsystem::new(
-			keystore.clone(),
-			IncomingRequestReceivers {
-				pov_req_receiver,
-				chunk_req_v1_receiver,
-				chunk_req_v2_receiver,
+		.pvf_checker(RecordingSubsystem::new(
+			PvfCheckerSubsystem::new(keystore.clone(), Metrics::register(registry)?),
+			"pvf-checker",
+			message_recorder.clone(),
+		))
+		.chain_api(RecordingSubsystem::new(
+			ChainApiSubsystem::new(runtime_client.clone(), Metrics::register(registry)?),
+			"chain-api",
+			message_recorder.clone(),
+		))
+		.collation_generation(RecordingSubsystem::new(
+			DummySubsystem,
+			"collation-generation",
+			message_recorder.clone(),
+		))
+		.collator_protocol(RecordingSubsystem::new(
+			{
+				let side = match is_parachain_node {
+					IsParachainNode::Collator(_) | IsParachainNode::FullNode =>
+						return Err(Error::Overseer(SubsystemError::Context(
+							"build validator overseer for parachain node".to_owned(),
+						))),
+					IsParachainNode::No => ProtocolSide::Validator {
+						keystore: keystore.clone(),
+						eviction_policy: Default::default(),
+						metrics: Metrics::register(registry)?,
+					},
+				};
+				CollatorProtocolSubsystem::new(side)
 			},
-			req_protocol_names.clone(),
-			Metrics::register(registry)?,
+			"collator-protocol",
+			message_recorder.clone(),
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::for_validator(
-			fetch_chunks_threshold,
-			available_data_req_receiver,
-			&req_protocol_names,
-			Metrics::register(registry)?,
+		.provisioner(RecordingSubsystem::new(
+			ProvisionerSubsystem::new(Metrics::register(registry)?),
+			"provisioner",
+			message_recorder.clone(),
 		))
-		.availability_store(AvailabilityStoreSubsystem::new(
-			parachains_db.clone(),
-			availability_config,
-			Box::new(sync_service.clone()),
-			Metrics::register(registry)?,
+		.runtime_api(RecordingSubsystem::new(
+			RuntimeApiSubsystem::new(
+				runtime_client.clone(),
+				Metrics::register(registry)?,
+				spawner.clone(),
+			),
+			"runtime-api",
+			message_recorder.clone(),
 		))
-		.bitfield_distribution(BitfieldDistributionSubsystem::new(Metrics::register(registry)?))
-		.bitfield_signing(BitfieldSigningSubsystem::new(
-			keystore.clone(),
-			Metrics::register(registry)?,
+		.statement_distribution(RecordingSubsystem::new(
+			StatementDistributionSubsystem::new(
+				keystore.clone(),
+				candidate_req_v2_receiver,
+				Metrics::register(registry)?,
+			),
+			"statement-distribution",
+			message_recorder.clone(),
 		))
-		.candidate_backing(CandidateBackingSubsystem::new(
-			keystore.clone(),
-			Metrics::register(registry)?,
+		.approval_distribution(RecordingSubsystem::new(
+			DummySubsystem,
+			"approval-distribution",
+			message_recorder.clone(),
 		))
-		.candidate_validation(CandidateValidationSubsystem::with_config(
-			candidate_validation_config,
-			keystore.clone(),
-			Metrics::register(registry)?, // candidate-validation metrics
-			Metrics::register(registry)?, // validation host metrics
+		.approval_voting(RecordingSubsystem::new(
+			DummySubsystem,
+			"approval-voting",
+			message_recorder.clone(),
 		))
-		.pvf_checker(PvfCheckerSubsystem::new(keystore.clone(), Metrics::register(registry)?))
-		.chain_api(ChainApiSubsystem::new(runtime_client.clone(), Metrics::register(registry)?))
-		.collation_generation(DummySubsystem)
-		.collator_protocol({
-			let side = match is_parachain_node {
-				IsParachainNode::Collator(_) | IsParachainNode::FullNode =>
-					return Err(Error::Overseer(SubsystemError::Context(
-						"build validator overseer for parachain node".to_owned(),
-					))),
-				IsParachainNode::No => ProtocolSide::Validator {
-					keystore: keystore.clone(),
-					eviction_policy: Default::default(),
-					metrics: Metrics::register(registry)?,
-				},
-			};
-			CollatorProtocolSubsystem::new(side)
-		})
-		.provisioner(ProvisionerSubsystem::new(Metrics::register(registry)?))
-		.runtime_api(RuntimeApiSubsystem::new(
-			runtime_client.clone(),
-			Metrics::register(registry)?,
-			spawner.clone(),
+		.approval_voting_parallel(RecordingSubsystem::new(
+			ApprovalVotingParallelSubsystem::with_config(
+				approval_voting_config,
+				parachains_db.clone(),
+				keystore.clone(),
+				Box::new(sync_service.clone()),
+				approval_voting_parallel_metrics,
+				spawner.clone(),
+				overseer_message_channel_capacity_override,
+			),
+			"approval-voting-parallel",
+			message_recorder.clone(),
 		))
-		.statement_distribution(StatementDistributionSubsystem::new(
-			keystore.clone(),
-			candidate_req_v2_receiver,
-			Metrics::register(registry)?,
+		.gossip_support(RecordingSubsystem::new(
+			GossipSupportSubsystem::new(
+				keystore.clone(),
+				authority_discovery_service.clone(),
+				Metrics::register(registry)?,
+			),
+			"gossip-support",
+			message_recorder.clone(),
 		))
-		.approval_distribution(DummySubsystem)
-		.approval_voting(DummySubsystem)
-		.approval_voting_parallel(ApprovalVotingParallelSubsystem::with_config(
-			approval_voting_config,
-			parachains_db.clone(),
-			keystore.clone(),
-			Box::new(sync_service.clone()),
-			approval_voting_parallel_metrics,
-			spawner.clone(),
-			overseer_message_channel_capacity_override,
+		.dispute_coordinator(RecordingSubsystem::new(
+			DisputeCoordinatorSubsystem::new(
+				parachains_db.clone(),
+				dispute_coordinator_config,
+				keystore.clone(),
+				Metrics::register(registry)?,
+			),
+			"dispute-coordinator",
+			message_recorder.clone(),
 		))
-		.gossip_support(GossipSupportSubsystem::new(
-			keystore.clone(),
-			authority_discovery_service.clone(),
-			Metrics::register(registry)?,
+		.dispute_distribution(RecordingSubsystem::new(
+			DisputeDistributionSubsystem::new(
+				keystore.clone(),
+				dispute_req_receiver,
+				authority_discovery_service.clone(),
+				Metrics::register(registry)?,
+			),
+			"dispute-distribution",
+			message_recorder.clone(),
 		))
-		.dispute_coordinator(DisputeCoordinatorSubsystem::new(
-			parachains_db.clone(),
-			dispute_coordinator_config,
-			keystore.clone(),
-			Metrics::register(registry)?,
+		.chain_selection(RecordingSubsystem::new(
+			ChainSelectionSubsystem::new(chain_selection_config, parachains_db),
+			"chain-selection",
+			message_recorder.clone(),
 		))
-		.dispute_distribution(DisputeDistributionSubsystem::new(
-			keystore.clone(),
-			dispute_req_receiver,
-			authority_discovery_service.clone(),
-			Metrics::register(registry)?,
+		.prospective_parachains(RecordingSubsystem::new(
+			ProspectiveParachainsSubsystem::new(Metrics::register(registry)?),
+			"prospective-parachains",
+			message_recorder,
 		))
-		.chain_selection(ChainSelectionSubsystem::new(chain_selection_config, parachains_db))
-		.prospective_parachains(ProspectiveParachainsSubsystem::new(Metrics::register(registry)?))
 		.activation_external_listeners(Default::default())
 		.active_leaves(Default::default())
 		.supports_parachains(runtime_client)
@@ -0,0 +1,470 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of the parachains DB to JSON, to inspect the database of a stopped node.
+
+use super::REAL_COLUMNS;
+use codec::Encode;
+use polkadot_node_core_approval_voting::approval_db::{common as approval_db, v3 as approval_v3};
+use polkadot_node_core_av_store::inspect::{self as av_store, CandidateState};
+use polkadot_node_core_chain_selection::inspect as chain_selection;
+use polkadot_node_core_dispute_coordinator::inspect as dispute_coordinator;
+use polkadot_node_primitives::DisputeStatus;
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{CandidateHash, SessionIndex, ValidatorIndex, ValidatorSignature};
+use serde_json::{json, Value};
+use sp_core::Bytes;
+use std::{collections::HashSet, fmt, str::FromStr, sync::Arc};
+
+/// A part of the parachains DB, owned by a single subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParachainsDbColumn {
+	/// Candidate state and stored chunks of the availability store.
+	AvailabilityStore,
+	/// Block and candidate entries of approval voting.
+	ApprovalVoting,
+	/// Recent disputes and votes of the dispute coordinator.
+	DisputeCoordinator,
+	/// Unfinalized blocks and leaves of chain selection.
+	ChainSelection,
+}
+
+impl ParachainsDbColumn {
+	/// All the columns, in the order they are inspected in.
+	pub const ALL: [ParachainsDbColumn; 4] = [
+		ParachainsDbColumn::AvailabilityStore,
+		ParachainsDbColumn::ApprovalVoting,
+		ParachainsDbColumn::DisputeCoordinator,
+		ParachainsDbColumn::ChainSelection,
+	];
+
+	fn name(&self) -> &'static str {
+		match self {
+			ParachainsDbColumn::AvailabilityStore => "availability-store",
+			ParachainsDbColumn::ApprovalVoting => "approval-voting",
+			ParachainsDbColumn::DisputeCoordinator => "dispute-coordinator",
+			ParachainsDbColumn::ChainSelection => "chain-selection",
+		}
+	}
+}
+
+impl fmt::Display for ParachainsDbColumn {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl FromStr for ParachainsDbColumn {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::ALL.into_iter().find(|column| column.name() == s).ok_or_else(|| {
+			format!(
+				"unknown column `{}`, expected one of: {}",
+				s,
+				Self::ALL.map(|column| column.name()).join(", ")
+			)
+		})
+	}
+}
+
+/// Filters applied to the inspected entries.
+///
+/// The availability store and chain selection don't record sessions, the session filter doesn't
+/// apply to them.
+#[derive(Debug, Clone, Default)]
+pub struct ParachainsDbFilter {
+	/// Only include entries of the given session.
+	pub session: Option<SessionIndex>,
+	/// Only include entries related to the given candidate.
+	pub candidate_hash: Option<CandidateHash>,
+}
+
+impl ParachainsDbFilter {
+	fn session(&self, session: SessionIndex) -> bool {
+		self.session.map_or(true, |s| s == session)
+	}
+
+	fn candidate(&self, candidate_hash: &CandidateHash) -> bool {
+		self.candidate_hash.as_ref().map_or(true, |c| c == candidate_hash)
+	}
+}
+
+/// Decode the given `columns` of the parachains DB to a JSON object keyed by column name.
+pub fn inspect(
+	db: Arc<dyn Database>,
+	columns: &[ParachainsDbColumn],
+	filter: &ParachainsDbFilter,
+) -> Result<Value, String> {
+	let mut output = serde_json::Map::new();
+	for column in columns {
+		let value = match column {
+			ParachainsDbColumn::AvailabilityStore => inspect_av_store(&db, filter),
+			ParachainsDbColumn::ApprovalVoting => inspect_approval_voting(&*db, filter),
+			ParachainsDbColumn::DisputeCoordinator => inspect_dispute_coordinator(&*db, filter),
+			ParachainsDbColumn::ChainSelection => inspect_chain_selection(db.clone(), filter),
+		}
+		.map_err(|err| format!("Failed to decode the {} column: {}", column, err))?;
+		output.insert(column.name().into(), value);
+	}
+
+	Ok(Value::Object(output))
+}
+
+fn inspect_av_store(db: &Arc<dyn Database>, filter: &ParachainsDbFilter) -> Result<Value, String> {
+	let config = polkadot_node_core_av_store::Config {
+		col_data: REAL_COLUMNS.col_availability_data,
+		col_meta: REAL_COLUMNS.col_availability_meta,
+		keep_finalized_for: Default::default(),
+	};
+	let candidates = match filter.candidate_hash {
+		Some(candidate_hash) => av_store::load_candidate(db, &config, &candidate_hash)
+			.map(|candidate| candidate.into_iter().collect()),
+		None => av_store::load_candidates(db, &config),
+	}
+	.map_err(|err| err.to_string())?;
+
+	Ok(candidates
+		.into_iter()
+		.map(|candidate| {
+			let state = match candidate.state {
+				CandidateState::Unavailable { first_seen } =>
+					json!({ "unavailable": { "first_seen": first_seen.as_secs() } }),
+				CandidateState::Unfinalized { first_seen, included_in } => json!({
+					"unfinalized": {
+						"first_seen": first_seen.as_secs(),
+						"included_in": included_in
+							.into_iter()
+							.map(|(number, hash)| json!({ "number": number, "hash": hash }))
+							.collect::<Vec<_>>(),
+					}
+				}),
+				CandidateState::Finalized { finalized_at } =>
+					json!({ "finalized": { "finalized_at": finalized_at.as_secs() } }),
+			};
+			json!({
+				"candidate_hash": candidate.candidate_hash.0,
+				"state": state,
+				"data_available": candidate.data_available,
+				"chunks_stored": candidate.chunks_stored.iter().map(|index| index.0).collect::<Vec<_>>(),
+			})
+		})
+		.collect())
+}
+
+fn inspect_approval_voting(
+	db: &dyn Database,
+	filter: &ParachainsDbFilter,
+) -> Result<Value, String> {
+	let config = approval_db::Config { col_approval_data: REAL_COLUMNS.col_approval_data };
+	let stored_blocks =
+		approval_db::load_stored_blocks(db, &config).map_err(|err| err.to_string())?;
+	let block_hashes = approval_db::load_all_blocks(db, &config).map_err(|err| err.to_string())?;
+
+	let mut blocks = Vec::new();
+	let mut candidate_hashes = Vec::new();
+	let mut seen = HashSet::new();
+	for block_hash in block_hashes {
+		let Some(block) = approval_db::load_block_entry(db, &config, &block_hash)
+			.map_err(|err| err.to_string())?
+		else {
+			continue
+		};
+		if !filter.session(block.session) ||
+			!block
+				.candidates
+				.iter()
+				.any(|(_, candidate_hash)| filter.candidate(candidate_hash))
+		{
+			continue
+		}
+
+		for (_, candidate_hash) in block.candidates.iter() {
+			if filter.candidate(candidate_hash) && seen.insert(*candidate_hash) {
+				candidate_hashes.push(*candidate_hash);
+			}
+		}
+		blocks.push(approval_block_json(block));
+	}
+
+	let mut candidates = Vec::new();
+	for candidate_hash in candidate_hashes {
+		let Some(candidate) = approval_db::load_candidate_entry(db, &config, &candidate_hash)
+			.map_err(|err| err.to_string())?
+		else {
+			continue
+		};
+		if filter.session(candidate.session) {
+			candidates.push(approval_candidate_json(candidate_hash, candidate));
+		}
+	}
+
+	Ok(json!({
+		"stored_blocks": stored_blocks.map(|range| json!({ "start": range.0, "end": range.1 })),
+		"blocks": blocks,
+		"candidates": candidates,
+	}))
+}
+
+fn approval_block_json(block: approval_v3::BlockEntry) -> Value {
+	json!({
+		"block_hash": block.block_hash,
+		"block_number": block.block_number,
+		"parent_hash": block.parent_hash,
+		"session": block.session,
+		"slot": u64::from(block.slot),
+		"candidates": block
+			.candidates
+			.iter()
+			.map(|(core_index, candidate_hash)| {
+				json!({ "core_index": core_index.0, "candidate_hash": candidate_hash.0 })
+			})
+			.collect::<Vec<_>>(),
+		"approved_candidates": block.approved_bitfield.iter_ones().collect::<Vec<_>>(),
+		"children": block.children,
+	})
+}
+
+fn approval_candidate_json(
+	candidate_hash: CandidateHash,
+	candidate: approval_v3::CandidateEntry,
+) -> Value {
+	let block_assignments = candidate
+		.block_assignments
+		.into_iter()
+		.map(|(block_hash, entry)| {
+			let tranches = entry
+				.tranches
+				.into_iter()
+				.map(|tranche| {
+					json!({
+						"tranche": tranche.tranche,
+						"assignments": tranche
+							.assignments
+							.into_iter()
+							.map(|(validator_index, tick)| {
+								json!({ "validator_index": validator_index.0, "tick": u64::from(tick) })
+							})
+							.collect::<Vec<_>>(),
+					})
+				})
+				.collect::<Vec<_>>();
+			json!({
+				"block_hash": block_hash,
+				"backing_group": entry.backing_group.0,
+				"approved": entry.approved,
+				"assigned_validators": entry.assigned_validators.iter_ones().collect::<Vec<_>>(),
+				"tranches": tranches,
+				"our_assignment": entry.our_assignment.map(|assignment| json!({
+					"tranche": assignment.tranche,
+					"validator_index": assignment.validator_index.0,
+					"triggered": assignment.triggered,
+				})),
+				"our_approval_signed": entry.our_approval_sig.is_some(),
+			})
+		})
+		.collect::<Vec<_>>();
+
+	json!({
+		"candidate_hash": candidate_hash.0,
+		"para_id": u32::from(candidate.candidate.descriptor.para_id()),
+		"relay_parent": candidate.candidate.descriptor.relay_parent(),
+		"session": candidate.session,
+		"approvals": candidate.approvals.iter_ones().collect::<Vec<_>>(),
+		"block_assignments": block_assignments,
+	})
+}
+
+fn inspect_dispute_coordinator(
+	db: &dyn Database,
+	filter: &ParachainsDbFilter,
+) -> Result<Value, String> {
+	let config = polkadot_node_core_dispute_coordinator::Config {
+		col_dispute_data: REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+	let earliest_session =
+		dispute_coordinator::load_earliest_session(db, &config).map_err(|err| err.to_string())?;
+	let disputes =
+		dispute_coordinator::load_disputes(db, &config).map_err(|err| err.to_string())?;
+
+	let disputes = disputes
+		.into_iter()
+		.filter(|dispute| {
+			filter.session(dispute.session) && filter.candidate(&dispute.candidate_hash)
+		})
+		.map(|dispute| {
+			let status = match dispute.status {
+				DisputeStatus::Active => json!("active"),
+				DisputeStatus::Confirmed => json!("confirmed"),
+				DisputeStatus::ConcludedFor(at) => json!({ "concluded_for": at }),
+				DisputeStatus::ConcludedAgainst(at) => json!({ "concluded_against": at }),
+			};
+			let votes = dispute.votes.map(|votes| {
+				json!({
+					"para_id": u32::from(votes.candidate_receipt.descriptor.para_id()),
+					"valid": votes
+						.valid
+						.iter()
+						.map(|(kind, validator_index, _)| {
+							json!({ "validator_index": validator_index.0, "kind": format!("{:?}", kind) })
+						})
+						.collect::<Vec<_>>(),
+					"invalid": votes
+						.invalid
+						.iter()
+						.map(|(kind, validator_index, _)| {
+							json!({ "validator_index": validator_index.0, "kind": format!("{:?}", kind) })
+						})
+						.collect::<Vec<_>>(),
+				})
+			});
+			json!({
+				"session": dispute.session,
+				"candidate_hash": dispute.candidate_hash.0,
+				"status": status,
+				"votes": votes,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	Ok(json!({ "earliest_session": earliest_session, "disputes": disputes }))
+}
+
+/// Export the concluded disputes of the dispute coordinator to a JSON array.
+///
+/// Unlike the output of [`inspect`], the exported disputes include the vote signatures and the
+/// SCALE-encoded candidate receipts, needed to re-validate the candidates offline.
+pub fn export_concluded_disputes(
+	db: &dyn Database,
+	filter: &ParachainsDbFilter,
+) -> Result<Value, String> {
+	let config = polkadot_node_core_dispute_coordinator::Config {
+		col_dispute_data: REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+	let disputes =
+		dispute_coordinator::load_disputes(db, &config).map_err(|err| err.to_string())?;
+
+	let disputes = disputes
+		.into_iter()
+		.filter(|dispute| {
+			filter.session(dispute.session) && filter.candidate(&dispute.candidate_hash)
+		})
+		.filter_map(|dispute| {
+			let (outcome, concluded_at) = match dispute.status {
+				DisputeStatus::ConcludedFor(at) => ("valid", at),
+				DisputeStatus::ConcludedAgainst(at) => ("invalid", at),
+				DisputeStatus::Active | DisputeStatus::Confirmed => return None,
+			};
+			let candidate = dispute.votes.as_ref().map(|votes| {
+				let receipt = &votes.candidate_receipt;
+				json!({
+					"para_id": u32::from(receipt.descriptor.para_id()),
+					"relay_parent": receipt.descriptor.relay_parent(),
+					"pov_hash": receipt.descriptor.pov_hash(),
+					"validation_code_hash": receipt.descriptor.validation_code_hash().0,
+					"para_head": receipt.descriptor.para_head(),
+					"commitments_hash": receipt.commitments_hash,
+					"receipt": Bytes(receipt.encode()),
+				})
+			});
+			let votes = dispute.votes.map(|votes| {
+				json!({
+					"valid": votes
+						.valid
+						.iter()
+						.map(|(kind, validator_index, signature)| {
+							exported_vote_json(kind, *validator_index, signature)
+						})
+						.collect::<Vec<_>>(),
+					"invalid": votes
+						.invalid
+						.iter()
+						.map(|(kind, validator_index, signature)| {
+							exported_vote_json(kind, *validator_index, signature)
+						})
+						.collect::<Vec<_>>(),
+				})
+			});
+
+			Some(json!({
+				"session": dispute.session,
+				"candidate_hash": dispute.candidate_hash.0,
+				"outcome": outcome,
+				"concluded_at": concluded_at,
+				"candidate": candidate,
+				"votes": votes,
+			}))
+		})
+		.collect();
+
+	Ok(Value::Array(disputes))
+}
+
+fn exported_vote_json(
+	kind: &impl fmt::Debug,
+	validator_index: ValidatorIndex,
+	signature: &ValidatorSignature,
+) -> Value {
+	json!({
+		"validator_index": validator_index.0,
+		"kind": format!("{:?}", kind),
+		"signature": Bytes(signature.encode()),
+	})
+}
+
+fn inspect_chain_selection(
+	db: Arc<dyn Database>,
+	filter: &ParachainsDbFilter,
+) -> Result<Value, String> {
+	// Chain selection tracks blocks, not candidates.
+	if filter.candidate_hash.is_some() {
+		return Ok(Value::Null)
+	}
+
+	let config = polkadot_node_core_chain_selection::Config {
+		col_data: REAL_COLUMNS.col_chain_selection_data,
+		stagnant_check_interval: polkadot_node_core_chain_selection::StagnantCheckInterval::never(),
+		stagnant_check_mode: polkadot_node_core_chain_selection::StagnantCheckMode::PruneOnly,
+	};
+	let snapshot = chain_selection::load_snapshot(db, &config).map_err(|err| err.to_string())?;
+
+	Ok(json!({
+		"leaves": snapshot.leaves,
+		"blocks": snapshot
+			.blocks
+			.into_iter()
+			.map(|block| {
+				json!({
+					"block_hash": block.block_hash,
+					"block_number": block.block_number,
+					"parent_hash": block.parent_hash,
+					"children": block.children,
+					"weight": block.weight,
+					"approved": block.approved,
+					"stagnant": block.stagnant,
+					"explicitly_reverted": block.explicitly_reverted,
+					"earliest_unviable_ancestor": block.earliest_unviable_ancestor,
+					"viable": block.viable,
+				})
+			})
+			.collect::<Vec<_>>(),
+		"stagnant_at": snapshot
+			.stagnant_at
+			.into_iter()
+			.map(|(timestamp, blocks)| json!({ "timestamp": timestamp, "blocks": blocks }))
+			.collect::<Vec<_>>(),
+	}))
+}
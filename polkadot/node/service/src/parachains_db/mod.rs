@@ -18,9 +18,14 @@ use {
 	polkadot_node_subsystem_util::database::Database, std::io, std::path::PathBuf, std::sync::Arc,
 };
 
+#[cfg(feature = "full-node")]
+mod inspect;
 #[cfg(feature = "full-node")]
 mod upgrade;
 
+#[cfg(feature = "full-node")]
+pub use inspect::{export_concluded_disputes, inspect, ParachainsDbColumn, ParachainsDbFilter};
+
 const LOG_TARGET: &str = "parachain::db";
 
 /// Column configuration per version.
@@ -173,3 +178,61 @@ pub fn open_creating_paritydb(
 	);
 	Ok(Arc::new(db))
 }
+
+/// Open an existing rocksdb database for inspection.
+///
+/// Unlike [`open_creating_rocksdb`], this neither creates nor migrates the database. It is opened
+/// as a secondary instance, which is read-only and writes its info log next to the database.
+#[cfg(feature = "full-node")]
+pub fn open_existing_rocksdb(root: PathBuf) -> io::Result<Arc<dyn Database>> {
+	use kvdb_rocksdb::{Database, DatabaseConfig};
+
+	let path = root.join("parachains").join("db");
+	let path_str = path
+		.to_str()
+		.ok_or_else(|| other_io_error(format!("Bad database path: {:?}", path)))?;
+	if !path.exists() {
+		return Err(io::Error::new(
+			io::ErrorKind::NotFound,
+			format!("No parachains database at {:?}", path),
+		))
+	}
+
+	upgrade::check_db_version(&path, DatabaseKind::RocksDB)?;
+
+	let mut db_config = DatabaseConfig::with_columns(columns::v4::NUM_COLUMNS);
+	db_config.create_if_missing = false;
+	db_config.secondary = Some(root.join("parachains").join("db-secondary"));
+	let db = Database::open(&db_config, &path_str)?;
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
+		db,
+		columns::v4::ORDERED_COL,
+	);
+
+	Ok(Arc::new(db))
+}
+
+/// Open an existing parity db database for inspection.
+///
+/// Unlike [`open_creating_paritydb`], this neither creates nor migrates the database.
+#[cfg(feature = "full-node")]
+pub fn open_existing_paritydb(root: PathBuf) -> io::Result<Arc<dyn Database>> {
+	let path = root.join("parachains");
+	if !path.exists() {
+		return Err(io::Error::new(
+			io::ErrorKind::NotFound,
+			format!("No parachains database at {:?}", path),
+		))
+	}
+
+	upgrade::check_db_version(&path, DatabaseKind::ParityDB)?;
+
+	let db = parity_db::Db::open_read_only(&upgrade::paritydb_version_3_config(&path))
+		.map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{:?}", err)))?;
+
+	let db = polkadot_node_subsystem_util::database::paritydb_impl::DbAdapter::new(
+		db,
+		columns::v4::ORDERED_COL,
+	);
+	Ok(Arc::new(db))
+}
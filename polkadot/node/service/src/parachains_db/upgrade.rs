@@ -50,6 +50,8 @@ pub enum Error {
 	CorruptedVersionFile,
 	#[error("Parachains DB has a future version (expected {current:?}, found {got:?})")]
 	FutureVersion { current: Version, got: Version },
+	#[error("Parachains DB has an outdated version (expected {current:?}, found {got:?}), start the node once to migrate it")]
+	OutdatedVersion { current: Version, got: Version },
 	#[error("Parachain DB migration failed")]
 	MigrationFailed,
 	#[error("Parachain DB migration would take forever")]
@@ -131,6 +133,20 @@ pub(crate) fn try_upgrade_db_to_next_version(
 	Ok(new_version)
 }
 
+/// Check that the database at the given path is at the current version, without migrating it.
+pub(crate) fn check_db_version(db_path: &Path, db_kind: DatabaseKind) -> Result<(), Error> {
+	match get_db_version(db_path)? {
+		Some(CURRENT_VERSION) => Ok(()),
+		Some(v) if v > CURRENT_VERSION =>
+			Err(Error::FutureVersion { current: CURRENT_VERSION, got: v }),
+		Some(v) => Err(Error::OutdatedVersion { current: CURRENT_VERSION, got: v }),
+		// No version file. For `RocksDB` this is the current version.
+		None if db_kind == DatabaseKind::RocksDB => Ok(()),
+		// No version file. `ParityDB` did not previously have a version defined.
+		None => Err(Error::OutdatedVersion { current: CURRENT_VERSION, got: 0 }),
+	}
+}
+
 /// Reads current database version from the file at given path.
 /// If the file does not exist returns `None`, otherwise the version stored in the file.
 fn get_db_version(path: &Path) -> Result<Option<Version>, Error> {
@@ -699,6 +715,36 @@ mod tests {
 		assert_eq!(db.num_columns(), columns::v4::NUM_COLUMNS as u8);
 	}
 
+	#[test]
+	fn test_check_db_version() {
+		let db_dir = tempfile::tempdir().unwrap();
+		let path = db_dir.path();
+
+		// Without a version file, only `RocksDB` is considered current.
+		assert!(check_db_version(&path, DatabaseKind::RocksDB).is_ok());
+		assert!(matches!(
+			check_db_version(&path, DatabaseKind::ParityDB),
+			Err(Error::OutdatedVersion { got: 0, .. })
+		));
+
+		fs::write(version_file_path(path), "3").expect("Failed to write DB version");
+		assert!(matches!(
+			check_db_version(&path, DatabaseKind::RocksDB),
+			Err(Error::OutdatedVersion { got: 3, .. })
+		));
+
+		fs::write(version_file_path(path), CURRENT_VERSION.to_string())
+			.expect("Failed to write DB version");
+		assert!(check_db_version(&path, DatabaseKind::ParityDB).is_ok());
+
+		fs::write(version_file_path(path), (CURRENT_VERSION + 1).to_string())
+			.expect("Failed to write DB version");
+		assert!(matches!(
+			check_db_version(&path, DatabaseKind::ParityDB),
+			Err(Error::FutureVersion { .. })
+		));
+	}
+
 	#[test]
 	fn test_paritydb_migrate_2_to_3() {
 		use parity_db::Db;
@@ -192,6 +192,21 @@ pub fn prepare_test(
 			sc_network::NetworkWorker<Block, Hash>,
 		>(&state.req_protocol_names);
 
+	// Available data blocks are not fetched in the benchmark, but the inbound queues need to be
+	// kept alive as well.
+	let (available_data_manifest_req_receiver, available_data_manifest_req_cfg) =
+		IncomingRequest::<v1::AvailableDataManifestRequest>::get_config_receiver::<
+			Block,
+			sc_network::NetworkWorker<Block, Hash>,
+		>(&state.req_protocol_names);
+	std::mem::forget(available_data_manifest_req_cfg);
+	let (available_data_bitswap_req_receiver, available_data_bitswap_req_cfg) =
+		IncomingRequest::<v1::AvailableDataBitswapRequest>::get_config_receiver::<
+			Block,
+			sc_network::NetworkWorker<Block, Hash>,
+		>(&state.req_protocol_names);
+	std::mem::forget(available_data_bitswap_req_cfg);
+
 	let (network, network_interface, network_receiver) = new_network(
 		&state.config,
 		&dependencies,
@@ -265,6 +280,8 @@ pub fn prepare_test(
 					pov_req_receiver,
 					chunk_req_v1_receiver,
 					chunk_req_v2_receiver,
+					available_data_manifest_req_receiver,
+					available_data_bitswap_req_receiver,
 				},
 				state.req_protocol_names.clone(),
 				Metrics::try_register(&dependencies.registry).unwrap(),
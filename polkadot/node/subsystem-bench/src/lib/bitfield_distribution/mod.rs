@@ -0,0 +1,212 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Subsystem benchmark for the bitfield distribution subsystem.
+//!
+//! Unlike the availability write benchmark, which only feeds bitfields to the subsystem, every
+//! block the node under test distributes its own bitfield and relays the bitfields of all
+//! connected validators to its grid and random peers.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{new_network, NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver},
+	statement::generate_topology,
+	usage::BenchmarkUsage,
+	NODE_UNDER_TEST,
+};
+use colored::Colorize;
+use polkadot_availability_bitfield_distribution::BitfieldDistribution;
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{grid_topology::NewGossipTopology, our_view, view};
+use polkadot_node_subsystem::messages::{
+	AllMessages, BitfieldDistributionMessage, NetworkBridgeEvent,
+};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::ValidatorIndex;
+use sc_service::SpawnTaskHandle;
+use std::{sync::Arc, time::Instant};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::bitfield-distribution";
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	);
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, None);
+	let bitfield_distribution =
+		BitfieldDistribution::new(Metrics::try_register(&dependencies.registry).unwrap());
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx)
+		.replace_bitfield_distribution(|_| bitfield_distribution);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let (network, network_interface, network_receiver) = new_network(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+pub async fn benchmark_bitfield_distribution(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	let peer_connected_messages = env.network().generate_peer_connected(|e| {
+		AllMessages::BitfieldDistribution(BitfieldDistributionMessage::NetworkBridgeUpdate(e))
+	});
+	let topology_message =
+		AllMessages::BitfieldDistribution(BitfieldDistributionMessage::NetworkBridgeUpdate(
+			NetworkBridgeEvent::NewGossipTopology(NewGossipTopology {
+				session: 0,
+				topology: generate_topology(&state.test_authorities),
+				local_index: Some(ValidatorIndex(NODE_UNDER_TEST)),
+			}),
+		));
+	for message in peer_connected_messages.into_iter().chain(std::iter::once(topology_message)) {
+		env.send_message(message).await;
+	}
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+
+		let block_start_ts = Instant::now();
+		env.import_block(block_info.clone()).await;
+		env.send_message(AllMessages::BitfieldDistribution(
+			BitfieldDistributionMessage::NetworkBridgeUpdate(NetworkBridgeEvent::OurViewChange(
+				our_view![block_info.hash],
+			)),
+		))
+		.await;
+		for peer_view_change in
+			env.network().generate_peer_view_change(view![block_info.hash], |e| {
+				AllMessages::BitfieldDistribution(BitfieldDistributionMessage::NetworkBridgeUpdate(
+					e,
+				))
+			}) {
+			env.send_message(peer_view_change).await;
+		}
+
+		let own_bitfield = state.own_bitfields.get(&block_info.hash).expect("pregenerated").clone();
+		env.send_message(AllMessages::BitfieldDistribution(
+			BitfieldDistributionMessage::DistributeBitfield(block_info.hash, own_bitfield),
+		))
+		.await;
+
+		let messages = state.peer_bitfields.get(&block_info.hash).expect("pregenerated");
+		for (index, message) in messages.iter().enumerate().skip(1) {
+			let from_peer = &state.test_authorities.validator_authority_id[index];
+			// Send the bitfield from peer only if it is connected to our node.
+			if env.network().is_peer_connected(from_peer) {
+				let _ = env.network().send_message_from_peer(from_peer, message.clone());
+			}
+		}
+
+		gum::info!(
+			target: LOG_TARGET,
+			"Waiting for {} bitfields to be received and processed",
+			config.connected_count()
+		);
+		env.wait_until_metric(
+			"polkadot_parachain_received_availability_bitfields_total",
+			None,
+			|value| value == (config.connected_count() * block_num) as f64,
+		)
+		.await;
+
+		let block_time = block_start_ts.elapsed().as_millis() as u64;
+		env.metrics().set_block_time(block_time);
+		gum::info!(target: LOG_TARGET, "All work for block completed in {}", format!("{block_time:?}ms").cyan());
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{duration:?}ms").cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", test_start.elapsed().as_millis() / env.config().num_blocks as u128).red()
+	);
+	gum::info!(target: LOG_TARGET,
+		"Bitfields relayed to peers: {}",
+		format!("{}", state.relayed_bitfields()).cyan()
+	);
+
+	env.stop().await;
+	env.collect_resource_usage(&["bitfield-distribution"], false)
+}
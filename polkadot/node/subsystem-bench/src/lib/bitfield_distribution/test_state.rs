@@ -0,0 +1,173 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	configuration::{TestAuthorities, TestConfiguration},
+	network::{HandleNetworkMessage, NetworkMessage},
+	NODE_UNDER_TEST,
+};
+use bitvec::bitvec;
+use polkadot_node_network_protocol::{
+	v3::{BitfieldDistributionMessage, ValidationProtocol},
+	ValidationProtocols, VersionedValidationProtocol,
+};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	AvailabilityBitfield, BlockNumber, Hash, Header, Signed, SignedAvailabilityBitfield,
+	SigningContext, ValidatorIndex,
+};
+use sp_core::H256;
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+};
+
+const SESSION_INDEX: u32 = 0;
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// Pregenerated bitfield messages of all validators, indexed by validator. The node under test
+	// distributes its own bitfield from `own_bitfields` instead.
+	pub peer_bitfields: HashMap<H256, Vec<VersionedValidationProtocol>>,
+	// Pregenerated bitfields of the node under test
+	pub own_bitfields: HashMap<H256, SignedAvailabilityBitfield>,
+	// Number of bitfields received by the emulated peers
+	pub relayed_bitfields: Arc<AtomicUsize>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration) -> Self {
+		let test_authorities = config.generate_authorities();
+		let block_infos: Vec<BlockInfo> = (1..=config.num_blocks)
+			.map(|block_num| {
+				new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+			})
+			.collect();
+		let block_headers = block_infos
+			.iter()
+			.map(|info| {
+				(
+					info.hash,
+					Header {
+						digest: Default::default(),
+						number: info.number,
+						parent_hash: info.parent_hash,
+						extrinsics_root: Default::default(),
+						state_root: Default::default(),
+					},
+				)
+			})
+			.collect();
+
+		let mut peer_bitfields = HashMap::new();
+		let mut own_bitfields = HashMap::new();
+		for block_info in block_infos.iter() {
+			let signing_context =
+				SigningContext { session_index: SESSION_INDEX, parent_hash: block_info.hash };
+			let bitfields = (0..config.n_validators)
+				.map(|index| sign_bitfield(config, &test_authorities, &signing_context, index))
+				.collect::<Vec<_>>();
+			own_bitfields.insert(block_info.hash, bitfields[NODE_UNDER_TEST as usize].clone());
+			peer_bitfields.insert(
+				block_info.hash,
+				bitfields
+					.into_iter()
+					.map(|bitfield| peer_bitfield_message(block_info.hash, bitfield))
+					.collect(),
+			);
+		}
+
+		Self {
+			config: config.clone(),
+			test_authorities,
+			block_infos,
+			block_headers,
+			peer_bitfields,
+			own_bitfields,
+			relayed_bitfields: Default::default(),
+		}
+	}
+
+	pub fn relayed_bitfields(&self) -> usize {
+		self.relayed_bitfields.load(Ordering::SeqCst)
+	}
+}
+
+fn sign_bitfield(
+	config: &TestConfiguration,
+	test_authorities: &TestAuthorities,
+	signing_context: &SigningContext,
+	index: usize,
+) -> SignedAvailabilityBitfield {
+	let validator_public = test_authorities
+		.validator_public
+		.get(index)
+		.expect("All validator keys are known");
+	// Every validator has all the chunks.
+	let payload = AvailabilityBitfield(bitvec![u8, bitvec::order::Lsb0; 1u8; config.n_cores]);
+
+	Signed::<AvailabilityBitfield>::sign(
+		&test_authorities.keyring.keystore(),
+		payload,
+		signing_context,
+		ValidatorIndex(index as u32),
+		validator_public,
+	)
+	.ok()
+	.flatten()
+	.expect("should be signed")
+}
+
+fn peer_bitfield_message(
+	relay_hash: H256,
+	signed_bitfield: SignedAvailabilityBitfield,
+) -> VersionedValidationProtocol {
+	ValidationProtocols::V3(ValidationProtocol::BitfieldDistribution(
+		BitfieldDistributionMessage::Bitfield(relay_hash, signed_bitfield.into()),
+	))
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::MessageFromNode(
+				_authority_id,
+				ValidationProtocols::V3(ValidationProtocol::BitfieldDistribution(_)),
+			) => {
+				self.relayed_bitfields.fetch_add(1, Ordering::SeqCst);
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
@@ -0,0 +1,344 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Subsystem benchmark for the validator side of the collator protocol.
+//!
+//! Scenario:
+//! - `n_paras` paras share the core of the node under test, the claim queue advances by one para
+//!   every block.
+//! - Every para has `collators_per_para` emulated collators. Each of them declares itself once its
+//!   para is assigned and advertises a unique collation at every relay parent its para has a claim
+//!   at.
+//! - The collator protocol fetches the advertised collations from the emulated collators and the
+//!   mocked candidate backing subsystem seconds all of them.
+//!
+//! Besides CPU and network usage, the benchmark reports the collation fetch latency and how fairly
+//! the claims were served across paras.
+
+use crate::{
+	configuration::PeerLatency,
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		candidate_backing::MockCandidateBacking,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		prospective_parachains::MockProspectiveParachains,
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{
+		new_network_with_collators, NetworkEmulatorHandle, NetworkInterface,
+		NetworkInterfaceReceiver,
+	},
+	usage::BenchmarkUsage,
+	NODE_UNDER_TEST,
+};
+use colored::Colorize;
+use polkadot_collator_protocol::{CollatorProtocolSubsystem, ProtocolSide};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	our_view,
+	peer_set::CollationVersion,
+	v2::{CollationProtocol, CollatorProtocolMessage as WireMessage},
+	CollationProtocols, ObservedRole,
+};
+use polkadot_node_subsystem::messages::{AllMessages, CollatorProtocolMessage, NetworkBridgeEvent};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use polkadot_primitives::{AuthorityDiscoveryId, ValidatorId};
+use sc_keystore::LocalKeystore;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::RuntimeAppPublic;
+use std::{
+	collections::{BTreeMap, HashSet},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::collator-protocol";
+
+/// Parameters specific to the collator protocol benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CollatorProtocolOptions {
+	#[clap(short, long, default_value_t = 5)]
+	/// The number of paras sharing the core of the node under test.
+	pub n_paras: usize,
+	#[clap(short, long, default_value_t = 20)]
+	/// The number of collators of every para.
+	pub collators_per_para: usize,
+	#[clap(long, default_value_t = 3)]
+	/// The length of the claim queue.
+	pub claim_queue_len: usize,
+	#[clap(long, default_value_t = 3000)]
+	/// How long to wait for the claims of a block to be seconded, in milliseconds.
+	pub block_timeout_ms: u64,
+}
+
+pub fn make_keystore() -> KeystorePtr {
+	let keystore: KeystorePtr = Arc::new(LocalKeystore::in_memory());
+	Keystore::sr25519_generate_new(&*keystore, ValidatorId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	Keystore::sr25519_generate_new(&*keystore, AuthorityDiscoveryId::ID, Some("//Node0"))
+		.expect("Insert key into keystore");
+	keystore
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	)
+	.with_claim_queues(state.claim_queues.clone());
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let mock_prospective_parachains = MockProspectiveParachains::new()
+		.with_minimum_relay_parents(state.minimum_relay_parents.clone())
+		.with_validation_data(state.pvd.clone());
+	let mock_candidate_backing = MockCandidateBacking::new(
+		state.config.clone(),
+		state
+			.test_authorities
+			.validator_pairs
+			.get(NODE_UNDER_TEST as usize)
+			.unwrap()
+			.clone(),
+		state.pvd.clone(),
+		Default::default(),
+	);
+	let subsystem = CollatorProtocolSubsystem::new(ProtocolSide::Validator {
+		keystore: make_keystore(),
+		eviction_policy: Default::default(),
+		metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+	});
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, None);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_prospective_parachains(|_| mock_prospective_parachains)
+		.replace_candidate_backing(|_| mock_candidate_backing)
+		.replace_collator_protocol(|_| subsystem)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let collators = state
+		.collators
+		.iter()
+		.map(|collator| (collator.peer_id, collator.authority_id.clone()))
+		.collect::<Vec<_>>();
+	let (network, network_interface, network_receiver) = new_network_with_collators(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		&collators,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+// An upper bound of the emulated latency, messages sent by a peer are delivered in order after it.
+fn max_latency(latency: Option<&PeerLatency>) -> Duration {
+	latency
+		.map(|latency| {
+			Duration::from_millis(
+				latency.mean_latency_ms as u64 + (latency.std_dev * 4.0).ceil() as u64,
+			)
+		})
+		.unwrap_or_default()
+}
+
+pub async fn benchmark_collator_protocol(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+	let block_timeout = Duration::from_millis(state.options.block_timeout_ms);
+	let declare_delay = max_latency(config.latency.as_ref()) + Duration::from_millis(50);
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	// All collators stay connected for the whole test.
+	for collator in state.collators.iter() {
+		env.send_message(AllMessages::CollatorProtocol(
+			CollatorProtocolMessage::NetworkBridgeUpdate(NetworkBridgeEvent::PeerConnected(
+				collator.peer_id,
+				ObservedRole::Full,
+				CollationVersion::V2.into(),
+				None,
+			)),
+		))
+		.await;
+	}
+
+	let mut declared = HashSet::new();
+	let mut total_claims = BTreeMap::new();
+	let mut expected_seconded = 0;
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+
+		let block_start_ts = Instant::now();
+		env.import_block(block_info.clone()).await;
+		env.send_message(AllMessages::CollatorProtocol(
+			CollatorProtocolMessage::NetworkBridgeUpdate(NetworkBridgeEvent::OurViewChange(
+				our_view![block_info.hash],
+			)),
+		))
+		.await;
+
+		let claims = state.claims(&block_info.hash);
+		let collators = state
+			.collators
+			.iter()
+			.enumerate()
+			.filter(|(_, collator)| claims.contains_key(&collator.para_id))
+			.collect::<Vec<_>>();
+
+		// Collators of newly assigned paras declare themselves before advertising.
+		let mut declarations = 0;
+		for (_, collator) in collators.iter() {
+			if declared.insert(collator.peer_id) {
+				let _ = env.network().send_collation_message_from_peer(
+					&collator.authority_id,
+					collator.declare_message(),
+				);
+				declarations += 1;
+			}
+		}
+		if declarations > 0 {
+			gum::debug!(target: LOG_TARGET, "{} collators declared", declarations);
+			tokio::time::sleep(declare_delay).await;
+		}
+
+		let collations = state.collations.get(&block_info.hash).expect("pregenerated");
+		for (index, collator) in collators.iter() {
+			let receipt = &collations[*index];
+			let candidate_hash = receipt.hash();
+			let message = CollationProtocols::V2(CollationProtocol::CollatorProtocol(
+				WireMessage::AdvertiseCollation {
+					relay_parent: block_info.hash,
+					candidate_hash,
+					parent_head_data_hash: state.pvd.parent_head.hash(),
+				},
+			));
+			state.tracker.on_advertised(candidate_hash);
+			let _ = env.network().send_collation_message_from_peer(&collator.authority_id, message);
+		}
+
+		for (para_id, para_claims) in claims {
+			let para_collators =
+				collators.iter().filter(|(_, collator)| collator.para_id == para_id).count();
+			expected_seconded += para_claims.min(para_collators);
+			*total_claims.entry(para_id).or_insert(0) += para_claims;
+		}
+
+		gum::info!(target: LOG_TARGET, "Waiting for {} collations to be seconded", expected_seconded);
+		while state.tracker.seconded_count() < expected_seconded {
+			if block_start_ts.elapsed() > block_timeout {
+				gum::warn!(
+					target: LOG_TARGET,
+					"Only {}/{} collations seconded after {}ms",
+					state.tracker.seconded_count(),
+					expected_seconded,
+					block_timeout.as_millis(),
+				);
+				break
+			}
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+
+		let block_time = block_start_ts.elapsed().as_millis() as u64;
+		env.metrics().set_block_time(block_time);
+		gum::info!(target: LOG_TARGET, "All work for block completed in {}", format!("{block_time:?}ms").cyan());
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{duration:?}ms").cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", test_start.elapsed().as_millis() / env.config().num_blocks as u128).red()
+	);
+	gum::info!(target: LOG_TARGET,
+		"Avg collation fetch latency: {}, avg seconding latency: {}",
+		format!("{} ms", state.tracker.avg_fetch_latency().as_millis()).red(),
+		format!("{} ms", state.tracker.avg_seconding_latency().as_millis()).red(),
+	);
+
+	// The share of its claims every para got seconded.
+	let seconded = state.tracker.seconded();
+	for (para_id, claims) in total_claims {
+		let para_seconded = seconded.get(&para_id).copied().unwrap_or_default();
+		gum::info!(target: LOG_TARGET,
+			"Para {}: {}/{} claims seconded ({})",
+			para_id,
+			para_seconded,
+			claims,
+			format!("{:.1}%", para_seconded as f64 * 100.0 / claims as f64).cyan(),
+		);
+	}
+
+	env.stop().await;
+	env.collect_resource_usage(&["collator-protocol"], false)
+}
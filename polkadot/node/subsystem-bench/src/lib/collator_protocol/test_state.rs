@@ -0,0 +1,364 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	collator_protocol::CollatorProtocolOptions,
+	configuration::{TestAuthorities, TestConfiguration},
+	environment::GENESIS_HASH,
+	network::{HandleNetworkMessage, NetworkMessage},
+};
+use codec::Encode;
+use polkadot_node_network_protocol::{
+	request_response::{v1::CollationFetchingResponse, Requests},
+	v2::{self as protocol_v2, CollationProtocol, CollatorProtocolMessage},
+	CollationProtocols, VersionedCollationProtocol,
+};
+use polkadot_node_primitives::{BlockData, PoV, Statement};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	vstaging::{CandidateReceiptV2 as CandidateReceipt, MutateDescriptorV2},
+	AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash, CollatorPair,
+	CoreIndex, Hash, Header, Id as ParaId, PersistedValidationData,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_committed_candidate_receipt_v2, dummy_hash, dummy_head_data, dummy_pvd,
+};
+use sc_network::ProtocolName;
+use sc_network_types::PeerId;
+use sp_core::{Pair, H256};
+use std::{
+	collections::{BTreeMap, HashMap, VecDeque},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+const SESSION_INDEX: u32 = 0;
+
+/// The core the node under test is assigned to.
+pub const CORE_INDEX: CoreIndex = CoreIndex(0);
+
+/// An emulated collator.
+#[derive(Clone)]
+pub struct TestCollator {
+	pub pair: CollatorPair,
+	pub peer_id: PeerId,
+	pub authority_id: AuthorityDiscoveryId,
+	pub para_id: ParaId,
+}
+
+impl TestCollator {
+	/// The `Declare` message the collator sends after connecting to the node.
+	pub fn declare_message(&self) -> VersionedCollationProtocol {
+		let signature = self.pair.sign(&protocol_v2::declare_signature_payload(&self.peer_id));
+
+		CollationProtocols::V2(CollationProtocol::CollatorProtocol(
+			CollatorProtocolMessage::Declare(self.pair.public(), self.para_id, signature),
+		))
+	}
+}
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Objective specific options
+	pub options: CollatorProtocolOptions,
+	// Authority keys for the network emulation, including the collators' peer ids.
+	pub test_authorities: TestAuthorities,
+	// Emulated collators
+	pub collators: Vec<TestCollator>,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// The claim queue at every relay chain block
+	pub claim_queues: HashMap<H256, BTreeMap<CoreIndex, VecDeque<ParaId>>>,
+	// Minimum relay parents of all paras at every relay chain block
+	pub minimum_relay_parents: HashMap<H256, Vec<(ParaId, BlockNumber)>>,
+	// Collations advertised at every relay chain block, indexed by collator
+	pub collations: HashMap<H256, Vec<CandidateReceipt>>,
+	// PersistedValidationData, we use one for all candidates
+	pub pvd: PersistedValidationData,
+	// PoV served by the collators of each para
+	pub povs: HashMap<ParaId, PoV>,
+	// Tracks the metrics gathered by the emulated collators
+	pub tracker: CollationTracker,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &CollatorProtocolOptions) -> Self {
+		assert!(options.n_paras > 0, "At least one para is required");
+		assert!(options.claim_queue_len > 0, "The claim queue can't be empty");
+
+		let mut test_authorities = config.generate_authorities();
+		let para_ids = (1..=options.n_paras as u32).map(ParaId::from).collect::<Vec<_>>();
+		let collators = para_ids
+			.iter()
+			.flat_map(|para_id| (0..options.collators_per_para).map(move |i| (*para_id, i)))
+			.map(|(para_id, i)| {
+				let seed = format!("//Collator{}/{}", u32::from(para_id), i);
+				let pair = CollatorPair::from_string(&seed, None).expect("Valid seed");
+				let authority_id = test_authorities.keyring.sr25519_new(seed.as_str()).into();
+				TestCollator { pair, peer_id: PeerId::random(), authority_id, para_id }
+			})
+			.collect::<Vec<_>>();
+		test_authorities.peer_id_to_authority.extend(
+			collators
+				.iter()
+				.map(|collator| (collator.peer_id, collator.authority_id.clone())),
+		);
+
+		let block_infos: Vec<BlockInfo> =
+			(1..=config.num_blocks).map(generate_block_info).collect();
+		let block_headers = block_infos
+			.iter()
+			.map(generate_block_header)
+			.chain(std::iter::once(generate_genesis_header()))
+			.collect();
+
+		// The claim queue advances over all paras by one claim every block.
+		let claim_queues = block_infos
+			.iter()
+			.map(|block_info| {
+				let claims = (0..options.claim_queue_len)
+					.map(|i| para_ids[(block_info.number as usize + i) % para_ids.len()])
+					.collect();
+				(block_info.hash, BTreeMap::from([(CORE_INDEX, claims)]))
+			})
+			.collect();
+		let minimum_relay_parents = block_infos
+			.iter()
+			.map(|block_info| {
+				(
+					block_info.hash,
+					para_ids.iter().map(|para_id| (*para_id, block_info.number)).collect(),
+				)
+			})
+			.collect();
+
+		let pvd = dummy_pvd(dummy_head_data(), 0);
+		let pov_sizes = config.pov_sizes();
+		let povs = para_ids
+			.iter()
+			.enumerate()
+			.map(|(index, para_id)| {
+				let pov_size = pov_sizes[index % pov_sizes.len()];
+				(*para_id, PoV { block_data: BlockData(vec![index as u8; pov_size]) })
+			})
+			.collect::<HashMap<_, _>>();
+
+		// The mocked backing subsystem seconds candidates with default commitments.
+		let commitments: CandidateCommitments = Default::default();
+		let commitments_hash = commitments.hash();
+		let collations = block_infos
+			.iter()
+			.map(|block_info| {
+				let receipts = collators
+					.iter()
+					.enumerate()
+					.map(|(index, collator)| {
+						let mut descriptor =
+							dummy_committed_candidate_receipt_v2(dummy_hash()).descriptor;
+						descriptor.set_para_id(collator.para_id);
+						descriptor.set_relay_parent(block_info.hash);
+						descriptor.set_core_index(CORE_INDEX);
+						descriptor.set_session_index(SESSION_INDEX);
+						descriptor.set_persisted_validation_data_hash(pvd.hash());
+						// Makes every collation unique.
+						descriptor.set_pov_hash(H256::from_low_u64_be(
+							(block_info.number as u64) << 32 | index as u64,
+						));
+
+						CandidateReceipt { descriptor, commitments_hash }
+					})
+					.collect();
+				(block_info.hash, receipts)
+			})
+			.collect();
+
+		Self {
+			config: config.clone(),
+			options: options.clone(),
+			test_authorities,
+			collators,
+			block_infos,
+			block_headers,
+			claim_queues,
+			minimum_relay_parents,
+			collations,
+			pvd,
+			povs,
+			tracker: Default::default(),
+		}
+	}
+
+	/// Paras with claims in the claim queue of `block_hash`, with the number of claims.
+	pub fn claims(&self, block_hash: &H256) -> BTreeMap<ParaId, usize> {
+		let mut claims = BTreeMap::new();
+		for para_id in self.claim_queues[block_hash][&CORE_INDEX].iter() {
+			*claims.entry(*para_id).or_default() += 1;
+		}
+		claims
+	}
+
+	// Returns the collation advertised with `candidate_hash`.
+	fn collation(&self, relay_parent: &H256, candidate_hash: &CandidateHash) -> &CandidateReceipt {
+		self.collations
+			.get(relay_parent)
+			.and_then(|collations| collations.iter().find(|c| &c.hash() == candidate_hash))
+			.expect("Only generated collations are fetched")
+	}
+}
+
+/// Book keeping of the advertised, fetched and seconded collations.
+#[derive(Clone, Default)]
+pub struct CollationTracker {
+	inner: Arc<Mutex<CollationTrackerInner>>,
+}
+
+#[derive(Default)]
+struct CollationTrackerInner {
+	advertised_at: HashMap<CandidateHash, Instant>,
+	fetch_latencies: Vec<Duration>,
+	seconding_latencies: Vec<Duration>,
+	seconded: HashMap<ParaId, usize>,
+}
+
+impl CollationTracker {
+	pub fn on_advertised(&self, candidate_hash: CandidateHash) {
+		self.inner.lock().unwrap().advertised_at.insert(candidate_hash, Instant::now());
+	}
+
+	fn on_fetched(&self, candidate_hash: &CandidateHash) {
+		let mut inner = self.inner.lock().unwrap();
+		if let Some(advertised_at) = inner.advertised_at.get(candidate_hash) {
+			let latency = advertised_at.elapsed();
+			inner.fetch_latencies.push(latency);
+		}
+	}
+
+	fn on_seconded(&self, candidate_hash: &CandidateHash, para_id: ParaId) {
+		let mut inner = self.inner.lock().unwrap();
+		if let Some(advertised_at) = inner.advertised_at.get(candidate_hash) {
+			let latency = advertised_at.elapsed();
+			inner.seconding_latencies.push(latency);
+		}
+		*inner.seconded.entry(para_id).or_default() += 1;
+	}
+
+	/// Total number of seconded collations.
+	pub fn seconded_count(&self) -> usize {
+		self.inner.lock().unwrap().seconded.values().sum()
+	}
+
+	/// Number of seconded collations per para.
+	pub fn seconded(&self) -> HashMap<ParaId, usize> {
+		self.inner.lock().unwrap().seconded.clone()
+	}
+
+	/// Average time between advertising and being requested to serve a collation.
+	pub fn avg_fetch_latency(&self) -> Duration {
+		average(&self.inner.lock().unwrap().fetch_latencies)
+	}
+
+	/// Average time between advertising and being notified that a collation was seconded.
+	pub fn avg_seconding_latency(&self) -> Duration {
+		average(&self.inner.lock().unwrap().seconding_latencies)
+	}
+}
+
+fn average(durations: &[Duration]) -> Duration {
+	if durations.is_empty() {
+		return Duration::ZERO
+	}
+	durations.iter().sum::<Duration>() / durations.len() as u32
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+// The finalized block reported by the chain api mock.
+fn generate_genesis_header() -> (H256, Header) {
+	(
+		GENESIS_HASH,
+		Header {
+			digest: Default::default(),
+			number: 0,
+			parent_hash: Default::default(),
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(authority_id, requests) => {
+				let Requests::CollationFetchingV2(req) = *requests else {
+					return Some(NetworkMessage::RequestFromNode(authority_id, requests))
+				};
+				let payload = req.payload;
+				self.tracker.on_fetched(&payload.candidate_hash);
+
+				let receipt =
+					self.collation(&payload.relay_parent, &payload.candidate_hash).clone();
+				let pov = self.povs[&payload.para_id].clone();
+				let response = CollationFetchingResponse::Collation(receipt, pov);
+				let _ = req.pending_response.send(Ok((response.encode(), ProtocolName::from(""))));
+				None
+			},
+			NetworkMessage::CollationMessageFromNode(
+				authority_id,
+				CollationProtocols::V2(CollationProtocol::CollatorProtocol(
+					CollatorProtocolMessage::CollationSeconded(_relay_parent, statement),
+				)),
+			) => {
+				let para_id = self
+					.collators
+					.iter()
+					.find(|collator| collator.authority_id == authority_id)
+					.expect("Only collators receive collation messages")
+					.para_id;
+				if let Statement::Seconded(receipt) = statement.unchecked_payload() {
+					self.tracker.on_seconded(&receipt.hash(), para_id);
+				}
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
@@ -19,6 +19,8 @@ pub const NODE_UNDER_TEST: u32 = 0;
 
 pub mod approval;
 pub mod availability;
+pub mod bitfield_distribution;
+pub mod collator_protocol;
 pub mod configuration;
 pub(crate) mod display;
 pub mod disputes;
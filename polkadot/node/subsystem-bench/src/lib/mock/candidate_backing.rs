@@ -18,12 +18,19 @@
 
 use crate::{configuration::TestConfiguration, NODE_UNDER_TEST};
 use futures::FutureExt;
-use polkadot_node_primitives::{SignedFullStatementWithPVD, Statement, StatementWithPVD};
+use polkadot_node_primitives::{
+	SignedFullStatement, SignedFullStatementWithPVD, Statement, StatementWithPVD,
+};
 use polkadot_node_subsystem::{
-	messages::CandidateBackingMessage, overseer, SpawnedSubsystem, SubsystemError,
+	messages::{CandidateBackingMessage, CollatorProtocolMessage},
+	overseer, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_types::OverseerSignal;
 use polkadot_primitives::{
+	vstaging::{
+		CandidateReceiptV2 as CandidateReceipt,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+	},
 	CandidateHash, Hash, PersistedValidationData, SigningContext, ValidatorIndex, ValidatorPair,
 };
 use sp_core::Pair;
@@ -123,6 +130,33 @@ impl MockCandidateBacking {
 
 		messages
 	}
+
+	// Seconds a fetched collation without validating it. Collations are expected to be generated
+	// with default commitments.
+	fn handle_second(
+		&self,
+		relay_parent: Hash,
+		receipt: CandidateReceipt,
+	) -> CollatorProtocolMessage {
+		let statement = Statement::Seconded(CommittedCandidateReceipt {
+			descriptor: receipt.descriptor,
+			commitments: Default::default(),
+		});
+		let context = SigningContext { parent_hash: relay_parent, session_index: 0 };
+		let payload = statement.to_compact().signing_payload(&context);
+
+		CollatorProtocolMessage::Seconded(
+			relay_parent,
+			SignedFullStatement::new(
+				statement,
+				ValidatorIndex(NODE_UNDER_TEST),
+				self.state.pair.sign(&payload[..]),
+				&context,
+				&self.state.pair.public(),
+			)
+			.unwrap(),
+		)
+	}
 }
 
 #[overseer::subsystem(CandidateBacking, error=SubsystemError, prefix=self::overseer)]
@@ -160,6 +194,13 @@ impl MockCandidateBacking {
 								ctx.send_message(message).await;
 							}
 						},
+						CandidateBackingMessage::CanSecond(_request, tx) => {
+							let _ = tx.send(true);
+						},
+						CandidateBackingMessage::Second(relay_parent, receipt, _pvd, _pov) => {
+							let message = self.handle_second(relay_parent, receipt);
+							ctx.send_message(message).await;
+						},
 						_ => {
 							unimplemented!("Unexpected candidate-backing message")
 						},
@@ -22,9 +22,11 @@ use crate::{
 	network::{NetworkEmulatorHandle, NetworkInterfaceReceiver, NetworkMessage, RequestExt},
 };
 use futures::{channel::mpsc::UnboundedSender, FutureExt, StreamExt};
-use polkadot_node_network_protocol::ValidationProtocols;
+use polkadot_node_network_protocol::{
+	CollatorProtocolMessage as CollationMessage, ValidationProtocols,
+};
 use polkadot_node_subsystem::{
-	messages::{ApprovalVotingParallelMessage, NetworkBridgeTxMessage},
+	messages::{ApprovalVotingParallelMessage, CollatorProtocolMessage, NetworkBridgeTxMessage},
 	overseer, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_types::{
@@ -138,6 +140,23 @@ impl MockNetworkBridgeTx {
 					NetworkBridgeTxMessage::ReportPeer(_) => {
 						// ignore rep changes
 					},
+					NetworkBridgeTxMessage::DisconnectPeers(_, _) => {
+						// emulated peers are never disconnected
+					},
+					NetworkBridgeTxMessage::SendCollationMessage(peers, message) => {
+						for peer in peers {
+							self.to_network_interface
+								.unbounded_send(NetworkMessage::CollationMessageFromNode(
+									self.test_authorities
+										.peer_id_to_authority
+										.get(&peer)
+										.unwrap()
+										.clone(),
+									message.clone(),
+								))
+								.expect("Should not fail");
+						}
+					},
 					NetworkBridgeTxMessage::SendValidationMessage(peers, message) => {
 						for peer in peers {
 							self.to_network_interface
@@ -210,6 +229,13 @@ impl MockNetworkBridgeRx {
 									).await;
 								},
 							},
+							NetworkMessage::CollationMessageFromPeer(peer_id, message) => {
+								let message = CollationMessage::try_from(message)
+									.expect("Only collator protocol messages are emulated");
+								ctx.send_message(
+									CollatorProtocolMessage::NetworkBridgeUpdate(NetworkBridgeEvent::PeerMessage(peer_id, message))
+								).await;
+							},
 							NetworkMessage::RequestFromPeer(request) => {
 								if let Some(protocol) = self.chunk_request_sender.as_mut() {
 									assert!(ALLOWED_PROTOCOLS.contains(&&*protocol.name), "Unexpected protocol {:?}", protocol.name);
@@ -222,7 +248,7 @@ impl MockNetworkBridgeRx {
 								}
 							},
 							_ => {
-								panic!("NetworkMessage::RequestFromNode is not expected to be received from a peer")
+								panic!("Messages from the node are not expected to be received from a peer")
 							}
 						}
 					}
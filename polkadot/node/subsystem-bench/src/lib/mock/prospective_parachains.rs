@@ -21,13 +21,34 @@ use polkadot_node_subsystem::{
 	messages::ProspectiveParachainsMessage, overseer, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_types::OverseerSignal;
-use polkadot_primitives::Hash;
+use polkadot_primitives::{BlockNumber, Hash, Id as ParaId, PersistedValidationData};
+use std::collections::HashMap;
 
-pub struct MockProspectiveParachains {}
+pub struct MockProspectiveParachains {
+	// Minimum relay parents reported for every leaf.
+	minimum_relay_parents: HashMap<Hash, Vec<(ParaId, BlockNumber)>>,
+	// Validation data returned for every prospective candidate.
+	validation_data: Option<PersistedValidationData>,
+}
 
 impl MockProspectiveParachains {
 	pub fn new() -> Self {
-		Self {}
+		Self { minimum_relay_parents: Default::default(), validation_data: None }
+	}
+
+	/// Report `minimum_relay_parents` for the given leaves instead of no paras.
+	pub fn with_minimum_relay_parents(
+		mut self,
+		minimum_relay_parents: HashMap<Hash, Vec<(ParaId, BlockNumber)>>,
+	) -> Self {
+		self.minimum_relay_parents = minimum_relay_parents;
+		self
+	}
+
+	/// Answer prospective validation data requests with `validation_data`.
+	pub fn with_validation_data(mut self, validation_data: PersistedValidationData) -> Self {
+		self.validation_data = Some(validation_data);
+		self
 	}
 }
 
@@ -51,8 +72,17 @@ impl MockProspectiveParachains {
 						return
 					},
 				orchestra::FromOrchestra::Communication { msg } => match msg {
-					ProspectiveParachainsMessage::GetMinimumRelayParents(_relay_parent, tx) => {
-						tx.send(vec![]).unwrap();
+					ProspectiveParachainsMessage::GetMinimumRelayParents(relay_parent, tx) => {
+						tx.send(
+							self.minimum_relay_parents
+								.get(&relay_parent)
+								.cloned()
+								.unwrap_or_default(),
+						)
+						.unwrap();
+					},
+					ProspectiveParachainsMessage::GetProspectiveValidationData(_request, tx) => {
+						tx.send(self.validation_data.clone()).unwrap();
 					},
 					ProspectiveParachainsMessage::GetHypotheticalMembership(req, tx) => {
 						tx.send(
@@ -54,6 +54,8 @@ pub struct RuntimeApiState {
 	session_index: SessionIndex,
 	// The claim queue
 	claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+	// Claim queues overriding `claim_queue` at specific blocks
+	claim_queues: HashMap<H256, BTreeMap<CoreIndex, VecDeque<ParaId>>>,
 }
 
 #[derive(Clone)]
@@ -88,13 +90,13 @@ impl MockRuntimeApi {
 			session_info_for_peers(&config, &authorities).validator_groups.len();
 
 		// Each para gets one core assigned and there is only one candidate per
-		// parachain per relay chain block (no elastic scaling).
+		// parachain per relay chain block (no elastic scaling). Tests without candidates get an
+		// empty claim queue.
 		let claim_queue = candidate_hashes
-			.iter()
+			.values()
 			.next()
-			.expect("Candidates are generated at test start")
-			.1
-			.iter()
+			.into_iter()
+			.flatten()
 			.enumerate()
 			.map(|(index, candidate_receipt)| {
 				// Ensure test breaks if badly configured.
@@ -112,12 +114,22 @@ impl MockRuntimeApi {
 				session_index,
 				node_features,
 				claim_queue,
+				claim_queues: Default::default(),
 			},
 			config,
 			core_state,
 		}
 	}
 
+	/// Use per block claim queues instead of the one derived from the candidates.
+	pub fn with_claim_queues(
+		mut self,
+		claim_queues: HashMap<H256, BTreeMap<CoreIndex, VecDeque<ParaId>>>,
+	) -> Self {
+		self.state.claim_queues = claim_queues;
+		self
+	}
+
 	fn session_info(&self) -> SessionInfo {
 		session_info_for_peers(&self.config, &self.state.authorities)
 	}
@@ -328,8 +340,13 @@ impl MockRuntimeApi {
 							if let Err(err) = tx.send(Ok(ApprovalVotingParams::default())) {
 								gum::error!(target: LOG_TARGET, ?err, "Voting params weren't received");
 							},
-						RuntimeApiMessage::Request(_parent, RuntimeApiRequest::ClaimQueue(tx)) => {
-							tx.send(Ok(self.state.claim_queue.clone())).unwrap();
+						RuntimeApiMessage::Request(parent, RuntimeApiRequest::ClaimQueue(tx)) => {
+							let claim_queue = self
+								.state
+								.claim_queues
+								.get(&parent)
+								.unwrap_or(&self.state.claim_queue);
+							tx.send(Ok(claim_queue.clone())).unwrap();
 						},
 						RuntimeApiMessage::Request(
 							_parent,
@@ -54,9 +54,11 @@ use itertools::Itertools;
 use net_protocol::{
 	peer_set::ValidationVersion,
 	request_response::{Recipient, Requests, ResponseSender},
-	ObservedRole, VersionedValidationProtocol, View,
+	ObservedRole, VersionedCollationProtocol, VersionedValidationProtocol, View,
+};
+use polkadot_node_network_protocol::{
+	self as net_protocol, CollationProtocols, ValidationProtocols,
 };
-use polkadot_node_network_protocol::{self as net_protocol, ValidationProtocols};
 use polkadot_node_subsystem::messages::StatementDistributionMessage;
 use polkadot_node_subsystem_types::messages::NetworkBridgeEvent;
 use polkadot_node_subsystem_util::metrics::prometheus::{
@@ -159,6 +161,10 @@ pub enum NetworkMessage {
 	RequestFromNode(AuthorityDiscoveryId, Box<Requests>),
 	/// A request originating from an emulated peer
 	RequestFromPeer(IncomingRequest),
+	/// A collation protocol message from peer to node.
+	CollationMessageFromPeer(PeerId, VersionedCollationProtocol),
+	/// A collation protocol message from node to a peer.
+	CollationMessageFromNode(AuthorityDiscoveryId, VersionedCollationProtocol),
 }
 
 impl NetworkMessage {
@@ -171,6 +177,11 @@ impl NetworkMessage {
 				message.encoded_size(),
 			NetworkMessage::RequestFromNode(_peer_id, incoming) => incoming.size(),
 			NetworkMessage::RequestFromPeer(request) => request.payload.encoded_size(),
+			NetworkMessage::CollationMessageFromPeer(_, message) |
+			NetworkMessage::CollationMessageFromNode(_, message) => match message {
+				CollationProtocols::V1(message) => message.encoded_size(),
+				CollationProtocols::V2(message) => message.encoded_size(),
+			},
 		}
 	}
 
@@ -178,7 +189,8 @@ impl NetworkMessage {
 	pub fn peer(&self) -> Option<&AuthorityDiscoveryId> {
 		match &self {
 			NetworkMessage::MessageFromNode(peer_id, _) |
-			NetworkMessage::RequestFromNode(peer_id, _) => Some(peer_id),
+			NetworkMessage::RequestFromNode(peer_id, _) |
+			NetworkMessage::CollationMessageFromNode(peer_id, _) => Some(peer_id),
 			_ => None,
 		}
 	}
@@ -347,6 +359,8 @@ impl NetworkInterface {
 					match peer_message {
 						NetworkMessage::MessageFromNode(peer, message) =>
 							tx_network.send_message_to_peer(&peer, message),
+						NetworkMessage::CollationMessageFromNode(peer, message) =>
+							tx_network.send_collation_message_to_peer(&peer, message),
 						NetworkMessage::RequestFromNode(peer, request) => {
 							// Send request through a proxy so we can account and limit bandwidth
 							// usage for the node.
@@ -450,6 +464,13 @@ impl EmulatedPeerHandle {
 			.expect("Peer action channel hangup");
 	}
 
+	/// Send a collation protocol message to the node.
+	pub fn send_collation_message(&self, message: VersionedCollationProtocol) {
+		self.actions_tx
+			.unbounded_send(NetworkMessage::CollationMessageFromPeer(self.peer_id, message))
+			.expect("Peer action channel hangup");
+	}
+
 	/// Send a `request` to the node.
 	pub fn send_request(&self, request: IncomingRequest) {
 		self.actions_tx
@@ -737,22 +758,29 @@ pub struct NetworkEmulatorHandle {
 	peers: Vec<Peer>,
 	/// Per peer stats.
 	stats: Vec<Arc<PeerEmulatorStats>>,
-	/// Each emulated peer is a validator.
+	/// Maps the authority id of each emulated peer, validators first and then collators, to its
+	/// index in `peers`.
 	validator_authority_ids: HashMap<AuthorityDiscoveryId, usize>,
 }
 
 impl NetworkEmulatorHandle {
 	pub fn generate_statement_distribution_peer_view_change(&self, view: View) -> Vec<AllMessages> {
+		self.generate_peer_view_change(view, |event| {
+			AllMessages::StatementDistribution(StatementDistributionMessage::NetworkBridgeUpdate(
+				event,
+			))
+		})
+	}
+
+	/// Generates peer_view_change messages for all connected peers
+	pub fn generate_peer_view_change<F, T>(&self, view: View, mapper: F) -> Vec<AllMessages>
+	where
+		F: Fn(NetworkBridgeEvent<T>) -> AllMessages,
+	{
 		self.peers
 			.iter()
 			.filter(|peer| peer.is_connected())
-			.map(|peer| {
-				AllMessages::StatementDistribution(
-					StatementDistributionMessage::NetworkBridgeUpdate(
-						NetworkBridgeEvent::PeerViewChange(peer.peer_id(), view.clone()),
-					),
-				)
-			})
+			.map(|peer| mapper(NetworkBridgeEvent::PeerViewChange(peer.peer_id(), view.clone())))
 			.collect_vec()
 	}
 
@@ -783,6 +811,20 @@ pub fn new_network(
 	dependencies: &TestEnvironmentDependencies,
 	authorities: &TestAuthorities,
 	handlers: Vec<Arc<dyn HandleNetworkMessage + Sync + Send>>,
+) -> (NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver) {
+	new_network_with_collators(config, dependencies, authorities, &[], handlers)
+}
+
+/// Create a new emulated network based on `config` that also contains the given `collators`.
+///
+/// Collators are emulated as additional peers after the validators, are addressed by the
+/// given `AuthorityDiscoveryId` and are always connected to the node under test.
+pub fn new_network_with_collators(
+	config: &TestConfiguration,
+	dependencies: &TestEnvironmentDependencies,
+	authorities: &TestAuthorities,
+	collators: &[(PeerId, AuthorityDiscoveryId)],
+	handlers: Vec<Arc<dyn HandleNetworkMessage + Sync + Send>>,
 ) -> (NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver) {
 	let n_peers = config.n_validators;
 	gum::info!(target: LOG_TARGET, "{}",format!("Initializing emulation for a {n_peers} peer network.").bright_blue());
@@ -796,9 +838,16 @@ pub fn new_network(
 	let (to_network_interface, from_network) = mpsc::unbounded();
 
 	// Create a `PeerEmulator` for each peer.
-	let (stats, mut peers): (_, Vec<_>) = (0..n_peers)
+	let peer_identities = authorities
+		.peer_ids
+		.iter()
+		.cloned()
 		.zip(authorities.validator_authority_id.clone())
-		.map(|(peer_index, authority_id)| {
+		.take(n_peers)
+		.chain(collators.iter().cloned());
+	let (stats, mut peers): (Vec<_>, Vec<_>) = peer_identities
+		.enumerate()
+		.map(|(peer_index, (peer_id, authority_id))| {
 			validator_authority_id_mapping.insert(authority_id.clone(), peer_index);
 			let stats = Arc::new(PeerEmulatorStats::new(peer_index, metrics.clone()));
 			(
@@ -810,7 +859,7 @@ pub fn new_network(
 					stats,
 					to_network_interface.clone(),
 					random_latency(config.latency.as_ref()),
-					*authorities.peer_ids.get(peer_index).unwrap(),
+					peer_id,
 					authority_id,
 				)),
 			)
@@ -830,6 +879,9 @@ pub fn new_network(
 	}
 
 	gum::info!(target: LOG_TARGET, "{}",format!("Network created, connected validator count {connected_count}").bright_black());
+	if !collators.is_empty() {
+		gum::info!(target: LOG_TARGET, "{}",format!("Emulating {} collators", collators.len()).bright_black());
+	}
 
 	let handle = NetworkEmulatorHandle {
 		peers,
@@ -872,6 +924,19 @@ impl NetworkEmulatorHandle {
 		peer.handle().receive(NetworkMessage::MessageFromNode(peer_id.clone(), message));
 	}
 
+	/// Forward collation protocol `message` to an emulated `peer`.
+	/// Panics if peer is not connected.
+	pub fn send_collation_message_to_peer(
+		&self,
+		peer_id: &AuthorityDiscoveryId,
+		message: VersionedCollationProtocol,
+	) {
+		let peer = self.peer(peer_id);
+		assert!(peer.is_connected(), "forward message only for connected peers.");
+		peer.handle()
+			.receive(NetworkMessage::CollationMessageFromNode(peer_id.clone(), message));
+	}
+
 	/// Forward a `request`` to an emulated `peer`.
 	/// Panics if peer is not connected.
 	pub fn send_request_to_peer(&self, peer_id: &AuthorityDiscoveryId, request: Requests) {
@@ -898,6 +963,23 @@ impl NetworkEmulatorHandle {
 		Ok(())
 	}
 
+	/// Send a collation protocol message from a peer to the node.
+	pub fn send_collation_message_from_peer(
+		&self,
+		from_peer: &AuthorityDiscoveryId,
+		message: VersionedCollationProtocol,
+	) -> Result<(), EmulatedPeerError> {
+		let dst_peer = self.peer(from_peer);
+
+		if !dst_peer.is_connected() {
+			gum::warn!(target: LOG_TARGET, "Attempted to send message from a peer not connected to our node, operation ignored");
+			return Err(EmulatedPeerError::NotConnected)
+		}
+
+		dst_peer.handle().send_collation_message(message);
+		Ok(())
+	}
+
 	/// Send a request from a peer to the node.
 	pub fn send_request_from_peer(
 		&self,
@@ -1029,6 +1111,7 @@ impl RequestExt for Requests {
 			},
 			// Requested by PeerId
 			Requests::AttestedCandidateV2(_) => None,
+			Requests::CollationFetchingV2(_) => None,
 			Requests::DisputeSendingV1(request) => {
 				if let Recipient::Authority(authority_id) = &request.peer {
 					Some(authority_id)
@@ -1048,6 +1131,10 @@ impl RequestExt for Requests {
 				Recipient::Authority(_) => None,
 				Recipient::Peer(peer_id) => Some(peer_id),
 			},
+			Requests::CollationFetchingV2(request) => match &request.peer {
+				Recipient::Authority(_) => None,
+				Recipient::Peer(peer_id) => Some(peer_id),
+			},
 			request => {
 				unimplemented!("peer_id() is not implemented for {:?}", request)
 			},
@@ -1060,6 +1147,7 @@ impl RequestExt for Requests {
 			Requests::AvailableDataFetchingV1(outgoing_request) =>
 				outgoing_request.pending_response,
 			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.pending_response,
+			Requests::CollationFetchingV2(outgoing_request) => outgoing_request.pending_response,
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1075,6 +1163,8 @@ impl RequestExt for Requests {
 				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
 			Requests::DisputeSendingV1(outgoing_request) =>
 				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
+			Requests::CollationFetchingV2(outgoing_request) =>
+				std::mem::replace(&mut outgoing_request.pending_response, new_sender),
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1088,6 +1178,8 @@ impl RequestExt for Requests {
 			Requests::AttestedCandidateV2(outgoing_request) =>
 				outgoing_request.payload.encoded_size(),
 			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.payload.encoded_size(),
+			Requests::CollationFetchingV2(outgoing_request) =>
+				outgoing_request.payload.encoded_size(),
 			_ => unimplemented!("received an unexpected request"),
 		}
 	}
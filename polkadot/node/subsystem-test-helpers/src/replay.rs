@@ -0,0 +1,149 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Replaying recorded message traffic against a single subsystem.
+
+use std::collections::{HashMap, HashSet};
+
+use polkadot_node_subsystem::{
+	overseer::recorder::{
+		RecordEntry, RecordableMessage, RecordedEvent, RecordedSignal, ResponseFuture,
+	},
+	ActiveLeavesUpdate, FromOrchestra, OverseerSignal,
+};
+use polkadot_node_subsystem_util::TimeoutExt;
+
+use crate::{mock::new_leaf, TestSubsystemContextHandle};
+
+/// Replay the recorded traffic of `subsystem` against the subsystem under test.
+///
+/// Recorded signals and incoming messages are sent to the subsystem in their recorded order. The
+/// messages sent by the subsystem are expected to match the recorded ones and are answered with the
+/// recorded responses. Consecutive outgoing messages may be received in any order, as subsystems
+/// often send them while iterating over hash maps. The responses of the subsystem to incoming
+/// messages are compared to the recorded ones once all events have been replayed.
+///
+/// `Conclude` is not replayed, it is up to the test harness to conclude the subsystem.
+///
+/// Panics if the behaviour of the subsystem diverges from the recording, or if events were
+/// dropped while recording.
+pub async fn replay<M: RecordableMessage>(
+	virtual_overseer: &mut TestSubsystemContextHandle<M>,
+	subsystem: &str,
+	recording: &[RecordEntry],
+) {
+	if let Some(entry) = recording
+		.iter()
+		.enumerate()
+		.find(|(position, entry)| entry.index != *position as u64)
+	{
+		panic!("Events were dropped while recording, before event {}", entry.1.index);
+	}
+
+	let events = recording
+		.iter()
+		.filter(|entry| entry.subsystem == subsystem)
+		.map(|entry| (entry.index, &entry.event))
+		.collect::<Vec<_>>();
+	let responses = events
+		.iter()
+		.filter_map(|(_, event)| match event {
+			RecordedEvent::Response { request, response } => Some((*request, response)),
+			_ => None,
+		})
+		.collect::<HashMap<_, _>>();
+
+	let mut received = HashSet::new();
+	let mut pending_responses = Vec::new();
+	for (position, (index, event)) in events.iter().enumerate() {
+		match event {
+			RecordedEvent::Signal(signal) => replay_signal(virtual_overseer, signal).await,
+			RecordedEvent::Incoming(encoded) => {
+				let (msg, response) = M::decode_message(encoded)
+					.unwrap_or_else(|err| panic!("Invalid recorded message {}: {}", index, err));
+				virtual_overseer.send(FromOrchestra::Communication { msg }).await;
+				if let Some(response) = response {
+					pending_responses.push((*index, response));
+				}
+			},
+			RecordedEvent::Outgoing(_) if received.contains(index) => {},
+			RecordedEvent::Outgoing(_) => {
+				let msg = virtual_overseer.recv().await;
+				let encoded = msg.encode_message();
+
+				// Match the message against the outgoing messages sent before the subsystem
+				// received anything else.
+				let matched = events[position..]
+					.iter()
+					.filter(|(_, event)| !matches!(event, RecordedEvent::Response { .. }))
+					.take_while(|(_, event)| matches!(event, RecordedEvent::Outgoing(_)))
+					.find(|(index, event)| {
+						!received.contains(index) &&
+							matches!(event, RecordedEvent::Outgoing(recorded) if *recorded == encoded)
+					})
+					.map(|(index, _)| *index)
+					.unwrap_or_else(|| {
+						panic!("Unexpected message sent by {}: {:?}", subsystem, msg)
+					});
+				received.insert(matched);
+
+				// Responses which were never sent are dropped along with the message.
+				if let Some(response) = responses.get(&matched) {
+					msg.respond(response).unwrap_or_else(|err| {
+						panic!("Invalid recorded response to {}: {}", matched, err)
+					});
+				}
+			},
+			RecordedEvent::Response { .. } => {},
+		}
+	}
+
+	for (index, response) in pending_responses {
+		check_response(index, response, responses.get(&index).map(|response| &response[..])).await;
+	}
+}
+
+async fn replay_signal<M>(
+	virtual_overseer: &mut TestSubsystemContextHandle<M>,
+	signal: &RecordedSignal,
+) {
+	let signal = match signal {
+		RecordedSignal::ActiveLeaves { activated, deactivated } =>
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate {
+				activated: activated.map(|(hash, number)| new_leaf(hash, number)),
+				deactivated: deactivated.clone().into(),
+			}),
+		RecordedSignal::BlockFinalized(hash, number) =>
+			OverseerSignal::BlockFinalized(*hash, *number),
+		RecordedSignal::Conclude => return,
+	};
+
+	virtual_overseer.send(FromOrchestra::Signal(signal)).await;
+}
+
+async fn check_response(index: u64, response: ResponseFuture, expected: Option<&[u8]>) {
+	let response = response
+		.timeout(TestSubsystemContextHandle::<()>::TIMEOUT)
+		.await
+		.unwrap_or_else(|| panic!("The response to recorded message {} timed out", index));
+
+	assert_eq!(
+		response.as_deref(),
+		expected,
+		"The response to recorded message {} diverges from the recording",
+		index,
+	);
+}
@@ -22,6 +22,7 @@
 //!
 //! Subsystems' APIs are defined separately from their implementation, leading to easier mocking.
 
+use codec::{Decode, Encode};
 use futures::channel::oneshot;
 use sc_network::{Multiaddr, ReputationChange};
 use thiserror::Error;
@@ -52,12 +53,12 @@ use polkadot_primitives::{
 	HeadData, Header as BlockHeader, Id as ParaId, InboundDownwardMessage, InboundHrmpMessage,
 	MultiDisputeStatementSet, NodeFeatures, OccupiedCoreAssumption, PersistedValidationData,
 	PvfCheckStatement, PvfExecKind as RuntimePvfExecKind, SessionIndex, SessionInfo,
-	SignedAvailabilityBitfield, SignedAvailabilityBitfields, ValidationCode, ValidationCodeHash,
-	ValidatorId, ValidatorIndex, ValidatorSignature,
+	SignedAvailabilityBitfield, SignedAvailabilityBitfields, UpgradeRestriction, ValidationCode,
+	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature,
 };
 use polkadot_statement_table::v2::Misbehavior;
 use std::{
-	collections::{BTreeMap, HashMap, HashSet, VecDeque},
+	collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
 	sync::Arc,
 };
 
@@ -110,6 +111,22 @@ pub enum CandidateBackingMessage {
 	/// Disputes Subsystem, though that escalation is deferred until the approval voting stage to
 	/// guarantee availability. Agreements are simply tallied until a quorum is reached.
 	Statement(Hash, SignedFullStatementWithPVD),
+	/// Get the statements issued by the local validator in the given session.
+	GetBackingDuties(SessionIndex, oneshot::Sender<Vec<BackingDuty>>),
+}
+
+/// A statement issued by the local validator as a member of a backing group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackingDuty {
+	/// The candidate the statement is about.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate is from.
+	pub para_id: ParaId,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// Whether the local validator seconded the candidate, rather than only attesting its
+	/// validity.
+	pub seconded: bool,
 }
 
 /// Blanket error for validation failing for internal reasons.
@@ -346,6 +363,20 @@ pub enum DisputeCoordinatorMessage {
 		/// The block to vote on, might be base in case there is no better.
 		tx: oneshot::Sender<(BlockNumber, Hash)>,
 	},
+	/// Get the recent disputes of the given session, along with the votes cast in them by the
+	/// local validator.
+	GetDisputeDuties(SessionIndex, oneshot::Sender<Vec<DisputeDuty>>),
+}
+
+/// A recent dispute and the participation of the local validator in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisputeDuty {
+	/// The disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The status of the dispute.
+	pub status: DisputeStatus,
+	/// The vote of the local validator, `true` indicating validity, if it voted.
+	pub vote: Option<bool>,
 }
 
 /// The result of `DisputeCoordinatorMessage::ImportStatements`.
@@ -518,11 +549,25 @@ pub enum BitfieldDistributionMessage {
 	/// Distribute a bitfield via gossip to other validators.
 	DistributeBitfield(Hash, SignedAvailabilityBitfield),
 
+	/// Get the bitfields signed by the local validator in the given session.
+	GetBitfieldDuties(SessionIndex, oneshot::Sender<Vec<BitfieldDuty>>),
+
 	/// Event from the network bridge.
 	#[from]
 	NetworkBridgeUpdate(NetworkBridgeEvent<net_protocol::BitfieldDistributionMessage>),
 }
 
+/// An availability bitfield signed and distributed by the local validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitfieldDuty {
+	/// The relay parent the bitfield was signed at.
+	pub relay_parent: Hash,
+	/// The number of availability cores the bitfield attests availability for.
+	pub available_cores: u32,
+	/// The number of availability cores covered by the bitfield.
+	pub cores: u32,
+}
+
 /// Availability store subsystem message.
 #[derive(Debug)]
 pub enum AvailabilityStoreMessage {
@@ -1032,6 +1077,9 @@ pub enum ApprovalVotingParallelMessage {
 	),
 	/// Gets mapped into `ApprovalDistributionMessage::ApprovalCheckingLagUpdate`
 	ApprovalCheckingLagUpdate(BlockNumber),
+
+	/// Gets mapped into `ApprovalVotingMessage::GetApprovalDuties`
+	GetApprovalDuties(SessionIndex, oneshot::Sender<Vec<ApprovalDuty>>),
 }
 
 impl TryFrom<ApprovalVotingParallelMessage> for ApprovalVotingMessage {
@@ -1043,6 +1091,8 @@ impl TryFrom<ApprovalVotingParallelMessage> for ApprovalVotingMessage {
 				Ok(ApprovalVotingMessage::ApprovedAncestor(hash, number, tx)),
 			ApprovalVotingParallelMessage::GetApprovalSignaturesForCandidate(candidate, tx) =>
 				Ok(ApprovalVotingMessage::GetApprovalSignaturesForCandidate(candidate, tx)),
+			ApprovalVotingParallelMessage::GetApprovalDuties(session, tx) =>
+				Ok(ApprovalVotingMessage::GetApprovalDuties(session, tx)),
 			_ => Err(()),
 		}
 	}
@@ -1182,6 +1232,33 @@ pub enum ApprovalVotingMessage {
 		CandidateHash,
 		oneshot::Sender<HashMap<ValidatorIndex, (Vec<CandidateHash>, ValidatorSignature)>>,
 	),
+
+	/// Get the approval assignments taken by the local validator in the given session.
+	GetApprovalDuties(SessionIndex, oneshot::Sender<Vec<ApprovalDuty>>),
+}
+
+/// The outcome of an approval assignment taken by the local validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalDutyOutcome {
+	/// The candidate has not been approved by the local validator yet.
+	Pending,
+	/// The local validator approved the candidate.
+	Approved,
+	/// The candidate got approved while the local validator was counted as a no-show.
+	NoShow,
+}
+
+/// An approval assignment taken by the local validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalDuty {
+	/// The candidate to check.
+	pub candidate_hash: CandidateHash,
+	/// The relay chain block the candidate was included in.
+	pub block_hash: Hash,
+	/// The tranche of the assignment.
+	pub tranche: DelayTranche,
+	/// The outcome of the assignment.
+	pub outcome: ApprovalDutyOutcome,
 }
 
 /// Message to the Approval Distribution subsystem.
@@ -1219,7 +1296,7 @@ pub enum GossipSupportMessage {
 }
 
 /// Request introduction of a seconded candidate into the prospective parachains subsystem.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
 pub struct IntroduceSecondedCandidateRequest {
 	/// The para-id of the candidate.
 	pub candidate_para: ParaId,
@@ -1238,7 +1315,7 @@ pub struct IntroduceSecondedCandidateRequest {
 /// claims about properties that a fetched candidate would have.
 ///
 /// Complete candidates can be evaluated more strictly than incomplete candidates.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
 pub enum HypotheticalCandidate {
 	/// A complete candidate.
 	Complete {
@@ -1338,7 +1415,7 @@ impl HypotheticalCandidate {
 /// Request specifying which candidates are either already included
 /// or might become included in fragment chain under a given active leaf (or any active leaf if
 /// `fragment_chain_relay_parent` is `None`).
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
 pub struct HypotheticalMembershipRequest {
 	/// Candidates, in arbitrary order, which should be checked for
 	/// hypothetical/actual membership in fragment chains.
@@ -1349,7 +1426,7 @@ pub struct HypotheticalMembershipRequest {
 
 /// A request for the persisted validation data stored in the prospective
 /// parachains subsystem.
-#[derive(Debug)]
+#[derive(Debug, Encode, Decode)]
 pub struct ProspectiveValidationDataRequest {
 	/// The para-id of the candidate.
 	pub para_id: ParaId,
@@ -1360,7 +1437,7 @@ pub struct ProspectiveValidationDataRequest {
 }
 
 /// The parent head-data hash with optional data itself.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum ParentHeadData {
 	/// Parent head-data hash.
 	OnlyHash(Hash),
@@ -1390,6 +1467,67 @@ pub type HypotheticalMembership = Vec<Hash>;
 /// A collection of ancestor candidates of a parachain.
 pub type Ancestors = HashSet<CandidateHash>;
 
+/// The state of the fragment chain of a para under an active leaf.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentChainState {
+	/// The active leaf the fragment chain is built on.
+	pub leaf: Hash,
+	/// The para the fragment chain is for.
+	pub para_id: ParaId,
+	/// The number of the earliest relay parent candidates are allowed to build on.
+	pub earliest_relay_parent: BlockNumber,
+	/// The maximum length of the best backable chain, including the candidates pending
+	/// availability.
+	pub max_backable_len: u32,
+	/// The constraints of the latest included candidate, all candidates build on.
+	pub constraints: FragmentChainConstraints,
+	/// The candidates pending availability at the leaf.
+	pub pending_availability: Vec<CandidateHash>,
+	/// The best chain of backable candidates, in order.
+	pub best_chain: Vec<FragmentChainCandidate>,
+	/// The candidates which are not part of the best chain, but may become part of it.
+	pub unconnected: Vec<FragmentChainCandidate>,
+	/// The cores claimed by the para at each depth of the claim queue of the leaf.
+	pub claim_queue: BTreeMap<u8, BTreeSet<CoreIndex>>,
+}
+
+/// The constraints of a fragment chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentChainConstraints {
+	/// The minimum relay-parent number accepted under these constraints.
+	pub min_relay_parent_number: BlockNumber,
+	/// The maximum Proof-of-Validity size allowed, in bytes.
+	pub max_pov_size: u32,
+	/// The maximum new validation code size allowed, in bytes.
+	pub max_code_size: u32,
+	/// The maximum head-data size, in bytes.
+	pub max_head_data_size: u32,
+	/// The hash of the required parent head-data of the para.
+	pub required_parent: Hash,
+	/// The expected validation-code-hash of the para.
+	pub validation_code_hash: ValidationCodeHash,
+	/// The code upgrade restriction signal of the para.
+	pub upgrade_restriction: Option<UpgradeRestriction>,
+	/// The future validation code hash, if any, and at what relay-parent number the upgrade
+	/// would be minimally applied.
+	pub future_validation_code: Option<(BlockNumber, ValidationCodeHash)>,
+}
+
+/// A candidate of a fragment chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentChainCandidate {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// The hash of the parent head-data of the candidate.
+	pub parent_head_data_hash: Hash,
+	/// The hash of the head-data output by the candidate.
+	pub output_head_data_hash: Hash,
+	/// Whether the candidate was backed, or only seconded.
+	pub backed: bool,
+}
+
 /// Messages sent to the Prospective Parachains subsystem.
 #[derive(Debug)]
 pub enum ProspectiveParachainsMessage {
@@ -1456,4 +1594,10 @@ pub enum ProspectiveParachainsMessage {
 		ProspectiveValidationDataRequest,
 		oneshot::Sender<Option<PersistedValidationData>>,
 	),
+	/// Get the state of the fragment chains under all the active leaves, optionally only the
+	/// ones of the given para.
+	///
+	/// This is meant for inspecting why candidates are not backed and is not used by other
+	/// subsystems.
+	GetFragmentChainsState(Option<ParaId>, oneshot::Sender<Vec<FragmentChainState>>),
 }
@@ -0,0 +1,104 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `DutyRecords` implementation.
+
+use polkadot_node_primitives::DISPUTE_WINDOW;
+use polkadot_primitives::SessionIndex;
+use std::{
+	collections::{BTreeMap, HashMap},
+	hash::Hash,
+};
+
+/// Keeps records of the parachain duties performed by the local validator, grouped by session.
+///
+/// Only the records of the sessions within the dispute window of the most recent session are
+/// kept, older sessions are pruned when records for a new session are inserted.
+#[derive(Debug)]
+pub struct DutyRecords<K, V> {
+	sessions: BTreeMap<SessionIndex, HashMap<K, V>>,
+}
+
+impl<K, V> Default for DutyRecords<K, V> {
+	fn default() -> Self {
+		Self { sessions: BTreeMap::new() }
+	}
+}
+
+impl<K: Hash + Eq, V> DutyRecords<K, V> {
+	/// Insert the record of a duty, unless there is a record for the same key already.
+	///
+	/// Records for sessions older than the dispute window are ignored.
+	pub fn insert(&mut self, session: SessionIndex, key: K, value: V) {
+		if let Some(&latest) = self.sessions.keys().next_back() {
+			if session < oldest_in_window(latest) {
+				return
+			}
+		}
+
+		self.sessions.entry(session).or_default().entry(key).or_insert(value);
+
+		if let Some(&latest) = self.sessions.keys().next_back() {
+			self.sessions = self.sessions.split_off(&oldest_in_window(latest));
+		}
+	}
+
+	/// Get a mutable reference to the record of a duty.
+	pub fn get_mut(&mut self, session: SessionIndex, key: &K) -> Option<&mut V> {
+		self.sessions.get_mut(&session).and_then(|records| records.get_mut(key))
+	}
+
+	/// Iterate over the records of the given session.
+	pub fn session(&self, session: SessionIndex) -> impl Iterator<Item = (&K, &V)> {
+		self.sessions.get(&session).into_iter().flat_map(|records| records.iter())
+	}
+}
+
+fn oldest_in_window(latest: SessionIndex) -> SessionIndex {
+	latest.saturating_sub(DISPUTE_WINDOW.get() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn old_sessions_are_pruned() {
+		let mut records = DutyRecords::default();
+		records.insert(1, 1u32, ());
+		records.insert(DISPUTE_WINDOW.get(), 2, ());
+		assert_eq!(records.session(1).count(), 1);
+
+		records.insert(DISPUTE_WINDOW.get() + 1, 3, ());
+		assert_eq!(records.session(1).count(), 0);
+		assert_eq!(records.session(DISPUTE_WINDOW.get()).count(), 1);
+
+		// Records of pruned sessions are not inserted again.
+		records.insert(1, 4, ());
+		assert_eq!(records.session(1).count(), 0);
+	}
+
+	#[test]
+	fn existing_records_are_kept() {
+		let mut records = DutyRecords::default();
+		records.insert(1, 1u32, "first");
+		records.insert(1, 1, "second");
+		assert_eq!(records.session(1).collect::<Vec<_>>(), vec![(&1, &"first")]);
+
+		*records.get_mut(1, &1).unwrap() = "updated";
+		assert_eq!(records.session(1).collect::<Vec<_>>(), vec![(&1, &"updated")]);
+	}
+}
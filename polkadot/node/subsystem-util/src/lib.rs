@@ -102,6 +102,9 @@ mod determine_new_blocks;
 mod controlled_validator_indices;
 pub use controlled_validator_indices::ControlledValidatorIndices;
 
+mod duty_records;
+pub use duty_records::DutyRecords;
+
 #[cfg(test)]
 mod tests;
 
@@ -98,6 +98,8 @@ fn main() -> Result<()> {
 						prepare_workers_hard_max_num: None,
 						prepare_workers_soft_max_num: None,
 						keep_finalized_for: None,
+						message_recording_path: None,
+						fetch_available_data_blocks: false,
 					},
 				)
 				.map_err(|e| e.to_string())?;
@@ -120,6 +120,11 @@ pub enum ExecutorParam {
 	/// Enables WASM bulk memory proposal
 	#[codec(index = 7)]
 	WasmExtBulkMemory,
+	/// Enables the execution of PolkaVM (RISC-V) validation code.
+	///
+	/// Without it, validation code which is a PolkaVM program blob fails prevalidation.
+	#[codec(index = 8)]
+	PolkaVm,
 }
 
 /// Possible inconsistencies of executor params.
@@ -242,6 +247,7 @@ impl ExecutorParams {
 				PvfPrepTimeout(..) => Some(param),
 				PvfExecTimeout(..) => None,
 				WasmExtBulkMemory => Some(param),
+				PolkaVm => Some(param),
 			})
 			.for_each(|p| enc.extend(p.encode()));
 
@@ -272,6 +278,11 @@ impl ExecutorParams {
 		None
 	}
 
+	/// Returns whether the execution of PolkaVM validation code is enabled
+	pub fn polkavm_enabled(&self) -> bool {
+		self.0.iter().any(|param| matches!(param, ExecutorParam::PolkaVm))
+	}
+
 	/// Returns pre-checking memory limit, if any
 	pub fn prechecking_max_memory(&self) -> Option<u64> {
 		for param in &self.0 {
@@ -325,6 +336,7 @@ impl ExecutorParams {
 					PvfExecKind::Approval => "PvfExecKind::Approval",
 				},
 				WasmExtBulkMemory => "WasmExtBulkMemory",
+				PolkaVm => "PolkaVm",
 			};
 
 			match *param {
@@ -359,6 +371,10 @@ impl ExecutorParams {
 				WasmExtBulkMemory => {
 					check!(param_ident, 1);
 				},
+
+				PolkaVm => {
+					check!(param_ident, 1);
+				},
 			}
 		}
 
@@ -429,6 +445,7 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(PvfExecKind::Backing, 0),
 			PvfExecTimeout(PvfExecKind::Approval, 0),
 			WasmExtBulkMemory,
+			PolkaVm,
 		][..],
 	);
 
@@ -452,6 +469,7 @@ fn ensure_prep_hash_changes() {
 			PvfExecTimeout(_, _) => continue,
 			WasmExtBulkMemory =>
 				(ExecutorParams::default(), ExecutorParams::from(&[WasmExtBulkMemory][..])),
+			PolkaVm => (ExecutorParams::default(), ExecutorParams::from(&[PolkaVm][..])),
 		};
 
 		assert_ne!(ep1.prep_hash(), ep2.prep_hash());
@@ -110,6 +110,17 @@ impl<Payload: EncodeAs<RealPayload>, RealPayload: Encode> Signed<Payload, RealPa
 		}
 	}
 
+	/// Convert from `UncheckedSigned` without checking the signature.
+	///
+	/// Attention: Only to be used with data whose signature was checked before, like messages
+	/// restored from a recording of subsystem traffic.
+	#[cfg(feature = "std")]
+	pub fn new_unchecked_from_trusted_source(
+		unchecked: UncheckedSigned<Payload, RealPayload>,
+	) -> Self {
+		Self(unchecked)
+	}
+
 	/// Get a reference to data as unchecked.
 	pub fn as_unchecked(&self) -> &UncheckedSigned<Payload, RealPayload> {
 		&self.0
@@ -21,7 +21,9 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use polkadot_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use polkadot_primitives::{
+	runtime_api::ParachainHost, AccountId, Balance, Block, BlockNumber, Hash, Nonce,
+};
 use sc_client_api::AuxStore;
 use sc_consensus_beefy::communication::notification::{
 	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
@@ -38,6 +40,9 @@ use sp_consensus_babe::BabeApi;
 use sp_consensus_beefy::AuthorityIdBound;
 use sp_keystore::KeystorePtr;
 
+pub mod prospective_parachains;
+pub mod validator_performance;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = RpcModule<()>;
 
@@ -91,17 +96,26 @@ pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	pub beefy: BeefyDeps<AuthorityId>,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
+	/// A handle to the overseer, if the node runs one.
+	pub overseer_handle: Option<polkadot_overseer::Handle>,
+	/// Whether the node is a validator.
+	pub is_validator: bool,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, SC, B, AuthorityId>(
-	FullDeps { client, pool, select_chain, chain_spec, babe, grandpa, beefy, backend }: FullDeps<
-		C,
-		P,
-		SC,
-		B,
-		AuthorityId,
-	>,
+	FullDeps {
+		client,
+		pool,
+		select_chain,
+		chain_spec,
+		babe,
+		grandpa,
+		beefy,
+		backend,
+		overseer_handle,
+		is_validator,
+	}: FullDeps<C, P, SC, B, AuthorityId>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block>
@@ -116,6 +130,7 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: ParachainHost<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
@@ -125,12 +140,14 @@ where
 {
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use prospective_parachains::{ProspectiveParachains, ProspectiveParachainsApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_beefy_rpc::{Beefy, BeefyApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+	use validator_performance::{ValidatorPerformance, ValidatorPerformanceApiServer};
 
 	let mut io = RpcModule::new(());
 	let BabeDeps { babe_worker_handle, keystore } = babe;
@@ -167,6 +184,15 @@ where
 		)
 		.into_rpc(),
 	)?;
+	if let Some(overseer_handle) = overseer_handle {
+		// Only validators run the subsystems the validator performance is collected from.
+		if is_validator {
+			io.merge(
+				ValidatorPerformance::new(client.clone(), overseer_handle.clone()).into_rpc(),
+			)?;
+		}
+		io.merge(ProspectiveParachains::new(overseer_handle).into_rpc())?;
+	}
 	io.merge(
 		SyncState::new(chain_spec, client, shared_authority_set, babe_worker_handle)?.into_rpc(),
 	)?;
@@ -0,0 +1,231 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC exposing the fragment chains tracked by the prospective parachains subsystem.
+//!
+//! Meant for diagnosing why the candidates of a para are not backed, by showing which candidates
+//! are part of the best backable chain, which ones could not be connected to it and the
+//! constraints and claims of the para under each active leaf.
+
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use polkadot_node_subsystem_types::messages::{
+	FragmentChainCandidate, FragmentChainState, ProspectiveParachainsMessage,
+};
+use polkadot_overseer::Handle;
+use polkadot_primitives::{BlockNumber, Hash, ValidationCodeHash};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const PROSPECTIVE_PARACHAINS_ERROR: i32 = 9600;
+
+const LOG_TARGET: &str = "parachain::prospective-parachains-rpc";
+
+/// Provides RPC methods inspecting the fragment chains of the prospective parachains subsystem.
+#[rpc(client, server)]
+pub trait ProspectiveParachainsApi {
+	/// Returns the fragment chains under all the active leaves, optionally only the ones of the
+	/// given para.
+	#[method(name = "parachain_fragmentChains", with_extensions)]
+	async fn fragment_chains(&self, para_id: Option<u32>) -> Result<Vec<FragmentChain>, Error>;
+}
+
+/// Implements the [`ProspectiveParachainsApiServer`] RPC trait.
+pub struct ProspectiveParachains {
+	/// A handle to the overseer, used to query the subsystem.
+	overseer_handle: Handle,
+}
+
+impl ProspectiveParachains {
+	/// Creates a new instance of the prospective parachains RPC handler.
+	pub fn new(overseer_handle: Handle) -> Self {
+		Self { overseer_handle }
+	}
+}
+
+#[async_trait]
+impl ProspectiveParachainsApiServer for ProspectiveParachains {
+	async fn fragment_chains(
+		&self,
+		ext: &Extensions,
+		para_id: Option<u32>,
+	) -> Result<Vec<FragmentChain>, Error> {
+		check_if_safe(ext)?;
+
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle
+			.clone()
+			.send_msg(
+				ProspectiveParachainsMessage::GetFragmentChainsState(para_id.map(Into::into), tx),
+				LOG_TARGET,
+			)
+			.await;
+		let states = rx.await.map_err(|_| Error::SubsystemUnavailable)?;
+
+		Ok(states.into_iter().map(Into::into).collect())
+	}
+}
+
+/// The fragment chain of a para under an active leaf.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FragmentChain {
+	/// The active leaf the fragment chain is built on.
+	pub leaf: Hash,
+	/// The para the fragment chain is for.
+	pub para_id: u32,
+	/// The number of the earliest relay parent candidates are allowed to build on.
+	pub earliest_relay_parent: BlockNumber,
+	/// The maximum length of the best backable chain, including the candidates pending
+	/// availability.
+	pub max_backable_len: u32,
+	/// The constraints all candidates of the fragment chain build on.
+	pub constraints: Constraints,
+	/// The candidates pending availability at the leaf.
+	pub pending_availability: Vec<Hash>,
+	/// The best chain of backable candidates, in order.
+	pub best_chain: Vec<Candidate>,
+	/// The candidates which are not connected to the best chain.
+	pub unconnected: Vec<Candidate>,
+	/// The cores claimed by the para at each depth of the claim queue of the leaf.
+	pub claim_queue: BTreeMap<u8, Vec<u32>>,
+}
+
+impl From<FragmentChainState> for FragmentChain {
+	fn from(state: FragmentChainState) -> Self {
+		let constraints = state.constraints;
+		Self {
+			leaf: state.leaf,
+			para_id: state.para_id.into(),
+			earliest_relay_parent: state.earliest_relay_parent,
+			max_backable_len: state.max_backable_len,
+			constraints: Constraints {
+				min_relay_parent_number: constraints.min_relay_parent_number,
+				max_pov_size: constraints.max_pov_size,
+				max_code_size: constraints.max_code_size,
+				max_head_data_size: constraints.max_head_data_size,
+				required_parent: constraints.required_parent,
+				validation_code_hash: code_hash(constraints.validation_code_hash),
+				upgrade_restricted: constraints.upgrade_restriction.is_some(),
+				future_validation_code: constraints.future_validation_code.map(
+					|(applied_at, hash)| FutureValidationCode {
+						applied_at,
+						code_hash: code_hash(hash),
+					},
+				),
+			},
+			pending_availability: state.pending_availability.into_iter().map(|c| c.0).collect(),
+			best_chain: state.best_chain.into_iter().map(Into::into).collect(),
+			unconnected: state.unconnected.into_iter().map(Into::into).collect(),
+			claim_queue: state
+				.claim_queue
+				.into_iter()
+				.map(|(depth, cores)| (depth, cores.into_iter().map(|core| core.0).collect()))
+				.collect(),
+		}
+	}
+}
+
+/// The constraints of a fragment chain.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Constraints {
+	/// The minimum relay-parent number accepted under these constraints.
+	pub min_relay_parent_number: BlockNumber,
+	/// The maximum Proof-of-Validity size allowed, in bytes.
+	pub max_pov_size: u32,
+	/// The maximum new validation code size allowed, in bytes.
+	pub max_code_size: u32,
+	/// The maximum head-data size, in bytes.
+	pub max_head_data_size: u32,
+	/// The hash of the head-data the first candidate has to build on.
+	pub required_parent: Hash,
+	/// The expected validation code hash of the para.
+	pub validation_code_hash: Hash,
+	/// Whether the para is currently restricted from upgrading its code.
+	pub upgrade_restricted: bool,
+	/// The pending code upgrade of the para, if any.
+	pub future_validation_code: Option<FutureValidationCode>,
+}
+
+/// A pending code upgrade of a para.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FutureValidationCode {
+	/// The minimum relay-parent number at which the upgrade is applied.
+	pub applied_at: BlockNumber,
+	/// The hash of the new validation code.
+	pub code_hash: Hash,
+}
+
+/// A candidate of a fragment chain.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candidate {
+	/// The hash of the candidate.
+	pub candidate_hash: Hash,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// The hash of the parent head-data of the candidate.
+	pub parent_head_data_hash: Hash,
+	/// The hash of the head-data output by the candidate.
+	pub output_head_data_hash: Hash,
+	/// Whether the candidate was backed, or only seconded.
+	pub backed: bool,
+}
+
+impl From<FragmentChainCandidate> for Candidate {
+	fn from(candidate: FragmentChainCandidate) -> Self {
+		Self {
+			candidate_hash: candidate.candidate_hash.0,
+			relay_parent: candidate.relay_parent,
+			parent_head_data_hash: candidate.parent_head_data_hash,
+			output_head_data_hash: candidate.output_head_data_hash,
+			backed: candidate.backed,
+		}
+	}
+}
+
+fn code_hash(hash: ValidationCodeHash) -> Hash {
+	Hash::from_slice(hash.as_ref())
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The prospective parachains subsystem did not answer the query.
+	#[error("The prospective parachains subsystem is unavailable")]
+	SubsystemUnavailable,
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::SubsystemUnavailable =>
+				ErrorObject::owned(PROSPECTIVE_PARACHAINS_ERROR + 1, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
@@ -0,0 +1,307 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC reporting the parachain duties performed by the local validator.
+//!
+//! The duties are queried from the subsystems performing them via the overseer, which only keep
+//! the records of the sessions within the dispute window.
+
+use std::sync::Arc;
+
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use polkadot_node_primitives::DisputeStatus;
+use polkadot_node_subsystem_types::messages::{
+	ApprovalDuty, ApprovalDutyOutcome, ApprovalVotingParallelMessage, BackingDuty,
+	BitfieldDistributionMessage, BitfieldDuty, CandidateBackingMessage, DisputeCoordinatorMessage,
+	DisputeDuty,
+};
+use polkadot_overseer::{AllMessages, Handle};
+use polkadot_primitives::{runtime_api::ParachainHost, Block, Hash, SessionIndex};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+const VALIDATOR_PERFORMANCE_ERROR: i32 = 9500;
+
+const LOG_TARGET: &str = "parachain::validator-performance-rpc";
+
+/// Provides RPC methods reporting the parachain duties of the local validator.
+#[rpc(client, server)]
+pub trait ValidatorPerformanceApi {
+	/// Returns the parachain duties performed by the local validator in the given session, or in
+	/// the current session if none is given.
+	#[method(name = "parachain_validatorPerformance", with_extensions)]
+	async fn validator_performance(
+		&self,
+		session: Option<SessionIndex>,
+	) -> Result<SessionPerformance, Error>;
+}
+
+/// Implements the [`ValidatorPerformanceApiServer`] RPC trait.
+pub struct ValidatorPerformance<C> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// A handle to the overseer, used to query the subsystems.
+	overseer_handle: Handle,
+}
+
+impl<C> ValidatorPerformance<C> {
+	/// Creates a new instance of the validator performance RPC handler.
+	pub fn new(client: Arc<C>, overseer_handle: Handle) -> Self {
+		Self { client, overseer_handle }
+	}
+
+	async fn request<T>(
+		&self,
+		subsystem: &'static str,
+		make_msg: impl FnOnce(oneshot::Sender<T>) -> AllMessages,
+	) -> Result<T, Error> {
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle.clone().send_msg(make_msg(tx), LOG_TARGET).await;
+		rx.await.map_err(|_| Error::SubsystemUnavailable(subsystem))
+	}
+}
+
+#[async_trait]
+impl<C> ValidatorPerformanceApiServer for ValidatorPerformance<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ParachainHost<Block>,
+{
+	async fn validator_performance(
+		&self,
+		ext: &Extensions,
+		session: Option<SessionIndex>,
+	) -> Result<SessionPerformance, Error> {
+		check_if_safe(ext)?;
+
+		let session = match session {
+			Some(session) => session,
+			None => self
+				.client
+				.runtime_api()
+				.session_index_for_child(self.client.info().best_hash)
+				.map_err(|e| Error::SessionIndex(e.to_string()))?,
+		};
+
+		let backed = self
+			.request("candidate-backing", |tx| {
+				CandidateBackingMessage::GetBackingDuties(session, tx).into()
+			})
+			.await?;
+		let bitfields = self
+			.request("bitfield-distribution", |tx| {
+				BitfieldDistributionMessage::GetBitfieldDuties(session, tx).into()
+			})
+			.await?;
+		let approvals = self
+			.request("approval-voting", |tx| {
+				ApprovalVotingParallelMessage::GetApprovalDuties(session, tx).into()
+			})
+			.await?;
+		let disputes = self
+			.request("dispute-coordinator", |tx| {
+				DisputeCoordinatorMessage::GetDisputeDuties(session, tx).into()
+			})
+			.await?;
+
+		Ok(SessionPerformance {
+			session,
+			backed: backed.into_iter().map(Into::into).collect(),
+			bitfields: bitfields.into_iter().map(Into::into).collect(),
+			approvals: approvals.into_iter().map(Into::into).collect(),
+			disputes: disputes.into_iter().map(Into::into).collect(),
+		})
+	}
+}
+
+/// The parachain duties performed by the local validator in a session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPerformance {
+	/// The session the duties were performed in.
+	pub session: SessionIndex,
+	/// The candidates the local validator issued backing statements for.
+	pub backed: Vec<BackedCandidate>,
+	/// The availability bitfields signed by the local validator.
+	pub bitfields: Vec<SignedBitfield>,
+	/// The approval assignments taken by the local validator.
+	pub approvals: Vec<ApprovalAssignment>,
+	/// The recent disputes and the participation of the local validator in them.
+	pub disputes: Vec<DisputeParticipation>,
+}
+
+/// A candidate the local validator issued a backing statement for.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackedCandidate {
+	/// The hash of the candidate.
+	pub candidate_hash: Hash,
+	/// The para the candidate is from.
+	pub para_id: u32,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// Whether the candidate was seconded by the local validator.
+	pub seconded: bool,
+}
+
+impl From<BackingDuty> for BackedCandidate {
+	fn from(duty: BackingDuty) -> Self {
+		Self {
+			candidate_hash: duty.candidate_hash.0,
+			para_id: duty.para_id.into(),
+			relay_parent: duty.relay_parent,
+			seconded: duty.seconded,
+		}
+	}
+}
+
+/// An availability bitfield signed by the local validator.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedBitfield {
+	/// The relay parent the bitfield was signed at.
+	pub relay_parent: Hash,
+	/// The number of cores the bitfield attests availability for.
+	pub available_cores: u32,
+	/// The number of cores covered by the bitfield.
+	pub cores: u32,
+}
+
+impl From<BitfieldDuty> for SignedBitfield {
+	fn from(duty: BitfieldDuty) -> Self {
+		Self {
+			relay_parent: duty.relay_parent,
+			available_cores: duty.available_cores,
+			cores: duty.cores,
+		}
+	}
+}
+
+/// An approval assignment taken by the local validator.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalAssignment {
+	/// The hash of the candidate.
+	pub candidate_hash: Hash,
+	/// The relay chain block the candidate was included in.
+	pub block_hash: Hash,
+	/// The tranche of the assignment.
+	pub tranche: u32,
+	/// The outcome of the assignment.
+	pub outcome: ApprovalOutcome,
+}
+
+/// The outcome of an approval assignment.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApprovalOutcome {
+	/// The candidate has not been approved by the local validator yet.
+	Pending,
+	/// The local validator approved the candidate.
+	Approved,
+	/// The candidate got approved while the local validator was a no-show.
+	NoShow,
+}
+
+impl From<ApprovalDuty> for ApprovalAssignment {
+	fn from(duty: ApprovalDuty) -> Self {
+		Self {
+			candidate_hash: duty.candidate_hash.0,
+			block_hash: duty.block_hash,
+			tranche: duty.tranche,
+			outcome: match duty.outcome {
+				ApprovalDutyOutcome::Pending => ApprovalOutcome::Pending,
+				ApprovalDutyOutcome::Approved => ApprovalOutcome::Approved,
+				ApprovalDutyOutcome::NoShow => ApprovalOutcome::NoShow,
+			},
+		}
+	}
+}
+
+/// A recent dispute and the participation of the local validator in it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeParticipation {
+	/// The hash of the disputed candidate.
+	pub candidate_hash: Hash,
+	/// The status of the dispute.
+	pub status: DisputeState,
+	/// The vote of the local validator, `true` indicating validity, if it voted.
+	pub vote: Option<bool>,
+}
+
+/// The status of a dispute.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DisputeState {
+	/// The dispute is active and unconcluded.
+	Active,
+	/// The dispute has been confirmed, but not concluded yet.
+	Confirmed,
+	/// The dispute concluded in favour of the candidate.
+	ConcludedFor,
+	/// The dispute concluded against the candidate.
+	ConcludedAgainst,
+}
+
+impl From<DisputeDuty> for DisputeParticipation {
+	fn from(duty: DisputeDuty) -> Self {
+		Self {
+			candidate_hash: duty.candidate_hash.0,
+			status: match duty.status {
+				DisputeStatus::Active => DisputeState::Active,
+				DisputeStatus::Confirmed => DisputeState::Confirmed,
+				DisputeStatus::ConcludedFor(_) => DisputeState::ConcludedFor,
+				DisputeStatus::ConcludedAgainst(_) => DisputeState::ConcludedAgainst,
+			},
+			vote: duty.vote,
+		}
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Failed to determine the current session.
+	#[error("Failed to determine the current session: {0}")]
+	SessionIndex(String),
+	/// A subsystem did not answer the query.
+	#[error("The {0} subsystem is unavailable")]
+	SubsystemUnavailable(&'static str),
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::SessionIndex(e) =>
+				ErrorObject::owned(VALIDATOR_PERFORMANCE_ERROR + 1, e, None::<()>),
+			Error::SubsystemUnavailable(_) =>
+				ErrorObject::owned(VALIDATOR_PERFORMANCE_ERROR + 2, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
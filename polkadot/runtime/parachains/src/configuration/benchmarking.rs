@@ -71,6 +71,7 @@ mod benchmarks {
 					ExecutorParam::StackLogicalMax(65536),
 					ExecutorParam::StackNativeMax(256 * 1024 * 1024),
 					ExecutorParam::WasmExtBulkMemory,
+					ExecutorParam::PolkaVm,
 					ExecutorParam::PrecheckingMaxMemory(2 * 1024 * 1024 * 1024),
 					ExecutorParam::PvfPrepTimeout(PvfPrepKind::Precheck, 60_000),
 					ExecutorParam::PvfPrepTimeout(PvfPrepKind::Prepare, 360_000),
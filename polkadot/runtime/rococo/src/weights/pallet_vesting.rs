@@ -17,7 +17,7 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `d3a9aad6f7a3`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
@@ -52,7 +52,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -61,20 +61,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 34_644_000 picoseconds.
-		Weight::from_parts(34_294_075, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_502
-			.saturating_add(Weight::from_parts(33_997, 0).saturating_mul(l.into()))
-			// Standard Error: 4_451
-			.saturating_add(Weight::from_parts(82_158, 0).saturating_mul(s.into()))
+		//  Measured:  `277 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 36_399_000 picoseconds.
+		Weight::from_parts(36_031_802, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_718
+			.saturating_add(Weight::from_parts(32_870, 0).saturating_mul(l.into()))
+			// Standard Error: 4_239
+			.saturating_add(Weight::from_parts(94_259, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -83,20 +83,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 37_051_000 picoseconds.
-		Weight::from_parts(37_406_778, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_143
-			.saturating_add(Weight::from_parts(25_479, 0).saturating_mul(l.into()))
-			// Standard Error: 3_813
-			.saturating_add(Weight::from_parts(59_478, 0).saturating_mul(s.into()))
+		//  Measured:  `277 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 38_219_000 picoseconds.
+		Weight::from_parts(38_585_741, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_316
+			.saturating_add(Weight::from_parts(25_524, 0).saturating_mul(l.into()))
+			// Standard Error: 4_025
+			.saturating_add(Weight::from_parts(65_660, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -107,20 +107,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 36_942_000 picoseconds.
-		Weight::from_parts(36_397_510, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_194
-			.saturating_add(Weight::from_parts(43_154, 0).saturating_mul(l.into()))
-			// Standard Error: 3_904
-			.saturating_add(Weight::from_parts(78_209, 0).saturating_mul(s.into()))
+		//  Measured:  `380 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 38_156_000 picoseconds.
+		Weight::from_parts(37_593_805, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_403
+			.saturating_add(Weight::from_parts(44_286, 0).saturating_mul(l.into()))
+			// Standard Error: 3_967
+			.saturating_add(Weight::from_parts(89_299, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -131,20 +131,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 39_129_000 picoseconds.
-		Weight::from_parts(39_172_044, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_803
-			.saturating_add(Weight::from_parts(33_251, 0).saturating_mul(l.into()))
-			// Standard Error: 4_988
-			.saturating_add(Weight::from_parts(83_808, 0).saturating_mul(s.into()))
+		//  Measured:  `380 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 40_479_000 picoseconds.
+		Weight::from_parts(40_523_469, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_863
+			.saturating_add(Weight::from_parts(32_412, 0).saturating_mul(l.into()))
+			// Standard Error: 4_688
+			.saturating_add(Weight::from_parts(96_246, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -155,20 +155,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 75_395_000 picoseconds.
-		Weight::from_parts(77_352_873, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 4_000
-			.saturating_add(Weight::from_parts(33_084, 0).saturating_mul(l.into()))
-			// Standard Error: 7_117
-			.saturating_add(Weight::from_parts(78_022, 0).saturating_mul(s.into()))
+		//  Measured:  `451 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 79_974_000 picoseconds.
+		Weight::from_parts(82_050_680, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 4_216
+			.saturating_add(Weight::from_parts(33_535, 0).saturating_mul(l.into()))
+			// Standard Error: 6_893
+			.saturating_add(Weight::from_parts(84_333, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -179,20 +179,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `554 + l * (25 ±0) + s * (36 ±0)`
+		//  Measured:  `554 + l * (25 ±0) + s * (38 ±0)`
 		//  Estimated: `6196`
-		// Minimum execution time: 76_648_000 picoseconds.
-		Weight::from_parts(77_970_331, 0)
+		// Minimum execution time: 81_091_000 picoseconds.
+		Weight::from_parts(82_489_714, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_437
-			.saturating_add(Weight::from_parts(45_910, 0).saturating_mul(l.into()))
-			// Standard Error: 6_116
-			.saturating_add(Weight::from_parts(105_157, 0).saturating_mul(s.into()))
+			// Standard Error: 3_380
+			.saturating_add(Weight::from_parts(44_905, 0).saturating_mul(l.into()))
+			// Standard Error: 6_636
+			.saturating_add(Weight::from_parts(120_747, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -201,20 +201,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 34_725_000 picoseconds.
-		Weight::from_parts(35_867_784, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_233
-			.saturating_add(Weight::from_parts(30_025, 0).saturating_mul(l.into()))
-			// Standard Error: 4_125
-			.saturating_add(Weight::from_parts(56_964, 0).saturating_mul(s.into()))
+		//  Measured:  `277 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 37_118_000 picoseconds.
+		Weight::from_parts(38_339_639, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_347
+			.saturating_add(Weight::from_parts(29_265, 0).saturating_mul(l.into()))
+			// Standard Error: 3_990
+			.saturating_add(Weight::from_parts(66_109, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -223,20 +223,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 37_897_000 picoseconds.
-		Weight::from_parts(37_679_187, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_178
-			.saturating_add(Weight::from_parts(37_935, 0).saturating_mul(l.into()))
-			// Standard Error: 4_022
-			.saturating_add(Weight::from_parts(89_640, 0).saturating_mul(s.into()))
+		//  Measured:  `277 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 39_658_000 picoseconds.
+		Weight::from_parts(39_430_345, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_975
+			.saturating_add(Weight::from_parts(39_776, 0).saturating_mul(l.into()))
+			// Standard Error: 4_113
+			.saturating_add(Weight::from_parts(97_783, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -247,16 +247,40 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_163_000 picoseconds.
-		Weight::from_parts(42_827_949, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_205
-			.saturating_add(Weight::from_parts(28_809, 0).saturating_mul(l.into()))
-			// Standard Error: 4_073
-			.saturating_add(Weight::from_parts(67_463, 0).saturating_mul(s.into()))
+		//  Measured:  `451 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 44_459_000 picoseconds.
+		Weight::from_parts(45_160_437, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_278
+			.saturating_add(Weight::from_parts(28_759, 0).saturating_mul(l.into()))
+			// Standard Error: 4_162
+			.saturating_add(Weight::from_parts(75_920, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `416 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 55_637_000 picoseconds.
+		Weight::from_parts(54_853_013, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_804
+			.saturating_add(Weight::from_parts(39_598, 0).saturating_mul(l.into()))
+			// Standard Error: 3_519
+			.saturating_add(Weight::from_parts(100_589, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -17,7 +17,7 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `3a2e9ae8a8f5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
@@ -52,7 +52,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -61,20 +61,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 38_225_000 picoseconds.
-		Weight::from_parts(37_860_470, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_479
-			.saturating_add(Weight::from_parts(41_149, 0).saturating_mul(l.into()))
-			// Standard Error: 2_631
-			.saturating_add(Weight::from_parts(76_064, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 39_674_000 picoseconds.
+		Weight::from_parts(39_296_081, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_445
+			.saturating_add(Weight::from_parts(41_302, 0).saturating_mul(l.into()))
+			// Standard Error: 2_530
+			.saturating_add(Weight::from_parts(87_313, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -83,20 +83,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_682_000 picoseconds.
-		Weight::from_parts(40_558_815, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_473
-			.saturating_add(Weight::from_parts(35_138, 0).saturating_mul(l.into()))
-			// Standard Error: 2_620
-			.saturating_add(Weight::from_parts(72_425, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 42_303_000 picoseconds.
+		Weight::from_parts(42_175_000, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_351
+			.saturating_add(Weight::from_parts(35_250, 0).saturating_mul(l.into()))
+			// Standard Error: 2_371
+			.saturating_add(Weight::from_parts(82_105, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -107,20 +107,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_813_000 picoseconds.
-		Weight::from_parts(40_248_990, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_925
-			.saturating_add(Weight::from_parts(47_778, 0).saturating_mul(l.into()))
-			// Standard Error: 3_425
-			.saturating_add(Weight::from_parts(88_421, 0).saturating_mul(s.into()))
+		//  Measured:  `448 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 41_730_000 picoseconds.
+		Weight::from_parts(41_153_620, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_877
+			.saturating_add(Weight::from_parts(49_691, 0).saturating_mul(l.into()))
+			// Standard Error: 3_740
+			.saturating_add(Weight::from_parts(95_623, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -131,20 +131,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 43_330_000 picoseconds.
-		Weight::from_parts(43_588_745, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_075
-			.saturating_add(Weight::from_parts(35_838, 0).saturating_mul(l.into()))
-			// Standard Error: 3_693
-			.saturating_add(Weight::from_parts(73_951, 0).saturating_mul(s.into()))
+		//  Measured:  `448 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 45_140_000 picoseconds.
+		Weight::from_parts(45_409_895, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_981
+			.saturating_add(Weight::from_parts(35_431, 0).saturating_mul(l.into()))
+			// Standard Error: 3_827
+			.saturating_add(Weight::from_parts(80_794, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -155,20 +155,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 80_026_000 picoseconds.
-		Weight::from_parts(82_148_674, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 3_243
-			.saturating_add(Weight::from_parts(30_866, 0).saturating_mul(l.into()))
-			// Standard Error: 5_770
-			.saturating_add(Weight::from_parts(99_755, 0).saturating_mul(s.into()))
+		//  Measured:  `519 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 85_245_000 picoseconds.
+		Weight::from_parts(87_506_387, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_972
+			.saturating_add(Weight::from_parts(30_533, 0).saturating_mul(l.into()))
+			// Standard Error: 5_609
+			.saturating_add(Weight::from_parts(109_051, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -179,20 +179,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `622 + l * (25 ±0) + s * (36 ±0)`
+		//  Measured:  `622 + l * (25 ±0) + s * (38 ±0)`
 		//  Estimated: `6196`
-		// Minimum execution time: 81_979_000 picoseconds.
-		Weight::from_parts(83_373_383, 0)
+		// Minimum execution time: 85_772_000 picoseconds.
+		Weight::from_parts(87_230_540, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_069
-			.saturating_add(Weight::from_parts(49_002, 0).saturating_mul(l.into()))
-			// Standard Error: 5_460
-			.saturating_add(Weight::from_parts(105_265, 0).saturating_mul(s.into()))
+			// Standard Error: 3_301
+			.saturating_add(Weight::from_parts(49_061, 0).saturating_mul(l.into()))
+			// Standard Error: 5_693
+			.saturating_add(Weight::from_parts(119_142, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -201,20 +201,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 39_190_000 picoseconds.
-		Weight::from_parts(38_673_517, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_789
-			.saturating_add(Weight::from_parts(38_146, 0).saturating_mul(l.into()))
-			// Standard Error: 3_305
-			.saturating_add(Weight::from_parts(97_870, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 40_225_000 picoseconds.
+		Weight::from_parts(39_694_749, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_903
+			.saturating_add(Weight::from_parts(37_630, 0).saturating_mul(l.into()))
+			// Standard Error: 3_083
+			.saturating_add(Weight::from_parts(112_566, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -223,20 +223,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_229_000 picoseconds.
-		Weight::from_parts(42_040_081, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_659
-			.saturating_add(Weight::from_parts(38_531, 0).saturating_mul(l.into()))
-			// Standard Error: 3_065
-			.saturating_add(Weight::from_parts(76_527, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 44_647_000 picoseconds.
+		Weight::from_parts(44_447_208, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_615
+			.saturating_add(Weight::from_parts(38_344, 0).saturating_mul(l.into()))
+			// Standard Error: 3_218
+			.saturating_add(Weight::from_parts(89_707, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -247,16 +247,40 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 46_474_000 picoseconds.
-		Weight::from_parts(46_105_020, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_706
-			.saturating_add(Weight::from_parts(39_879, 0).saturating_mul(l.into()))
-			// Standard Error: 3_151
-			.saturating_add(Weight::from_parts(87_824, 0).saturating_mul(s.into()))
+		//  Measured:  `519 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 47_806_000 picoseconds.
+		Weight::from_parts(47_426_341, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_579
+			.saturating_add(Weight::from_parts(38_360, 0).saturating_mul(l.into()))
+			// Standard Error: 2_944
+			.saturating_add(Weight::from_parts(101_698, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `484 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 55_581_000 picoseconds.
+		Weight::from_parts(54_797_736, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_986
+			.saturating_add(Weight::from_parts(40_360, 0).saturating_mul(l.into()))
+			// Standard Error: 3_433
+			.saturating_add(Weight::from_parts(97_958, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
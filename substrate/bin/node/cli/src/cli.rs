@@ -91,6 +91,9 @@ pub enum Subcommand {
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
+	/// Record the storage operations done while executing a block.
+	TraceBlock(sc_cli::TraceBlockCmd),
+
 	/// Export blocks.
 	ExportBlocks(sc_cli::ExportBlocksCmd),
 
@@ -389,6 +389,58 @@ parameter_types! {
 	pub const DepositFactor: Balance = deposit(0, 32);
 }
 
+/// Resolves the calls wrapped by `utility`, `proxy`, `multisig` and `sudo` calls, so that the
+/// per-call thresholds of stateful multisigs apply to the calls actually dispatched.
+pub struct MultisigCallClassifier;
+impl MultisigCallClassifier {
+	/// Wrappers nested deeper than this are treated as unknown.
+	const MAX_DEPTH: u32 = 8;
+
+	fn collect(
+		call: &RuntimeCall,
+		depth: u32,
+		indices: &mut Vec<pallet_multisig::CallIndex>,
+	) -> Option<()> {
+		if depth > Self::MAX_DEPTH {
+			return None
+		}
+		indices.push(pallet_multisig::call_index_of(call)?);
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+				calls.iter().try_for_each(|call| Self::collect(call, depth + 1, indices)),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::dispatch_as { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::dispatch_as_fallible { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_stateful_multi { call, .. }) |
+			RuntimeCall::Sudo(SudoCall::sudo { call }) |
+			RuntimeCall::Sudo(SudoCall::sudo_unchecked_weight { call, .. }) |
+			RuntimeCall::Sudo(SudoCall::sudo_as { call, .. }) => Self::collect(call, depth + 1, indices),
+			RuntimeCall::Utility(pallet_utility::Call::if_else { main, fallback }) => {
+				Self::collect(main, depth + 1, indices)?;
+				Self::collect(fallback, depth + 1, indices)
+			},
+			// Any other utility call might wrap a call this classifier doesn't know about.
+			RuntimeCall::Utility(_) => None,
+			_ => Some(()),
+		}
+	}
+}
+
+impl pallet_multisig::ClassifyCall<RuntimeCall> for MultisigCallClassifier {
+	fn call_indices(call: &RuntimeCall) -> Option<Vec<pallet_multisig::CallIndex>> {
+		let mut indices = Vec::new();
+		Self::collect(call, 0, &mut indices)?;
+		Some(indices)
+	}
+}
+
 impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -398,6 +450,7 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = ConstU32<100>;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type CallClassifier = MultisigCallClassifier;
 }
 
 parameter_types! {
@@ -473,11 +526,99 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// The amount of funds a call moves out of the origin, used to enforce proxy spending
+/// allowances.
+///
+/// Calls wrapping other calls are valued by the calls they dispatch. Only calls known not to move
+/// funds are free; any other call, including transfers of assets other than the native one,
+/// exhausts the allowance.
+pub struct ProxyCallValue;
+impl ProxyCallValue {
+	fn sum<'a>(calls: impl IntoIterator<Item = &'a RuntimeCall>) -> Option<Balance> {
+		use pallet_proxy::CallValue;
+		calls.into_iter().filter_map(Self::value).reduce(|a, b| a.saturating_add(b))
+	}
+}
+
+impl pallet_proxy::CallValue<RuntimeCall, Balance> for ProxyCallValue {
+	fn value(call: &RuntimeCall) -> Option<Balance> {
+		match call {
+			RuntimeCall::Balances(BalancesCall::transfer_allow_death { value, .. }) |
+			RuntimeCall::Balances(BalancesCall::transfer_keep_alive { value, .. }) => Some(*value),
+			RuntimeCall::Balances(BalancesCall::transfer_all { .. }) => Some(Balance::MAX),
+			RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { schedule, .. }) =>
+				Some(schedule.locked()),
+			RuntimeCall::Staking(pallet_staking::Call::bond { value, .. }) => Some(*value),
+			RuntimeCall::Staking(pallet_staking::Call::bond_extra { max_additional }) =>
+				Some(*max_additional),
+			RuntimeCall::NominationPools(pallet_nomination_pools::Call::join {
+				amount, ..
+			}) |
+			RuntimeCall::NominationPools(pallet_nomination_pools::Call::create {
+				amount, ..
+			}) => Some(*amount),
+			RuntimeCall::NominationPools(pallet_nomination_pools::Call::bond_extra {
+				extra: pallet_nomination_pools::BondExtra::FreeBalance(amount),
+			}) => Some(*amount),
+			RuntimeCall::System(_) |
+			RuntimeCall::Session(_) |
+			RuntimeCall::Staking(
+				pallet_staking::Call::unbond { .. } |
+				pallet_staking::Call::withdraw_unbonded { .. } |
+				pallet_staking::Call::validate { .. } |
+				pallet_staking::Call::nominate { .. } |
+				pallet_staking::Call::chill { .. } |
+				pallet_staking::Call::payout_stakers { .. } |
+				pallet_staking::Call::payout_stakers_by_page { .. } |
+				pallet_staking::Call::rebond { .. },
+			) |
+			RuntimeCall::NominationPools(
+				pallet_nomination_pools::Call::claim_payout { .. } |
+				pallet_nomination_pools::Call::unbond { .. } |
+				pallet_nomination_pools::Call::pool_withdraw_unbonded { .. } |
+				pallet_nomination_pools::Call::withdraw_unbonded { .. } |
+				pallet_nomination_pools::Call::nominate { .. } |
+				pallet_nomination_pools::Call::chill { .. } |
+				pallet_nomination_pools::Call::bond_extra {
+					extra: pallet_nomination_pools::BondExtra::Rewards,
+				},
+			) |
+			RuntimeCall::ConvictionVoting(
+				pallet_conviction_voting::Call::vote { .. } |
+				pallet_conviction_voting::Call::remove_vote { .. } |
+				pallet_conviction_voting::Call::unlock { .. },
+			) |
+			RuntimeCall::Proxy(
+				pallet_proxy::Call::announce { .. } |
+				pallet_proxy::Call::remove_announcement { .. } |
+				pallet_proxy::Call::reject_announcement { .. },
+			) => None,
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => Self::sum(calls),
+			RuntimeCall::Utility(pallet_utility::Call::if_else { main, fallback }) =>
+				Self::sum([&**main, &**fallback]),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::dispatch_as { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::dispatch_as_fallible { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy { call, .. }) |
+			RuntimeCall::Proxy(pallet_proxy::Call::proxy_announced { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_multi { call, .. }) |
+			RuntimeCall::Multisig(pallet_multisig::Call::as_stateful_multi { call, .. }) =>
+				Self::value(call),
+			_ => Some(Balance::MAX),
+		}
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
 	type ProxyType = ProxyType;
+	type CallValue = ProxyCallValue;
 	type ProxyDepositBase = ProxyDepositBase;
 	type ProxyDepositFactor = ProxyDepositFactor;
 	type MaxProxies = ConstU32<32>;
@@ -2121,6 +2262,7 @@ impl pallet_nft_fractionalization::Config for Runtime {
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const MaxRoyaltyRecipients: u32 = 5;
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -2142,6 +2284,7 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -3291,6 +3434,17 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[cfg(feature = "experimental")]
+	impl frame_system_rpc_runtime_api::TasksApi<Block, RuntimeTask> for Runtime {
+		fn valid_tasks(max: u32) -> Vec<(RuntimeTask, Weight)> {
+			System::valid_tasks(max)
+		}
+
+		fn task_extrinsic(task: RuntimeTask) -> <Block as BlockT>::Extrinsic {
+			System::task_extrinsic(task)
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
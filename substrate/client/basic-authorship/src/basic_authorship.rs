@@ -29,7 +29,9 @@ use futures::{
 use log::{debug, error, info, trace, warn};
 use sc_block_builder::{BlockBuilderApi, BlockBuilderBuilder};
 use sc_telemetry::{telemetry, TelemetryHandle, CONSENSUS_INFO};
-use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TxInvalidityReportMap};
+use sc_transaction_pool_api::{
+	InPoolTransaction, ReadyTransactions, TransactionPool, TxHash, TxInvalidityReportMap,
+};
 use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
 use sp_blockchain::{ApplyExtrinsicFailed::Validity, Error::ApplyExtrinsicFailed, HeaderBackend};
 use sp_consensus::{DisableProofRecording, EnableProofRecording, ProofRecording, Proposal};
@@ -37,13 +39,25 @@ use sp_core::traits::SpawnNamed;
 use sp_inherents::InherentData;
 use sp_runtime::{
 	traits::{BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT},
+	transaction_validity::TransactionTag,
 	Digest, ExtrinsicInclusionMode, Percent, SaturatedConversion,
 };
-use std::{marker::PhantomData, pin::Pin, sync::Arc, time};
+use std::{
+	collections::{HashMap, HashSet},
+	marker::PhantomData,
+	pin::Pin,
+	sync::Arc,
+	time,
+};
 
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_proposer_metrics::{EndProposingReason, MetricsLink as PrometheusMetrics};
 
+use crate::inclusion_policy::{
+	AcceptAll, Candidate, DeterministicReady, InclusionDecision, InclusionPolicy, InclusionReport,
+	SkipReason, TransactionOrdering,
+};
+
 /// Default block size limit in bytes used by [`Proposer`].
 ///
 /// Can be overwritten by [`ProposerFactory::set_default_block_size_limit`].
@@ -58,7 +72,7 @@ const DEFAULT_SOFT_DEADLINE_PERCENT: Percent = Percent::from_percent(50);
 const LOG_TARGET: &'static str = "basic-authorship";
 
 /// [`Proposer`] factory.
-pub struct ProposerFactory<A, C, PR> {
+pub struct ProposerFactory<A, C, PR, P = AcceptAll> {
 	spawn_handle: Box<dyn SpawnNamed>,
 	/// The client instance.
 	client: Arc<C>,
@@ -82,11 +96,13 @@ pub struct ProposerFactory<A, C, PR> {
 	telemetry: Option<TelemetryHandle>,
 	/// When estimating the block size, should the proof be included?
 	include_proof_in_block_size_estimation: bool,
+	/// The policy deciding which transactions are put into the block.
+	inclusion_policy: Arc<P>,
 	/// phantom member to pin the `ProofRecording` type.
 	_phantom: PhantomData<PR>,
 }
 
-impl<A, C, PR> Clone for ProposerFactory<A, C, PR> {
+impl<A, C, PR, P> Clone for ProposerFactory<A, C, PR, P> {
 	fn clone(&self) -> Self {
 		Self {
 			spawn_handle: self.spawn_handle.clone(),
@@ -97,6 +113,7 @@ impl<A, C, PR> Clone for ProposerFactory<A, C, PR> {
 			soft_deadline_percent: self.soft_deadline_percent,
 			telemetry: self.telemetry.clone(),
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			inclusion_policy: self.inclusion_policy.clone(),
 			_phantom: self._phantom,
 		}
 	}
@@ -123,6 +140,7 @@ impl<A, C> ProposerFactory<A, C, DisableProofRecording> {
 			telemetry,
 			client,
 			include_proof_in_block_size_estimation: false,
+			inclusion_policy: Arc::new(AcceptAll),
 			_phantom: PhantomData,
 		}
 	}
@@ -151,6 +169,7 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 			soft_deadline_percent: DEFAULT_SOFT_DEADLINE_PERCENT,
 			telemetry,
 			include_proof_in_block_size_estimation: true,
+			inclusion_policy: Arc::new(AcceptAll),
 			_phantom: PhantomData,
 		}
 	}
@@ -161,7 +180,7 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 	}
 }
 
-impl<A, C, PR> ProposerFactory<A, C, PR> {
+impl<A, C, PR, P> ProposerFactory<A, C, PR, P> {
 	/// Set the default block size limit in bytes.
 	///
 	/// The default value for the block size limit is:
@@ -188,32 +207,77 @@ impl<A, C, PR> ProposerFactory<A, C, PR> {
 	pub fn set_soft_deadline(&mut self, percent: Percent) {
 		self.soft_deadline_percent = percent;
 	}
+
+	/// Set the policy deciding which transactions are put into the block.
+	///
+	/// By default, [`AcceptAll`] is used, which includes transactions in the order the
+	/// transaction pool yields them.
+	pub fn with_inclusion_policy<NP>(self, inclusion_policy: NP) -> ProposerFactory<A, C, PR, NP> {
+		ProposerFactory {
+			spawn_handle: self.spawn_handle,
+			client: self.client,
+			transaction_pool: self.transaction_pool,
+			metrics: self.metrics,
+			default_block_size_limit: self.default_block_size_limit,
+			soft_deadline_percent: self.soft_deadline_percent,
+			telemetry: self.telemetry,
+			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			inclusion_policy: Arc::new(inclusion_policy),
+			_phantom: self._phantom,
+		}
+	}
 }
 
-impl<Block, C, A, PR> ProposerFactory<A, C, PR>
+impl<Block, C, A, PR, P> ProposerFactory<A, C, PR, P>
 where
 	A: TransactionPool<Block = Block> + 'static,
 	Block: BlockT,
 	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + Send + Sync + 'static,
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+	P: InclusionPolicy<Block>,
 {
 	fn init_with_now(
 		&mut self,
 		parent_header: &<Block as BlockT>::Header,
 		now: Box<dyn Fn() -> time::Instant + Send + Sync>,
-	) -> Proposer<Block, C, A, PR> {
-		let parent_hash = parent_header.hash();
-
+	) -> Proposer<Block, C, A, PR, P> {
 		info!(
 			"🙌 Starting consensus session on top of parent {:?} (#{})",
-			parent_hash,
+			parent_header.hash(),
+			parent_header.number()
+		);
+
+		self.new_proposer(parent_header, now)
+	}
+
+	/// Create a proposer on top of `parent_header` which is only used for
+	/// [`Proposer::dry_run`].
+	///
+	/// Unlike [`sp_consensus::Environment::init`] this does not announce a new consensus session,
+	/// as dry runs are triggered externally, e.g. through RPC.
+	pub fn init_dry_run(
+		&mut self,
+		parent_header: &<Block as BlockT>::Header,
+	) -> Proposer<Block, C, A, PR, P> {
+		debug!(
+			target: LOG_TARGET,
+			"Starting dry run on top of parent {:?} (#{})",
+			parent_header.hash(),
 			parent_header.number()
 		);
 
-		let proposer = Proposer::<_, _, _, PR> {
+		self.new_proposer(parent_header, Box::new(time::Instant::now))
+	}
+
+	fn new_proposer(
+		&mut self,
+		parent_header: &<Block as BlockT>::Header,
+		now: Box<dyn Fn() -> time::Instant + Send + Sync>,
+	) -> Proposer<Block, C, A, PR, P> {
+		let proposer = Proposer::<_, _, _, PR, P> {
 			spawn_handle: self.spawn_handle.clone(),
 			client: self.client.clone(),
-			parent_hash,
+			parent_hash: parent_header.hash(),
 			parent_number: *parent_header.number(),
 			transaction_pool: self.transaction_pool.clone(),
 			now,
@@ -223,22 +287,24 @@ where
 			telemetry: self.telemetry.clone(),
 			_phantom: PhantomData,
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			inclusion_policy: self.inclusion_policy.clone(),
 		};
 
 		proposer
 	}
 }
 
-impl<A, Block, C, PR> sp_consensus::Environment<Block> for ProposerFactory<A, C, PR>
+impl<A, Block, C, PR, P> sp_consensus::Environment<Block> for ProposerFactory<A, C, PR, P>
 where
 	A: TransactionPool<Block = Block> + 'static,
 	Block: BlockT,
 	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + CallApiAt<Block> + Send + Sync + 'static,
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 	PR: ProofRecording,
+	P: InclusionPolicy<Block>,
 {
 	type CreateProposer = future::Ready<Result<Self::Proposer, Self::Error>>;
-	type Proposer = Proposer<Block, C, A, PR>;
+	type Proposer = Proposer<Block, C, A, PR, P>;
 	type Error = sp_blockchain::Error;
 
 	fn init(&mut self, parent_header: &<Block as BlockT>::Header) -> Self::CreateProposer {
@@ -247,7 +313,7 @@ where
 }
 
 /// The proposer logic.
-pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR> {
+pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR, P = AcceptAll> {
 	spawn_handle: Box<dyn SpawnNamed>,
 	client: Arc<C>,
 	parent_hash: Block::Hash,
@@ -259,16 +325,18 @@ pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR> {
 	include_proof_in_block_size_estimation: bool,
 	soft_deadline_percent: Percent,
 	telemetry: Option<TelemetryHandle>,
+	inclusion_policy: Arc<P>,
 	_phantom: PhantomData<PR>,
 }
 
-impl<A, Block, C, PR> sp_consensus::Proposer<Block> for Proposer<Block, C, A, PR>
+impl<A, Block, C, PR, P> sp_consensus::Proposer<Block> for Proposer<Block, C, A, PR, P>
 where
 	A: TransactionPool<Block = Block> + 'static,
 	Block: BlockT,
 	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + CallApiAt<Block> + Send + Sync + 'static,
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 	PR: ProofRecording,
+	P: InclusionPolicy<Block>,
 {
 	type Proposal =
 		Pin<Box<dyn Future<Output = Result<Proposal<Block, PR::Proof>, Self::Error>> + Send>>;
@@ -313,14 +381,83 @@ where
 /// It allows us to increase block utilization.
 const MAX_SKIPPED_TRANSACTIONS: usize = 8;
 
-impl<A, Block, C, PR> Proposer<Block, C, A, PR>
+impl<A, Block, C, PR, P> Proposer<Block, C, A, PR, P>
 where
-	A: TransactionPool<Block = Block>,
+	A: TransactionPool<Block = Block> + 'static,
 	Block: BlockT,
 	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + CallApiAt<Block> + Send + Sync + 'static,
 	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
 	PR: ProofRecording,
+	P: InclusionPolicy<Block>,
 {
+	/// Do a dry run of the block production.
+	///
+	/// Builds a block on top of the parent like [`sp_consensus::Proposer::propose`] does, but
+	/// instead of returning the block, reports which transactions would be included and why the
+	/// other ones were skipped. Invalid transactions found along the way are not reported to the
+	/// transaction pool. Like the block production, the dry run is spawned as a blocking task.
+	pub fn dry_run(
+		self,
+		inherent_data: InherentData,
+		inherent_digests: Digest,
+		max_duration: time::Duration,
+		block_size_limit: Option<usize>,
+	) -> impl Future<Output = Result<InclusionReport<TxHash<A>>, sp_blockchain::Error>> {
+		let (tx, rx) = oneshot::channel();
+		let spawn_handle = self.spawn_handle.clone();
+
+		spawn_handle.spawn_blocking(
+			"basic-authorship-dry-run",
+			None,
+			Box::pin(async move {
+				let deadline = (self.now)() + max_duration - max_duration / 10;
+				let res = self
+					.dry_run_with(inherent_data, inherent_digests, deadline, block_size_limit)
+					.await;
+				if tx.send(res).is_err() {
+					trace!(target: LOG_TARGET, "Could not send dry run result!");
+				}
+			}),
+		);
+
+		async move { rx.await? }
+	}
+
+	async fn dry_run_with(
+		self,
+		inherent_data: InherentData,
+		inherent_digests: Digest,
+		deadline: time::Instant,
+		block_size_limit: Option<usize>,
+	) -> Result<InclusionReport<TxHash<A>>, sp_blockchain::Error> {
+		let mut report = InclusionReport::default();
+		let mut block_builder = BlockBuilderBuilder::new(&*self.client)
+			.on_parent_block(self.parent_hash)
+			.with_parent_block_number(self.parent_number)
+			.with_proof_recording(PR::ENABLED)
+			.with_inherent_digests(inherent_digests)
+			.build()?;
+
+		self.apply_inherents(&mut block_builder, inherent_data)?;
+
+		let end_reason = match block_builder.extrinsic_inclusion_mode() {
+			ExtrinsicInclusionMode::AllExtrinsics =>
+				self.apply_extrinsics(
+					&mut block_builder,
+					deadline,
+					block_size_limit,
+					Some(&mut report),
+				)
+				.await?,
+			ExtrinsicInclusionMode::OnlyInherents => EndProposingReason::TransactionForbidden,
+		};
+
+		report.block_size =
+			block_builder.estimate_block_size(self.include_proof_in_block_size_estimation);
+		report.end_reason = format!("{:?}", end_reason);
+		Ok(report)
+	}
+
 	async fn propose_with(
 		self,
 		inherent_data: InherentData,
@@ -341,7 +478,8 @@ where
 		let mode = block_builder.extrinsic_inclusion_mode();
 		let end_reason = match mode {
 			ExtrinsicInclusionMode::AllExtrinsics =>
-				self.apply_extrinsics(&mut block_builder, deadline, block_size_limit).await?,
+				self.apply_extrinsics(&mut block_builder, deadline, block_size_limit, None)
+					.await?,
 			ExtrinsicInclusionMode::OnlyInherents => EndProposingReason::TransactionForbidden,
 		};
 		let (block, storage_changes, proof) = block_builder.build()?.into_inner();
@@ -399,11 +537,15 @@ where
 	}
 
 	/// Apply as many extrinsics as possible to the block.
+	///
+	/// If `report` is given, the decision taken for every transaction is recorded in it and
+	/// invalid transactions are not reported to the transaction pool.
 	async fn apply_extrinsics(
 		&self,
 		block_builder: &mut sc_block_builder::BlockBuilder<'_, Block, C>,
 		deadline: time::Instant,
 		block_size_limit: Option<usize>,
+		mut report: Option<&mut InclusionReport<TxHash<A>>>,
 	) -> Result<EndProposingReason, sp_blockchain::Error> {
 		// proceed with transactions
 		// We calculate soft deadline used only in case we start skipping transactions.
@@ -414,10 +556,19 @@ where
 			now + time::Duration::from_micros(self.soft_deadline_percent.mul_floor(left_micros));
 		let mut skipped = 0;
 		let mut unqueue_invalid = TxInvalidityReportMap::new();
+		let mut policy_state = P::State::default();
+		// The tags provided by skipped transactions, with the hash of the providing transaction.
+		let mut skipped_provides = HashMap::<TransactionTag, TxHash<A>>::new();
 
 		let delay = deadline.saturating_duration_since((self.now)()) / 8;
-		let mut pending_iterator =
+		let pending_iterator =
 			self.transaction_pool.ready_at_with_timeout(self.parent_hash, delay).await;
+		let mut pending_iterator: Box<dyn ReadyTransactions<Item = _>> =
+			match self.inclusion_policy.ordering() {
+				TransactionOrdering::Pool => pending_iterator,
+				TransactionOrdering::Deterministic =>
+					Box::new(DeterministicReady::new(pending_iterator)),
+			};
 
 		let block_size_limit = block_size_limit.unwrap_or(self.default_block_size_limit);
 
@@ -448,11 +599,38 @@ where
 
 			let pending_tx_data = (**pending_tx.data()).clone();
 			let pending_tx_hash = pending_tx.hash().clone();
+			let pending_tx_size = pending_tx_data.encoded_size();
+			let note_skipped_provides = |skipped_provides: &mut HashMap<_, _>| {
+				skipped_provides.extend(
+					pending_tx.provides().iter().map(|tag| (tag.clone(), pending_tx_hash.clone())),
+				);
+			};
+
+			// The pool doesn't yield transactions depending on reported ones, but they are
+			// yielded in the deterministic order.
+			if let Some(dependency) =
+				pending_tx.requires().iter().find_map(|tag| skipped_provides.get(tag)).cloned()
+			{
+				pending_iterator.report_invalid(&pending_tx);
+				trace!(
+					target: LOG_TARGET,
+					"[{:?}] Skipped as it depends on skipped {:?}", pending_tx_hash, dependency
+				);
+				note_skipped_provides(&mut skipped_provides);
+				if let Some(report) = report.as_deref_mut() {
+					report.note_skipped(pending_tx_hash, SkipReason::Dependency { dependency });
+				}
+				continue
+			}
 
 			let block_size =
 				block_builder.estimate_block_size(self.include_proof_in_block_size_estimation);
-			if block_size + pending_tx_data.encoded_size() > block_size_limit {
+			if block_size + pending_tx_size > block_size_limit {
 				pending_iterator.report_invalid(&pending_tx);
+				note_skipped_provides(&mut skipped_provides);
+				if let Some(report) = report.as_deref_mut() {
+					report.note_skipped(pending_tx_hash.clone(), SkipReason::BlockSizeLimit);
+				}
 				if skipped < MAX_SKIPPED_TRANSACTIONS {
 					skipped += 1;
 					debug!(
@@ -479,14 +657,49 @@ where
 				}
 			}
 
+			let candidate = Candidate {
+				extrinsic: &**pending_tx.data(),
+				priority: *pending_tx.priority(),
+				requires: pending_tx.requires(),
+				provides: pending_tx.provides(),
+				encoded_size: pending_tx_size,
+				block_size,
+				block_size_limit,
+			};
+			if let InclusionDecision::Skip(reason) =
+				self.inclusion_policy.check(&mut policy_state, &candidate)
+			{
+				pending_iterator.report_invalid(&pending_tx);
+				note_skipped_provides(&mut skipped_provides);
+				trace!(
+					target: LOG_TARGET,
+					"[{:?}] Skipped by the inclusion policy: {}", pending_tx_hash, reason
+				);
+				if let Some(report) = report.as_deref_mut() {
+					report.note_skipped(
+						pending_tx_hash,
+						SkipReason::Policy { reason: reason.into() },
+					);
+				}
+				continue
+			}
+
 			trace!(target: LOG_TARGET, "[{:?}] Pushing to the block.", pending_tx_hash);
 			match sc_block_builder::BlockBuilder::push(block_builder, pending_tx_data) {
 				Ok(()) => {
 					transaction_pushed = true;
+					self.inclusion_policy.note_included(&mut policy_state, &candidate);
 					trace!(target: LOG_TARGET, "[{:?}] Pushed to the block.", pending_tx_hash);
+					if let Some(report) = report.as_deref_mut() {
+						report.included.push(pending_tx_hash);
+					}
 				},
 				Err(ApplyExtrinsicFailed(Validity(e))) if e.exhausted_resources() => {
 					pending_iterator.report_invalid(&pending_tx);
+					note_skipped_provides(&mut skipped_provides);
+					if let Some(report) = report.as_deref_mut() {
+						report.note_skipped(pending_tx_hash, SkipReason::ExhaustedResources);
+					}
 					if skipped < MAX_SKIPPED_TRANSACTIONS {
 						skipped += 1;
 						debug!(target: LOG_TARGET,
@@ -508,10 +721,17 @@ where
 				},
 				Err(e) => {
 					pending_iterator.report_invalid(&pending_tx);
+					note_skipped_provides(&mut skipped_provides);
 					debug!(
 						target: LOG_TARGET,
 						"[{:?}] Invalid transaction: {} at: {}", pending_tx_hash, e, self.parent_hash
 					);
+					if let Some(report) = report.as_deref_mut() {
+						report.note_skipped(
+							pending_tx_hash.clone(),
+							SkipReason::Invalid { error: e.to_string() },
+						);
+					}
 
 					let error_to_report = match e {
 						ApplyExtrinsicFailed(Validity(e)) => Some(e),
@@ -523,6 +743,66 @@ where
 			}
 		};
 
+		// Not `Send` in the deterministic order, so it must not be held across the awaits below.
+		drop(pending_iterator);
+
+		if matches!(end_reason, EndProposingReason::NoMoreTransactions) {
+			let fillers = self.inclusion_policy.fill_block(&mut policy_state, self.parent_hash);
+			for extrinsic in fillers {
+				if (self.now)() > deadline {
+					debug!(target: LOG_TARGET, "Deadline reached while filling the block.");
+					break
+				}
+
+				let hash = self.transaction_pool.hash_of(&extrinsic);
+				let block_size =
+					block_builder.estimate_block_size(self.include_proof_in_block_size_estimation);
+				if block_size + extrinsic.encoded_size() > block_size_limit {
+					if let Some(report) = report.as_deref_mut() {
+						report.note_skipped(hash, SkipReason::BlockSizeLimit);
+					}
+					// The same limit applies as for the transactions of the pool.
+					if skipped < MAX_SKIPPED_TRANSACTIONS {
+						skipped += 1;
+						continue
+					} else if (self.now)() < soft_deadline {
+						continue
+					}
+					debug!(target: LOG_TARGET, "Block is full, stopping to fill it.");
+					break
+				}
+
+				match sc_block_builder::BlockBuilder::push(block_builder, extrinsic) {
+					Ok(()) => {
+						trace!(target: LOG_TARGET, "[{:?}] Filled into the block.", hash);
+						if let Some(report) = report.as_deref_mut() {
+							report.included.push(hash);
+						}
+					},
+					Err(ApplyExtrinsicFailed(Validity(e))) if e.exhausted_resources() => {
+						if let Some(report) = report.as_deref_mut() {
+							report.note_skipped(hash, SkipReason::ExhaustedResources);
+						}
+						if skipped < MAX_SKIPPED_TRANSACTIONS {
+							skipped += 1;
+						} else if (self.now)() >= soft_deadline {
+							debug!(target: LOG_TARGET, "Block is full, stopping to fill it.");
+							break
+						}
+					},
+					Err(e) => {
+						debug!(
+							target: LOG_TARGET,
+							"[{:?}] Invalid filler extrinsic: {} at: {}", hash, e, self.parent_hash
+						);
+						if let Some(report) = report.as_deref_mut() {
+							report.note_skipped(hash, SkipReason::Invalid { error: e.to_string() });
+						}
+					},
+				}
+			}
+		}
+
 		if matches!(end_reason, EndProposingReason::HitBlockSizeLimit) && !transaction_pushed {
 			warn!(
 				target: LOG_TARGET,
@@ -530,12 +810,56 @@ where
 			);
 		}
 
-		self.transaction_pool
-			.report_invalid(Some(self.parent_hash), unqueue_invalid)
-			.await;
+		match report {
+			Some(report) => self.report_skipped_dependents(report, skipped_provides).await,
+			None =>
+				self.transaction_pool
+					.report_invalid(Some(self.parent_hash), unqueue_invalid)
+					.await,
+		}
 		Ok(end_reason)
 	}
 
+	/// Report the ready transactions depending on skipped transactions as skipped as well.
+	///
+	/// Once a transaction is skipped, the pool doesn't yield the transactions depending on it
+	/// anymore, so they are looked up among all ready transactions.
+	async fn report_skipped_dependents(
+		&self,
+		report: &mut InclusionReport<TxHash<A>>,
+		mut skipped_provides: HashMap<TransactionTag, TxHash<A>>,
+	) {
+		if skipped_provides.is_empty() {
+			return
+		}
+
+		let mut reported = report
+			.included
+			.iter()
+			.chain(report.skipped.iter().map(|skipped| &skipped.hash))
+			.cloned()
+			.collect::<HashSet<_>>();
+		let ready = self
+			.transaction_pool
+			.ready_at_with_timeout(self.parent_hash, time::Duration::ZERO)
+			.await;
+
+		// The ready transactions are yielded after the ones they depend on.
+		for tx in ready {
+			let Some(dependency) =
+				tx.requires().iter().find_map(|tag| skipped_provides.get(tag)).cloned()
+			else {
+				continue
+			};
+
+			skipped_provides
+				.extend(tx.provides().iter().map(|tag| (tag.clone(), tx.hash().clone())));
+			if reported.insert(tx.hash().clone()) {
+				report.note_skipped(tx.hash().clone(), SkipReason::Dependency { dependency });
+			}
+		}
+	}
+
 	/// Prints a summary and does telemetry + metrics.
 	///
 	/// - `block`: The block that was build.
@@ -605,6 +929,7 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::inclusion_policy::{DeterministicOrdering, MaxPerSender};
 
 	use futures::executor::block_on;
 	use parking_lot::Mutex;
@@ -1160,4 +1485,207 @@ mod tests {
 			"Not enough calls to current time, which indicates the test might have ended because of deadline, not soft deadline"
 		);
 	}
+
+	#[test]
+	fn inclusion_policy_limits_transactions_per_sender() {
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let txpool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		));
+		let genesis_hash = client.info().genesis_hash;
+
+		block_on(txpool.submit_at(
+			genesis_hash,
+			SOURCE,
+			vec![extrinsic(0), extrinsic(1), extrinsic(2)],
+		))
+		.unwrap();
+		block_on(txpool.maintain(chain_event(
+			client.expect_header(genesis_hash).expect("there should be header"),
+		)));
+
+		// all transactions are signed by the same account.
+		let mut proposer_factory =
+			ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None)
+				.with_inclusion_policy(MaxPerSender::new(2, |_: &Extrinsic| Some(vec![0u8])));
+
+		// when
+		let proposer = proposer_factory.init_with_now(
+			&client.expect_header(genesis_hash).unwrap(),
+			Box::new(move || time::Instant::now()),
+		);
+		let deadline = time::Duration::from_secs(9);
+		let block =
+			block_on(proposer.propose(Default::default(), Default::default(), deadline, None))
+				.map(|r| r.block)
+				.unwrap();
+
+		// then
+		assert_eq!(block.extrinsics().len(), 2);
+		assert_eq!(txpool.ready().count(), 3);
+	}
+
+	#[test]
+	fn dry_run_reports_skipped_transactions() {
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let txpool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		));
+		let genesis_hash = client.info().genesis_hash;
+
+		let xts = vec![extrinsic(0), extrinsic(1), extrinsic(2)];
+		let hashes = xts.iter().map(|xt| txpool.hash_of(xt)).collect::<Vec<_>>();
+		block_on(txpool.submit_at(genesis_hash, SOURCE, xts)).unwrap();
+		block_on(txpool.maintain(chain_event(
+			client.expect_header(genesis_hash).expect("there should be header"),
+		)));
+
+		let mut proposer_factory =
+			ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None)
+				.with_inclusion_policy(MaxPerSender::new(1, |_: &Extrinsic| Some(vec![0u8])));
+
+		// when
+		let proposer = proposer_factory.init_with_now(
+			&client.expect_header(genesis_hash).unwrap(),
+			Box::new(move || time::Instant::now()),
+		);
+		let deadline = time::Duration::from_secs(9);
+		let report =
+			block_on(proposer.dry_run(Default::default(), Default::default(), deadline, None))
+				.unwrap();
+
+		// then the first transaction is included and the second one is skipped by the policy,
+		// which also skips the third one depending on it.
+		assert_eq!(report.included, vec![hashes[0]]);
+		assert_eq!(report.skipped.len(), 2);
+		assert_eq!(report.skipped[0].hash, hashes[1]);
+		assert!(matches!(report.skipped[0].reason, SkipReason::Policy { .. }));
+		assert_eq!(report.skipped[1].hash, hashes[2]);
+		assert_eq!(report.skipped[1].reason, SkipReason::Dependency { dependency: hashes[1] });
+		assert_eq!(report.end_reason, "NoMoreTransactions");
+		// nothing was removed from the pool.
+		assert_eq!(txpool.ready().count(), 3);
+	}
+
+	#[test]
+	fn deterministic_ordering_does_not_depend_on_submission_order() {
+		let xt = |who, nonce| {
+			ExtrinsicBuilder::new_fill_block(Perbill::from_parts(TINY))
+				.signer(Sr25519Keyring::numeric(who))
+				.nonce(nonce)
+				.build()
+		};
+		let dry_run = |xts: Vec<Extrinsic>| {
+			let client = Arc::new(substrate_test_runtime_client::new());
+			let spawner = sp_core::testing::TaskExecutor::new();
+			let txpool = Arc::from(BasicPool::new_full(
+				Default::default(),
+				true.into(),
+				None,
+				spawner.clone(),
+				client.clone(),
+			));
+			let genesis_hash = client.info().genesis_hash;
+
+			block_on(txpool.submit_at(genesis_hash, SOURCE, xts)).unwrap();
+			block_on(txpool.maintain(chain_event(
+				client.expect_header(genesis_hash).expect("there should be header"),
+			)));
+
+			let mut proposer_factory =
+				ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None)
+					.with_inclusion_policy(DeterministicOrdering);
+			let proposer = proposer_factory.init_with_now(
+				&client.expect_header(genesis_hash).unwrap(),
+				Box::new(move || time::Instant::now()),
+			);
+			let deadline = time::Duration::from_secs(9);
+			block_on(proposer.dry_run(Default::default(), Default::default(), deadline, None))
+				.unwrap()
+				.included
+		};
+
+		// given
+		let xts = vec![xt(1, 0), xt(1, 1), xt(2, 0), xt(3, 0), xt(4, 0)];
+		let hash_of = |xt: &Extrinsic| BlakeTwo256::hash_of(xt);
+
+		// when
+		let included = dry_run(xts.clone());
+		let included_reversed = dry_run(xts.iter().rev().cloned().collect());
+
+		// then
+		assert_eq!(included.len(), xts.len());
+		assert_eq!(included, included_reversed);
+		let position = |xt: &Extrinsic| included.iter().position(|h| *h == hash_of(xt)).unwrap();
+		assert!(position(&xts[0]) < position(&xts[1]));
+	}
+
+	#[test]
+	fn inclusion_policy_fills_the_block() {
+		struct Filler;
+
+		impl InclusionPolicy<Block> for Filler {
+			type State = ();
+
+			fn check(&self, _: &mut (), _: &Candidate<Block>) -> InclusionDecision {
+				InclusionDecision::Include
+			}
+
+			fn fill_block(&self, _: &mut (), _: <Block as BlockT>::Hash) -> Vec<Extrinsic> {
+				// the second one is a duplicate of the transaction in the pool.
+				vec![extrinsic(1), extrinsic(0)]
+			}
+		}
+
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let txpool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		));
+		let genesis_hash = client.info().genesis_hash;
+
+		block_on(txpool.submit_at(genesis_hash, SOURCE, vec![extrinsic(0)])).unwrap();
+		block_on(txpool.maintain(chain_event(
+			client.expect_header(genesis_hash).expect("there should be header"),
+		)));
+
+		let mut proposer_factory =
+			ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None)
+				.with_inclusion_policy(Filler);
+
+		// when
+		let proposer = proposer_factory.init_with_now(
+			&client.expect_header(genesis_hash).unwrap(),
+			Box::new(move || time::Instant::now()),
+		);
+		let deadline = time::Duration::from_secs(9);
+		let report =
+			block_on(proposer.dry_run(Default::default(), Default::default(), deadline, None))
+				.unwrap();
+
+		// then the pool transaction and the first filler are included.
+		assert_eq!(
+			report.included,
+			vec![txpool.hash_of(&extrinsic(0)), txpool.hash_of(&extrinsic(1))]
+		);
+		assert_eq!(report.skipped.len(), 1);
+		assert!(matches!(report.skipped[0].reason, SkipReason::Invalid { .. }));
+	}
 }
@@ -0,0 +1,390 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pluggable policies deciding which ready transactions the [`Proposer`](crate::Proposer) puts
+//! into a block, and the report produced when doing a dry run of the block production.
+
+use codec::Encode;
+use sc_transaction_pool_api::{InPoolTransaction, ReadyTransactions};
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::Block as BlockT,
+	transaction_validity::{TransactionPriority, TransactionTag},
+	Percent,
+};
+use std::{
+	cmp::Reverse,
+	collections::{BinaryHeap, HashMap, HashSet},
+	sync::Arc,
+	vec,
+};
+
+/// A ready transaction the proposer is about to push into the block.
+pub struct Candidate<'a, Block: BlockT> {
+	/// The transaction itself.
+	pub extrinsic: &'a Block::Extrinsic,
+	/// The priority assigned by the transaction pool.
+	pub priority: TransactionPriority,
+	/// Tags the transaction requires.
+	pub requires: &'a [TransactionTag],
+	/// Tags the transaction provides.
+	pub provides: &'a [TransactionTag],
+	/// Encoded size of the transaction in bytes.
+	pub encoded_size: usize,
+	/// The estimated size of the block before pushing this transaction.
+	pub block_size: usize,
+	/// The block size limit the proposer is working with.
+	pub block_size_limit: usize,
+}
+
+/// The verdict of an [`InclusionPolicy`] for one [`Candidate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InclusionDecision {
+	/// Try to push the transaction into the block.
+	Include,
+	/// Do not include the transaction (and everything depending on it) in this block.
+	///
+	/// The transaction stays in the pool; the string explains why it was skipped.
+	Skip(&'static str),
+}
+
+/// The order in which the proposer considers the ready transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOrdering {
+	/// The order the transaction pool yields them in.
+	///
+	/// Transactions of the same priority are yielded in the order they entered the pool, which
+	/// differs between nodes.
+	Pool,
+	/// By priority and then by the transaction hash, while still putting transactions after the
+	/// ones they depend on.
+	///
+	/// The same ready transactions are always considered in the same order, at the cost of
+	/// fetching and sorting all of them before building the block.
+	Deterministic,
+}
+
+/// Decides which of the transactions yielded by the pool end up in a block.
+///
+/// The proposer asks the policy about every ready transaction before pushing it to the block
+/// builder. The checks done by the proposer itself (block size, deadlines, resource exhaustion)
+/// are still applied on top of the decisions of the policy.
+///
+/// Policies can be combined by using a tuple, in which case a transaction is only included when
+/// all of them agree.
+pub trait InclusionPolicy<Block: BlockT>: Send + Sync + 'static {
+	/// State kept by the policy while building one block.
+	///
+	/// A fresh `Default` value is created for each block.
+	type State: Default + Send;
+
+	/// Check whether the given `candidate` may be pushed into the block.
+	fn check(&self, state: &mut Self::State, candidate: &Candidate<Block>) -> InclusionDecision;
+
+	/// Called after `candidate` was successfully pushed into the block.
+	fn note_included(&self, _state: &mut Self::State, _candidate: &Candidate<Block>) {}
+
+	/// The order in which the ready transactions are considered.
+	fn ordering(&self) -> TransactionOrdering {
+		TransactionOrdering::Pool
+	}
+
+	/// Extrinsics to fill the rest of the block with, once the transaction pool is drained.
+	///
+	/// The proposer tries to push them in order, skipping the ones that fail or that do not fit
+	/// into the block anymore. They are not checked against [`Self::check`].
+	fn fill_block(
+		&self,
+		_state: &mut Self::State,
+		_parent_hash: Block::Hash,
+	) -> Vec<Block::Extrinsic> {
+		Vec::new()
+	}
+}
+
+/// The default policy, including transactions in the order the pool yields them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AcceptAll;
+
+impl<Block: BlockT> InclusionPolicy<Block> for AcceptAll {
+	type State = ();
+
+	fn check(&self, _: &mut (), _: &Candidate<Block>) -> InclusionDecision {
+		InclusionDecision::Include
+	}
+}
+
+/// Considers the ready transactions in a deterministic order, see
+/// [`TransactionOrdering::Deterministic`].
+///
+/// Meant for tests, where blocks should not depend on the order transactions were submitted in.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeterministicOrdering;
+
+impl<Block: BlockT> InclusionPolicy<Block> for DeterministicOrdering {
+	type State = ();
+
+	fn check(&self, _: &mut (), _: &Candidate<Block>) -> InclusionDecision {
+		InclusionDecision::Include
+	}
+
+	fn ordering(&self) -> TransactionOrdering {
+		TransactionOrdering::Deterministic
+	}
+}
+
+/// Reserves a fraction of the block space for high priority transactions.
+///
+/// Transactions with a priority below `priority_threshold` may only fill the block up to
+/// `1 - reserved` of the block size limit. The remaining space is kept for transactions with a
+/// priority of at least `priority_threshold`, e.g. operational transactions.
+#[derive(Debug, Clone, Copy)]
+pub struct ReservedSpace {
+	/// The minimum priority a transaction needs to use the reserved space.
+	pub priority_threshold: TransactionPriority,
+	/// The fraction of the block size limit that is reserved.
+	pub reserved: Percent,
+}
+
+impl<Block: BlockT> InclusionPolicy<Block> for ReservedSpace {
+	type State = ();
+
+	fn check(&self, _: &mut (), candidate: &Candidate<Block>) -> InclusionDecision {
+		if candidate.priority >= self.priority_threshold {
+			return InclusionDecision::Include
+		}
+
+		let available = self.reserved.left_from_one().mul_floor(candidate.block_size_limit);
+		if candidate.block_size + candidate.encoded_size > available {
+			InclusionDecision::Skip("block space reserved for high priority transactions")
+		} else {
+			InclusionDecision::Include
+		}
+	}
+}
+
+/// Limits the number of transactions of one sender in a single block.
+///
+/// The sender is determined by `sender_of`. Transactions for which no sender can be determined,
+/// e.g. unsigned transactions, are not limited.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxPerSender<F> {
+	max: usize,
+	sender_of: F,
+}
+
+impl<F> MaxPerSender<F> {
+	/// Create a new instance allowing at most `max` transactions per sender.
+	pub fn new(max: usize, sender_of: F) -> Self {
+		Self { max, sender_of }
+	}
+}
+
+impl<Block, F> InclusionPolicy<Block> for MaxPerSender<F>
+where
+	Block: BlockT,
+	F: Fn(&Block::Extrinsic) -> Option<Vec<u8>> + Send + Sync + 'static,
+{
+	type State = HashMap<Vec<u8>, usize>;
+
+	fn check(&self, state: &mut Self::State, candidate: &Candidate<Block>) -> InclusionDecision {
+		let Some(sender) = (self.sender_of)(candidate.extrinsic) else {
+			return InclusionDecision::Include
+		};
+
+		if state.get(&sender).copied().unwrap_or_default() >= self.max {
+			InclusionDecision::Skip("sender reached the maximum number of transactions per block")
+		} else {
+			InclusionDecision::Include
+		}
+	}
+
+	fn note_included(&self, state: &mut Self::State, candidate: &Candidate<Block>) {
+		if let Some(sender) = (self.sender_of)(candidate.extrinsic) {
+			*state.entry(sender).or_default() += 1;
+		}
+	}
+}
+
+impl<Block, A, B> InclusionPolicy<Block> for (A, B)
+where
+	Block: BlockT,
+	A: InclusionPolicy<Block>,
+	B: InclusionPolicy<Block>,
+{
+	type State = (A::State, B::State);
+
+	fn check(&self, state: &mut Self::State, candidate: &Candidate<Block>) -> InclusionDecision {
+		match self.0.check(&mut state.0, candidate) {
+			InclusionDecision::Include => self.1.check(&mut state.1, candidate),
+			skip => skip,
+		}
+	}
+
+	fn note_included(&self, state: &mut Self::State, candidate: &Candidate<Block>) {
+		self.0.note_included(&mut state.0, candidate);
+		self.1.note_included(&mut state.1, candidate);
+	}
+
+	fn ordering(&self) -> TransactionOrdering {
+		match (self.0.ordering(), self.1.ordering()) {
+			(TransactionOrdering::Pool, TransactionOrdering::Pool) => TransactionOrdering::Pool,
+			_ => TransactionOrdering::Deterministic,
+		}
+	}
+
+	fn fill_block(
+		&self,
+		state: &mut Self::State,
+		parent_hash: Block::Hash,
+	) -> Vec<Block::Extrinsic> {
+		let mut extrinsics = self.0.fill_block(&mut state.0, parent_hash);
+		extrinsics.extend(self.1.fill_block(&mut state.1, parent_hash));
+		extrinsics
+	}
+}
+
+/// The ready transactions in the [`TransactionOrdering::Deterministic`] order.
+///
+/// Unlike the iterator of the pool, transactions depending on reported invalid ones are still
+/// yielded, the proposer skips them itself.
+pub(crate) struct DeterministicReady<T> {
+	transactions: vec::IntoIter<Arc<T>>,
+}
+
+impl<T> DeterministicReady<T>
+where
+	T: InPoolTransaction,
+	T::Hash: Encode,
+{
+	pub(crate) fn new(ready: impl Iterator<Item = Arc<T>>) -> Self {
+		let ready = ready.collect::<Vec<_>>();
+		let provided = ready.iter().flat_map(|tx| tx.provides()).collect::<HashSet<_>>();
+		let key = |index: usize| {
+			let tx = &ready[index];
+			(*tx.priority(), Reverse(tx.hash().encode()), Reverse(index))
+		};
+
+		// Tags provided by other ready transactions must be provided before a transaction can be
+		// put in order, the other ones are provided by the parent block already.
+		let mut waiting = HashMap::<&TransactionTag, Vec<usize>>::new();
+		let mut unmet = vec![0; ready.len()];
+		let mut available = BinaryHeap::new();
+		for (index, tx) in ready.iter().enumerate() {
+			for tag in tx.requires().iter().filter(|tag| provided.contains(tag)) {
+				waiting.entry(tag).or_default().push(index);
+				unmet[index] += 1;
+			}
+			if unmet[index] == 0 {
+				available.push(key(index));
+			}
+		}
+
+		let mut order = Vec::with_capacity(ready.len());
+		while let Some((_, _, Reverse(index))) = available.pop() {
+			order.push(ready[index].clone());
+			for tag in ready[index].provides() {
+				// A tag provided by several transactions is met by the first one.
+				for waiter in waiting.remove(tag).unwrap_or_default() {
+					unmet[waiter] -= 1;
+					if unmet[waiter] == 0 {
+						available.push(key(waiter));
+					}
+				}
+			}
+		}
+
+		Self { transactions: order.into_iter() }
+	}
+}
+
+impl<T> Iterator for DeterministicReady<T> {
+	type Item = Arc<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.transactions.next()
+	}
+}
+
+impl<T> ReadyTransactions for DeterministicReady<T> {
+	fn report_invalid(&mut self, _tx: &Self::Item) {}
+}
+
+/// Why a transaction was not put into the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum SkipReason<Hash> {
+	/// The [`InclusionPolicy`] refused the transaction.
+	Policy {
+		/// The explanation given by the policy.
+		reason: String,
+	},
+	/// A transaction this one depends on was skipped.
+	Dependency {
+		/// Hash of the skipped transaction providing a tag this one requires.
+		dependency: Hash,
+	},
+	/// The transaction would have overflown the block size limit.
+	BlockSizeLimit,
+	/// The transaction exhausted the resources (e.g. weight) of the block.
+	ExhaustedResources,
+	/// The transaction failed to apply.
+	Invalid {
+		/// The error returned when applying the transaction.
+		error: String,
+	},
+}
+
+/// A transaction that was not included into the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedTransaction<Hash> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// Why it was skipped.
+	pub reason: SkipReason<Hash>,
+}
+
+/// Report of a dry run of the block production.
+///
+/// Lists the transactions the proposer would include into the next block and the ones it
+/// skipped, in the order they were considered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionReport<Hash> {
+	/// Transactions that would be included.
+	pub included: Vec<Hash>,
+	/// Transactions that were skipped.
+	pub skipped: Vec<SkippedTransaction<Hash>>,
+	/// Estimated size of the resulting block in bytes.
+	pub block_size: usize,
+	/// Why the proposer stopped adding transactions.
+	pub end_reason: String,
+}
+
+impl<Hash> Default for InclusionReport<Hash> {
+	fn default() -> Self {
+		Self { included: Vec::new(), skipped: Vec::new(), block_size: 0, end_reason: String::new() }
+	}
+}
+
+impl<Hash> InclusionReport<Hash> {
+	pub(crate) fn note_skipped(&mut self, hash: Hash, reason: SkipReason<Hash>) {
+		self.skipped.push(SkippedTransaction { hash, reason });
+	}
+}
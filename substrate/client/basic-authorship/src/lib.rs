@@ -71,5 +71,10 @@
 //! ```
 
 mod basic_authorship;
+pub mod inclusion_policy;
+pub mod rpc;
 
-pub use crate::basic_authorship::{Proposer, ProposerFactory, DEFAULT_BLOCK_SIZE_LIMIT};
+pub use crate::{
+	basic_authorship::{Proposer, ProposerFactory, DEFAULT_BLOCK_SIZE_LIMIT},
+	inclusion_policy::{InclusionPolicy, InclusionReport},
+};
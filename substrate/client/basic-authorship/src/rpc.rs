@@ -0,0 +1,175 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface exposing dry runs of the block production.
+
+use crate::{inclusion_policy::InclusionPolicy, InclusionReport, ProposerFactory};
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use sc_block_builder::BlockBuilderApi;
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use sc_transaction_pool_api::{TransactionPool, TxHash};
+use sp_api::{ApiExt, CallApiAt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_consensus::ProofRecording;
+use sp_inherents::{CreateInherentDataProviders, InherentData, InherentDataProvider};
+use sp_runtime::{traits::Block as BlockT, Digest};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+const AUTHORSHIP_ERROR: i32 = 9100;
+
+/// The proposing duration used when none is given to `authorship_dryRunProposal`.
+const DEFAULT_DRY_RUN_DURATION: Duration = Duration::from_secs(2);
+
+/// The maximum proposing duration of `authorship_dryRunProposal`.
+const MAX_DRY_RUN_DURATION: Duration = Duration::from_secs(10);
+
+/// Provides RPC methods for inspecting the block production.
+#[rpc(client, server)]
+pub trait AuthorshipApi<Hash> {
+	/// Returns which transactions the proposer would put into a block built on top of the best
+	/// block and why the other ready transactions were skipped.
+	///
+	/// `max_duration` is the time in milliseconds given to the proposer, at most 10 seconds.
+	#[method(name = "authorship_dryRunProposal", with_extensions)]
+	async fn dry_run_proposal(
+		&self,
+		max_duration: Option<u64>,
+		block_size_limit: Option<usize>,
+	) -> Result<InclusionReport<Hash>, Error>;
+}
+
+/// Creates the inherent digests for the block built by a dry run.
+pub type InherentDigestsProvider = Box<dyn Fn(&InherentData) -> Digest + Send + Sync>;
+
+/// Implements the [`AuthorshipApiServer`] on top of a [`ProposerFactory`].
+pub struct Authorship<Block, C, A, PR, P, CIDP> {
+	client: Arc<C>,
+	proposer_factory: ProposerFactory<A, C, PR, P>,
+	create_inherent_data_providers: CIDP,
+	inherent_digests: Option<InherentDigestsProvider>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block, C, A, PR, P, CIDP> Authorship<Block, C, A, PR, P, CIDP> {
+	/// Create a new instance.
+	///
+	/// `proposer_factory` should be configured the same way as the one used for authoring
+	/// blocks, so that the dry runs reflect what the node would actually propose.
+	pub fn new(
+		client: Arc<C>,
+		proposer_factory: ProposerFactory<A, C, PR, P>,
+		create_inherent_data_providers: CIDP,
+	) -> Self {
+		Self {
+			client,
+			proposer_factory,
+			create_inherent_data_providers,
+			inherent_digests: None,
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Set the function creating the inherent digests of the dry run blocks.
+	///
+	/// Runtimes expecting a consensus pre-runtime digest, e.g. the slot of the block, need this
+	/// to be set. By default no digests are added.
+	pub fn with_inherent_digests(mut self, inherent_digests: InherentDigestsProvider) -> Self {
+		self.inherent_digests = Some(inherent_digests);
+		self
+	}
+}
+
+#[async_trait]
+impl<Block, C, A, PR, P, CIDP> AuthorshipApiServer<TxHash<A>>
+	for Authorship<Block, C, A, PR, P, CIDP>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + CallApiAt<Block> + Send + Sync + 'static,
+	C::Api: ApiExt<Block> + BlockBuilderApi<Block>,
+	A: TransactionPool<Block = Block> + 'static,
+	PR: ProofRecording,
+	P: InclusionPolicy<Block>,
+	CIDP: CreateInherentDataProviders<Block, ()> + 'static,
+{
+	async fn dry_run_proposal(
+		&self,
+		ext: &Extensions,
+		max_duration: Option<u64>,
+		block_size_limit: Option<usize>,
+	) -> Result<InclusionReport<TxHash<A>>, Error> {
+		check_if_safe(ext)?;
+
+		let best_hash = self.client.info().best_hash;
+		let parent = self.client.expect_header(best_hash)?;
+
+		let inherent_data = self
+			.create_inherent_data_providers
+			.create_inherent_data_providers(best_hash, ())
+			.await
+			.map_err(|e| Error::InherentData(e.to_string()))?
+			.create_inherent_data()
+			.await
+			.map_err(|e| Error::InherentData(e.to_string()))?;
+		let inherent_digests =
+			self.inherent_digests.as_ref().map(|f| f(&inherent_data)).unwrap_or_default();
+
+		let proposer = self.proposer_factory.clone().init_dry_run(&parent);
+		let report = proposer
+			.dry_run(
+				inherent_data,
+				inherent_digests,
+				max_duration
+					.map_or(DEFAULT_DRY_RUN_DURATION, Duration::from_millis)
+					.min(MAX_DRY_RUN_DURATION),
+				block_size_limit,
+			)
+			.await?;
+
+		Ok(report)
+	}
+}
+
+/// Errors returned by the authorship RPC.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Failed to build the block.
+	#[error(transparent)]
+	Blockchain(#[from] sp_blockchain::Error),
+	/// Failed to create the inherent data for the block.
+	#[error("Failed to create inherent data: {0}")]
+	InherentData(String),
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::Blockchain(e) =>
+				ErrorObject::owned(AUTHORSHIP_ERROR + 1, e.to_string(), None::<()>),
+			Error::InherentData(e) => ErrorObject::owned(AUTHORSHIP_ERROR + 2, e, None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
@@ -140,6 +140,16 @@ pub enum OutputType {
 	Text,
 }
 
+/// The format of an execution trace.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum TraceFormat {
+	/// Output as json.
+	Json,
+	/// Output the SCALE encoded trace.
+	Binary,
+}
+
 /// How to execute blocks
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
@@ -36,6 +36,7 @@ mod revert_cmd;
 mod run_cmd;
 mod sign;
 mod test;
+mod trace_block_cmd;
 pub mod utils;
 mod vanity;
 mod verify;
@@ -47,5 +48,5 @@ pub use self::{
 	generate_node_key::GenerateKeyCmdCommon, import_blocks_cmd::ImportBlocksCmd,
 	insert_key::InsertKeyCmd, inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
 	key::KeySubcommand, purge_chain_cmd::PurgeChainCmd, revert_cmd::RevertCmd, run_cmd::RunCmd,
-	sign::SignCmd, vanity::VanityCmd, verify::VerifyCmd,
+	sign::SignCmd, trace_block_cmd::TraceBlockCmd, vanity::VanityCmd, verify::VerifyCmd,
 };
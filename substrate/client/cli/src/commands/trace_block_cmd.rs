@@ -0,0 +1,126 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, ImportParams, SharedParams},
+	CliConfiguration, TraceFormat,
+};
+use clap::Parser;
+use codec::{Decode, Encode};
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sc_tracing::block::BlockExecutor;
+use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use sp_state_machine::ExecutionTrace;
+use std::{fmt::Debug, io::Write, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `trace-block` command used to record the storage operations done while executing a block.
+///
+/// Running the command twice with different runtimes, e.g. by using `--wasm-runtime-overrides`,
+/// and comparing the traces with `--compare` shows where the runtimes start to diverge.
+#[derive(Debug, Clone, Parser)]
+pub struct TraceBlockCmd {
+	/// Block hash or number.
+	#[arg(value_name = "HASH or NUMBER")]
+	pub input: BlockNumberOrHash,
+
+	/// The format of the trace.
+	#[arg(long, value_enum, ignore_case = true, default_value_t = TraceFormat::Json)]
+	pub format: TraceFormat,
+
+	/// Write the trace to the given file instead of stdout.
+	#[arg(long, short = 'o', value_name = "FILE")]
+	pub output: Option<PathBuf>,
+
+	/// Compare the trace with the binary trace stored in the given file.
+	///
+	/// Reports the first operation where both traces differ.
+	#[arg(long, value_name = "FILE")]
+	pub compare: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl TraceBlockCmd {
+	/// Run the trace-block command
+	pub async fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT,
+		C: BlockBackend<B>
+			+ HeaderBackend<B>
+			+ ProvideRuntimeApi<B>
+			+ CallApiAt<B>
+			+ Send
+			+ Sync
+			+ 'static,
+		C::Api: Metadata<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let hash = client.expect_block_hash_from_id(&self.input.parse()?)?;
+
+		info!("Tracing execution of block {}...", hash);
+		let trace = BlockExecutor::new(client, hash, None, None, None)
+			.execution_trace()
+			.map_err(|e| error::Error::Application(e.into()))?;
+		info!("Recorded {} storage operations", trace.entries.len());
+
+		if let Some(compare) = &self.compare {
+			let other = ExecutionTrace::decode(&mut &std::fs::read(compare)?[..])?;
+			match trace.first_divergence(&other) {
+				None => info!("The trace matches {}", compare.display()),
+				Some(index) => info!(
+					"The traces diverge at operation {}: {:?} != {:?}",
+					index,
+					trace.entries.get(index),
+					other.entries.get(index),
+				),
+			}
+		}
+
+		let bytes = match self.format {
+			TraceFormat::Json => serde_json::to_vec_pretty(&trace)
+				.map_err(|e| format!("Failed to serialize trace: {}", e))?,
+			TraceFormat::Binary => trace.encode(),
+		};
+		match &self.output {
+			Some(path) => std::fs::write(path, bytes)?,
+			None => std::io::stdout().write_all(&bytes)?,
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for TraceBlockCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
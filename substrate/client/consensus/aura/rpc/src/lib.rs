@@ -0,0 +1,511 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC api for aura.
+//!
+//! Exposes the slot-level authoring statistics recorded by the Aura import queue and slot worker
+//! in the aux-db.
+
+use std::{
+	collections::{hash_map::Entry, HashMap},
+	hash::Hash,
+	marker::PhantomData,
+	sync::Arc,
+};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use serde::{Deserialize, Serialize};
+
+use sc_client_api::AuxStore;
+use sc_consensus_aura::{find_pre_digest, standalone::slot_author};
+use sc_consensus_slots::{load_slot_stats, MAX_SLOT_STATS_CAPACITY, SLOT_STATS_PRUNING_BOUND};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::{Error as BlockChainError, HeaderBackend};
+use sp_consensus_aura::AuraApi as AuraRuntimeApi;
+use sp_consensus_slots::Slot;
+use sp_core::crypto::Pair;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Zero};
+
+const AURA_ERROR: i32 = 9400;
+
+type AuthorityId<P> = <P as Pair>::Public;
+
+/// Provides rpc methods for inspecting the authoring of Aura slots.
+#[rpc(client, server)]
+pub trait AuraApi<Hash, AuthorityId> {
+	/// Returns what is known about the slots `from..=to`: who was expected to author them, the
+	/// blocks imported for them and how long this node took to propose its own blocks.
+	#[method(name = "aura_slotStats", with_extensions)]
+	async fn slot_stats(
+		&self,
+		from: u64,
+		to: u64,
+	) -> Result<Vec<SlotReport<Hash, AuthorityId>>, Error>;
+
+	/// Returns the authoring statistics per authority over the slots `from..=to`.
+	#[method(name = "aura_authoringStats", with_extensions)]
+	async fn authoring_stats(
+		&self,
+		from: u64,
+		to: u64,
+	) -> Result<HashMap<AuthorityId, AuthoringStats>, Error>;
+}
+
+/// A block imported for a slot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReport<Hash, AuthorityId> {
+	/// Hash of the block.
+	pub hash: Hash,
+	/// Hash of the parent of the block, if the block is still known.
+	pub parent_hash: Option<Hash>,
+	/// The authority that authored the block.
+	pub author: AuthorityId,
+	/// Milliseconds between the start of the slot and the import of the block.
+	pub import_lag: u64,
+}
+
+/// What is known about a single slot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotReport<Hash, AuthorityId> {
+	/// The slot number.
+	pub slot: u64,
+	/// The authority that should have authored the slot.
+	///
+	/// When the slot was not seen by this node, it is derived from the authority set at the last
+	/// block of the best chain before the slot.
+	pub expected_author: Option<AuthorityId>,
+	/// The blocks imported for this slot.
+	pub blocks: Vec<BlockReport<Hash, AuthorityId>>,
+	/// How long this node took to propose a block in this slot, in milliseconds.
+	pub proposal_duration: Option<u64>,
+}
+
+/// Authoring statistics of one authority over a range of slots.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthoringStats {
+	/// Number of slots the authority was expected to author.
+	pub expected: u64,
+	/// Number of slots the authority authored at least one block in.
+	pub authored: u64,
+	/// Number of slots the authority was expected to author, but no block was imported for.
+	pub missed: u64,
+	/// Number of blocks of the authority which were forked out: additional blocks in slots it
+	/// already had a block in, and blocks a block of a later slot was built next to, on the same
+	/// parent, because they were imported too late.
+	pub forks: u64,
+	/// Average import lag of the blocks of the authority, in milliseconds.
+	pub average_import_lag: Option<u64>,
+	/// Maximum import lag of the blocks of the authority, in milliseconds.
+	pub max_import_lag: Option<u64>,
+	/// Average duration of the proposals done by this node for the authority, in milliseconds.
+	pub average_proposal_duration: Option<u64>,
+}
+
+/// Sums used to compute the averages of [`AuthoringStats`].
+#[derive(Default)]
+struct Totals {
+	stats: AuthoringStats,
+	import_lag: (u64, u64),
+	proposal_duration: (u64, u64),
+}
+
+impl Totals {
+	fn into_stats(self) -> AuthoringStats {
+		let average = |(sum, count): (u64, u64)| (count > 0).then(|| sum / count);
+
+		AuthoringStats {
+			average_import_lag: average(self.import_lag),
+			average_proposal_duration: average(self.proposal_duration),
+			..self.stats
+		}
+	}
+}
+
+/// Provides RPC methods for inspecting the authoring of Aura slots.
+pub struct Aura<B, C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_phantom: PhantomData<fn() -> (B, P)>,
+}
+
+impl<B, C, P> Aura<B, C, P> {
+	/// Creates a new instance of the Aura Rpc handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _phantom: PhantomData }
+	}
+}
+
+impl<B, C, P> Aura<B, C, P>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + AuxStore,
+	C::Api: AuraRuntimeApi<B, AuthorityId<P>>,
+	P: Pair,
+	P::Public: Codec,
+	P::Signature: Codec,
+{
+	/// The blocks of the best chain with their slots, newest first, down to the last block before
+	/// the slot `from`.
+	///
+	/// Empty if the statistics of `from` are pruned already.
+	fn best_chain_parents(&self, from: Slot) -> Result<Vec<(Slot, B::Hash)>, Error> {
+		let mut parents = Vec::new();
+		let mut hash = self.client.info().best_hash;
+
+		while let Some(header) = self.client.header(hash)? {
+			let Ok(slot) = find_pre_digest::<B, P::Signature>(&header) else { break };
+			if parents.is_empty() && slot.saturating_sub(*from) >= SLOT_STATS_PRUNING_BOUND {
+				break
+			}
+
+			parents.push((slot, hash));
+			if slot < from || header.number().is_zero() {
+				break
+			}
+			hash = *header.parent_hash();
+		}
+
+		Ok(parents)
+	}
+
+	fn slot_reports(
+		&self,
+		from: u64,
+		to: u64,
+	) -> Result<Vec<SlotReport<B::Hash, AuthorityId<P>>>, Error> {
+		if to < from || to - from >= MAX_SLOT_STATS_CAPACITY {
+			return Err(Error::InvalidSlotRange(from, to))
+		}
+
+		let runtime_api = self.client.runtime_api();
+		let slot_duration = runtime_api.slot_duration(self.client.info().best_hash)?;
+		let parents = self.best_chain_parents(from.into())?;
+		let mut authorities = HashMap::new();
+
+		(from..=to)
+			.map(|slot| {
+				let slot = Slot::from(slot);
+				let stats = load_slot_stats::<_, B::Hash, AuthorityId<P>>(&*self.client, slot)?
+					.unwrap_or_default();
+				let slot_start = slot.timestamp(slot_duration).map_or(u64::MAX, |t| t.as_millis());
+
+				let expected_author = match stats.expected_author {
+					Some(author) => Some(author),
+					None => match parents.iter().find(|(parent_slot, _)| *parent_slot < slot) {
+						Some((_, parent)) => {
+							let authorities = match authorities.entry(*parent) {
+								Entry::Occupied(entry) => entry.into_mut(),
+								Entry::Vacant(entry) =>
+									entry.insert(runtime_api.authorities(*parent)?),
+							};
+							slot_author::<P>(slot, authorities).cloned()
+						},
+						None => None,
+					},
+				};
+
+				let blocks = stats
+					.blocks
+					.into_iter()
+					.map(|block| {
+						Ok(BlockReport {
+							parent_hash: self
+								.client
+								.header(block.hash)?
+								.map(|header| *header.parent_hash()),
+							hash: block.hash,
+							author: block.author,
+							import_lag: block.imported_at.saturating_sub(slot_start),
+						})
+					})
+					.collect::<Result<_, Error>>()?;
+
+				Ok(SlotReport {
+					slot: *slot,
+					expected_author,
+					blocks,
+					proposal_duration: stats.proposal_duration,
+				})
+			})
+			.collect()
+	}
+}
+
+#[async_trait]
+impl<B, C, P> AuraApiServer<B::Hash, AuthorityId<P>> for Aura<B, C, P>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + AuxStore + Send + Sync + 'static,
+	C::Api: AuraRuntimeApi<B, AuthorityId<P>>,
+	P: Pair + 'static,
+	P::Public: Codec + Hash + Eq + Serialize,
+	P::Signature: Codec,
+{
+	async fn slot_stats(
+		&self,
+		ext: &Extensions,
+		from: u64,
+		to: u64,
+	) -> Result<Vec<SlotReport<B::Hash, AuthorityId<P>>>, Error> {
+		check_if_safe(ext)?;
+
+		self.slot_reports(from, to)
+	}
+
+	async fn authoring_stats(
+		&self,
+		ext: &Extensions,
+		from: u64,
+		to: u64,
+	) -> Result<HashMap<AuthorityId<P>, AuthoringStats>, Error> {
+		check_if_safe(ext)?;
+
+		let reports = self.slot_reports(from, to)?;
+		let mut totals: HashMap<AuthorityId<P>, Totals> = HashMap::new();
+
+		// The last slot a block was built in on top of each parent.
+		let mut last_child_slots = HashMap::new();
+		for report in &reports {
+			for parent_hash in report.blocks.iter().filter_map(|block| block.parent_hash) {
+				let last_child_slot = last_child_slots.entry(parent_hash).or_insert(report.slot);
+				*last_child_slot = report.slot.max(*last_child_slot);
+			}
+		}
+
+		for report in reports {
+			if let Some(expected) = report.expected_author {
+				let authored = report.blocks.iter().any(|block| block.author == expected);
+				let expected_totals = totals.entry(expected).or_default();
+
+				expected_totals.stats.expected += 1;
+				if !authored {
+					expected_totals.stats.missed += 1;
+				}
+				if let Some(duration) = report.proposal_duration {
+					expected_totals.proposal_duration.0 += duration;
+					expected_totals.proposal_duration.1 += 1;
+				}
+			}
+
+			let mut seen = Vec::new();
+			for block in report.blocks {
+				let author_totals = totals.entry(block.author.clone()).or_default();
+
+				let equivocated = seen.contains(&block.author);
+				let built_next_to = block
+					.parent_hash
+					.map_or(false, |parent_hash| last_child_slots[&parent_hash] > report.slot);
+
+				if equivocated || built_next_to {
+					author_totals.stats.forks += 1;
+				}
+				if !equivocated {
+					author_totals.stats.authored += 1;
+					seen.push(block.author);
+				}
+
+				author_totals.import_lag.0 += block.import_lag;
+				author_totals.import_lag.1 += 1;
+				author_totals.stats.max_import_lag =
+					author_totals.stats.max_import_lag.max(Some(block.import_lag));
+			}
+		}
+
+		Ok(totals
+			.into_iter()
+			.map(|(author, totals)| (author, totals.into_stats()))
+			.collect())
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The requested slot range is empty or too large.
+	#[error(
+		"Invalid slot range {0}..={1}, at most {max} slots can be queried at once",
+		max = MAX_SLOT_STATS_CAPACITY
+	)]
+	InvalidSlotRange(u64, u64),
+	/// Failed to read the slot statistics.
+	#[error(transparent)]
+	Client(#[from] BlockChainError),
+	/// Failed to call the runtime.
+	#[error(transparent)]
+	RuntimeApi(#[from] ApiError),
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::InvalidSlotRange(..) =>
+				ErrorObject::owned(AURA_ERROR + 1, error.to_string(), None::<()>),
+			Error::Client(e) => ErrorObject::owned(AURA_ERROR + 2, e.to_string(), None::<()>),
+			Error::RuntimeApi(e) => ErrorObject::owned(AURA_ERROR + 3, e.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_consensus_slots::{note_slot_block, note_slot_proposal_duration};
+	use sc_rpc_api::DenyUnsafe;
+	use sp_consensus_aura::sr25519::{AuthorityId, AuthorityPair};
+	use sp_core::H256;
+	use sp_runtime::{traits::Block as _, Digest, DigestItem};
+	use substrate_test_runtime_client::{
+		prelude::BlockBuilderBuilder, runtime::Block, BlockOrigin, ClientBlockImportExt, TestClient,
+	};
+
+	fn test_aura_rpc_module() -> (Arc<TestClient>, Aura<Block, TestClient, AuthorityPair>) {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		(client.clone(), Aura::new(client))
+	}
+
+	#[tokio::test]
+	async fn authoring_stats_works() {
+		let (client, aura_rpc) = test_aura_rpc_module();
+		let authorities = client.runtime_api().authorities(client.info().best_hash).unwrap();
+		let author_of =
+			|slot: u64| slot_author::<AuthorityPair>(slot.into(), &authorities).cloned();
+
+		// Slot 1000 was authored in time and proposed by us, slot 1001 was missed and slot 1002
+		// got two blocks.
+		let (first, second, third) =
+			(author_of(1000).unwrap(), author_of(1001).unwrap(), author_of(1002).unwrap());
+		note_slot_proposal_duration::<_, H256, AuthorityId>(
+			&*client,
+			1000.into(),
+			std::time::Duration::from_millis(300),
+		)
+		.unwrap();
+		note_slot_block(&*client, 1000.into(), H256::random(), first.clone(), 1_000_500).unwrap();
+		note_slot_block(&*client, 1002.into(), H256::random(), third.clone(), 1_002_100).unwrap();
+		note_slot_block(&*client, 1002.into(), H256::random(), third.clone(), 1_002_300).unwrap();
+
+		let mut api = aura_rpc.into_rpc();
+		api.extensions_mut().insert(DenyUnsafe::No);
+
+		let stats: HashMap<AuthorityId, AuthoringStats> =
+			api.call("aura_authoringStats", [1000, 1002]).await.unwrap();
+
+		assert_eq!(
+			stats[&first],
+			AuthoringStats {
+				expected: 1,
+				authored: 1,
+				average_import_lag: Some(500),
+				max_import_lag: Some(500),
+				average_proposal_duration: Some(300),
+				..Default::default()
+			}
+		);
+		assert_eq!(stats[&second], AuthoringStats { expected: 1, missed: 1, ..Default::default() });
+		assert_eq!(
+			stats[&third],
+			AuthoringStats {
+				expected: 1,
+				authored: 1,
+				forks: 1,
+				average_import_lag: Some(200),
+				max_import_lag: Some(300),
+				..Default::default()
+			}
+		);
+	}
+
+	#[tokio::test]
+	async fn authoring_stats_counts_late_blocks_as_forks() {
+		let (client, aura_rpc) = test_aura_rpc_module();
+		let genesis_hash = client.info().genesis_hash;
+		let authorities = client.runtime_api().authorities(genesis_hash).unwrap();
+		let author_of =
+			|slot: u64| slot_author::<AuthorityPair>(slot.into(), &authorities).cloned().unwrap();
+
+		// The block of slot 1000 was imported too late, so the author of slot 1001 built on the
+		// same parent.
+		let block_on_genesis = |seed: u8| {
+			BlockBuilderBuilder::new(&*client)
+				.on_parent_block(genesis_hash)
+				.with_parent_block_number(0)
+				.with_inherent_digests(Digest { logs: vec![DigestItem::Other(vec![seed])] })
+				.build()
+				.unwrap()
+				.build()
+				.unwrap()
+				.block
+		};
+		let (late, next) = (block_on_genesis(0), block_on_genesis(1));
+		client.import(BlockOrigin::Own, late.clone()).await.unwrap();
+		client.import(BlockOrigin::Own, next.clone()).await.unwrap();
+
+		let (first, second) = (author_of(1000), author_of(1001));
+		note_slot_block(&*client, 1000.into(), late.hash(), first.clone(), 1_005_000).unwrap();
+		note_slot_block(&*client, 1001.into(), next.hash(), second.clone(), 1_001_100).unwrap();
+
+		let mut api = aura_rpc.into_rpc();
+		api.extensions_mut().insert(DenyUnsafe::No);
+
+		let stats: HashMap<AuthorityId, AuthoringStats> =
+			api.call("aura_authoringStats", [1000, 1001]).await.unwrap();
+
+		assert_eq!((stats[&first].authored, stats[&first].forks), (1, 1));
+		assert_eq!((stats[&second].authored, stats[&second].forks), (1, 0));
+	}
+
+	#[tokio::test]
+	async fn authoring_stats_rejects_invalid_ranges() {
+		let (_, aura_rpc) = test_aura_rpc_module();
+		let mut api = aura_rpc.into_rpc();
+		api.extensions_mut().insert(DenyUnsafe::No);
+
+		let request = r#"{"jsonrpc":"2.0","method":"aura_authoringStats","params":[2,1],"id":1}"#;
+		let (response, _) = api.raw_json_request(request, 1).await.unwrap();
+		let expected = r#"{"jsonrpc":"2.0","id":1,"error":{"code":9401,"message":"Invalid slot range 2..=1, at most 14400 slots can be queried at once"}}"#;
+
+		assert_eq!(response, expected);
+	}
+
+	#[tokio::test]
+	async fn authoring_stats_is_unsafe() {
+		let (_, aura_rpc) = test_aura_rpc_module();
+		let mut api = aura_rpc.into_rpc();
+		api.extensions_mut().insert(DenyUnsafe::Yes);
+
+		let request = r#"{"jsonrpc":"2.0","method":"aura_authoringStats","params":[1,2],"id":1}"#;
+		let (response, _) = api.raw_json_request(request, 1).await.unwrap();
+		let expected = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"RPC call is unsafe to be called externally"}}"#;
+
+		assert_eq!(response, expected);
+	}
+}
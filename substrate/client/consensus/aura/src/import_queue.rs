@@ -19,23 +19,28 @@
 //! Module implementing the logic for verifying and importing AuRa blocks.
 
 use crate::{
-	authorities, standalone::SealVerificationError, AuthorityId, CompatibilityMode, Error,
-	LOG_TARGET,
+	authorities,
+	standalone::{find_pre_digest, slot_author, SealVerificationError},
+	AuthorityId, CompatibilityMode, Error, LOG_TARGET,
 };
 use codec::Codec;
-use log::{debug, info, trace};
+use log::{debug, info, trace, warn};
 use prometheus_endpoint::Registry;
 use sc_client_api::{backend::AuxStore, BlockOf, UsageProvider};
 use sc_consensus::{
-	block_import::{BlockImport, BlockImportParams, ForkChoiceStrategy},
+	block_import::{
+		BlockCheckParams, BlockImport, BlockImportParams, ForkChoiceStrategy, ImportResult,
+	},
 	import_queue::{BasicQueue, BoxJustificationImport, DefaultImportQueue, Verifier},
 };
-use sc_consensus_slots::{check_equivocation, CheckedHeader, InherentDataProviderExt};
+use sc_consensus_slots::{
+	check_equivocation, note_slot_block, CheckedHeader, InherentDataProviderExt,
+};
 use sc_telemetry::{telemetry, TelemetryHandle, CONSENSUS_DEBUG, CONSENSUS_TRACE};
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder as BlockBuilderApi;
 use sp_blockchain::HeaderBackend;
-use sp_consensus::Error as ConsensusError;
+use sp_consensus::{BlockOrigin, Error as ConsensusError};
 use sp_consensus_aura::{inherents::AuraInherentData, AuraApi};
 use sp_consensus_slots::Slot;
 use sp_core::crypto::Pair;
@@ -253,6 +258,110 @@ where
 	}
 }
 
+/// A block import recording the blocks imported for each slot, see [`note_slot_block`].
+///
+/// Blocks are recorded once they were imported, when they were imported. Blocks imported during
+/// the initial sync are not recorded, their import time says nothing about their authoring.
+pub struct SlotStatsBlockImport<I, C, P, N> {
+	inner: I,
+	client: Arc<C>,
+	compatibility_mode: CompatibilityMode<N>,
+	_phantom: PhantomData<fn() -> P>,
+}
+
+impl<I, C, P, N> SlotStatsBlockImport<I, C, P, N> {
+	/// Wrap the given block import.
+	pub fn new(inner: I, client: Arc<C>, compatibility_mode: CompatibilityMode<N>) -> Self {
+		Self { inner, client, compatibility_mode, _phantom: PhantomData }
+	}
+}
+
+impl<I, C, P, N> Clone for SlotStatsBlockImport<I, C, P, N>
+where
+	I: Clone,
+	N: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+			client: self.client.clone(),
+			compatibility_mode: self.compatibility_mode.clone(),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<B, I, C, P> SlotStatsBlockImport<I, C, P, NumberFor<B>>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + AuxStore,
+	C::Api: AuraApi<B, AuthorityId<P>>,
+	P: Pair,
+	P::Public: Codec + Debug,
+	P::Signature: Codec,
+{
+	fn note_block(&self, header: &B::Header, hash: B::Hash) {
+		let Ok(slot) = find_pre_digest::<B, P::Signature>(header) else { return };
+		let authorities = match authorities(
+			self.client.as_ref(),
+			*header.parent_hash(),
+			*header.number(),
+			&self.compatibility_mode,
+		) {
+			Ok(authorities) => authorities,
+			Err(e) => {
+				warn!(target: LOG_TARGET, "Failed to record authoring of slot {}: {}", slot, e);
+				return
+			},
+		};
+		let Some(author) = slot_author::<P>(slot, &authorities) else { return };
+
+		if let Err(e) = note_slot_block(
+			self.client.as_ref(),
+			slot,
+			hash,
+			author.clone(),
+			crate::unix_time_millis(),
+		) {
+			warn!(target: LOG_TARGET, "Failed to record authoring of slot {}: {}", slot, e);
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl<B, I, C, P> BlockImport<B> for SlotStatsBlockImport<I, C, P, NumberFor<B>>
+where
+	B: BlockT,
+	I: BlockImport<B> + Send + Sync,
+	C: ProvideRuntimeApi<B> + AuxStore + Send + Sync,
+	C::Api: AuraApi<B, AuthorityId<P>>,
+	P: Pair,
+	P::Public: Codec + Debug,
+	P::Signature: Codec,
+{
+	type Error = I::Error;
+
+	async fn check_block(&self, block: BlockCheckParams<B>) -> Result<ImportResult, Self::Error> {
+		self.inner.check_block(block).await
+	}
+
+	async fn import_block(&self, block: BlockImportParams<B>) -> Result<ImportResult, Self::Error> {
+		// Blocks whose seal wasn't checked are not recorded either.
+		let noted = (block.origin != BlockOrigin::NetworkInitialSync &&
+			!block.with_state() &&
+			!block.state_action.skip_execution_checks())
+		.then(|| (block.header.clone(), block.post_hash()));
+
+		let result = self.inner.import_block(block).await?;
+
+		if let (ImportResult::Imported(_), Some((header, hash))) = (&result, noted) {
+			self.note_block(&header, hash);
+		}
+
+		Ok(result)
+	}
+}
+
 /// Should we check for equivocation of a block author?
 #[derive(Debug, Clone, Copy)]
 pub enum CheckForEquivocation {
@@ -302,6 +411,8 @@ pub struct ImportQueueParams<'a, Block: BlockT, I, C, S, CIDP> {
 }
 
 /// Start an import queue for the Aura consensus algorithm.
+///
+/// The blocks imported for each slot are recorded, see [`SlotStatsBlockImport`].
 pub fn import_queue<P, Block, I, C, S, CIDP>(
 	ImportQueueParams {
 		block_import,
@@ -334,6 +445,11 @@ where
 	CIDP: CreateInherentDataProviders<Block, ()> + Sync + Send + 'static,
 	CIDP::InherentDataProviders: InherentDataProviderExt + Send + Sync,
 {
+	let block_import = SlotStatsBlockImport::<_, _, P, _>::new(
+		block_import,
+		client.clone(),
+		compatibility_mode.clone(),
+	);
 	let verifier = build_verifier::<P, _, _, _>(BuildVerifierParams {
 		client,
 		create_inherent_data_providers,
@@ -30,14 +30,22 @@
 //!
 //! NOTE: Aura itself is designed to be generic over the crypto used.
 #![forbid(missing_docs, unsafe_code)]
-use std::{fmt::Debug, marker::PhantomData, pin::Pin, sync::Arc};
+use std::{
+	fmt::Debug,
+	marker::PhantomData,
+	pin::Pin,
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use codec::Codec;
 use futures::prelude::*;
+use log::warn;
 
 use sc_client_api::{backend::AuxStore, BlockOf};
 use sc_consensus::{BlockImport, BlockImportParams, ForkChoiceStrategy, StateAction};
 use sc_consensus_slots::{
+	note_slot_block, note_slot_expected_author, note_slot_proposal_duration,
 	BackoffAuthoringBlocksStrategy, InherentDataProviderExt, SimpleSlotWorkerToSlotWorker,
 	SlotInfo, StorageChanges,
 };
@@ -58,7 +66,7 @@ pub mod standalone;
 pub use crate::standalone::{find_pre_digest, slot_duration};
 pub use import_queue::{
 	build_verifier, import_queue, AuraVerifier, BuildVerifierParams, CheckForEquivocation,
-	ImportQueueParams,
+	ImportQueueParams, SlotStatsBlockImport,
 };
 pub use sc_consensus_slots::SlotProportion;
 pub use sp_consensus::SyncOracle;
@@ -72,6 +80,11 @@ const LOG_TARGET: &str = "aura";
 
 type AuthorityId<P> = <P as Pair>::Public;
 
+/// The current time in milliseconds since the unix epoch.
+fn unix_time_millis() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
 /// Run `AURA` in a compatibility mode.
 ///
 /// This is required for when the chain was launched and later there
@@ -327,7 +340,7 @@ impl<B, C, E, I, P, Error, SO, L, BS> sc_consensus_slots::SimpleSlotWorker<B>
 	for AuraWorker<C, E, I, P, SO, L, BS, NumberFor<B>>
 where
 	B: BlockT,
-	C: ProvideRuntimeApi<B> + BlockOf + HeaderBackend<B> + Sync,
+	C: ProvideRuntimeApi<B> + BlockOf + AuxStore + HeaderBackend<B> + Sync,
 	C::Api: AuraApi<B, AuthorityId<P>>,
 	E: Environment<B, Error = Error> + Send + Sync,
 	E::Proposer: Proposer<B, Error = Error>,
@@ -379,6 +392,26 @@ where
 		crate::standalone::claim_slot::<P>(slot, authorities, &self.keystore).await
 	}
 
+	fn notify_slot(&self, _header: &B::Header, slot: Slot, authorities: &Self::AuxData) {
+		let Some(author) = crate::standalone::slot_author::<P>(slot, authorities) else { return };
+
+		if let Err(e) =
+			note_slot_expected_author::<_, B::Hash, _>(&*self.client, slot, author.clone())
+		{
+			warn!(target: LOG_TARGET, "Failed to record expected author of slot {}: {}", slot, e);
+		}
+	}
+
+	fn notify_proposal(&self, slot: Slot, proposing_took: Duration) {
+		if let Err(e) = note_slot_proposal_duration::<_, B::Hash, AuthorityId<P>>(
+			&*self.client,
+			slot,
+			proposing_took,
+		) {
+			warn!(target: LOG_TARGET, "Failed to record proposal of slot {}: {}", slot, e);
+		}
+	}
+
 	fn pre_digest_data(&self, slot: Slot, _claim: &Self::Claim) -> Vec<sp_runtime::DigestItem> {
 		vec![crate::standalone::pre_digest::<P>(slot)]
 	}
@@ -402,6 +435,18 @@ where
 			StateAction::ApplyChanges(sc_consensus::StorageChanges::Changes(storage_changes));
 		import_block.fork_choice = Some(ForkChoiceStrategy::LongestChain);
 
+		if let Ok(slot) = find_pre_digest::<B, P::Signature>(&import_block.header) {
+			if let Err(e) = note_slot_block(
+				&*self.client,
+				slot,
+				import_block.post_hash(),
+				public,
+				unix_time_millis(),
+			) {
+				warn!(target: LOG_TARGET, "Failed to record authoring of slot {}: {}", slot, e);
+			}
+		}
+
 		Ok(import_block)
 	}
 
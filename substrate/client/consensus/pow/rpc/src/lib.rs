@@ -0,0 +1,355 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC api for PoW.
+//!
+//! Exposes the [`MiningHandle`] of the node, allowing an external miner process to fetch the
+//! current work and to submit the seals it found.
+
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use serde::{Deserialize, Serialize};
+
+use sc_consensus::JustificationSyncLink;
+use sc_consensus_pow::{MiningHandle, PowAlgorithm};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+const POW_ERROR: i32 = 9300;
+
+/// Provides rpc methods for external miners.
+#[rpc(client, server)]
+pub trait PowApi<Hash, Difficulty> {
+	/// Returns the work the node is currently mining on.
+	///
+	/// Returns `None` if there is no work available, e.g. because the node is syncing.
+	#[method(name = "pow_getWork", with_extensions)]
+	async fn get_work(&self) -> Result<Option<Work<Hash, Difficulty>>, Error>;
+
+	/// Submit a seal for the work identified by `pre_hash`.
+	///
+	/// Returns whether the block was sealed and imported successfully.
+	#[method(name = "pow_submitWork", with_extensions)]
+	async fn submit_work(&self, pre_hash: Hash, seal: Bytes) -> Result<bool, Error>;
+}
+
+/// The work handed out to external miners.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work<Hash, Difficulty> {
+	/// The block the work builds on.
+	pub best_hash: Hash,
+	/// The hash of the block to seal.
+	pub pre_hash: Hash,
+	/// Pre-runtime digest of the block to seal, if any.
+	pub pre_runtime: Option<Bytes>,
+	/// The difficulty the seal has to satisfy.
+	pub difficulty: Difficulty,
+}
+
+/// Provides RPC methods for interacting with the PoW mining worker.
+pub struct Pow<
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block>,
+	L: JustificationSyncLink<Block>,
+	Proof,
+> {
+	handle: MiningHandle<Block, Algorithm, L, Proof>,
+}
+
+impl<Block, Algorithm, L, Proof> Pow<Block, Algorithm, L, Proof>
+where
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block>,
+	L: JustificationSyncLink<Block>,
+{
+	/// Creates a new instance of the Pow Rpc handler.
+	pub fn new(handle: MiningHandle<Block, Algorithm, L, Proof>) -> Self {
+		Self { handle }
+	}
+}
+
+#[async_trait]
+impl<Block, Algorithm, L, Proof> PowApiServer<Block::Hash, Algorithm::Difficulty>
+	for Pow<Block, Algorithm, L, Proof>
+where
+	Block: BlockT,
+	Algorithm: PowAlgorithm<Block> + Send + Sync + 'static,
+	Algorithm::Difficulty: Serialize + Send + Sync + 'static,
+	L: JustificationSyncLink<Block> + 'static,
+	Proof: Send + 'static,
+{
+	async fn get_work(
+		&self,
+		ext: &Extensions,
+	) -> Result<Option<Work<Block::Hash, Algorithm::Difficulty>>, Error> {
+		check_if_safe(ext)?;
+
+		Ok(self.handle.metadata().map(|metadata| Work {
+			best_hash: metadata.best_hash,
+			pre_hash: metadata.pre_hash,
+			pre_runtime: metadata.pre_runtime.map(Into::into),
+			difficulty: metadata.difficulty,
+		}))
+	}
+
+	async fn submit_work(
+		&self,
+		ext: &Extensions,
+		pre_hash: Block::Hash,
+		seal: Bytes,
+	) -> Result<bool, Error> {
+		check_if_safe(ext)?;
+
+		let metadata = self.handle.metadata().ok_or(Error::NoWork)?;
+		if metadata.pre_hash != pre_hash {
+			return Err(Error::StaleWork)
+		}
+
+		Ok(self.handle.submit(seal.0).await)
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The node is not mining on any block.
+	#[error("No work available")]
+	NoWork,
+	/// The submitted seal is for a block the node is no longer mining on.
+	#[error("The work was superseded by a new block")]
+	StaleWork,
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::NoWork => ErrorObject::owned(POW_ERROR + 1, error.to_string(), None::<()>),
+			Error::StaleWork => ErrorObject::owned(POW_ERROR + 2, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonrpsee::{core::EmptyServerParams as EmptyParams, rpc_params, MethodsError, RpcModule};
+	use sc_basic_authorship::ProposerFactory;
+	use sc_client_api::HeaderBackend;
+	use sc_consensus_pow::{Error as PowError, PowBlockImport};
+	use sc_rpc_api::DenyUnsafe;
+	use sc_transaction_pool::BasicPool;
+	use sp_core::{testing::TaskExecutor, H256, U256};
+	use sp_runtime::generic::BlockId;
+	use std::{future::Future, sync::Arc, time::Duration};
+	use substrate_test_runtime_client::{
+		runtime::Block, DefaultTestClientBuilderExt, TestClient, TestClientBuilder,
+		TestClientBuilderExt,
+	};
+
+	const DIFFICULTY: u64 = 1000;
+	const VALID_SEAL: &[u8] = b"valid";
+
+	/// Accepts [`VALID_SEAL`] and nothing else.
+	#[derive(Clone)]
+	struct TestAlgorithm;
+
+	impl PowAlgorithm<Block> for TestAlgorithm {
+		type Difficulty = U256;
+
+		fn difficulty(&self, _parent: H256) -> Result<U256, PowError<Block>> {
+			Ok(DIFFICULTY.into())
+		}
+
+		fn verify(
+			&self,
+			_parent: &BlockId<Block>,
+			_pre_hash: &H256,
+			_pre_digest: Option<&[u8]>,
+			seal: &Vec<u8>,
+			_difficulty: U256,
+		) -> Result<bool, PowError<Block>> {
+			Ok(seal == VALID_SEAL)
+		}
+	}
+
+	/// Creates the rpc module together with the future of the mining worker it is connected to.
+	fn test_pow_rpc_module(
+		deny_unsafe: DenyUnsafe,
+	) -> (RpcModule<impl Send + Sync + 'static>, Arc<TestClient>, impl Future<Output = ()>) {
+		let (client, select_chain) = TestClientBuilder::new().build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = TaskExecutor::new();
+		let pool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		));
+		let env = ProposerFactory::new(spawner, client.clone(), pool, None, None);
+
+		let block_import = PowBlockImport::new(
+			client.clone(),
+			client.clone(),
+			TestAlgorithm,
+			0,
+			select_chain.clone(),
+			|_, _| async { Ok(()) },
+		);
+
+		let (handle, worker) = sc_consensus_pow::start_mining_worker(
+			Box::new(block_import),
+			client.clone(),
+			select_chain,
+			TestAlgorithm,
+			env,
+			sp_consensus::NoNetwork,
+			(),
+			None,
+			|_, _| async { Ok(()) },
+			Duration::from_millis(50),
+			Duration::from_secs(1),
+		);
+
+		let mut api = Pow::new(handle).into_rpc();
+		api.extensions_mut().insert(deny_unsafe);
+		(api, client, worker)
+	}
+
+	/// Spawns the mining `worker` and waits until it hands out work.
+	async fn wait_for_work(
+		api: &RpcModule<impl Send + Sync + 'static>,
+		worker: impl Future<Output = ()> + Send + 'static,
+	) -> Work<H256, U256> {
+		tokio::spawn(worker);
+
+		loop {
+			let work = api
+				.call::<_, Option<Work<H256, U256>>>("pow_getWork", EmptyParams::new())
+				.await
+				.unwrap();
+			if let Some(work) = work {
+				return work
+			}
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	}
+
+	#[tokio::test]
+	async fn no_work_before_the_worker_built_a_block() {
+		let (api, _, _worker) = test_pow_rpc_module(DenyUnsafe::No);
+
+		let work = api
+			.call::<_, Option<Work<H256, U256>>>("pow_getWork", EmptyParams::new())
+			.await
+			.unwrap();
+		assert_eq!(work, None);
+
+		let result = api
+			.call::<_, bool>(
+				"pow_submitWork",
+				rpc_params![H256::zero(), Bytes(VALID_SEAL.to_vec())],
+			)
+			.await;
+		assert!(
+			matches!(result, Err(MethodsError::JsonRpc(e)) if e.code() == POW_ERROR + 1),
+			"No work to submit to",
+		);
+	}
+
+	#[tokio::test]
+	async fn submitting_work_imports_the_block() {
+		let (api, client, worker) = test_pow_rpc_module(DenyUnsafe::No);
+		let genesis_hash = client.info().genesis_hash;
+
+		let work = wait_for_work(&api, worker).await;
+		assert_eq!(work.best_hash, genesis_hash);
+		assert_eq!(work.pre_runtime, None);
+		assert_eq!(work.difficulty, U256::from(DIFFICULTY));
+
+		let submitted = api
+			.call::<_, bool>(
+				"pow_submitWork",
+				rpc_params![work.pre_hash, Bytes(VALID_SEAL.to_vec())],
+			)
+			.await
+			.unwrap();
+		assert!(submitted);
+		assert_eq!(client.info().best_number, 1);
+		assert_eq!(
+			client.header(client.info().best_hash).unwrap().unwrap().parent_hash,
+			genesis_hash
+		);
+	}
+
+	#[tokio::test]
+	async fn invalid_seal_is_rejected() {
+		let (api, client, worker) = test_pow_rpc_module(DenyUnsafe::No);
+
+		let work = wait_for_work(&api, worker).await;
+		let submitted = api
+			.call::<_, bool>(
+				"pow_submitWork",
+				rpc_params![work.pre_hash, Bytes(b"invalid".to_vec())],
+			)
+			.await
+			.unwrap();
+		assert!(!submitted);
+		assert_eq!(client.info().best_number, 0);
+	}
+
+	#[tokio::test]
+	async fn stale_work_is_rejected() {
+		let (api, client, worker) = test_pow_rpc_module(DenyUnsafe::No);
+
+		let work = wait_for_work(&api, worker).await;
+		assert_ne!(work.pre_hash, H256::zero());
+		let result = api
+			.call::<_, bool>(
+				"pow_submitWork",
+				rpc_params![H256::zero(), Bytes(VALID_SEAL.to_vec())],
+			)
+			.await;
+		assert!(
+			matches!(result, Err(MethodsError::JsonRpc(e)) if e.code() == POW_ERROR + 2),
+			"Work for another block must be rejected",
+		);
+		assert_eq!(client.info().best_number, 0);
+	}
+
+	#[tokio::test]
+	async fn mining_rpc_is_unsafe() {
+		let (api, _, _worker) = test_pow_rpc_module(DenyUnsafe::Yes);
+
+		let request = r#"{"jsonrpc":"2.0","method":"pow_getWork","params":[],"id":1}"#;
+		let (response, _) = api.raw_json_request(request, 1).await.unwrap();
+		let expected = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"RPC call is unsafe to be called externally"}}"#;
+
+		assert_eq!(response, expected);
+	}
+}
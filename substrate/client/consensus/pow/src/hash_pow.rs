@@ -0,0 +1,226 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Reference [`PowAlgorithm`] based on a plain hash function.
+//!
+//! The work of a block is `hash(pre_hash ++ nonce)`. A seal is valid if the work, interpreted as
+//! a big endian 256-bit number, multiplied by the difficulty does not overflow. The difficulty
+//! of the next block is queried from the runtime through [`DifficultyApi`].
+//!
+//! The algorithm is not ASIC resistant in any way and is mostly meant for testing and as a
+//! starting point for chains implementing their own algorithm.
+
+use crate::{Error, PowAlgorithm};
+use codec::{Decode, Encode};
+use sp_api::ProvideRuntimeApi;
+use sp_consensus_pow::{DifficultyApi, Seal};
+use sp_core::{H256, U256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Hash function used to compute the work of a block.
+pub trait PowHasher: Send + Sync + 'static {
+	/// Hash the given `data`.
+	fn hash(data: &[u8]) -> [u8; 32];
+}
+
+/// SHA3-256, as standardised in FIPS 202.
+pub struct Sha3_256;
+
+impl PowHasher for Sha3_256 {
+	fn hash(data: &[u8]) -> [u8; 32] {
+		use sha3::Digest;
+		sha3::Sha3_256::digest(data).into()
+	}
+}
+
+/// Keccak-256, the original Keccak submission which differs from [`Sha3_256`] in its padding.
+pub struct Keccak256;
+
+impl PowHasher for Keccak256 {
+	fn hash(data: &[u8]) -> [u8; 32] {
+		sp_core::hashing::keccak_256(data)
+	}
+}
+
+/// Blake2b with a 256-bit output.
+pub struct Blake2;
+
+impl PowHasher for Blake2 {
+	fn hash(data: &[u8]) -> [u8; 32] {
+		sp_core::hashing::blake2_256(data)
+	}
+}
+
+/// The seal of a block mined with [`HashPow`].
+///
+/// This is SCALE encoded into the [`Seal`] submitted to the
+/// [`MiningHandle`](crate::MiningHandle).
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct HashSeal {
+	/// The difficulty the block was mined at.
+	pub difficulty: U256,
+	/// The nonce found by the miner.
+	pub nonce: H256,
+}
+
+/// Compute the work for the given `pre_hash` and `nonce`.
+pub fn compute_work<H: PowHasher>(pre_hash: &[u8], nonce: &H256) -> H256 {
+	let mut data = Vec::with_capacity(pre_hash.len() + 32);
+	data.extend_from_slice(pre_hash);
+	data.extend_from_slice(nonce.as_bytes());
+	H256(H::hash(&data))
+}
+
+/// Check whether `work` satisfies `difficulty`.
+pub fn work_meets_difficulty(work: &H256, difficulty: U256) -> bool {
+	let work = U256::from_big_endian(work.as_bytes());
+	!work.overflowing_mul(difficulty).1
+}
+
+/// Try the nonces `start..start + rounds` and return the first seal satisfying `difficulty`.
+///
+/// This is a naive CPU miner, suitable for tests and development chains.
+pub fn mine<H: PowHasher>(
+	pre_hash: &[u8],
+	difficulty: U256,
+	start: u64,
+	rounds: u64,
+) -> Option<HashSeal> {
+	(start..start.saturating_add(rounds))
+		.map(H256::from_low_u64_be)
+		.find_map(|nonce| {
+			work_meets_difficulty(&compute_work::<H>(pre_hash, &nonce), difficulty)
+				.then(|| HashSeal { difficulty, nonce })
+		})
+}
+
+/// A [`PowAlgorithm`] using the hash function `H`, with the difficulty provided by the runtime.
+pub struct HashPow<B, C, H> {
+	client: Arc<C>,
+	_phantom: PhantomData<fn() -> (B, H)>,
+}
+
+/// [`HashPow`] using [`Sha3_256`].
+pub type Sha3Pow<B, C> = HashPow<B, C, Sha3_256>;
+
+/// [`HashPow`] using [`Keccak256`].
+pub type Keccak256Pow<B, C> = HashPow<B, C, Keccak256>;
+
+/// [`HashPow`] using [`Blake2`].
+pub type Blake2Pow<B, C> = HashPow<B, C, Blake2>;
+
+impl<B, C, H> HashPow<B, C, H> {
+	/// Create a new instance reading the difficulty through `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _phantom: PhantomData }
+	}
+}
+
+impl<B, C, H> Clone for HashPow<B, C, H> {
+	fn clone(&self) -> Self {
+		Self { client: self.client.clone(), _phantom: PhantomData }
+	}
+}
+
+impl<B, C, H> PowAlgorithm<B> for HashPow<B, C, H>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: DifficultyApi<B, U256>,
+	H: PowHasher,
+{
+	type Difficulty = U256;
+
+	fn difficulty(&self, parent: B::Hash) -> Result<U256, Error<B>> {
+		self.client.runtime_api().difficulty(parent).map_err(|e| {
+			Error::Runtime(format!("Fetching difficulty from runtime failed: {:?}", e))
+		})
+	}
+
+	fn preliminary_verify(
+		&self,
+		pre_hash: &B::Hash,
+		seal: &Seal,
+	) -> Result<Option<bool>, Error<B>> {
+		let Ok(seal) = HashSeal::decode(&mut &seal[..]) else { return Ok(Some(false)) };
+
+		Ok(Some(work_meets_difficulty(
+			&compute_work::<H>(pre_hash.as_ref(), &seal.nonce),
+			seal.difficulty,
+		)))
+	}
+
+	fn verify(
+		&self,
+		_parent: &BlockId<B>,
+		pre_hash: &B::Hash,
+		_pre_digest: Option<&[u8]>,
+		seal: &Seal,
+		difficulty: U256,
+	) -> Result<bool, Error<B>> {
+		let Ok(seal) = HashSeal::decode(&mut &seal[..]) else { return Ok(false) };
+
+		if seal.difficulty != difficulty {
+			return Ok(false)
+		}
+
+		Ok(work_meets_difficulty(&compute_work::<H>(pre_hash.as_ref(), &seal.nonce), difficulty))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mined_seal_meets_difficulty() {
+		let pre_hash = [7u8; 32];
+		let difficulty = U256::from(1_000);
+
+		let seal = mine::<Blake2>(&pre_hash, difficulty, 0, 100_000).expect("difficulty is low");
+		let work = compute_work::<Blake2>(&pre_hash, &seal.nonce);
+
+		assert_eq!(seal.difficulty, difficulty);
+		assert!(work_meets_difficulty(&work, difficulty));
+		// The work depends on the hash function.
+		assert_ne!(work, compute_work::<Sha3_256>(&pre_hash, &seal.nonce));
+	}
+
+	#[test]
+	fn sha3_256_is_not_keccak() {
+		// Test vector from FIPS 202.
+		assert_eq!(
+			Sha3_256::hash(b""),
+			hex_literal::hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"),
+		);
+		assert_ne!(Sha3_256::hash(b""), Keccak256::hash(b""));
+	}
+
+	#[test]
+	fn work_meets_difficulty_works() {
+		let max = H256::repeat_byte(0xff);
+		let zero = H256::zero();
+
+		assert!(work_meets_difficulty(&max, U256::one()));
+		assert!(!work_meets_difficulty(&max, U256::from(2)));
+		assert!(work_meets_difficulty(&zero, U256::MAX));
+		assert!(work_meets_difficulty(&H256::from_low_u64_be(1), U256::MAX));
+		assert!(!work_meets_difficulty(&H256::from_low_u64_be(2), U256::MAX));
+	}
+}
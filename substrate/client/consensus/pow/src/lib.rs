@@ -38,7 +38,11 @@
 //! for the auxiliary storage. It is also possible to just use the runtime
 //! as the storage, but it is not recommended as it won't work well with light
 //! clients.
+//!
+//! A reference algorithm hashing the pre-hash together with a nonce, with the difficulty
+//! provided by the runtime, is available in the [`hash_pow`] module.
 
+pub mod hash_pow;
 mod worker;
 
 pub use crate::worker::{MiningBuild, MiningHandle, MiningMetadata};
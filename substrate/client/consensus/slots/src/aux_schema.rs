@@ -18,6 +18,8 @@
 
 //! Schema for slots in the aux-db.
 
+use std::sync::Mutex;
+
 use codec::{Decode, Encode};
 use sc_client_api::backend::AuxStore;
 use sp_blockchain::{Error as ClientError, Result as ClientResult};
@@ -26,12 +28,57 @@ use sp_runtime::traits::Header;
 
 const SLOT_HEADER_MAP_KEY: &[u8] = b"slot_header_map";
 const SLOT_HEADER_START: &[u8] = b"slot_header_start";
+const SLOT_STATS_MAP_KEY: &[u8] = b"slot_stats_map";
+const SLOT_STATS_START: &[u8] = b"slot_stats_start";
 
 /// We keep at least this number of slots in database.
 pub const MAX_SLOT_CAPACITY: u64 = 1000;
 /// We prune slots when they reach this number.
 pub const PRUNING_BOUND: u64 = 2 * MAX_SLOT_CAPACITY;
 
+/// We keep the authoring statistics of at least this number of slots in database.
+pub const MAX_SLOT_STATS_CAPACITY: u64 = 14_400;
+/// We prune the authoring statistics when they reach this number of slots.
+pub const SLOT_STATS_PRUNING_BOUND: u64 = 2 * MAX_SLOT_STATS_CAPACITY;
+
+/// A block that was imported for a slot.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SlotBlock<H, A> {
+	/// Hash of the block.
+	pub hash: H,
+	/// The authority that authored the block.
+	pub author: A,
+	/// When the block was imported, in milliseconds since the unix epoch.
+	pub imported_at: u64,
+}
+
+/// Serialises the updates of the authoring statistics.
+///
+/// They are updated by reading, modifying and writing them back, from both the import queue and
+/// the slot worker.
+static SLOT_STATS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Authoring statistics of a single slot.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SlotStats<H, A> {
+	/// The authority that was expected to author a block in this slot, if known.
+	pub expected_author: Option<A>,
+	/// The blocks that were imported for this slot.
+	///
+	/// More than one block means that the slot author equivocated or that forks were created.
+	pub blocks: Vec<SlotBlock<H, A>>,
+	/// How long it took this node to propose a block in this slot, in milliseconds.
+	///
+	/// Only set when this node authored a block in this slot.
+	pub proposal_duration: Option<u64>,
+}
+
+impl<H, A> Default for SlotStats<H, A> {
+	fn default() -> Self {
+		Self { expected_author: None, blocks: Vec::new(), proposal_duration: None }
+	}
+}
+
 fn load_decode<C, T>(backend: &C, key: &[u8]) -> ClientResult<Option<T>>
 where
 	C: AuxStore,
@@ -132,13 +179,124 @@ where
 	Ok(None)
 }
 
+fn slot_stats_key(slot: Slot) -> Vec<u8> {
+	let mut key = SLOT_STATS_MAP_KEY.to_vec();
+	slot.using_encoded(|s| key.extend(s));
+	key
+}
+
+/// Load the authoring statistics of the given `slot`.
+pub fn load_slot_stats<C, H, A>(backend: &C, slot: Slot) -> ClientResult<Option<SlotStats<H, A>>>
+where
+	C: AuxStore,
+	H: Decode,
+	A: Decode,
+{
+	load_decode(backend, &slot_stats_key(slot))
+}
+
+/// Modify the authoring statistics of the given `slot` with `f`.
+///
+/// Statistics of slots older than [`MAX_SLOT_STATS_CAPACITY`] are pruned along the way.
+fn update_slot_stats<C, H, A>(
+	backend: &C,
+	slot: Slot,
+	f: impl FnOnce(&mut SlotStats<H, A>),
+) -> ClientResult<()>
+where
+	C: AuxStore,
+	H: Encode + Decode,
+	A: Encode + Decode,
+{
+	let _lock = SLOT_STATS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+	let key = slot_stats_key(slot);
+	let mut stats = load_decode::<_, SlotStats<H, A>>(backend, &key)?.unwrap_or_default();
+	f(&mut stats);
+
+	let first_saved_slot = load_decode::<_, Slot>(backend, SLOT_STATS_START)?.unwrap_or(slot);
+	let mut new_first_saved_slot = first_saved_slot.min(slot);
+	let mut keys_to_delete = vec![];
+
+	if slot.saturating_sub(*first_saved_slot) >= SLOT_STATS_PRUNING_BOUND {
+		new_first_saved_slot = slot.saturating_sub(MAX_SLOT_STATS_CAPACITY);
+
+		for s in u64::from(first_saved_slot)..new_first_saved_slot.into() {
+			keys_to_delete.push(slot_stats_key(s.into()));
+		}
+	}
+
+	backend.insert_aux(
+		&[
+			(&key[..], stats.encode().as_slice()),
+			(SLOT_STATS_START, new_first_saved_slot.encode().as_slice()),
+		],
+		&keys_to_delete.iter().map(|k| &k[..]).collect::<Vec<&[u8]>>()[..],
+	)
+}
+
+/// Record the authority that is expected to author a block in `slot`.
+pub fn note_slot_expected_author<C, H, A>(backend: &C, slot: Slot, author: A) -> ClientResult<()>
+where
+	C: AuxStore,
+	H: Encode + Decode,
+	A: Encode + Decode,
+{
+	update_slot_stats::<_, H, A>(backend, slot, |stats| stats.expected_author = Some(author))
+}
+
+/// Record that the block `hash`, authored by `author`, was imported for `slot`.
+///
+/// `author` is also recorded as the expected author of `slot`, as the block could only be
+/// imported when its seal was created by the expected author.
+pub fn note_slot_block<C, H, A>(
+	backend: &C,
+	slot: Slot,
+	hash: H,
+	author: A,
+	imported_at: u64,
+) -> ClientResult<()>
+where
+	C: AuxStore,
+	H: Encode + Decode + PartialEq,
+	A: Encode + Decode + Clone,
+{
+	update_slot_stats(backend, slot, |stats| {
+		if stats.blocks.iter().any(|b| b.hash == hash) {
+			return
+		}
+
+		stats.expected_author.get_or_insert_with(|| author.clone());
+		stats.blocks.push(SlotBlock { hash, author, imported_at });
+	})
+}
+
+/// Record how long it took this node to propose a block in `slot`.
+pub fn note_slot_proposal_duration<C, H, A>(
+	backend: &C,
+	slot: Slot,
+	duration: std::time::Duration,
+) -> ClientResult<()>
+where
+	C: AuxStore,
+	H: Encode + Decode,
+	A: Encode + Decode,
+{
+	update_slot_stats::<_, H, A>(backend, slot, |stats| {
+		stats.proposal_duration = Some(duration.as_millis() as u64)
+	})
+}
+
 #[cfg(test)]
 mod test {
 	use sp_core::{hash::H256, sr25519, Pair};
 	use sp_runtime::testing::{Digest as DigestTest, Header as HeaderTest};
 	use substrate_test_runtime_client;
 
-	use super::{check_equivocation, MAX_SLOT_CAPACITY, PRUNING_BOUND};
+	use super::{
+		check_equivocation, load_slot_stats, note_slot_block, note_slot_expected_author,
+		note_slot_proposal_duration, SlotBlock, SlotStats, MAX_SLOT_CAPACITY, PRUNING_BOUND,
+		SLOT_STATS_PRUNING_BOUND,
+	};
 
 	fn create_header(number: u64) -> HeaderTest {
 		// so that different headers for the same number get different hashes
@@ -220,4 +378,48 @@ mod test {
 		.unwrap()
 		.is_none(),);
 	}
+
+	#[test]
+	fn slot_stats_are_recorded_and_pruned() {
+		let client = substrate_test_runtime_client::new();
+		let hash = H256::random();
+
+		note_slot_expected_author::<_, H256, u32>(&client, 10.into(), 1).unwrap();
+		note_slot_proposal_duration::<_, H256, u32>(
+			&client,
+			10.into(),
+			std::time::Duration::from_millis(150),
+		)
+		.unwrap();
+		note_slot_block(&client, 10.into(), hash, 1u32, 1_000).unwrap();
+		// Importing the same block again is not recorded twice.
+		note_slot_block(&client, 10.into(), hash, 1u32, 2_000).unwrap();
+		// The expected author of slot 11 is only known from the imported block.
+		note_slot_block(&client, 11.into(), hash, 2u32, 3_000).unwrap();
+
+		assert_eq!(
+			load_slot_stats::<_, H256, u32>(&client, 10.into()).unwrap(),
+			Some(SlotStats {
+				expected_author: Some(1),
+				blocks: vec![SlotBlock { hash, author: 1, imported_at: 1_000 }],
+				proposal_duration: Some(150),
+			}),
+		);
+		assert_eq!(
+			load_slot_stats::<_, H256, u32>(&client, 11.into())
+				.unwrap()
+				.unwrap()
+				.expected_author,
+			Some(2),
+		);
+		assert!(load_slot_stats::<_, H256, u32>(&client, 12.into()).unwrap().is_none());
+
+		// Trigger the pruning.
+		let slot = 10 + SLOT_STATS_PRUNING_BOUND;
+		note_slot_expected_author::<_, H256, u32>(&client, slot.into(), 3).unwrap();
+
+		assert!(load_slot_stats::<_, H256, u32>(&client, 10.into()).unwrap().is_none());
+		assert!(load_slot_stats::<_, H256, u32>(&client, 11.into()).unwrap().is_none());
+		assert!(load_slot_stats::<_, H256, u32>(&client, slot.into()).unwrap().is_some());
+	}
 }
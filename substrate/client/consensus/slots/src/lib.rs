@@ -28,7 +28,11 @@
 mod aux_schema;
 mod slots;
 
-pub use aux_schema::{check_equivocation, MAX_SLOT_CAPACITY, PRUNING_BOUND};
+pub use aux_schema::{
+	check_equivocation, load_slot_stats, note_slot_block, note_slot_expected_author,
+	note_slot_proposal_duration, SlotBlock, SlotStats, MAX_SLOT_CAPACITY, MAX_SLOT_STATS_CAPACITY,
+	PRUNING_BOUND, SLOT_STATS_PRUNING_BOUND,
+};
 use slots::Slots;
 pub use slots::{time_until_next_slot, SlotInfo};
 
@@ -136,6 +140,9 @@ pub trait SimpleSlotWorker<B: BlockT> {
 	/// need to author blocks or not.
 	fn notify_slot(&self, _header: &B::Header, _slot: Slot, _aux_data: &Self::AuxData) {}
 
+	/// Notifies that this node proposed a block in the given slot, and how long it took.
+	fn notify_proposal(&self, _slot: Slot, _proposing_took: Duration) {}
+
 	/// Return the pre digest data to include in a block authored with the given claim.
 	fn pre_digest_data(&self, slot: Slot, claim: &Self::Claim) -> Vec<sp_runtime::DigestItem>;
 
@@ -375,7 +382,9 @@ pub trait SimpleSlotWorker<B: BlockT> {
 			},
 		};
 
+		let proposing_started = Instant::now();
 		let proposal = self.propose(proposer, &claim, slot_info, end_proposing_at).await?;
+		self.notify_proposal(slot, proposing_started.elapsed());
 
 		let (block, storage_proof) = (proposal.block, proposal.proof);
 		let (header, body) = block.deconstruct();
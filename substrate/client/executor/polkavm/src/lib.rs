@@ -25,15 +25,29 @@ use sp_wasm_interface::{
 	Function, FunctionContext, HostFunctions, Pointer, Value, ValueType, WordSize,
 };
 
-#[repr(transparent)]
-pub struct InstancePre(polkavm::InstancePre<(), String>);
+pub struct InstancePre {
+	instance_pre: polkavm::InstancePre<(), String>,
+	max_heap_size: Option<u32>,
+}
 
-#[repr(transparent)]
-pub struct Instance(polkavm::Instance<(), String>);
+pub struct Instance {
+	instance: polkavm::Instance<(), String>,
+	max_heap_size: Option<u32>,
+}
 
 impl WasmModule for InstancePre {
 	fn new_instance(&self) -> Result<Box<dyn WasmInstance>, Error> {
-		Ok(Box::new(Instance(self.0.instantiate()?)))
+		Ok(Box::new(Instance {
+			instance: self.instance_pre.instantiate()?,
+			max_heap_size: self.max_heap_size,
+		}))
+	}
+}
+
+impl Instance {
+	/// Whether the heap of the program is within the maximum heap size, if any.
+	fn heap_within_limit(&self) -> bool {
+		self.max_heap_size.map_or(true, |max| self.instance.heap_size() <= max)
 	}
 }
 
@@ -43,7 +57,7 @@ impl WasmInstance for Instance {
 		name: &str,
 		raw_data: &[u8],
 	) -> (Result<Vec<u8>, Error>, Option<AllocationStats>) {
-		let pc = match self.0.module().exports().find(|e| e.symbol() == name) {
+		let pc = match self.instance.module().exports().find(|e| e.symbol() == name) {
 			Some(export) => export.program_counter(),
 			None =>
 				return (
@@ -62,7 +76,7 @@ impl WasmInstance for Instance {
 		// TODO: This will leak guest memory; find a better solution.
 
 		// Make sure that the memory is cleared...
-		if let Err(err) = self.0.reset_memory() {
+		if let Err(err) = self.instance.reset_memory() {
 			return (
 				Err(format!(
 					"call into the runtime method '{name}' failed: reset memory failed: {err}"
@@ -73,7 +87,16 @@ impl WasmInstance for Instance {
 		}
 
 		// ... and allocate space for the input payload.
-		if let Err(err) = self.0.sbrk(raw_data_length) {
+		if self.max_heap_size.map_or(false, |max| raw_data_length > max) {
+			return (
+				Err(format!(
+					"cannot call runtime method '{name}': input payload exceeds the maximum heap size"
+				)
+				.into()),
+				None,
+			);
+		}
+		if let Err(err) = self.instance.sbrk(raw_data_length) {
 			return (
 				Err(format!(
 					"call into the runtime method '{name}' failed: reset memory failed: {err}"
@@ -85,13 +108,13 @@ impl WasmInstance for Instance {
 
 		// Grab the address of where the guest's heap starts; that's where we've just allocated
 		// the memory for the input payload.
-		let data_pointer = self.0.module().memory_map().heap_base();
+		let data_pointer = self.instance.module().memory_map().heap_base();
 
-		if let Err(err) = self.0.write_memory(data_pointer, raw_data) {
+		if let Err(err) = self.instance.write_memory(data_pointer, raw_data) {
 			return (Err(format!("call into the runtime method '{name}': failed to write the input payload into guest memory: {err}").into()), None);
 		}
 
-		match self.0.call_typed(&mut (), pc, (data_pointer, raw_data_length)) {
+		match self.instance.call_typed(&mut (), pc, (data_pointer, raw_data_length)) {
 			Ok(()) => {},
 			Err(CallError::Trap) =>
 				return (
@@ -112,9 +135,21 @@ impl WasmInstance for Instance {
 			Err(CallError::Step) => unreachable!("stepping is never enabled"),
 		};
 
-		let result_pointer = self.0.reg(Reg::A0);
-		let result_length = self.0.reg(Reg::A1);
-		let output = match self.0.read_memory(result_pointer as u32, result_length as u32) {
+		// Host calls already reject a heap grown beyond its maximum size, but the program could
+		// have grown it after its last host call.
+		if !self.heap_within_limit() {
+			return (
+				Err(format!(
+					"call into the runtime method '{name}' failed: the heap grew beyond its maximum size"
+				)
+				.into()),
+				None,
+			);
+		}
+
+		let result_pointer = self.instance.reg(Reg::A0);
+		let result_length = self.instance.reg(Reg::A1);
+		let output = match self.instance.read_memory(result_pointer as u32, result_length as u32) {
 			Ok(output) => output,
 			Err(error) => {
 				return (Err(format!("call into the runtime method '{name}' failed: failed to read the return payload: {error}").into()), None)
@@ -125,7 +160,10 @@ impl WasmInstance for Instance {
 	}
 }
 
-struct Context<'r, 'a>(&'r mut polkavm::Caller<'a, ()>);
+struct Context<'r, 'a> {
+	caller: &'r mut polkavm::Caller<'a, ()>,
+	max_heap_size: Option<u32>,
+}
 
 impl<'r, 'a> FunctionContext for Context<'r, 'a> {
 	fn read_memory_into(
@@ -133,7 +171,7 @@ impl<'r, 'a> FunctionContext for Context<'r, 'a> {
 		address: Pointer<u8>,
 		dest: &mut [u8],
 	) -> sp_wasm_interface::Result<()> {
-		self.0
+		self.caller
 			.instance
 			.read_memory_into(u32::from(address), dest)
 			.map_err(|error| error.to_string())
@@ -141,20 +179,26 @@ impl<'r, 'a> FunctionContext for Context<'r, 'a> {
 	}
 
 	fn write_memory(&mut self, address: Pointer<u8>, data: &[u8]) -> sp_wasm_interface::Result<()> {
-		self.0
+		self.caller
 			.instance
 			.write_memory(u32::from(address), data)
 			.map_err(|error| error.to_string())
 	}
 
 	fn allocate_memory(&mut self, size: WordSize) -> sp_wasm_interface::Result<Pointer<u8>> {
-		let pointer = match self.0.instance.sbrk(0) {
+		if let Some(max) = self.max_heap_size {
+			if self.caller.instance.heap_size().saturating_add(size) > max {
+				return Err(String::from("allocation exceeds the maximum heap size"))
+			}
+		}
+
+		let pointer = match self.caller.instance.sbrk(0) {
 			Ok(pointer) => pointer.expect("fetching the current heap pointer never fails"),
 			Err(err) => return Err(format!("sbrk failed: {err}")),
 		};
 
 		// TODO: This will leak guest memory; find a better solution.
-		match self.0.instance.sbrk(size) {
+		match self.caller.instance.sbrk(size) {
 			Ok(Some(_)) => (),
 			Ok(None) => return Err(String::from("allocation error")),
 			Err(err) => return Err(format!("sbrk failed: {err}")),
@@ -173,7 +217,20 @@ impl<'r, 'a> FunctionContext for Context<'r, 'a> {
 	}
 }
 
-fn call_host_function(caller: &mut Caller<()>, function: &dyn Function) -> Result<(), String> {
+fn call_host_function(
+	caller: &mut Caller<()>,
+	function: &dyn Function,
+	max_heap_size: Option<u32>,
+) -> Result<(), String> {
+	// The program grows its heap without involving the host, so catch it overstepping the limit
+	// as soon as it calls back into the host rather than letting it run to completion.
+	if max_heap_size.map_or(false, |max| caller.instance.heap_size() > max) {
+		let name = function.name();
+		return Err(format!(
+			"call into the host function '{name}' failed: the heap grew beyond its maximum size"
+		))
+	}
+
 	let mut args = [Value::I64(0); Reg::ARG_REGS.len()];
 	let mut nth_reg = 0;
 	for (nth_arg, kind) in function.signature().args.iter().enumerate() {
@@ -222,9 +279,10 @@ fn call_host_function(caller: &mut Caller<()>, function: &dyn Function) -> Resul
 		&args[..function.signature().args.len()]
 	);
 
-	let value = match function
-		.execute(&mut Context(caller), &mut args.into_iter().take(function.signature().args.len()))
-	{
+	let value = match function.execute(
+		&mut Context { caller, max_heap_size },
+		&mut args.into_iter().take(function.signature().args.len()),
+	) {
 		Ok(value) => value,
 		Err(error) => {
 			let name = function.name();
@@ -282,13 +340,30 @@ where
 	let module =
 		polkavm::Module::from_blob(&engine, &polkavm::ModuleConfig::default(), blob.clone())?;
 
+	create_runtime_from_module::<H>(&module, None)
+}
+
+/// Create a runtime from a module which was already compiled by the caller.
+///
+/// Allows the caller to pick the engine and module configuration instead of relying on the
+/// environment, e.g. to get deterministic execution. If `max_heap_size` is given, calls fail
+/// once the heap of the program, including the input payload, grows beyond it: allocations made
+/// by the host are rejected up front, and the program is stopped at its next host call or when
+/// it returns.
+pub fn create_runtime_from_module<H>(
+	module: &polkavm::Module,
+	max_heap_size: Option<u32>,
+) -> Result<Box<dyn WasmModule>, WasmError>
+where
+	H: HostFunctions,
+{
 	let mut linker = polkavm::Linker::new();
 
 	for function in H::host_functions() {
-		linker.define_untyped(function.name(), |mut caller: Caller<()>| {
-			call_host_function(&mut caller, function)
+		linker.define_untyped(function.name(), move |mut caller: Caller<()>| {
+			call_host_function(&mut caller, function, max_heap_size)
 		})?;
 	}
-	let instance_pre = linker.instantiate_pre(&module)?;
-	Ok(Box::new(InstancePre(instance_pre)))
+	let instance_pre = linker.instantiate_pre(module)?;
+	Ok(Box::new(InstancePre { instance_pre, max_heap_size }))
 }
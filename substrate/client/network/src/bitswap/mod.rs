@@ -20,6 +20,10 @@
 //! Allows querying transactions by hash over standard bitswap protocol
 //! Only supports bitswap 1.2.0.
 //! CID is expected to reference 256-bit Blake2b transaction hash.
+//!
+//! Other data can be served by implementing [`BitswapBlockProvider`] and answering requests with
+//! [`handle_request`]. [`want_blocks`] and [`received_blocks`] build requests and read responses
+//! on the requesting side.
 
 use crate::{
 	request_responses::{IncomingRequest, OutgoingResponse, ProtocolConfig},
@@ -34,9 +38,13 @@ use prost::Message;
 use sc_client_api::BlockBackend;
 use sc_network_types::PeerId;
 use schema::bitswap::{
-	message::{wantlist::WantType, Block as MessageBlock, BlockPresence, BlockPresenceType},
+	message::{
+		wantlist::{Entry, WantType},
+		Block as MessageBlock, BlockPresence, BlockPresenceType, Wantlist,
+	},
 	Message as BitswapMessage,
 };
+use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::traits::Block as BlockT;
 use std::{io, sync::Arc, time::Duration};
 use unsigned_varint::encode as varint_encode;
@@ -55,7 +63,10 @@ const MAX_PACKET_SIZE: u64 = MAX_RESPONSE_SIZE;
 const MAX_REQUEST_QUEUE: usize = 20;
 
 /// Max number of blocks per wantlist
-const MAX_WANTED_BLOCKS: usize = 16;
+pub const MAX_WANTED_BLOCKS: usize = 16;
+
+/// Multicodec of raw binary data, used for the CIDs of requested blocks.
+const RAW_CODEC: u64 = 0x55;
 
 /// Bitswap protocol name
 const PROTOCOL_NAME: &'static str = "/ipfs/bitswap/1.2.0";
@@ -93,6 +104,23 @@ impl Prefix {
 	}
 }
 
+/// Provides the blocks served over bitswap.
+pub trait BitswapBlockProvider: Send + Sync {
+	/// Returns the block whose content has the given 256-bit Blake2b hash, if it is known.
+	fn block(&self, hash: [u8; 32]) -> Result<Option<Vec<u8>>, BitswapError>;
+}
+
+/// Serves the transactions indexed in the client database.
+struct IndexedTransactions<'a, B>(&'a (dyn BlockBackend<B> + Send + Sync));
+
+impl<B: BlockT> BitswapBlockProvider for IndexedTransactions<'_, B> {
+	fn block(&self, hash: [u8; 32]) -> Result<Option<Vec<u8>>, BitswapError> {
+		let mut transaction_hash = B::Hash::default();
+		transaction_hash.as_mut().copy_from_slice(&hash);
+		Ok(self.0.indexed_transaction(transaction_hash)?)
+	}
+}
+
 /// Bitswap request handler
 pub struct BitswapRequestHandler<B> {
 	client: Arc<dyn BlockBackend<B> + Send + Sync>,
@@ -121,7 +149,7 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 		while let Some(request) = self.request_receiver.next().await {
 			let IncomingRequest { peer, payload, pending_response } = request;
 
-			match self.handle_message(&peer, &payload) {
+			match handle_request(&IndexedTransactions(&*self.client), &peer, &payload) {
 				Ok(response) => {
 					let response = OutgoingResponse {
 						result: Ok(response),
@@ -162,95 +190,129 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 			}
 		}
 	}
+}
 
-	/// Handle received Bitswap request
-	fn handle_message(
-		&mut self,
-		peer: &PeerId,
-		payload: &Vec<u8>,
-	) -> Result<Vec<u8>, BitswapError> {
-		let request = schema::bitswap::Message::decode(&payload[..])?;
-
-		trace!(target: LOG_TARGET, "Received request: {:?} from {}", request, peer);
+/// Handle a received bitswap request, answering it with the blocks of `provider`.
+///
+/// Returns the encoded response.
+pub fn handle_request(
+	provider: &dyn BitswapBlockProvider,
+	peer: &PeerId,
+	payload: &[u8],
+) -> Result<Vec<u8>, BitswapError> {
+	let request = schema::bitswap::Message::decode(&payload[..])?;
+
+	trace!(target: LOG_TARGET, "Received request: {:?} from {}", request, peer);
+
+	let mut response = BitswapMessage::default();
+
+	let wantlist = match request.wantlist {
+		Some(wantlist) => wantlist,
+		None => {
+			debug!(target: LOG_TARGET, "Unexpected bitswap message from {}", peer);
+			return Err(BitswapError::InvalidWantList)
+		},
+	};
 
-		let mut response = BitswapMessage::default();
+	if wantlist.entries.len() > MAX_WANTED_BLOCKS {
+		trace!(target: LOG_TARGET, "Ignored request: too many entries");
+		return Err(BitswapError::TooManyEntries)
+	}
 
-		let wantlist = match request.wantlist {
-			Some(wantlist) => wantlist,
-			None => {
-				debug!(target: LOG_TARGET, "Unexpected bitswap message from {}", peer);
-				return Err(BitswapError::InvalidWantList)
+	for entry in wantlist.entries {
+		let cid = match cid::Cid::read_bytes(entry.block.as_slice()) {
+			Ok(cid) => cid,
+			Err(e) => {
+				trace!(target: LOG_TARGET, "Bad CID {:?}: {:?}", entry.block, e);
+				continue
 			},
 		};
 
-		if wantlist.entries.len() > MAX_WANTED_BLOCKS {
-			trace!(target: LOG_TARGET, "Ignored request: too many entries");
-			return Err(BitswapError::TooManyEntries)
+		if cid.version() != cid::Version::V1 ||
+			cid.hash().code() != u64::from(cid::multihash::Code::Blake2b256) ||
+			cid.hash().size() != 32
+		{
+			debug!(target: LOG_TARGET, "Ignoring unsupported CID {}: {}", peer, cid);
+			continue
 		}
 
-		for entry in wantlist.entries {
-			let cid = match cid::Cid::read_bytes(entry.block.as_slice()) {
-				Ok(cid) => cid,
-				Err(e) => {
-					trace!(target: LOG_TARGET, "Bad CID {:?}: {:?}", entry.block, e);
-					continue
-				},
-			};
+		let mut hash = [0u8; 32];
+		hash.copy_from_slice(&cid.hash().digest()[0..32]);
+		let block = match provider.block(hash) {
+			Ok(block) => block,
+			Err(e) => {
+				error!(target: LOG_TARGET, "Error retrieving block {}: {}", HexDisplay::from(&hash), e);
+				None
+			},
+		};
 
-			if cid.version() != cid::Version::V1 ||
-				cid.hash().code() != u64::from(cid::multihash::Code::Blake2b256) ||
-				cid.hash().size() != 32
-			{
-				debug!(target: LOG_TARGET, "Ignoring unsupported CID {}: {}", peer, cid);
-				continue
-			}
+		match block {
+			Some(block) => {
+				trace!(target: LOG_TARGET, "Found CID {:?}, hash {}", cid, HexDisplay::from(&hash));
 
-			let mut hash = B::Hash::default();
-			hash.as_mut().copy_from_slice(&cid.hash().digest()[0..32]);
-			let transaction = match self.client.indexed_transaction(hash) {
-				Ok(ex) => ex,
-				Err(e) => {
-					error!(target: LOG_TARGET, "Error retrieving transaction {}: {}", hash, e);
-					None
-				},
-			};
-
-			match transaction {
-				Some(transaction) => {
-					trace!(target: LOG_TARGET, "Found CID {:?}, hash {:?}", cid, hash);
-
-					if entry.want_type == WantType::Block as i32 {
-						let prefix = Prefix {
-							version: cid.version(),
-							codec: cid.codec(),
-							mh_type: cid.hash().code(),
-							mh_len: cid.hash().size(),
-						};
-						response
-							.payload
-							.push(MessageBlock { prefix: prefix.to_bytes(), data: transaction });
-					} else {
-						response.block_presences.push(BlockPresence {
-							r#type: BlockPresenceType::Have as i32,
-							cid: cid.to_bytes(),
-						});
-					}
-				},
-				None => {
-					trace!(target: LOG_TARGET, "Missing CID {:?}, hash {:?}", cid, hash);
-
-					if entry.send_dont_have {
-						response.block_presences.push(BlockPresence {
-							r#type: BlockPresenceType::DontHave as i32,
-							cid: cid.to_bytes(),
-						});
-					}
-				},
-			}
-		}
+				if entry.want_type == WantType::Block as i32 {
+					let prefix = Prefix {
+						version: cid.version(),
+						codec: cid.codec(),
+						mh_type: cid.hash().code(),
+						mh_len: cid.hash().size(),
+					};
+					response.payload.push(MessageBlock { prefix: prefix.to_bytes(), data: block });
+				} else {
+					response.block_presences.push(BlockPresence {
+						r#type: BlockPresenceType::Have as i32,
+						cid: cid.to_bytes(),
+					});
+				}
+			},
+			None => {
+				trace!(target: LOG_TARGET, "Missing CID {:?}, hash {}", cid, HexDisplay::from(&hash));
 
-		Ok(response.encode_to_vec())
+				if entry.send_dont_have {
+					response.block_presences.push(BlockPresence {
+						r#type: BlockPresenceType::DontHave as i32,
+						cid: cid.to_bytes(),
+					});
+				}
+			},
+		}
 	}
+
+	Ok(response.encode_to_vec())
+}
+
+/// Build a bitswap request for the blocks whose content has the given 256-bit Blake2b hashes.
+///
+/// At most [`MAX_WANTED_BLOCKS`] blocks should be requested at once.
+pub fn want_blocks(hashes: &[[u8; 32]]) -> Vec<u8> {
+	let entries = hashes
+		.iter()
+		.map(|hash| Entry {
+			block: cid::Cid::new_v1(
+				RAW_CODEC,
+				cid::multihash::Multihash::wrap(
+					u64::from(cid::multihash::Code::Blake2b256),
+					&hash[..],
+				)
+				.expect("a 256-bit digest fits into a multihash; qed"),
+			)
+			.to_bytes(),
+			want_type: WantType::Block as i32,
+			send_dont_have: true,
+			..Default::default()
+		})
+		.collect();
+
+	BitswapMessage { wantlist: Some(Wantlist { entries, full: false }), ..Default::default() }
+		.encode_to_vec()
+}
+
+/// Extract the blocks from an encoded bitswap response.
+///
+/// The blocks are not checked against the requested hashes.
+pub fn received_blocks(response: &[u8]) -> Result<Vec<Vec<u8>>, BitswapError> {
+	let response = BitswapMessage::decode(response)?;
+	Ok(response.payload.into_iter().map(|block| block.data).collect())
 }
 
 /// Bitswap protocol error.
@@ -531,4 +593,39 @@ mod tests {
 			panic!("invalid event received");
 		}
 	}
+
+	#[test]
+	fn blocks_of_custom_provider_are_served() {
+		struct Blocks(Vec<Vec<u8>>);
+
+		impl BitswapBlockProvider for Blocks {
+			fn block(&self, hash: [u8; 32]) -> Result<Option<Vec<u8>>, BitswapError> {
+				Ok(self
+					.0
+					.iter()
+					.find(|block| sp_crypto_hashing::blake2_256(block) == hash)
+					.cloned())
+			}
+		}
+
+		let provider = Blocks(vec![vec![0x13, 0x37], vec![0x13, 0x38]]);
+		let request = want_blocks(&[
+			sp_crypto_hashing::blake2_256(&[0x13, 0x38]),
+			sp_crypto_hashing::blake2_256(&[0x42]),
+			sp_crypto_hashing::blake2_256(&[0x13, 0x37]),
+		]);
+
+		let response = handle_request(&provider, &PeerId::random(), &request).unwrap();
+		assert_eq!(received_blocks(&response).unwrap(), vec![vec![0x13, 0x38], vec![0x13, 0x37]]);
+
+		let response = BitswapMessage::decode(&response[..]).unwrap();
+		assert_eq!(response.block_presences.len(), 1);
+		assert_eq!(response.block_presences[0].r#type, BlockPresenceType::DontHave as i32);
+
+		let too_many = want_blocks(&vec![[0u8; 32]; MAX_WANTED_BLOCKS + 1]);
+		assert!(matches!(
+			handle_request(&provider, &PeerId::random(), &too_many),
+			Err(BitswapError::TooManyEntries)
+		));
+	}
 }
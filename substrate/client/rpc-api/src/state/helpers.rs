@@ -20,6 +20,7 @@
 
 use serde::{Deserialize, Serialize};
 use sp_core::Bytes;
+use sp_state_machine::ExecutionTrace;
 
 /// ReadProof struct returned by the RPC
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -30,3 +31,34 @@ pub struct ReadProof<Hash> {
 	/// A proof used to prove that storage entries are included in the storage trie
 	pub proof: Vec<Bytes>,
 }
+
+/// Format of the execution trace returned by `state_traceBlockExecution`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutionTraceFormat {
+	/// The trace as JSON object.
+	#[default]
+	Json,
+	/// The SCALE encoded trace.
+	Binary,
+}
+
+/// Execution trace returned by the RPC
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutionTraceResponse {
+	/// The trace as JSON object.
+	Json(ExecutionTrace),
+	/// The SCALE encoded trace.
+	Binary(Bytes),
+}
+
+/// Arguments of a view function called through `state_callViewFunction`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewFunctionArgs {
+	/// The SCALE encoded arguments, concatenated.
+	Scale(Bytes),
+	/// One JSON value per argument, encoded using the type information of the metadata.
+	Json(Vec<serde_json::Value>),
+}
@@ -28,7 +28,9 @@ use sp_version::RuntimeVersion;
 pub mod error;
 pub mod helpers;
 
-pub use self::helpers::ReadProof;
+pub use self::helpers::{
+	ExecutionTraceFormat, ExecutionTraceResponse, ReadProof, ViewFunctionArgs,
+};
 pub use error::Error;
 
 /// Substrate state API
@@ -38,6 +40,29 @@ pub trait StateApi<Hash> {
 	#[method(name = "state_call", aliases = ["state_callAt"], blocking)]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> Result<Bytes, Error>;
 
+	/// Call a view function of a pallet by name.
+	///
+	/// The view function is looked up in the metadata of the runtime, which is also used to
+	/// encode the arguments and to decode the result. The runtime must provide the metadata at
+	/// version 16.
+	///
+	/// ### Params
+	///
+	/// - `pallet` (param index 0): Name of the pallet, as found in the metadata.
+	/// - `function` (param index 1): Name of the view function.
+	/// - `args` (param index 2): Either `{ "scale": "0x.." }` with the SCALE encoded arguments or
+	/// `{ "json": [..] }` with one JSON value per argument.
+	/// - `hash` (param index 3): Hash of the block to call the view function at, defaults to the
+	/// best block.
+	#[method(name = "state_callViewFunction", blocking)]
+	fn call_view_function(
+		&self,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+		hash: Option<Hash>,
+	) -> Result<serde_json::Value, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[method(name = "state_getKeys", blocking)]
 	#[deprecated(since = "2.0.0", note = "Please use `getKeysPaged` with proper paging support")]
@@ -300,4 +325,24 @@ pub trait StateApi<Hash> {
 		storage_keys: Option<String>,
 		methods: Option<String>,
 	) -> Result<sp_rpc::tracing::TraceBlockResponse, Error>;
+
+	/// Re-execute the given block and return every storage operation done by the runtime, in
+	/// order.
+	///
+	/// Every operation is attributed to the extrinsic that was being executed, making it possible
+	/// to diff the traces of the same block executed by two runtime versions, e.g. by running a
+	/// node with `--wasm-runtime-overrides`. Unlike `state_traceBlock`, no tracing enabled runtime
+	/// is required.
+	///
+	/// ### Params
+	///
+	/// - `block` (param index 0): Hash of the block to trace.
+	/// - `format` (param index 1): Either `"json"` (default) or `"binary"` for the SCALE encoded
+	/// trace.
+	#[method(name = "state_traceBlockExecution", blocking, with_extensions)]
+	fn trace_block_execution(
+		&self,
+		block: Hash,
+		format: Option<ExecutionTraceFormat>,
+	) -> Result<ExecutionTraceResponse, Error>;
 }
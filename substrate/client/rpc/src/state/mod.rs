@@ -59,6 +59,15 @@ where
 		call_data: Bytes,
 	) -> Result<Bytes, Error>;
 
+	/// Call a view function of a pallet by name at given block.
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+	) -> Result<serde_json::Value, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	fn storage_keys(
 		&self,
@@ -148,6 +157,13 @@ where
 		methods: Option<String>,
 	) -> Result<sp_rpc::tracing::TraceBlockResponse, Error>;
 
+	/// Record the storage operations done while executing the block.
+	fn trace_block_execution(
+		&self,
+		block: Block::Hash,
+		format: ExecutionTraceFormat,
+	) -> Result<ExecutionTraceResponse, Error>;
+
 	/// New runtime version subscription
 	fn subscribe_runtime_version(&self, pending: PendingSubscriptionSink);
 
@@ -209,6 +225,18 @@ where
 		self.backend.call(block, method, data).map_err(Into::into)
 	}
 
+	fn call_view_function(
+		&self,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+		block: Option<Block::Hash>,
+	) -> Result<serde_json::Value, Error> {
+		self.backend
+			.call_view_function(block, pallet, function, args)
+			.map_err(Into::into)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
@@ -325,6 +353,21 @@ where
 			.map_err(Into::into)
 	}
 
+	/// Re-execute the given block and record all the storage operations done by the runtime.
+	///
+	/// Note: requires the node to run with `--rpc-methods=Unsafe`.
+	fn trace_block_execution(
+		&self,
+		ext: &Extensions,
+		block: Block::Hash,
+		format: Option<ExecutionTraceFormat>,
+	) -> Result<ExecutionTraceResponse, Error> {
+		check_if_safe(ext)?;
+		self.backend
+			.trace_block_execution(block, format.unwrap_or_default())
+			.map_err(Into::into)
+	}
+
 	fn subscribe_runtime_version(&self, pending: PendingSubscriptionSink) {
 		self.backend.subscribe_runtime_version(pending)
 	}
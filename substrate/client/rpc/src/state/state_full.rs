@@ -30,13 +30,21 @@ use crate::{
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
 
+use codec::Encode;
 use futures::{future, stream, StreamExt};
 use jsonrpsee::{core::async_trait, types::ErrorObject, PendingSubscriptionSink};
+use parking_lot::Mutex;
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ExecutorProvider, ProofProvider,
 	StorageProvider,
 };
-use sc_rpc_api::state::ReadProof;
+use sc_rpc_api::state::{
+	ExecutionTraceFormat, ExecutionTraceResponse, ReadProof, ViewFunctionArgs,
+};
+use sc_runtime_utilities::view_functions::{
+	self, ViewFunctions, EXECUTE_VIEW_FUNCTION, VIEW_FUNCTIONS_METADATA_VERSION,
+};
+use schnellru::{ByLength, LruMap};
 use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
 use sp_blockchain::{
 	CachedHeaderMetadata, Error as ClientError, HeaderBackend, HeaderMetadata,
@@ -44,7 +52,8 @@ use sp_blockchain::{
 };
 use sp_core::{
 	storage::{
-		ChildInfo, ChildType, PrefixedStorageKey, StorageChangeSet, StorageData, StorageKey,
+		well_known_keys, ChildInfo, ChildType, PrefixedStorageKey, StorageChangeSet, StorageData,
+		StorageKey,
 	},
 	traits::CallContext,
 	Bytes,
@@ -55,6 +64,9 @@ use sp_version::RuntimeVersion;
 /// The maximum time allowed for an RPC call when running without unsafe RPC enabled.
 const MAXIMUM_SAFE_RPC_CALL_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// The number of runtimes whose view functions are kept in memory.
+const VIEW_FUNCTIONS_CACHE_SIZE: u32 = 4;
+
 /// Ranges to query in state_queryStorage.
 struct QueryStorageRange<Block: BlockT> {
 	/// Hashes of all the blocks in the range.
@@ -65,6 +77,8 @@ struct QueryStorageRange<Block: BlockT> {
 pub struct FullState<BE, Block: BlockT, Client> {
 	client: Arc<Client>,
 	executor: SubscriptionTaskExecutor,
+	/// The view functions of the recently used runtimes, by hash of their code.
+	view_functions: Mutex<LruMap<Block::Hash, Arc<ViewFunctions>>>,
 	_phantom: PhantomData<(BE, Block)>,
 }
 
@@ -79,7 +93,12 @@ where
 {
 	/// Create new state API backend for full nodes.
 	pub fn new(client: Arc<Client>, executor: SubscriptionTaskExecutor) -> Self {
-		Self { client, executor, _phantom: PhantomData }
+		Self {
+			client,
+			executor,
+			view_functions: Mutex::new(LruMap::new(ByLength::new(VIEW_FUNCTIONS_CACHE_SIZE))),
+			_phantom: PhantomData,
+		}
 	}
 
 	/// Returns given block hash or best block hash if None is passed.
@@ -168,6 +187,49 @@ where
 	}
 }
 
+impl<BE, Block, Client> FullState<BE, Block, Client>
+where
+	BE: Backend<Block>,
+	Client: StorageProvider<Block, BE>
+		+ HeaderBackend<Block>
+		+ BlockBackend<Block>
+		+ HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ ProvideRuntimeApi<Block>,
+	Client::Api: Metadata<Block>,
+	Block: BlockT + 'static,
+{
+	/// Returns the view functions of the runtime at `block`.
+	///
+	/// Decoding the metadata is expensive, so the view functions are cached by code hash.
+	fn view_functions_at(&self, block: Block::Hash) -> Result<Arc<ViewFunctions>> {
+		let code_hash = self
+			.client
+			.storage_hash(block, &StorageKey(well_known_keys::CODE.to_vec()))
+			.map_err(client_err)?
+			.ok_or_else(|| client_err(ClientError::RuntimeCodeMissing))?;
+		if let Some(view_functions) = self.view_functions.lock().get(&code_hash) {
+			return Ok(view_functions.clone())
+		}
+
+		let metadata = self
+			.client
+			.runtime_api()
+			.metadata_at_version(block, VIEW_FUNCTIONS_METADATA_VERSION)
+			.map_err(|e| Error::Client(Box::new(e)))?
+			.ok_or_else(|| {
+				Error::ViewFunction(format!(
+					"The runtime does not provide the metadata at version {}",
+					VIEW_FUNCTIONS_METADATA_VERSION
+				))
+			})?;
+		let view_functions =
+			Arc::new(ViewFunctions::from_metadata(&metadata).map_err(view_function_err)?);
+		self.view_functions.lock().insert(code_hash, view_functions.clone());
+
+		Ok(view_functions)
+	}
+}
+
 #[async_trait]
 impl<BE, Block, Client> StateBackend<Block, Client> for FullState<BE, Block, Client>
 where
@@ -204,6 +266,35 @@ where
 			.map_err(client_err)
 	}
 
+	fn call_view_function(
+		&self,
+		block: Option<Block::Hash>,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+	) -> std::result::Result<serde_json::Value, Error> {
+		let block = self.block_or_best(block).map_err(client_err)?;
+		let view_functions = self.view_functions_at(block)?;
+		let view_function =
+			view_functions.view_function(&pallet, &function).map_err(view_function_err)?;
+		let call_data = match &args {
+			ViewFunctionArgs::Scale(args) =>
+				view_function.call_data(view_functions::ViewFunctionArgs::Scale(args)),
+			ViewFunctionArgs::Json(args) =>
+				view_function.call_data(view_functions::ViewFunctionArgs::Json(args)),
+		}
+		.map_err(view_function_err)?;
+
+		let output = self
+			.client
+			.executor()
+			.call(block, EXECUTE_VIEW_FUNCTION, &call_data, CallContext::Offchain)
+			.map_err(client_err)?;
+		let result = view_function.decode_output(&output).map_err(view_function_err)?;
+
+		view_function.result_to_json(&result).map_err(view_function_err)
+	}
+
 	// TODO: This is horribly broken; either remove it, or make it streaming.
 	fn storage_keys(
 		&self,
@@ -483,6 +574,22 @@ where
 		.trace_block()
 		.map_err(|e| invalid_block::<Block>(block, None, e.to_string()))
 	}
+
+	fn trace_block_execution(
+		&self,
+		block: Block::Hash,
+		format: ExecutionTraceFormat,
+	) -> std::result::Result<ExecutionTraceResponse, Error> {
+		let trace =
+			sc_tracing::block::BlockExecutor::new(self.client.clone(), block, None, None, None)
+				.execution_trace()
+				.map_err(|e| invalid_block::<Block>(block, None, e.to_string()))?;
+
+		Ok(match format {
+			ExecutionTraceFormat::Json => ExecutionTraceResponse::Json(trace),
+			ExecutionTraceFormat::Binary => ExecutionTraceResponse::Binary(trace.encode().into()),
+		})
+	}
 }
 
 impl<BE, Block, Client> ChildStateBackend<Block, Client> for FullState<BE, Block, Client>
@@ -647,3 +754,7 @@ fn invalid_block_range<B: BlockT>(
 fn invalid_block<B: BlockT>(from: B::Hash, to: Option<B::Hash>, details: String) -> Error {
 	Error::InvalidBlockRange { from: format!("{:?}", from), to: format!("{:?}", to), details }
 }
+
+fn view_function_err(err: sc_runtime_utilities::error::Error) -> Error {
+	Error::ViewFunction(err.to_string())
+}
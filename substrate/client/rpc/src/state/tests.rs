@@ -24,7 +24,11 @@ use futures::executor;
 use jsonrpsee::{core::EmptyServerParams as EmptyParams, MethodsError as RpcError};
 use sc_block_builder::BlockBuilderBuilder;
 use sp_consensus::BlockOrigin;
-use sp_core::{hash::H256, storage::ChildInfo};
+use sp_core::{
+	hash::H256,
+	storage::{well_known_keys, ChildInfo},
+};
+use sp_state_machine::TraceOp;
 use std::sync::Arc;
 use substrate_test_runtime_client::{
 	prelude::*,
@@ -207,6 +211,23 @@ async fn should_call_contract() {
 	)
 }
 
+#[tokio::test]
+async fn should_report_unknown_view_function() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (client, _child) = new_full(client, test_executor());
+
+	assert_matches!(
+		client.call_view_function(
+			"NotAPallet".into(),
+			"not_a_function".into(),
+			ViewFunctionArgs::Json(vec![]),
+			Some(genesis_hash),
+		),
+		Err(Error::ViewFunction(_))
+	)
+}
+
 #[tokio::test]
 async fn should_notify_about_storage_changes() {
 	let mut sub = {
@@ -538,3 +559,57 @@ async fn concrete_storage_subscriptions_are_rpc_safe() {
 
 	assert!(sub.is_ok());
 }
+
+#[tokio::test]
+async fn should_trace_block_execution() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let (api, _child) = new_full(client.clone(), test_executor());
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().best_hash)
+		.with_parent_block_number(client.chain_info().best_number)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	let hash = block.header.hash();
+	client.import(BlockOrigin::Own, block).await.unwrap();
+
+	let ext = allow_unsafe();
+	let trace = match api.trace_block_execution(&ext, hash, None) {
+		Ok(ExecutionTraceResponse::Json(trace)) => trace,
+		other => panic!("Unexpected response: {:?}", other),
+	};
+	assert!(trace.extrinsic(0).next().is_some());
+	// Initializing the block, which also sets the extrinsic index to 0, is attributed to no
+	// extrinsic, as is finalizing it.
+	let extrinsic_index = sp_core::Bytes(well_known_keys::EXTRINSIC_INDEX.to_vec());
+	let initialized = trace
+		.entries
+		.iter()
+		.position(|e| matches!(&e.op, TraceOp::Put { key, .. } if *key == extrinsic_index))
+		.unwrap();
+	assert!(trace.entries[..=initialized].iter().all(|e| e.extrinsic.is_none()));
+	assert_eq!(trace.entries.last().unwrap().extrinsic, None);
+
+	// The execution is deterministic, so tracing again yields the same trace.
+	let binary = match api.trace_block_execution(&ext, hash, Some(ExecutionTraceFormat::Binary)) {
+		Ok(ExecutionTraceResponse::Binary(binary)) => binary,
+		other => panic!("Unexpected response: {:?}", other),
+	};
+	assert_eq!(binary.0, codec::Encode::encode(&trace));
+
+	let mut api_rpc = api.into_rpc();
+	api_rpc.extensions_mut().insert(DenyUnsafe::Yes);
+	let err = api_rpc
+		.call::<_, ExecutionTraceResponse>("state_traceBlockExecution", [hash])
+		.await;
+	assert_matches!(err, Err(RpcError::JsonRpc(e)) if e.message() == "RPC call is unsafe to be called externally");
+}
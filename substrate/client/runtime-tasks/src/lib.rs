@@ -0,0 +1,478 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Node side execution of FRAME tasks.
+//!
+//! Pallets can declare idempotent service work as tasks, which anyone can execute through
+//! `frame_system::Call::do_task`. This crate discovers the currently valid tasks through the
+//! [`TasksApi`] runtime api and gets them executed:
+//!
+//! - [`TaskSubmitter`] submits them to the transaction pool whenever a new best block is imported,
+//!   skipping the tasks that are still waiting in the pool.
+//! - [`TaskFiller`] is an [`InclusionPolicy`] for the `sc-basic-authorship` proposer, filling the
+//!   block space left once the transaction pool is drained with tasks. Given the [`SubmittedTasks`]
+//!   of a [`TaskSubmitter`], it skips the tasks already in the pool.
+//!
+//! The extrinsics executing the tasks are created by a [`TaskExtrinsicBuilder`]. [`Unsigned`]
+//! creates unsigned extrinsics through the runtime api; signed extrinsics can be created by
+//! providing a closure signing the `do_task` call with a key of the node.
+
+use codec::{Codec, Encode};
+use futures::StreamExt;
+use log::{debug, trace, warn};
+use parking_lot::Mutex;
+use sc_basic_authorship::{
+	inclusion_policy::{Candidate, InclusionDecision},
+	InclusionPolicy,
+};
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TransactionSource, TxHash};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_runtime::traits::{
+	BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT, NumberFor, Zero,
+};
+use sp_weights::Weight;
+use std::{
+	collections::{HashMap, HashSet},
+	marker::PhantomData,
+	sync::Arc,
+};
+
+pub use frame_system_rpc_runtime_api::TasksApi;
+
+const LOG_TARGET: &str = "runtime-tasks";
+
+/// Errors of the task execution.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Calling the runtime api failed.
+	#[error(transparent)]
+	RuntimeApi(#[from] ApiError),
+	/// The extrinsic executing a task could not be created.
+	#[error("Failed to create the task extrinsic: {0}")]
+	Builder(String),
+}
+
+/// Creates the extrinsics executing tasks.
+pub trait TaskExtrinsicBuilder<Block: BlockT, Task>: Send + Sync {
+	/// Create the extrinsic executing `task` on top of the block `at`.
+	fn build(&self, at: Block::Hash, task: Task) -> Result<Block::Extrinsic, Error>;
+}
+
+impl<Block, Task, F> TaskExtrinsicBuilder<Block, Task> for F
+where
+	Block: BlockT,
+	F: Fn(Block::Hash, Task) -> Result<Block::Extrinsic, Error> + Send + Sync,
+{
+	fn build(&self, at: Block::Hash, task: Task) -> Result<Block::Extrinsic, Error> {
+		self(at, task)
+	}
+}
+
+/// Creates unsigned extrinsics through [`TasksApi::task_extrinsic`].
+pub struct Unsigned<C> {
+	client: Arc<C>,
+}
+
+impl<C> Unsigned<C> {
+	/// Create a new instance calling the runtime through `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> Clone for Unsigned<C> {
+	fn clone(&self) -> Self {
+		Self { client: self.client.clone() }
+	}
+}
+
+impl<Block, Task, C> TaskExtrinsicBuilder<Block, Task> for Unsigned<C>
+where
+	Block: BlockT,
+	Task: Codec,
+	C: ProvideRuntimeApi<Block> + Send + Sync,
+	C::Api: TasksApi<Block, Task>,
+{
+	fn build(&self, at: Block::Hash, task: Task) -> Result<Block::Extrinsic, Error> {
+		Ok(self.client.runtime_api().task_extrinsic(at, task)?)
+	}
+}
+
+/// The hash identifying a task, independently of the extrinsic executing it.
+pub type TaskHash = <BlakeTwo256 as HashT>::Output;
+
+fn task_hash<Task: Encode>(task: &Task) -> TaskHash {
+	BlakeTwo256::hash_of(task)
+}
+
+/// Fetch at most `max` tasks valid at `at`, together with their weight.
+fn valid_tasks<Block, Task, C>(
+	client: &C,
+	at: Block::Hash,
+	max: usize,
+) -> Result<Vec<(Task, Weight)>, Error>
+where
+	Block: BlockT,
+	Task: Codec,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: TasksApi<Block, Task>,
+{
+	Ok(client.runtime_api().valid_tasks(at, max.try_into().unwrap_or(u32::MAX))?)
+}
+
+/// The tasks a [`TaskSubmitter`] submitted and that are still in the transaction pool.
+///
+/// Cloning is cheap and all the clones share the same set.
+#[derive(Clone, Default)]
+pub struct SubmittedTasks(Arc<Mutex<HashSet<TaskHash>>>);
+
+impl SubmittedTasks {
+	/// Whether the task with the given hash is waiting in the pool.
+	pub fn contains(&self, task: &TaskHash) -> bool {
+		self.0.lock().contains(task)
+	}
+}
+
+/// Parameters of the [`TaskSubmitter`].
+pub struct TaskSubmitterParams<C, P, B> {
+	/// The client used to call the runtime and to follow the chain.
+	pub client: Arc<C>,
+	/// The transaction pool the tasks are submitted to.
+	pub transaction_pool: Arc<P>,
+	/// Creates the extrinsics executing the tasks.
+	pub builder: B,
+	/// Submit the tasks every `interval` best blocks.
+	pub interval: u32,
+	/// The maximum number of tasks submitted at once.
+	pub max_tasks: usize,
+}
+
+/// Submits the valid tasks of the runtime to the transaction pool.
+///
+/// A task is only submitted again once the extrinsic previously submitted for it left the pool,
+/// i.e. was included in a block or dropped.
+pub struct TaskSubmitter<Block: BlockT, Task, C, P: TransactionPool, B> {
+	client: Arc<C>,
+	transaction_pool: Arc<P>,
+	builder: B,
+	interval: u32,
+	max_tasks: usize,
+	/// The pool hash of the extrinsic submitted for every task.
+	submitted: HashMap<TaskHash, TxHash<P>>,
+	/// The keys of `submitted`, shared with the [`TaskFiller`].
+	shared: SubmittedTasks,
+	_phantom: PhantomData<fn() -> (Block, Task)>,
+}
+
+impl<Block, Task, C, P, B> TaskSubmitter<Block, Task, C, P, B>
+where
+	Block: BlockT,
+	Task: Codec,
+	C: ProvideRuntimeApi<Block> + BlockchainEvents<Block>,
+	C::Api: TasksApi<Block, Task>,
+	P: TransactionPool<Block = Block>,
+	B: TaskExtrinsicBuilder<Block, Task>,
+{
+	/// Create a new instance.
+	pub fn new(params: TaskSubmitterParams<C, P, B>) -> Self {
+		let TaskSubmitterParams { client, transaction_pool, builder, interval, max_tasks } = params;
+
+		Self {
+			client,
+			transaction_pool,
+			builder,
+			interval: interval.max(1),
+			max_tasks,
+			submitted: HashMap::new(),
+			shared: SubmittedTasks::default(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// The tasks submitted by this instance that are still in the pool.
+	pub fn submitted_tasks(&self) -> SubmittedTasks {
+		self.shared.clone()
+	}
+
+	/// Run the submitter, following the imported best blocks.
+	///
+	/// This future never ends and is meant to be spawned as an essential task of the node.
+	pub async fn run(mut self) {
+		let mut imports = self.client.import_notification_stream();
+		let interval: NumberFor<Block> = self.interval.into();
+
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue
+			}
+
+			let number = *notification.header.number();
+			if !(number % interval).is_zero() {
+				continue
+			}
+
+			match self.submit_at(notification.hash).await {
+				Ok(submitted) if submitted > 0 =>
+					debug!(target: LOG_TARGET, "Submitted {} tasks at {:?}", submitted, number),
+				Ok(_) => {},
+				Err(e) =>
+					warn!(target: LOG_TARGET, "Failed to submit tasks at {:?}: {}", number, e),
+			}
+		}
+	}
+
+	/// Submit the tasks valid at `at` that are not yet in the transaction pool.
+	///
+	/// Returns the number of submitted tasks.
+	pub async fn submit_at(&mut self, at: Block::Hash) -> Result<usize, Error> {
+		let pool = &self.transaction_pool;
+		// Extrinsics waiting for others, e.g. with a future nonce, are still in the pool.
+		let futures: HashSet<_> = pool.futures().iter().map(|tx| tx.hash().clone()).collect();
+		self.submitted
+			.retain(|_, hash| futures.contains(hash) || pool.ready_transaction(hash).is_some());
+
+		// The tasks still in the pool are likely valid as well, so fetch enough to find new ones.
+		let max = self.max_tasks.saturating_add(self.submitted.len());
+		let tasks = valid_tasks::<Block, Task, _>(&*self.client, at, max)?;
+
+		let mut submitted = 0;
+		for (task, _) in tasks {
+			if submitted >= self.max_tasks {
+				break
+			}
+
+			let key = task_hash(&task);
+			if self.submitted.contains_key(&key) {
+				trace!(target: LOG_TARGET, "Task {:?} is still in the pool", key);
+				continue
+			}
+
+			let extrinsic = self.builder.build(at, task)?;
+			match pool.submit_one(at, TransactionSource::Local, extrinsic).await {
+				Ok(hash) => {
+					self.submitted.insert(key, hash);
+					submitted += 1;
+				},
+				Err(e) => debug!(target: LOG_TARGET, "Task {:?} was rejected: {}", key, e),
+			}
+		}
+
+		*self.shared.0.lock() = self.submitted.keys().copied().collect();
+		Ok(submitted)
+	}
+}
+
+/// Fills the space left in a block with tasks.
+///
+/// Implements [`InclusionPolicy`] without restricting the transactions from the pool, use a tuple
+/// to combine it with other policies. Once the pool is drained, the tasks valid at the parent
+/// block are added, lightest first.
+///
+/// A task is not added if the same extrinsic was already taken from the pool, or if it was
+/// submitted by the [`TaskSubmitter`] given with [`Self::with_submitted_tasks`] and is still in
+/// the pool.
+pub struct TaskFiller<Block, Task, C, B> {
+	client: Arc<C>,
+	builder: B,
+	max_tasks: usize,
+	submitted: SubmittedTasks,
+	_phantom: PhantomData<fn() -> (Block, Task)>,
+}
+
+impl<Block, Task, C, B> TaskFiller<Block, Task, C, B> {
+	/// Create a new instance adding at most `max_tasks` tasks to every block.
+	pub fn new(client: Arc<C>, builder: B, max_tasks: usize) -> Self {
+		Self {
+			client,
+			builder,
+			max_tasks,
+			submitted: SubmittedTasks::default(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Skip the tasks that `submitted` are still in the pool.
+	pub fn with_submitted_tasks(mut self, submitted: SubmittedTasks) -> Self {
+		self.submitted = submitted;
+		self
+	}
+}
+
+impl<Block, Task, C, B> InclusionPolicy<Block> for TaskFiller<Block, Task, C, B>
+where
+	Block: BlockT,
+	Task: Codec + 'static,
+	C: ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	C::Api: TasksApi<Block, Task>,
+	B: TaskExtrinsicBuilder<Block, Task> + 'static,
+{
+	/// The hashes of the extrinsics taken from the pool for the block.
+	type State = HashSet<Block::Hash>;
+
+	fn check(&self, seen: &mut Self::State, candidate: &Candidate<Block>) -> InclusionDecision {
+		seen.insert(<<Block::Header as HeaderT>::Hashing as HashT>::hash_of(candidate.extrinsic));
+		InclusionDecision::Include
+	}
+
+	fn fill_block(
+		&self,
+		seen: &mut Self::State,
+		parent_hash: Block::Hash,
+	) -> Vec<Block::Extrinsic> {
+		let max = self.max_tasks.saturating_add(self.submitted.0.lock().len());
+		let mut tasks = match valid_tasks::<Block, Task, _>(&*self.client, parent_hash, max) {
+			Ok(tasks) => tasks,
+			Err(e) => {
+				warn!(target: LOG_TARGET, "Failed to fetch the tasks at {:?}: {}", parent_hash, e);
+				return Vec::new()
+			},
+		};
+		tasks.sort_by_key(|(_, weight)| (weight.ref_time(), weight.proof_size()));
+
+		let mut tasks_seen = HashSet::new();
+		tasks
+			.into_iter()
+			.filter(|(task, _)| {
+				let hash = task_hash(task);
+				tasks_seen.insert(hash) && !self.submitted.contains(&hash)
+			})
+			.filter_map(|(task, _)| match self.builder.build(parent_hash, task) {
+				Ok(extrinsic) => Some(extrinsic),
+				Err(e) => {
+					debug!(target: LOG_TARGET, "Failed to create a task extrinsic: {}", e);
+					None
+				},
+			})
+			.filter(|extrinsic| {
+				seen.insert(<<Block::Header as HeaderT>::Hashing as HashT>::hash_of(extrinsic))
+			})
+			.take(self.max_tasks)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_api::ApiRef;
+	use substrate_test_runtime_client::runtime::{Block, Extrinsic, ExtrinsicBuilder, Hash};
+
+	#[derive(Clone)]
+	struct TestApi {
+		tasks: Vec<(u64, Weight)>,
+	}
+
+	struct RuntimeApi {
+		inner: TestApi,
+	}
+
+	impl ProvideRuntimeApi<Block> for TestApi {
+		type Api = RuntimeApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			RuntimeApi { inner: self.clone() }.into()
+		}
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl TasksApi<Block, u64> for RuntimeApi {
+			fn valid_tasks(&self, max: u32) -> Vec<(u64, Weight)> {
+				self.inner.tasks.iter().take(max as usize).cloned().collect()
+			}
+
+			fn task_extrinsic(task: u64) -> Extrinsic {
+				ExtrinsicBuilder::new_include_data(task.encode()).build()
+			}
+		}
+	}
+
+	#[test]
+	fn task_filler_adds_lightest_tasks_first() {
+		let client = Arc::new(TestApi {
+			tasks: vec![
+				(1, Weight::from_parts(30, 0)),
+				(2, Weight::from_parts(10, 0)),
+				(3, Weight::from_parts(20, 0)),
+			],
+		});
+		let filler = TaskFiller::<Block, u64, _, _>::new(client.clone(), Unsigned::new(client), 2);
+
+		let extrinsics = filler.fill_block(&mut Default::default(), Hash::default());
+
+		assert_eq!(
+			extrinsics,
+			vec![
+				ExtrinsicBuilder::new_include_data(2u64.encode()).build(),
+				ExtrinsicBuilder::new_include_data(3u64.encode()).build(),
+			]
+		);
+	}
+
+	#[test]
+	fn closures_can_build_task_extrinsics() {
+		let client = Arc::new(TestApi { tasks: vec![(7, Weight::zero())] });
+		let builder = |_: Hash, task: u64| -> Result<Extrinsic, Error> {
+			Ok(ExtrinsicBuilder::new_include_data(vec![task as u8]).nonce(task).build())
+		};
+		let filler = TaskFiller::<Block, u64, _, _>::new(client, builder, 10);
+
+		assert_eq!(
+			filler.fill_block(&mut Default::default(), Hash::default()),
+			vec![ExtrinsicBuilder::new_include_data(vec![7]).nonce(7).build()]
+		);
+	}
+
+	#[test]
+	fn task_filler_skips_duplicates() {
+		let client = Arc::new(TestApi {
+			tasks: vec![
+				(1, Weight::from_parts(10, 0)),
+				(1, Weight::from_parts(10, 0)),
+				(2, Weight::from_parts(20, 0)),
+				(3, Weight::from_parts(30, 0)),
+				(4, Weight::from_parts(40, 0)),
+			],
+		});
+		let submitted = SubmittedTasks::default();
+		submitted.0.lock().insert(task_hash(&3u64));
+		let filler = TaskFiller::<Block, u64, _, _>::new(client.clone(), Unsigned::new(client), 10)
+			.with_submitted_tasks(submitted);
+
+		// The extrinsic of task 2 was already taken from the pool.
+		let mut state = Default::default();
+		let in_pool = ExtrinsicBuilder::new_include_data(2u64.encode()).build();
+		let candidate = Candidate {
+			extrinsic: &in_pool,
+			priority: 0,
+			requires: &[],
+			provides: &[],
+			encoded_size: in_pool.encoded_size(),
+			block_size: 0,
+			block_size_limit: usize::MAX,
+		};
+		assert_eq!(filler.check(&mut state, &candidate), InclusionDecision::Include);
+
+		assert_eq!(
+			filler.fill_block(&mut state, Hash::default()),
+			vec![
+				ExtrinsicBuilder::new_include_data(1u64.encode()).build(),
+				ExtrinsicBuilder::new_include_data(4u64.encode()).build(),
+			]
+		);
+	}
+}
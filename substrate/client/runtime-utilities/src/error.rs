@@ -32,4 +32,18 @@ pub enum Error {
 	StableMetadataVersionNotFound,
 	#[error("WASM executor error: {0}")]
 	Executor(#[from] sc_executor_common::error::Error),
+	#[error("Metadata version {0} is not supported")]
+	UnsupportedMetadataVersion(u32),
+	#[error("Pallet `{0}` not found")]
+	PalletNotFound(String),
+	#[error("View function `{0}::{1}` not found")]
+	ViewFunctionNotFound(String, String),
+	#[error("Invalid view function arguments: {0}")]
+	InvalidArguments(String),
+	#[error("View function failed: {0}")]
+	ViewFunctionDispatch(String),
+	#[error("Type {0} not found in the metadata")]
+	TypeNotFound(u32),
+	#[error("Type {0} is not supported")]
+	UnsupportedType(u32),
 }
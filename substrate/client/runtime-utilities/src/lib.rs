@@ -34,6 +34,7 @@ use sp_wasm_interface::HostFunctions;
 use std::borrow::Cow;
 
 pub mod error;
+pub mod view_functions;
 
 /// Fetches the latest metadata from the given runtime blob.
 pub fn fetch_latest_metadata_from_code_blob<HF: HostFunctions>(
@@ -122,8 +123,12 @@ impl<'a, 'b, HF: HostFunctions> RuntimeCaller<'a, 'b, HF> {
 
 #[cfg(test)]
 mod tests {
+	use super::view_functions::{
+		ViewFunctionArgs, ViewFunctions, EXECUTE_VIEW_FUNCTION, VIEW_FUNCTIONS_METADATA_VERSION,
+	};
 	use codec::Decode;
 	use sc_executor::WasmExecutor;
+	use sp_core::OpaqueMetadata;
 	use sp_version::RuntimeVersion;
 
 	type ParachainHostFunctions = (
@@ -159,4 +164,35 @@ mod tests {
 		let _runtime_version: RuntimeVersion = Decode::decode(&mut runtime_version.as_slice())
 			.expect("Should be able to decode runtime version");
 	}
+
+	#[test]
+	fn test_view_function_can_be_called_by_name() {
+		let executor: WasmExecutor<sp_io::SubstrateHostFunctions> = WasmExecutor::builder().build();
+		let code_bytes = kitchensink_runtime::WASM_BINARY
+			.expect("To run this test, build the wasm binary of kitchensink-runtime")
+			.to_vec();
+		let runtime_caller = super::RuntimeCaller::new(&executor, code_bytes.into());
+
+		let metadata = runtime_caller
+			.call("Metadata_metadata_at_version", VIEW_FUNCTIONS_METADATA_VERSION)
+			.unwrap();
+		let metadata = Option::<OpaqueMetadata>::decode(&mut metadata.as_slice())
+			.unwrap()
+			.expect("kitchensink-runtime provides the metadata at version 16");
+		let view_functions = ViewFunctions::from_metadata(&metadata).unwrap();
+		let view_function = view_functions.view_function("Proxy", "is_superset").unwrap();
+
+		let call = |args: serde_json::Value| {
+			let args = view_function
+				.encode_args(ViewFunctionArgs::Json(args.as_array().unwrap()))
+				.unwrap();
+			let output =
+				runtime_caller.call(EXECUTE_VIEW_FUNCTION, (view_function.id(), args)).unwrap();
+			let result = view_function.decode_output(&output).unwrap();
+			view_function.result_to_json(&result).unwrap()
+		};
+
+		assert_eq!(call(serde_json::json!(["Any", "Staking"])), serde_json::json!(true));
+		assert_eq!(call(serde_json::json!(["Staking", "Any"])), serde_json::json!(false));
+	}
 }
@@ -0,0 +1,575 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Calling the view functions of FRAME pallets by name.
+//!
+//! The view functions of a runtime are described in its metadata at version 16, which is the only
+//! version supported for now. The
+//! [`ViewFunctions`] resolve a view function from its pallet and function name, encode its
+//! arguments given either as SCALE or as JSON, and decode its result into JSON, using the type
+//! information of the metadata.
+//!
+//! JSON values are mapped to SCALE types as follows:
+//! - structs with named fields are objects, structs with a single unnamed field are the value of
+//!   the field and other structs and tuples are arrays;
+//! - enum variants without fields are strings, other variants are objects with the name of the
+//!   variant as single key;
+//! - byte sequences and arrays are hex strings, other sequences and arrays are arrays;
+//! - 128 bit integers that do not fit into a JSON number are decimal strings.
+
+use crate::error::{Error, Result};
+use codec::{Compact, Decode, Encode};
+use frame_metadata::{
+	v16::{PalletViewFunctionMetadata, RuntimeMetadataV16},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{Map, Value};
+use sp_core::bytes::{from_hex, to_hex};
+
+/// The runtime api method executing view functions.
+pub const EXECUTE_VIEW_FUNCTION: &str = "RuntimeViewFunction_execute_view_function";
+
+/// The metadata version describing the view functions.
+pub const VIEW_FUNCTIONS_METADATA_VERSION: u32 = 16;
+
+/// The arguments passed to a view function.
+#[derive(Debug, Clone, Copy)]
+pub enum ViewFunctionArgs<'a> {
+	/// The SCALE encoded arguments, concatenated.
+	Scale(&'a [u8]),
+	/// One JSON value per argument.
+	Json(&'a [Value]),
+}
+
+/// Mirrors `frame_support::view_functions::ViewFunctionDispatchError`.
+#[derive(Decode)]
+enum DispatchError {
+	NotImplemented,
+	NotFound([u8; 32]),
+	Codec,
+}
+
+/// The view functions of a runtime, as described by its metadata.
+pub struct ViewFunctions {
+	metadata: RuntimeMetadataV16,
+}
+
+impl ViewFunctions {
+	/// Create an instance from the SCALE encoded `RuntimeMetadataPrefixed` of a runtime.
+	///
+	/// Only metadata at [`VIEW_FUNCTIONS_METADATA_VERSION`] is supported.
+	pub fn from_metadata(mut encoded: &[u8]) -> Result<Self> {
+		let RuntimeMetadataPrefixed(_, metadata) = RuntimeMetadataPrefixed::decode(&mut encoded)?;
+
+		match metadata {
+			RuntimeMetadata::V16(metadata) => Ok(Self { metadata }),
+			other => Err(Error::UnsupportedMetadataVersion(other.version())),
+		}
+	}
+
+	/// Find the view function `function` of the pallet `pallet`.
+	pub fn view_function(&self, pallet: &str, function: &str) -> Result<ViewFunction<'_>> {
+		let metadata = self
+			.metadata
+			.pallets
+			.iter()
+			.find(|p| p.name == pallet)
+			.ok_or_else(|| Error::PalletNotFound(pallet.into()))?
+			.view_functions
+			.iter()
+			.find(|f| f.name == function)
+			.ok_or_else(|| Error::ViewFunctionNotFound(pallet.into(), function.into()))?;
+
+		Ok(ViewFunction { types: &self.metadata.types, metadata })
+	}
+}
+
+/// A view function resolved by [`ViewFunctions::view_function`].
+pub struct ViewFunction<'a> {
+	types: &'a PortableRegistry,
+	metadata: &'a PalletViewFunctionMetadata<PortableForm>,
+}
+
+impl ViewFunction<'_> {
+	/// The id of the view function.
+	pub fn id(&self) -> [u8; 32] {
+		self.metadata.id
+	}
+
+	/// Encode the arguments of the view function.
+	///
+	/// SCALE arguments are checked to decode as the expected types.
+	pub fn encode_args(&self, args: ViewFunctionArgs) -> Result<Vec<u8>> {
+		let inputs = &self.metadata.inputs;
+
+		match args {
+			ViewFunctionArgs::Scale(bytes) => {
+				let mut input = bytes;
+				for param in inputs {
+					decode_value(self.types, param.ty.id, &mut input)?;
+				}
+				if !input.is_empty() {
+					return Err(Error::InvalidArguments("trailing bytes".into()))
+				}
+				Ok(bytes.to_vec())
+			},
+			ViewFunctionArgs::Json(values) => {
+				if values.len() != inputs.len() {
+					return Err(Error::InvalidArguments(format!(
+						"expected {} arguments, got {}",
+						inputs.len(),
+						values.len()
+					)))
+				}
+				let mut out = Vec::new();
+				for (param, value) in inputs.iter().zip(values) {
+					encode_value(self.types, param.ty.id, value, &mut out)?;
+				}
+				Ok(out)
+			},
+		}
+	}
+
+	/// Encode the data passed to [`EXECUTE_VIEW_FUNCTION`] to call the view function.
+	pub fn call_data(&self, args: ViewFunctionArgs) -> Result<Vec<u8>> {
+		Ok((self.id(), self.encode_args(args)?).encode())
+	}
+
+	/// Decode the data returned by [`EXECUTE_VIEW_FUNCTION`] into the SCALE encoded result of
+	/// the view function.
+	pub fn decode_output(&self, mut output: &[u8]) -> Result<Vec<u8>> {
+		core::result::Result::<Vec<u8>, DispatchError>::decode(&mut output)?.map_err(|e| {
+			Error::ViewFunctionDispatch(match e {
+				DispatchError::NotImplemented => "view functions are not implemented".into(),
+				DispatchError::NotFound(id) =>
+					format!("unknown view function {}", to_hex(&id, false)),
+				DispatchError::Codec => "failed to decode the arguments".into(),
+			})
+		})
+	}
+
+	/// Decode the SCALE encoded result of the view function into JSON.
+	pub fn result_to_json(&self, mut result: &[u8]) -> Result<Value> {
+		let value = decode_value(self.types, self.metadata.output.id, &mut result)?;
+		if !result.is_empty() {
+			return Err(Error::ScaleCodec("trailing bytes in the view function result".into()))
+		}
+		Ok(value)
+	}
+}
+
+fn invalid(ty: u32, value: &Value) -> Error {
+	Error::InvalidArguments(format!("{} is not a valid value of type {}", value, ty))
+}
+
+fn resolve(types: &PortableRegistry, ty: u32) -> Result<&TypeDef<PortableForm>> {
+	types.resolve(ty).map(|t| &t.type_def).ok_or(Error::TypeNotFound(ty))
+}
+
+fn is_u8(types: &PortableRegistry, ty: u32) -> bool {
+	matches!(resolve(types, ty), Ok(TypeDef::Primitive(TypeDefPrimitive::U8)))
+}
+
+fn as_u128(value: &Value) -> Option<u128> {
+	match value {
+		Value::Number(n) => n.as_u64().map(Into::into),
+		Value::String(s) => s.parse().ok(),
+		_ => None,
+	}
+}
+
+fn as_i128(value: &Value) -> Option<i128> {
+	match value {
+		Value::Number(n) => n.as_i64().map(Into::into),
+		Value::String(s) => s.parse().ok(),
+		_ => None,
+	}
+}
+
+/// Encode the JSON `value` as the type `ty`.
+fn encode_value(types: &PortableRegistry, ty: u32, value: &Value, out: &mut Vec<u8>) -> Result<()> {
+	match resolve(types, ty)? {
+		TypeDef::Composite(composite) => encode_fields(types, &composite.fields, ty, value, out),
+		TypeDef::Variant(variants) => {
+			let (name, fields) = match value {
+				Value::String(name) => (name.as_str(), &Value::Null),
+				Value::Object(map) if map.len() == 1 => {
+					let (name, fields) = map.iter().next().expect("map has one entry; qed");
+					(name.as_str(), fields)
+				},
+				_ => return Err(invalid(ty, value)),
+			};
+			let variant = variants
+				.variants
+				.iter()
+				.find(|v| v.name == name)
+				.ok_or_else(|| invalid(ty, value))?;
+			variant.index.encode_to(out);
+			encode_fields(types, &variant.fields, ty, fields, out)
+		},
+		TypeDef::Sequence(sequence) => {
+			let item = sequence.type_param.id;
+			if let (true, Value::String(hex)) = (is_u8(types, item), value) {
+				let bytes = from_hex(hex).map_err(|_| invalid(ty, value))?;
+				bytes.encode_to(out);
+				return Ok(())
+			}
+			let items = value.as_array().ok_or_else(|| invalid(ty, value))?;
+			Compact(items.len() as u32).encode_to(out);
+			items.iter().try_for_each(|v| encode_value(types, item, v, out))
+		},
+		TypeDef::Array(array) => {
+			let item = array.type_param.id;
+			if let (true, Value::String(hex)) = (is_u8(types, item), value) {
+				let bytes = from_hex(hex).map_err(|_| invalid(ty, value))?;
+				if bytes.len() != array.len as usize {
+					return Err(invalid(ty, value))
+				}
+				out.extend(bytes);
+				return Ok(())
+			}
+			let items = value.as_array().ok_or_else(|| invalid(ty, value))?;
+			if items.len() != array.len as usize {
+				return Err(invalid(ty, value))
+			}
+			items.iter().try_for_each(|v| encode_value(types, item, v, out))
+		},
+		TypeDef::Tuple(tuple) => match (tuple.fields.len(), value) {
+			(0, Value::Null) => Ok(()),
+			(len, Value::Array(items)) if items.len() == len => tuple
+				.fields
+				.iter()
+				.zip(items)
+				.try_for_each(|(field, v)| encode_value(types, field.id, v, out)),
+			_ => Err(invalid(ty, value)),
+		},
+		TypeDef::Primitive(primitive) => encode_primitive(primitive, ty, value, out),
+		TypeDef::Compact(compact) => {
+			let n = as_u128(value).ok_or_else(|| invalid(ty, value))?;
+			if n > compact_max(types, compact.type_param.id)? {
+				return Err(invalid(ty, value))
+			}
+			Compact(n).encode_to(out);
+			Ok(())
+		},
+		TypeDef::BitSequence(_) => Err(Error::UnsupportedType(ty)),
+	}
+}
+
+/// The largest value of the integer, or wrapper of an integer, encoded as a compact.
+fn compact_max(types: &PortableRegistry, mut inner: u32) -> Result<u128> {
+	loop {
+		match resolve(types, inner)? {
+			TypeDef::Composite(composite) => match &composite.fields[..] {
+				[field] => inner = field.ty.id,
+				_ => return Err(Error::UnsupportedType(inner)),
+			},
+			TypeDef::Primitive(TypeDefPrimitive::U8) => return Ok(u8::MAX.into()),
+			TypeDef::Primitive(TypeDefPrimitive::U16) => return Ok(u16::MAX.into()),
+			TypeDef::Primitive(TypeDefPrimitive::U32) => return Ok(u32::MAX.into()),
+			TypeDef::Primitive(TypeDefPrimitive::U64) => return Ok(u64::MAX.into()),
+			TypeDef::Primitive(TypeDefPrimitive::U128) => return Ok(u128::MAX),
+			TypeDef::Tuple(tuple) if tuple.fields.is_empty() => return Ok(0),
+			_ => return Err(Error::UnsupportedType(inner)),
+		}
+	}
+}
+
+fn encode_fields(
+	types: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	ty: u32,
+	value: &Value,
+	out: &mut Vec<u8>,
+) -> Result<()> {
+	match (fields, value) {
+		([], Value::Null) => Ok(()),
+		([], Value::Array(items)) if items.is_empty() => Ok(()),
+		([field], value) if field.name.is_none() => encode_value(types, field.ty.id, value, out),
+		(fields, Value::Object(map)) if fields.iter().all(|f| f.name.is_some()) =>
+			fields.iter().try_for_each(|field| {
+				let name = field.name.as_deref().expect("all fields are named; qed");
+				let value = map.get(name).ok_or_else(|| {
+					Error::InvalidArguments(format!("missing field `{}` of type {}", name, ty))
+				})?;
+				encode_value(types, field.ty.id, value, out)
+			}),
+		(fields, Value::Array(items)) if items.len() == fields.len() => fields
+			.iter()
+			.zip(items)
+			.try_for_each(|(field, v)| encode_value(types, field.ty.id, v, out)),
+		_ => Err(invalid(ty, value)),
+	}
+}
+
+fn encode_primitive(
+	primitive: &TypeDefPrimitive,
+	ty: u32,
+	value: &Value,
+	out: &mut Vec<u8>,
+) -> Result<()> {
+	let err = || invalid(ty, value);
+	let unsigned = || as_u128(value).ok_or_else(err);
+	let signed = || as_i128(value).ok_or_else(err);
+
+	match primitive {
+		TypeDefPrimitive::Bool => value.as_bool().ok_or_else(err)?.encode_to(out),
+		TypeDefPrimitive::Char => {
+			let mut chars = value.as_str().ok_or_else(err)?.chars();
+			match (chars.next(), chars.next()) {
+				(Some(c), None) => (c as u32).encode_to(out),
+				_ => return Err(err()),
+			}
+		},
+		TypeDefPrimitive::Str => value.as_str().ok_or_else(err)?.encode_to(out),
+		TypeDefPrimitive::U8 => u8::try_from(unsigned()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::U16 => u16::try_from(unsigned()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::U32 => u32::try_from(unsigned()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::U64 => u64::try_from(unsigned()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::U128 => unsigned()?.encode_to(out),
+		TypeDefPrimitive::I8 => i8::try_from(signed()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::I16 => i16::try_from(signed()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::I32 => i32::try_from(signed()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::I64 => i64::try_from(signed()?).map_err(|_| err())?.encode_to(out),
+		TypeDefPrimitive::I128 => signed()?.encode_to(out),
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => return Err(Error::UnsupportedType(ty)),
+	}
+
+	Ok(())
+}
+
+/// Decode a value of the type `ty` into JSON.
+fn decode_value(types: &PortableRegistry, ty: u32, input: &mut &[u8]) -> Result<Value> {
+	Ok(match resolve(types, ty)? {
+		TypeDef::Composite(composite) => decode_fields(types, &composite.fields, input)?,
+		TypeDef::Variant(variants) => {
+			let index = u8::decode(input)?;
+			let variant = variants
+				.variants
+				.iter()
+				.find(|v| v.index == index)
+				.ok_or_else(|| Error::ScaleCodec("invalid variant index".into()))?;
+			if variant.fields.is_empty() {
+				Value::String(variant.name.clone())
+			} else {
+				let fields = decode_fields(types, &variant.fields, input)?;
+				Value::Object(Map::from_iter([(variant.name.clone(), fields)]))
+			}
+		},
+		TypeDef::Sequence(sequence) => {
+			let item = sequence.type_param.id;
+			if is_u8(types, item) {
+				Value::String(to_hex(&Vec::<u8>::decode(input)?, false))
+			} else {
+				let len = Compact::<u32>::decode(input)?.0;
+				(0..len)
+					.map(|_| decode_value(types, item, input))
+					.collect::<Result<Vec<_>>>()?
+					.into()
+			}
+		},
+		TypeDef::Array(array) => {
+			let item = array.type_param.id;
+			if is_u8(types, item) {
+				let mut bytes = vec![0; array.len as usize];
+				codec::Input::read(input, &mut bytes)?;
+				Value::String(to_hex(&bytes, false))
+			} else {
+				(0..array.len)
+					.map(|_| decode_value(types, item, input))
+					.collect::<Result<Vec<_>>>()?
+					.into()
+			}
+		},
+		TypeDef::Tuple(tuple) if tuple.fields.is_empty() => Value::Null,
+		TypeDef::Tuple(tuple) => tuple
+			.fields
+			.iter()
+			.map(|field| decode_value(types, field.id, input))
+			.collect::<Result<Vec<_>>>()?
+			.into(),
+		TypeDef::Primitive(primitive) => decode_primitive(primitive, ty, input)?,
+		TypeDef::Compact(compact) => {
+			let n = Compact::<u128>::decode(input)?.0;
+			if n > compact_max(types, compact.type_param.id)? {
+				return Err(Error::ScaleCodec("compact value out of range".into()))
+			}
+			unsigned_to_json(n)
+		},
+		TypeDef::BitSequence(_) => return Err(Error::UnsupportedType(ty)),
+	})
+}
+
+fn decode_fields(
+	types: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	input: &mut &[u8],
+) -> Result<Value> {
+	Ok(match fields {
+		[] => Value::Null,
+		[field] if field.name.is_none() => decode_value(types, field.ty.id, input)?,
+		fields if fields.iter().all(|f| f.name.is_some()) => fields
+			.iter()
+			.map(|field| {
+				let name = field.name.clone().expect("all fields are named; qed");
+				Ok((name, decode_value(types, field.ty.id, input)?))
+			})
+			.collect::<Result<Map<_, _>>>()?
+			.into(),
+		fields => fields
+			.iter()
+			.map(|field| decode_value(types, field.ty.id, input))
+			.collect::<Result<Vec<_>>>()?
+			.into(),
+	})
+}
+
+fn unsigned_to_json(n: u128) -> Value {
+	u64::try_from(n).map_or_else(|_| n.to_string().into(), Into::into)
+}
+
+fn signed_to_json(n: i128) -> Value {
+	i64::try_from(n).map_or_else(|_| n.to_string().into(), Into::into)
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, ty: u32, input: &mut &[u8]) -> Result<Value> {
+	Ok(match primitive {
+		TypeDefPrimitive::Bool => bool::decode(input)?.into(),
+		TypeDefPrimitive::Char => char::from_u32(u32::decode(input)?)
+			.ok_or_else(|| Error::ScaleCodec("invalid char".into()))?
+			.to_string()
+			.into(),
+		TypeDefPrimitive::Str => String::decode(input)?.into(),
+		TypeDefPrimitive::U8 => u8::decode(input)?.into(),
+		TypeDefPrimitive::U16 => u16::decode(input)?.into(),
+		TypeDefPrimitive::U32 => u32::decode(input)?.into(),
+		TypeDefPrimitive::U64 => u64::decode(input)?.into(),
+		TypeDefPrimitive::U128 => unsigned_to_json(u128::decode(input)?),
+		TypeDefPrimitive::I8 => i8::decode(input)?.into(),
+		TypeDefPrimitive::I16 => i16::decode(input)?.into(),
+		TypeDefPrimitive::I32 => i32::decode(input)?.into(),
+		TypeDefPrimitive::I64 => i64::decode(input)?.into(),
+		TypeDefPrimitive::I128 => signed_to_json(i128::decode(input)?),
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => return Err(Error::UnsupportedType(ty)),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use scale_info::{meta_type, Registry, TypeInfo};
+	use serde_json::json;
+
+	#[derive(Encode, TypeInfo)]
+	enum Status {
+		Idle,
+		Busy(u32),
+		Done { at: u64, note: Option<String> },
+	}
+
+	#[derive(Encode, TypeInfo)]
+	struct Account([u8; 4]);
+
+	#[derive(Encode, TypeInfo)]
+	struct Entry {
+		who: Account,
+		balance: u128,
+		#[codec(compact)]
+		count: u32,
+		data: Vec<u8>,
+		statuses: Vec<Status>,
+		pair: (bool, i16),
+	}
+
+	fn registry<T: TypeInfo + 'static>() -> (PortableRegistry, u32) {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<T>()).id;
+		(registry.into(), id)
+	}
+
+	fn entry() -> (Entry, Value) {
+		let entry = Entry {
+			who: Account([1, 2, 3, 4]),
+			balance: u128::MAX,
+			count: 1_000,
+			data: vec![0xde, 0xad],
+			statuses: vec![
+				Status::Idle,
+				Status::Busy(7),
+				Status::Done { at: 9, note: Some("ok".into()) },
+			],
+			pair: (true, -3),
+		};
+		let json = json!({
+			"who": "0x01020304",
+			"balance": u128::MAX.to_string(),
+			"count": 1_000,
+			"data": "0xdead",
+			"statuses": ["Idle", { "Busy": 7 }, { "Done": { "at": 9, "note": { "Some": "ok" } } }],
+			"pair": [true, -3],
+		});
+		(entry, json)
+	}
+
+	#[test]
+	fn json_values_are_encoded_according_to_the_type() {
+		let (types, id) = registry::<Entry>();
+		let (entry, json) = entry();
+
+		let mut out = Vec::new();
+		encode_value(&types, id, &json, &mut out).unwrap();
+
+		assert_eq!(out, entry.encode());
+	}
+
+	#[test]
+	fn scale_values_are_decoded_into_json() {
+		let (types, id) = registry::<Entry>();
+		let (entry, json) = entry();
+
+		let encoded = entry.encode();
+		let mut input = &encoded[..];
+
+		assert_eq!(decode_value(&types, id, &mut input).unwrap(), json);
+		assert!(input.is_empty());
+	}
+
+	#[test]
+	fn invalid_json_values_are_rejected() {
+		let (types, id) = registry::<Status>();
+		let mut out = Vec::new();
+
+		assert!(encode_value(&types, id, &json!("Unknown"), &mut out).is_err());
+		assert!(encode_value(&types, id, &json!({ "Busy": "seven" }), &mut out).is_err());
+		assert!(encode_value(&types, id, &json!({ "Done": { "at": 1 } }), &mut out).is_err());
+	}
+
+	#[test]
+	fn compact_values_are_range_checked() {
+		let (types, id) = registry::<Entry>();
+		let (_, mut json) = entry();
+		let mut out = Vec::new();
+
+		json["count"] = json!(u64::from(u32::MAX) + 1);
+		assert!(encode_value(&types, id, &json, &mut out).is_err());
+
+		let (types, id) = registry::<Compact<u32>>();
+		let encoded = Compact(u64::from(u32::MAX) + 1).encode();
+		assert!(decode_value(&types, id, &mut &encoded[..]).is_err());
+	}
+}
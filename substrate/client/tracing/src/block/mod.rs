@@ -18,6 +18,7 @@
 //! Utilities for tracing block execution
 
 use std::{
+	cell::RefCell,
 	collections::HashMap,
 	sync::{
 		atomic::{AtomicU64, Ordering},
@@ -26,7 +27,7 @@ use std::{
 	time::Instant,
 };
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use parking_lot::Mutex;
 use tracing::{
 	dispatcher,
@@ -36,13 +37,18 @@ use tracing::{
 
 use crate::{SpanDatum, TraceEvent, Values};
 use sc_client_api::BlockBackend;
-use sp_api::{Core, Metadata, ProvideRuntimeApi};
+use sp_api::{CallApiAt, CallApiAtParams, CallContext, Core, Metadata, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::hexdisplay::HexDisplay;
+use sp_externalities::Extensions;
 use sp_rpc::tracing::{BlockTrace, Span, TraceBlockResponse};
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Header},
+	ApplyExtrinsicResult,
+};
+use sp_state_machine::{
+	ExecutionTrace, ExecutionTraceExt, ExecutionTraceRecorder, OverlayedChanges,
 };
 use sp_tracing::{WASM_NAME_KEY, WASM_TARGET_KEY, WASM_TRACE_IDENTIFIER};
 
@@ -196,23 +202,7 @@ where
 	/// prefixes in `Self::storage_keys`.
 	pub fn trace_block(&self) -> TraceBlockResult<TraceBlockResponse> {
 		tracing::debug!(target: "state_tracing", "Tracing block: {}", self.block);
-		// Prepare the block
-		let mut header = self
-			.client
-			.header(self.block)
-			.map_err(Error::InvalidBlockId)?
-			.ok_or_else(|| Error::MissingBlockComponent("Header not found".to_string()))?;
-		let extrinsics = self
-			.client
-			.block_body(self.block)
-			.map_err(Error::InvalidBlockId)?
-			.ok_or_else(|| Error::MissingBlockComponent("Extrinsics not found".to_string()))?;
-		tracing::debug!(target: "state_tracing", "Found {} extrinsics", extrinsics.len());
-		let parent_hash = *header.parent_hash();
-		// Remove all `Seal`s as they are added by the consensus engines after building the block.
-		// On import they are normally removed by the consensus engine.
-		header.digest_mut().logs.retain(|d| d.as_seal().is_none());
-		let block = Block::new(header, extrinsics);
+		let (parent_hash, block) = self.prepare_block()?;
 
 		let targets = if let Some(t) = &self.targets { t } else { DEFAULT_TARGETS };
 		let block_subscriber = BlockSubscriber::new(targets);
@@ -279,6 +269,99 @@ where
 			events,
 		}))
 	}
+
+	/// Fetch the block and remove its seals, returning it together with the hash of its parent.
+	fn prepare_block(&self) -> TraceBlockResult<(Block::Hash, Block)> {
+		let mut header = self
+			.client
+			.header(self.block)
+			.map_err(Error::InvalidBlockId)?
+			.ok_or_else(|| Error::MissingBlockComponent("Header not found".to_string()))?;
+		let extrinsics = self
+			.client
+			.block_body(self.block)
+			.map_err(Error::InvalidBlockId)?
+			.ok_or_else(|| Error::MissingBlockComponent("Extrinsics not found".to_string()))?;
+		tracing::debug!(target: "state_tracing", "Found {} extrinsics", extrinsics.len());
+		let parent_hash = *header.parent_hash();
+		// Remove all `Seal`s as they are added by the consensus engines after building the block.
+		// On import they are normally removed by the consensus engine.
+		header.digest_mut().logs.retain(|d| d.as_seal().is_none());
+
+		Ok((parent_hash, Block::new(header, extrinsics)))
+	}
+}
+
+impl<Block, Client> BlockExecutor<Block, Client>
+where
+	Block: BlockT + 'static,
+	Client: HeaderBackend<Block>
+		+ BlockBackend<Block>
+		+ ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	Client::Api: Metadata<Block>,
+{
+	/// Execute block, recording every storage operation done by the runtime.
+	///
+	/// Unlike [`Self::trace_block`], this does not require a runtime compiled with tracing
+	/// support, and `targets`, `storage_keys` and `methods` are ignored.
+	///
+	/// The block is executed the way it was built, i.e. by initializing it, applying its
+	/// extrinsics one by one and finalizing it, so that every operation can be attributed to the
+	/// extrinsic that did it.
+	pub fn execution_trace(&self) -> TraceBlockResult<ExecutionTrace> {
+		tracing::debug!(target: "state_tracing", "Recording execution trace of block: {}", self.block);
+		let (parent_hash, block) = self.prepare_block()?;
+		let (header, extrinsics) = block.deconstruct();
+
+		let recorder = ExecutionTraceRecorder::default();
+		let overlayed_changes = RefCell::new(OverlayedChanges::default());
+		let mut extensions = Extensions::new();
+		extensions.register(ExecutionTraceExt::new(recorder.clone()));
+		let extensions = RefCell::new(extensions);
+		let call = |function: &'static str, arguments: Vec<u8>| {
+			self.client
+				.call_api_at(CallApiAtParams {
+					at: parent_hash,
+					function,
+					arguments,
+					overlayed_changes: &overlayed_changes,
+					call_context: CallContext::Offchain,
+					recorder: &None,
+					extensions: &extensions,
+				})
+				.map_err(|e| {
+					Error::Dispatch(format!(
+						"Failed to call {function} with execution tracing: {e}"
+					))
+				})
+		};
+
+		call("Core_initialize_block", header.encode())?;
+		for (index, extrinsic) in extrinsics.iter().enumerate() {
+			recorder.set_extrinsic(Some(index as u32));
+			let result = call("BlockBuilder_apply_extrinsic", extrinsic.encode())?;
+			match ApplyExtrinsicResult::decode(&mut &result[..]) {
+				Ok(Ok(_)) => {},
+				Ok(Err(e)) =>
+					return Err(Error::Dispatch(format!("Extrinsic {index} is invalid: {e:?}"))),
+				Err(e) =>
+					return Err(Error::Dispatch(format!(
+						"Failed to decode the result of extrinsic {index}: {e}"
+					))),
+			}
+		}
+		recorder.set_extrinsic(None);
+		call("BlockBuilder_finalize_block", Vec::new())?;
+
+		let trace = recorder.take();
+		tracing::debug!(target: "state_tracing", "Recorded {} storage operations", trace.entries.len());
+
+		Ok(trace)
+	}
 }
 
 fn event_values_filter(event: &TraceEvent, filter_kind: &str, values: &str) -> bool {
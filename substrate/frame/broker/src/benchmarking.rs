@@ -1319,6 +1319,118 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn list_region() -> Result<(), BenchmarkError> {
+		let sale_data = setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(sale_data.start_price),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), sale_data.start_price)
+			.expect("Offer not high enough for configuration.");
+
+		// Worst case partitions a Region with an existing provisional pool assignment.
+		Broker::<T>::do_pool(region, None, caller.clone(), Finality::Provisional)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let price = ListingPrice::Decaying {
+			start: sale_data.start_price,
+			end: 1u32.into(),
+			period: 10u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), region, price, Some(2));
+
+		assert_last_event::<T>(
+			Event::RegionListed {
+				region_id: RegionId { begin: region.begin + 2, ..region },
+				seller: caller,
+				price,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn buy_region() -> Result<(), BenchmarkError> {
+		let sale_data = setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(sale_data.start_price),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), sale_data.start_price)
+			.expect("Offer not high enough for configuration.");
+
+		let price = ListingPrice::Fixed(sale_data.start_price);
+		Broker::<T>::do_list_region(region, caller.clone(), price, None)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		T::Currency::set_balance(
+			&buyer.clone(),
+			T::Currency::minimum_balance().saturating_add(sale_data.start_price),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), region, sale_data.start_price);
+
+		assert_last_event::<T>(
+			Event::RegionSold {
+				region_id: region,
+				seller: caller,
+				buyer,
+				price: sale_data.start_price,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_listing() -> Result<(), BenchmarkError> {
+		let sale_data = setup_and_start_sale::<T>()?;
+
+		advance_to::<T>(2);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(
+			&caller.clone(),
+			T::Currency::minimum_balance().saturating_add(sale_data.start_price),
+		);
+
+		let region = Broker::<T>::do_purchase(caller.clone(), sale_data.start_price)
+			.expect("Offer not high enough for configuration.");
+
+		Broker::<T>::do_list_region(
+			region,
+			caller.clone(),
+			ListingPrice::Fixed(sale_data.start_price),
+			None,
+		)
+		.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert_last_event::<T>(Event::ListingRemoved { region_id: region }.into());
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -235,6 +235,7 @@ impl<T: Config> Pallet<T> {
 			ensure!(Some(check_owner) == region.owner, Error::<T>::NotOwner);
 		}
 
+		Self::remove_listing(region_id);
 		let old_owner = region.owner;
 		region.owner = Some(new_owner);
 		Regions::<T>::insert(&region_id, &region);
@@ -265,6 +266,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(pivot > region_id.begin, Error::<T>::PivotTooEarly);
 
 		region.paid = None;
+		Self::remove_listing(region_id);
 		let new_region_ids = (region_id, RegionId { begin: pivot, ..region_id });
 
 		// Remove this region from the pool in case it has been assigned provisionally. If we get
@@ -304,6 +306,7 @@ impl<T: Config> Pallet<T> {
 
 		// The old region should be removed.
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(region_id);
 
 		let one = RegionId { mask: pivot, ..region_id };
 		Regions::<T>::insert(&one, &region);
@@ -483,6 +486,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(status.last_committed_timeslice >= region.end, Error::<T>::StillValid);
 
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(region_id);
 		let duration = region.end.saturating_sub(region_id.begin);
 		Self::deposit_event(Event::RegionDropped { region_id, duration });
 		Ok(())
@@ -617,6 +621,65 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_list_region(
+		region_id: RegionId,
+		who: T::AccountId,
+		price: ListingPriceOf<T>,
+		pivot_offset: Option<Timeslice>,
+	) -> Result<RegionId, DispatchError> {
+		if let ListingPrice::Decaying { start, end, period } = price {
+			ensure!(start >= end && !period.is_zero(), Error::<T>::InvalidListingPrice);
+		}
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+
+		let region_id = match pivot_offset {
+			Some(pivot_offset) => Self::do_partition(region_id, Some(who.clone()), pivot_offset)?.1,
+			None => region_id,
+		};
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(region.owner.as_ref() == Some(&who), Error::<T>::NotOwner);
+		ensure!(status.last_committed_timeslice < region.end, Error::<T>::RegionEnded);
+
+		let listed_at = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+		Listings::<T>::insert(&region_id, ListingRecord { seller: who.clone(), price, listed_at });
+		Self::deposit_event(Event::RegionListed { region_id, seller: who, price });
+		Ok(region_id)
+	}
+
+	pub(crate) fn do_buy_region(
+		region_id: RegionId,
+		who: T::AccountId,
+		price_limit: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let status = Status::<T>::get().ok_or(Error::<T>::Uninitialized)?;
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(status.last_committed_timeslice < region.end, Error::<T>::RegionEnded);
+
+		let now = RCBlockNumberProviderOf::<T::Coretime>::current_block_number();
+		let price = Self::listing_price(&listing, now);
+		ensure!(price_limit >= price, Error::<T>::Overpriced);
+
+		T::Currency::transfer(&who, &listing.seller, price, Expendable)?;
+		// This also removes the listing.
+		Self::do_transfer(region_id, Some(listing.seller.clone()), who.clone())?;
+
+		Self::deposit_event(Event::RegionSold {
+			region_id,
+			seller: listing.seller,
+			buyer: who,
+			price,
+		});
+		Ok(price)
+	}
+
+	pub(crate) fn do_cancel_listing(region_id: RegionId, who: T::AccountId) -> DispatchResult {
+		let listing = Listings::<T>::get(&region_id).ok_or(Error::<T>::NotListed)?;
+		ensure!(listing.seller == who, Error::<T>::NotOwner);
+		Self::remove_listing(region_id);
+		Ok(())
+	}
+
 	pub(crate) fn ensure_cores_for_sale(
 		status: &StatusRecord,
 		sale: &SaleInfoRecordOf<T>,
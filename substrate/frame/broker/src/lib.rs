@@ -202,6 +202,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RevenueInbox<T> = StorageValue<_, OnDemandRevenueRecordOf<T>, OptionQuery>;
 
+	/// Regions listed for sale on the secondary market.
+	#[pallet::storage]
+	pub type Listings<T> =
+		StorageMap<_, Blake2_128Concat, RegionId, ListingRecordOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -494,6 +499,32 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// A Region has been listed for sale on the secondary market.
+		RegionListed {
+			/// The Region which has been listed.
+			region_id: RegionId,
+			/// The owner of the Region, who receives the price.
+			seller: T::AccountId,
+			/// The asking price of the Region.
+			price: ListingPriceOf<T>,
+		},
+		/// A Region listed on the secondary market has been sold.
+		RegionSold {
+			/// The Region which has been sold.
+			region_id: RegionId,
+			/// The previous owner of the Region.
+			seller: T::AccountId,
+			/// The new owner of the Region.
+			buyer: T::AccountId,
+			/// The price paid for the Region.
+			price: BalanceOf<T>,
+		},
+		/// A Region is no longer listed on the secondary market, either because the listing was
+		/// cancelled or because the Region was transferred, split or used.
+		ListingRemoved {
+			/// The Region which is no longer listed.
+			region_id: RegionId,
+		},
 	}
 
 	#[pallet::error]
@@ -577,6 +608,12 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.The amount of credits the user attempted to purchase is
 		/// below `T::MinimumCreditPurchase`.
 		CreditPurchaseTooSmall,
+		/// The Region is not listed for sale.
+		NotListed,
+		/// A decaying listing price must not increase and must decay over a non-zero period.
+		InvalidListingPrice,
+		/// The Region has already ended.
+		RegionEnded,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -1022,6 +1059,60 @@ pub mod pallet {
 			Self::do_remove_assignment(region_id)
 		}
 
+		/// List a Region for sale on the secondary market.
+		///
+		/// Listing an already listed Region replaces its asking price. The listing is removed if
+		/// the Region is transferred, partitioned, interlaced, assigned or pooled, and it can no
+		/// longer be bought once the Region has ended.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region to be listed.
+		/// - `price`: The asking price of the Region.
+		/// - `pivot_offset`: If `Some`, the Region is first partitioned at this offset from its
+		///   beginning and only the part starting at the pivot is listed.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::list_region())]
+		pub fn list_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			price: ListingPriceOf<T>,
+			pivot_offset: Option<Timeslice>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_list_region(region_id, who, price, pivot_offset)?;
+			Ok(())
+		}
+
+		/// Buy a Region listed on the secondary market, paying its current asking price to the
+		/// seller.
+		///
+		/// - `origin`: Must be a Signed origin with at least enough funds to pay the asking price.
+		/// - `region_id`: The Region to be bought.
+		/// - `price_limit`: An amount no more than which should be paid.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::buy_region())]
+		pub fn buy_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			price_limit: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_buy_region(region_id, who, price_limit)?;
+			Ok(())
+		}
+
+		/// Remove a Region from the secondary market.
+		///
+		/// - `origin`: Must be a Signed origin of the account which listed the Region.
+		/// - `region_id`: The Region to be removed from the market.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel_listing(region_id, who)?;
+			Ok(())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
@@ -96,6 +96,7 @@ impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 
 		record.owner = None;
 		Regions::<T>::insert(region_id, record);
+		Self::remove_listing(region_id);
 
 		Ok(())
 	}
@@ -2636,3 +2636,271 @@ fn force_reserve_works() {
 		assert_eq!(Workplan::<Test>::get((10, 0)), Some(system_workload.clone()));
 	});
 }
+
+#[test]
+fn list_and_buy_region_works() {
+	TestExt::new().endow(1, 1000).endow(2, 100).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let seller_balance = balance(1);
+
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(2), region, ListingPrice::Fixed(50), None),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+		System::assert_last_event(
+			Event::RegionListed { region_id: region, seller: 1, price: ListingPrice::Fixed(50) }
+				.into(),
+		);
+
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(2), region, 49),
+			Error::<Test>::Overpriced
+		);
+		assert_ok!(Broker::buy_region(RuntimeOrigin::signed(2), region, 60));
+		System::assert_last_event(
+			Event::RegionSold { region_id: region, seller: 1, buyer: 2, price: 50 }.into(),
+		);
+		System::assert_has_event(Event::ListingRemoved { region_id: region }.into());
+
+		assert_eq!(<Broker as NftInspect<_>>::owner(&region.into()), Some(2));
+		assert_eq!(balance(1), seller_balance + 50);
+		assert_eq!(balance(2), 50);
+		assert_eq!(Listings::<Test>::get(region), None);
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(1), region, 50),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn decaying_listing_price_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let seller_balance = balance(1);
+
+		let price = ListingPrice::Decaying { start: 100, end: 20, period: 8 };
+		assert_ok!(Broker::list_region(RuntimeOrigin::signed(1), region, price, None));
+		let listing = Listings::<Test>::get(region).unwrap();
+		assert_eq!(listing, ListingRecord { seller: 1, price, listed_at: 2 });
+
+		assert_eq!(Broker::listing_price(&listing, 2), 100);
+		assert_eq!(Broker::listing_price(&listing, 4), 80);
+		assert_eq!(Broker::listing_price(&listing, 8), 40);
+		assert_eq!(Broker::listing_price(&listing, 10), 20);
+		assert_eq!(Broker::listing_price(&listing, 100), 20);
+
+		advance_to(4);
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(2), region, 79),
+			Error::<Test>::Overpriced
+		);
+		assert_ok!(Broker::buy_region(RuntimeOrigin::signed(2), region, 80));
+		assert_eq!(balance(1), seller_balance + 80);
+		assert_eq!(balance(2), 920);
+	});
+}
+
+#[test]
+fn invalid_listing_price_fails() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_noop!(
+			Broker::list_region(
+				RuntimeOrigin::signed(1),
+				region,
+				ListingPrice::Decaying { start: 10, end: 20, period: 8 },
+				None
+			),
+			Error::<Test>::InvalidListingPrice
+		);
+		assert_noop!(
+			Broker::list_region(
+				RuntimeOrigin::signed(1),
+				region,
+				ListingPrice::Decaying { start: 20, end: 10, period: 0 },
+				None
+			),
+			Error::<Test>::InvalidListingPrice
+		);
+	});
+}
+
+#[test]
+fn list_region_with_pivot_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			Some(1)
+		));
+		let listed = RegionId { begin: region.begin + 1, ..region };
+		System::assert_has_event(
+			Event::Partitioned { old_region_id: region, new_region_ids: (region, listed) }.into(),
+		);
+		assert_eq!(Listings::<Test>::get(region), None);
+		assert!(Listings::<Test>::contains_key(listed));
+		assert_eq!(<Broker as NftInspect<_>>::owner(&region.into()), Some(1));
+		assert_eq!(<Broker as NftInspect<_>>::owner(&listed.into()), Some(1));
+	});
+}
+
+#[test]
+fn cancel_listing_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		assert_noop!(
+			Broker::cancel_listing(RuntimeOrigin::signed(1), region),
+			Error::<Test>::NotListed
+		);
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+		assert_noop!(
+			Broker::cancel_listing(RuntimeOrigin::signed(2), region),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Broker::cancel_listing(RuntimeOrigin::signed(1), region));
+		System::assert_last_event(Event::ListingRemoved { region_id: region }.into());
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(2), region, 50),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn listing_is_removed_when_region_changes() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+
+		// Transferring the Region removes the listing.
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+		assert_ok!(Broker::do_transfer(region, Some(1), 2));
+		assert_eq!(Listings::<Test>::get(region), None);
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(1), region, 50),
+			Error::<Test>::NotListed
+		);
+
+		// Interlacing the Region removes the listing.
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(2),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+		let (region1, region2) =
+			Broker::do_interlace(region, Some(2), CoreMask::from_chunk(0, 40)).unwrap();
+		assert_eq!(Listings::<Test>::get(region), None);
+
+		// Assigning the Region removes the listing.
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(2),
+			region1,
+			ListingPrice::Fixed(50),
+			None
+		));
+		assert_ok!(Broker::do_assign(region1, Some(2), 1001, Final));
+		assert_eq!(Listings::<Test>::get(region1), None);
+
+		// Pooling the Region removes the listing.
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(2),
+			region2,
+			ListingPrice::Fixed(50),
+			None
+		));
+		assert_ok!(Broker::do_pool(region2, Some(2), 2, Final));
+		assert_eq!(Listings::<Test>::get(region2), None);
+		assert_eq!(Listings::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn listing_is_removed_when_region_is_burned() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+
+		// Moving the Region to the holding register removes the listing.
+		assert_ok!(<Broker as Mutate<_>>::burn(&region.into(), Some(&1)));
+		assert_eq!(Listings::<Test>::get(region), None);
+		System::assert_has_event(Event::ListingRemoved { region_id: region }.into());
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(2), region, 50),
+			Error::<Test>::NotListed
+		);
+
+		// Minting it back does not restore the listing.
+		assert_ok!(<Broker as Mutate<_>>::mint_into(&region.into(), &1));
+		assert_eq!(Listings::<Test>::get(region), None);
+	});
+}
+
+#[test]
+fn ended_region_cannot_be_listed_or_bought() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::list_region(
+			RuntimeOrigin::signed(1),
+			region,
+			ListingPrice::Fixed(50),
+			None
+		));
+
+		advance_to(12);
+		assert_noop!(
+			Broker::buy_region(RuntimeOrigin::signed(2), region, 50),
+			Error::<Test>::RegionEnded
+		);
+		assert_noop!(
+			Broker::list_region(RuntimeOrigin::signed(1), region, ListingPrice::Fixed(50), None),
+			Error::<Test>::RegionEnded
+		);
+
+		// Dropping the Region removes the stale listing.
+		assert_ok!(Broker::do_drop_region(region));
+		assert_eq!(Listings::<Test>::get(region), None);
+	});
+}
@@ -376,3 +376,45 @@ pub struct AutoRenewalRecord {
 	/// tasks to ensure that the renewal process does not begin until the lease expires.
 	pub next_renewal: Timeslice,
 }
+
+/// The asking price of a Region listed on the secondary market.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum ListingPrice<Balance, RelayBlockNumber> {
+	/// The Region is sold at a fixed price.
+	Fixed(Balance),
+	/// The price decreases linearly from `start` to `end` over `period` relay-chain blocks from
+	/// the time of listing, and remains at `end` thereafter.
+	Decaying {
+		/// The price at the time of listing.
+		start: Balance,
+		/// The price at the end of the period.
+		end: Balance,
+		/// The number of relay-chain blocks over which the price decreases.
+		period: RelayBlockNumber,
+	},
+}
+pub type ListingPriceOf<T> = ListingPrice<BalanceOf<T>, RelayBlockNumberOf<T>>;
+
+/// A Region listed for sale on the secondary market.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ListingRecord<AccountId, Balance, RelayBlockNumber> {
+	/// The owner of the Region at the time of listing, to whom the price is paid.
+	pub seller: AccountId,
+	/// The asking price of the Region.
+	pub price: ListingPrice<Balance, RelayBlockNumber>,
+	/// The relay-chain block at which the Region was listed.
+	pub listed_at: RelayBlockNumber,
+}
+pub type ListingRecordOf<T> =
+	ListingRecord<<T as SConfig>::AccountId, BalanceOf<T>, RelayBlockNumberOf<T>>;
@@ -65,6 +65,26 @@ impl<T: Config> Pallet<T> {
 		T::PriceAdapter::leadin_factor_at(through).saturating_mul_int(sale.end_price)
 	}
 
+	/// The asking price of a listed Region at the relay-chain block `now`.
+	pub fn listing_price(listing: &ListingRecordOf<T>, now: RelayBlockNumberOf<T>) -> BalanceOf<T> {
+		match listing.price {
+			ListingPrice::Fixed(price) => price,
+			ListingPrice::Decaying { start, end, period } => {
+				let elapsed: u64 =
+					now.saturating_sub(listing.listed_at).min(period).saturated_into();
+				let through = FixedU64::from_rational(elapsed, period.saturated_into::<u64>());
+				start.saturating_sub(through.saturating_mul_int(start.saturating_sub(end)))
+			},
+		}
+	}
+
+	/// Remove a Region from the secondary market, if it is listed.
+	pub(crate) fn remove_listing(region_id: RegionId) {
+		if Listings::<T>::take(region_id).is_some() {
+			Self::deposit_event(Event::ListingRemoved { region_id });
+		}
+	}
+
 	pub(crate) fn charge(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
 		let credit = T::Currency::withdraw(&who, amount, Exact, Expendable, Polite)?;
 		T::OnRevenue::on_unbalanced(credit);
@@ -117,6 +137,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		Regions::<T>::remove(&region_id);
+		Self::remove_listing(region_id);
 
 		let last_committed_timeslice = status.last_committed_timeslice;
 		if region_id.begin <= last_committed_timeslice {
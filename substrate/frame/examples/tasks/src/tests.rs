@@ -135,6 +135,31 @@ fn task_execution_fails_for_invalid_task() {
 	});
 }
 
+#[cfg(feature = "experimental")]
+#[test]
+fn valid_tasks_and_task_extrinsic_work() {
+	new_test_ext().execute_with(|| {
+		assert!(System::valid_tasks(10).is_empty());
+
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+
+		assert_eq!(System::valid_tasks(1).len(), 1);
+		let tasks = System::valid_tasks(10);
+		assert_eq!(tasks.len(), 2);
+		for (task, weight) in &tasks {
+			assert!(task.is_valid());
+			assert_eq!(*weight, task.weight());
+		}
+
+		let (task, _) = tasks[0].clone();
+		let tx = System::task_extrinsic(task.clone());
+		use sp_runtime::traits::ExtrinsicLike;
+		assert!(tx.is_bare());
+		assert_eq!(tx.function, RuntimeCall::System(frame_system::Call::do_task { task }));
+	});
+}
+
 #[cfg(feature = "experimental")]
 #[test]
 fn task_with_offchain_worker() {
@@ -21,11 +21,38 @@ use super::*;
 
 use core::array;
 use frame_benchmarking::{v2::*, BenchmarkError};
+use frame_support::{
+	migrations::{SteppedMigration, SteppedTranslate, TranslateValue},
+	weights::WeightMeter,
+	Twox64Concat,
+};
 use frame_system::{Pallet as System, RawOrigin};
 use sp_core::{twox_128, Get};
 use sp_io::{storage, KillStorageResult};
 use sp_runtime::traits::One;
 
+#[frame_support::storage_alias]
+type TranslateBenchmarkMap = StorageMap<TranslateBenchmark, Twox64Concat, u32, [u8; 32]>;
+
+frame_support::parameter_types! {
+	const TranslateBenchmarkId: [u8; 16] = *b"TranslateBenchmk";
+}
+
+struct TranslateBenchmarkValue;
+impl TranslateValue<u32, [u8; 32], [u8; 32]> for TranslateBenchmarkValue {
+	fn translate(_key: &u32, old: [u8; 32]) -> Option<[u8; 32]> {
+		Some(old)
+	}
+}
+
+type TranslateBenchmarkMigration = SteppedTranslate<
+	TranslateBenchmarkId,
+	TranslateBenchmarkMap,
+	[u8; 32],
+	TranslateBenchmarkValue,
+	(),
+>;
+
 fn assert_has_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_has_event(generic_event.into());
 }
@@ -245,6 +272,23 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark(skip_meta, pov_mode = Measured)]
+	fn translate_map_item() -> Result<(), BenchmarkError> {
+		// 32 byte will trigger the worst case where the value is
+		// no longer stored inline
+		TranslateBenchmarkMap::insert(0, [0u8; 32]);
+		let mut meter = WeightMeter::new();
+
+		let result;
+		#[block]
+		{
+			result = TranslateBenchmarkMigration::step(None, &mut meter);
+		}
+
+		ensure!(matches!(result, Ok(None)), "Translation did not complete");
+		Ok(())
+	}
+
 	fn cursor<T: Config>() -> CursorOf<T> {
 		// Note: The weight of a function can depend on the weight of reading the `inner_cursor`.
 		// `Cursor` is a user provided type. Now instead of requiring something like `Cursor:
@@ -21,7 +21,7 @@ use core::marker::PhantomData;
 use frame_support::{
 	migrations::{SteppedMigration, SteppedMigrationError, StoreInCodeStorageVersion},
 	traits::{GetStorageVersion, PalletInfoAccess},
-	weights::WeightMeter,
+	weights::{Weight, WeightMeter},
 };
 use sp_core::{twox_128, Get};
 use sp_io::{storage::clear_prefix, KillStorageResult};
@@ -133,3 +133,17 @@ where
 		Ok(())
 	}
 }
+
+/// The weight of translating a single entry of a storage map with
+/// [`SteppedTranslate`](frame_support::migrations::SteppedTranslate), as benchmarked by this
+/// pallet.
+///
+/// The benchmark uses an identity translation of 32 byte values. Translations of larger values
+/// or more expensive translation functions need their own weight.
+pub struct TranslateMapItemWeight<T>(PhantomData<T>);
+
+impl<T: Config> Get<Weight> for TranslateMapItemWeight<T> {
+	fn get() -> Weight {
+		T::WeightInfo::translate_map_item()
+	}
+}
@@ -45,6 +45,31 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+fn stateful_members<T: Config>(s: u32) -> Vec<(T::AccountId, u32)> {
+	let mut members: Vec<_> = (0..s).map(|i| (account("member", i, SEED), 1)).collect();
+	members.sort();
+	members
+}
+
+/// Create a stateful multisig with `s` members, each with an approval weight of one.
+fn setup_stateful<T: Config>(s: u32) -> Result<T::AccountId, BenchmarkError> {
+	// Not whitelisted: the creator is not the origin of the calls made by the multisig, so the
+	// accesses to its account must be accounted for.
+	let creator: T::AccountId = account("creator", 0, SEED);
+	T::Currency::make_free_balance_be(&creator, BalanceOf::<T>::max_value());
+	let multisig = Multisig::<T>::stateful_account_id(&creator, 0, &Multisig::<T>::timepoint());
+	// The multisig pays the deposit for the changes to its configuration.
+	T::Currency::make_free_balance_be(&multisig, BalanceOf::<T>::max_value() / 2u32.into());
+	Multisig::<T>::create_stateful(
+		RawOrigin::Signed(creator).into(),
+		stateful_members::<T>(s),
+		s,
+		None,
+		0,
+	)?;
+	Ok(multisig)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -359,5 +384,73 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Members
+	#[benchmark]
+	fn create_stateful(s: Linear<1, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let members = stateful_members::<T>(s);
+		let multisig = Multisig::<T>::stateful_account_id(&caller, 0, &Multisig::<T>::timepoint());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), members, s, None, 0);
+
+		assert!(StatefulMultisigs::<T>::contains_key(multisig));
+		Ok(())
+	}
+
+	/// `s`: Members
+	#[benchmark]
+	fn reconfigure_stateful(
+		s: Linear<1, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let multisig = setup_stateful::<T>(1)?;
+		let members = stateful_members::<T>(s);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()), members, s, Some(10u32.into()));
+
+		let config = StatefulMultisigs::<T>::get(&multisig).ok_or("multisig not created")?;
+		assert_eq!(config.members.len() as u32, s);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_call_threshold() -> Result<(), BenchmarkError> {
+		let max = T::MaxSignatories::get();
+		let multisig = setup_stateful::<T>(max)?;
+		// Set all but one call threshold, so that the last one is inserted in front of them. They
+		// are set directly, so that the multisig still has to take the deposit over from its
+		// creator.
+		StatefulMultisigs::<T>::try_mutate(&multisig, |maybe_config| -> DispatchResult {
+			let config = maybe_config.as_mut().ok_or("multisig not created")?;
+			for i in 1..max {
+				config
+					.call_thresholds
+					.try_push((((i >> 8) as u8 + 1, i as u8), 1))
+					.map_err(|_| "too many call thresholds")?;
+			}
+			Ok(())
+		})?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()), (0, 0), Some(max));
+
+		let config = StatefulMultisigs::<T>::get(&multisig).ok_or("multisig not created")?;
+		assert_eq!(config.call_thresholds.len() as u32, max);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dissolve_stateful() -> Result<(), BenchmarkError> {
+		let multisig = setup_stateful::<T>(T::MaxSignatories::get())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multisig.clone()));
+
+		assert!(!StatefulMultisigs::<T>::contains_key(multisig));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
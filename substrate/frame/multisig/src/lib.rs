@@ -39,6 +39,29 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_stateful` - Create a stateful multisig account, whose members, approval weights and
+//!   thresholds are stored on chain.
+//! * `reconfigure_stateful` - Change the members and threshold of a stateful multisig, keeping its
+//!   address.
+//! * `set_call_threshold` - Require a different threshold for a kind of call dispatched from a
+//!   stateful multisig.
+//! * `dissolve_stateful` - Remove the configuration of a stateful multisig.
+//! * `as_stateful_multi` - Approve and if possible dispatch a call from a stateful multisig.
+//! * `approve_as_stateful_multi` - Approve a call from a stateful multisig.
+//! * `cancel_as_stateful_multi` - Cancel a call from a stateful multisig.
+//! * `remove_expired_multisig` - Remove an expired operation of a stateful multisig.
+//!
+//! ### Stateful multisig accounts
+//!
+//! The account of a classic multisig is derived from its signatories and threshold, so changing
+//! either means moving everything the account owns to a new one. A stateful multisig instead has
+//! a fixed address and stores its members, the weight of their approvals and its thresholds. The
+//! configuration can only be changed by the multisig itself, by dispatching
+//! `reconfigure_stateful` or `set_call_threshold` through one of its operations.
+//!
+//! Operations of a stateful multisig go through the same approval flow as the ones of a classic
+//! multisig and are stored alongside them. Optionally, they expire after a number of blocks,
+//! after which anyone can remove them.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -120,6 +143,118 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// Identifies the kind of a call by the index of its pallet and its index within that pallet.
+///
+/// These are the first two bytes of the SCALE encoding of a runtime call built by
+/// `construct_runtime`.
+pub type CallIndex = (u8, u8);
+
+/// The [`CallIndex`] of a runtime call built by `construct_runtime`.
+pub fn call_index_of<Call: Encode>(call: &Call) -> Option<CallIndex> {
+	call.using_encoded(|d| (d.len() >= 2).then(|| (d[0], d[1])))
+}
+
+/// Determines the kinds of calls a call dispatches, so that the per-call thresholds of a stateful
+/// multisig cannot be bypassed by wrapping a call into another one, e.g. into `utility.batch` or
+/// `proxy.proxy`.
+pub trait ClassifyCall<Call> {
+	/// The [`CallIndex`] of `call` and of every call it dispatches, recursively.
+	///
+	/// `None` if not all of them can be determined, in which case the strictest threshold of the
+	/// multisig applies.
+	fn call_indices(call: &Call) -> Option<Vec<CallIndex>>;
+}
+
+/// Doesn't inspect calls, so only the strictest threshold of a stateful multisig applies.
+impl<Call> ClassifyCall<Call> for () {
+	fn call_indices(_: &Call) -> Option<Vec<CallIndex>> {
+		None
+	}
+}
+
+/// The configuration of a stateful multisig account.
+///
+/// Unlike the accounts derived with [`Pallet::multi_account_id`], a stateful multisig account
+/// keeps its address when its members or thresholds change.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxMembers))]
+pub struct StatefulMultisig<BlockNumber, Balance, AccountId, MaxMembers>
+where
+	MaxMembers: Get<u32>,
+{
+	/// The members together with the weight of their approval. Always sorted by account.
+	pub members: BoundedVec<(AccountId, u32), MaxMembers>,
+	/// The total weight of approvals needed to dispatch a call, unless overridden in
+	/// `call_thresholds`.
+	pub threshold: u32,
+	/// The thresholds of specific kinds of calls. Always sorted by call index.
+	pub call_thresholds: BoundedVec<(CallIndex, u32), MaxMembers>,
+	/// The number of blocks after which an open operation can no longer be approved.
+	pub proposal_lifetime: Option<BlockNumber>,
+	/// The account holding the deposit for the configuration: the creator of the multisig, until
+	/// the multisig changes its configuration and takes the deposit over.
+	pub depositor: AccountId,
+	/// The amount held in reserve of the `depositor`, returned once the multisig is dissolved.
+	pub deposit: Balance,
+}
+
+impl<BlockNumber, Balance, AccountId: Ord, MaxMembers: Get<u32>>
+	StatefulMultisig<BlockNumber, Balance, AccountId, MaxMembers>
+{
+	/// The weight of the approval of `who`, if they are a member.
+	pub fn weight_of(&self, who: &AccountId) -> Option<u32> {
+		self.members
+			.binary_search_by(|(m, _)| m.cmp(who))
+			.ok()
+			.map(|i| self.members[i].1)
+	}
+
+	/// The total weight of all the members.
+	pub fn total_weight(&self) -> u32 {
+		self.members.iter().fold(0, |acc, (_, weight)| acc.saturating_add(*weight))
+	}
+
+	/// The total weight of the `approvals` given by current members.
+	pub fn approval_weight(&self, approvals: &[AccountId]) -> u32 {
+		approvals
+			.iter()
+			.filter_map(|who| self.weight_of(who))
+			.fold(0, |acc, weight| acc.saturating_add(weight))
+	}
+
+	/// The weight of approvals needed to dispatch a call of the given kind.
+	pub fn threshold_for(&self, call_index: Option<CallIndex>) -> u32 {
+		call_index
+			.and_then(|call_index| {
+				self.call_thresholds
+					.binary_search_by(|(c, _)| c.cmp(&call_index))
+					.ok()
+					.map(|i| self.call_thresholds[i].1)
+			})
+			.unwrap_or(self.threshold)
+	}
+
+	/// The weight of approvals needed to dispatch a call and the calls nested in it, given
+	/// their kinds as determined by [`ClassifyCall`].
+	///
+	/// This is the highest threshold of any of the calls. If their kinds are unknown, it is the
+	/// highest threshold of the multisig.
+	pub fn threshold_for_calls(&self, call_indices: Option<&[CallIndex]>) -> u32 {
+		match call_indices {
+			Some(call_indices) => call_indices
+				.iter()
+				.map(|call_index| self.threshold_for(Some(*call_index)))
+				.max()
+				.unwrap_or(self.threshold),
+			None => self
+				.call_thresholds
+				.iter()
+				.map(|(_, threshold)| *threshold)
+				.fold(self.threshold, u32::max),
+		}
+	}
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -191,6 +326,13 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Determines the calls dispatched by a call of a stateful multisig, to find the threshold
+		/// it needs.
+		///
+		/// Runtimes with calls dispatching other calls must take these into account. Use `()` to
+		/// always apply the strictest threshold of the multisig.
+		type CallClassifier: ClassifyCall<<Self as Config>::RuntimeCall>;
 	}
 
 	/// The in-code storage version.
@@ -211,6 +353,28 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The configuration of the stateful multisig accounts.
+	///
+	/// Their open operations are stored in [`Multisigs`] like the ones of any other multisig.
+	#[pallet::storage]
+	pub type StatefulMultisigs<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		StatefulMultisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
+	/// The block after which an open operation of a stateful multisig can no longer be approved.
+	#[pallet::storage]
+	pub type MultisigExpiry<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BlockNumberFor<T>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +406,22 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a stateful multisig.
+		NotStateful,
+		/// The sender is not a member of the stateful multisig.
+		NotMember,
+		/// Members must have a non-zero approval weight.
+		ZeroWeight,
+		/// The threshold is zero or exceeds the total weight of the members.
+		UnreachableThreshold,
+		/// There are too many call thresholds.
+		TooManyCallThresholds,
+		/// The multisig operation expired.
+		Expired,
+		/// The multisig operation did not expire yet.
+		NotExpired,
+		/// The stateful multisig still has open operations.
+		OperationsOpen,
 	}
 
 	#[pallet::event]
@@ -278,6 +458,16 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// A stateful multisig account has been created.
+		StatefulMultisigCreated { creator: T::AccountId, multisig: T::AccountId },
+		/// The members, threshold or proposal lifetime of a stateful multisig have changed.
+		StatefulMultisigReconfigured { multisig: T::AccountId },
+		/// The threshold of a kind of call has been set or, if `None`, removed.
+		CallThresholdSet { multisig: T::AccountId, call_index: CallIndex, threshold: Option<u32> },
+		/// A stateful multisig account has been dissolved.
+		StatefulMultisigDissolved { multisig: T::AccountId },
+		/// An expired multisig operation has been removed.
+		MultisigExpired { multisig: T::AccountId, call_hash: CallHash },
 	}
 
 	#[pallet::hooks]
@@ -612,6 +802,355 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Create a stateful multisig account.
+		///
+		/// The account is derived from the sender, `index` and the current timepoint, and keeps
+		/// its address when its members or thresholds change. Its configuration can only be
+		/// changed by the multisig itself, i.e. through an operation approved by its members.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for every member will be reserved from
+		/// the sender. It is returned once the multisig is dissolved or changes its configuration,
+		/// from when on the multisig holds the deposit itself.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `members`: The members and the weight of their approval, sorted by account. The sender
+		///   does not have to be a member.
+		/// - `threshold`: The total weight of approvals needed to dispatch a call.
+		/// - `proposal_lifetime`: The number of blocks after which an open operation can no longer
+		///   be approved, if any.
+		/// - `index`: Allows creating several multisigs in the same transaction.
+		///
+		/// Emits `StatefulMultisigCreated`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_stateful(members.len() as u32))]
+		pub fn create_stateful(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+			proposal_lifetime: Option<BlockNumberFor<T>>,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let members = Self::ensure_valid_members(members, threshold)?;
+
+			let multisig = Self::stateful_account_id(&who, index, &Self::timepoint());
+			ensure!(!StatefulMultisigs::<T>::contains_key(&multisig), Error::<T>::AlreadyStored);
+
+			let deposit = Self::stateful_deposit(members.len() as u32);
+			T::Currency::reserve(&who, deposit)?;
+
+			StatefulMultisigs::<T>::insert(
+				&multisig,
+				StatefulMultisig {
+					members,
+					threshold,
+					call_thresholds: Default::default(),
+					proposal_lifetime,
+					depositor: who.clone(),
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::StatefulMultisigCreated { creator: who, multisig });
+			Ok(())
+		}
+
+		/// Change the members, threshold and proposal lifetime of a stateful multisig.
+		///
+		/// Approvals already given to open operations only count as long as the approving
+		/// account is a member, with its current weight.
+		///
+		/// The deposit for the configuration, adjusted to the new number of members, is held by
+		/// the multisig from then on, and the deposit of its creator is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stateful multisig.
+		///
+		/// - `members`: The members and the weight of their approval, sorted by account.
+		/// - `threshold`: The total weight of approvals needed to dispatch a call.
+		/// - `proposal_lifetime`: The number of blocks after which an operation opened from now on
+		///   can no longer be approved, if any.
+		///
+		/// Emits `StatefulMultisigReconfigured`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::reconfigure_stateful(members.len() as u32))]
+		pub fn reconfigure_stateful(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+			proposal_lifetime: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			let members = Self::ensure_valid_members(members, threshold)?;
+
+			StatefulMultisigs::<T>::try_mutate(&multisig, |maybe_config| -> DispatchResult {
+				let config = maybe_config.as_mut().ok_or(Error::<T>::NotStateful)?;
+				config.members = members;
+				config.threshold = threshold;
+				config.proposal_lifetime = proposal_lifetime;
+
+				let total = config.total_weight();
+				ensure!(
+					config.call_thresholds.iter().all(|(_, threshold)| *threshold <= total),
+					Error::<T>::UnreachableThreshold
+				);
+
+				Self::update_stateful_deposit(&multisig, config)
+			})?;
+
+			Self::deposit_event(Event::StatefulMultisigReconfigured { multisig });
+			Ok(())
+		}
+
+		/// Set the threshold needed to dispatch a kind of call from a stateful multisig,
+		/// overriding its default threshold.
+		///
+		/// A call dispatching other calls, e.g. `utility.batch`, needs the highest threshold of all
+		/// of them, as determined by [`Config::CallClassifier`].
+		///
+		/// The deposit for the configuration, adjusted to the new number of call thresholds, is
+		/// held by the multisig from then on, and the deposit of its creator is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stateful multisig.
+		///
+		/// - `call_index`: The index of the pallet and of the call within the pallet.
+		/// - `threshold`: The total weight of approvals needed to dispatch such calls, or `None` to
+		///   fall back to the default threshold.
+		///
+		/// Emits `CallThresholdSet`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_call_threshold())]
+		pub fn set_call_threshold(
+			origin: OriginFor<T>,
+			call_index: CallIndex,
+			threshold: Option<u32>,
+		) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+
+			StatefulMultisigs::<T>::try_mutate(&multisig, |maybe_config| -> DispatchResult {
+				let config = maybe_config.as_mut().ok_or(Error::<T>::NotStateful)?;
+				let pos = config.call_thresholds.binary_search_by(|(c, _)| c.cmp(&call_index));
+
+				match (pos, threshold) {
+					(Ok(pos), Some(threshold)) => config.call_thresholds[pos].1 = threshold,
+					(Err(pos), Some(threshold)) => config
+						.call_thresholds
+						.try_insert(pos, (call_index, threshold))
+						.map_err(|_| Error::<T>::TooManyCallThresholds)?,
+					(Ok(pos), None) => {
+						config.call_thresholds.remove(pos);
+					},
+					(Err(_), None) => (),
+				}
+
+				if let Some(threshold) = threshold {
+					ensure!(
+						threshold > 0 && threshold <= config.total_weight(),
+						Error::<T>::UnreachableThreshold
+					);
+				}
+
+				Self::update_stateful_deposit(&multisig, config)
+			})?;
+
+			Self::deposit_event(Event::CallThresholdSet { multisig, call_index, threshold });
+			Ok(())
+		}
+
+		/// Dissolve a stateful multisig, returning the deposit for its configuration.
+		///
+		/// The account itself and its funds are left untouched, but no further operation can be
+		/// dispatched from it. All open operations must be cancelled, executed or removed after
+		/// expiry beforehand.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stateful multisig.
+		///
+		/// Emits `StatefulMultisigDissolved`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::dissolve_stateful())]
+		pub fn dissolve_stateful(origin: OriginFor<T>) -> DispatchResult {
+			let multisig = ensure_signed(origin)?;
+			ensure!(
+				<Multisigs<T>>::iter_prefix(&multisig).next().is_none(),
+				Error::<T>::OperationsOpen
+			);
+			let config = StatefulMultisigs::<T>::take(&multisig).ok_or(Error::<T>::NotStateful)?;
+
+			let err_amount = T::Currency::unreserve(&config.depositor, config.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			Self::deposit_event(Event::StatefulMultisigDissolved { multisig });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a stateful multisig account.
+		///
+		/// If the total weight of the approvals of the current members reaches the threshold of
+		/// the call, then dispatch it.
+		///
+		/// This follows the flow of `as_multi`, with the multisig account given explicitly
+		/// instead of being derived from the signatories and the threshold.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for every member will be reserved if this
+		/// is the first approval. It is returned once this dispatch happens or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The stateful multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		#[pallet::call_index(9)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+				.max(T::WeightInfo::as_multi_approve(s, z))
+				.max(T::WeightInfo::as_multi_complete(s, z))
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stateful(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a stateful multisig account.
+		///
+		/// This follows the flow of `approve_as_multi`, with the multisig account given
+		/// explicitly instead of being derived from the signatories and the threshold.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for every member will be reserved if this
+		/// is the first approval. It is returned once this dispatch happens or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The stateful multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_stateful_multi` instead.
+		#[pallet::call_index(10)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stateful(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel an open operation of a stateful multisig. The deposit reserved for it is
+		/// returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account who opened the
+		/// operation.
+		///
+		/// - `multisig`: The stateful multisig account.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_as_multi(T::MaxSignatories::get())
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn cancel_as_stateful_multi(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+			<MultisigExpiry<T>>::remove(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigCancelled {
+				cancelling: who,
+				timepoint,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Remove an expired operation of a stateful multisig, returning the deposit reserved for
+		/// it to its depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_. Anyone can remove an expired
+		/// operation.
+		///
+		/// - `multisig`: The stateful multisig account.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// Emits `MultisigExpired`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::WeightInfo::cancel_as_multi(T::MaxSignatories::get())
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn remove_expired_multisig(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let expiry =
+				<MultisigExpiry<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() > expiry,
+				Error::<T>::NotExpired
+			);
+
+			let m = <Multisigs<T>>::take(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<MultisigExpiry<T>>::remove(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigExpired { multisig, call_hash });
+			Ok(())
+		}
 	}
 }
 
@@ -755,6 +1294,224 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Derive the account ID of a stateful multisig created by `who` at `timepoint`.
+	pub fn stateful_account_id(
+		who: &T::AccountId,
+		index: u16,
+		timepoint: &Timepoint<BlockNumberFor<T>>,
+	) -> T::AccountId {
+		let entropy = (b"modlpy/stmultisg", who, index, timepoint).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	fn operate_stateful(
+		who: T::AccountId,
+		multisig: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let config = <StatefulMultisigs<T>>::get(&multisig).ok_or(Error::<T>::NotStateful)?;
+		let who_weight = config.weight_of(&who).ok_or(Error::<T>::NotMember)?;
+		let members_len = config.members.len() as u32;
+		let overhead = T::DbWeight::get().reads_writes(2, 1);
+
+		let (call_hash, call_len, maybe_call) = match call_or_hash {
+			CallOrHash::Call(call) => {
+				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
+				(call_hash, call_len as u32, Some(call))
+			},
+			CallOrHash::Hash(h) => (h, 0, None),
+		};
+
+		// The approvals needed depend on the kinds of calls dispatched, so they are only known
+		// once the call is provided.
+		let threshold_reached = |call: &<T as Config>::RuntimeCall, approved: u32| {
+			let call_indices = T::CallClassifier::call_indices(call);
+			approved >= config.threshold_for_calls(call_indices.as_deref())
+		};
+
+		// Branch on whether the operation has already started or not.
+		let (timepoint, call) = if let Some(mut m) = <Multisigs<T>>::get(&multisig, call_hash) {
+			// Yes; ensure that the timepoint exists and agrees, and that it did not expire.
+			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			if let Some(expiry) = <MultisigExpiry<T>>::get(&multisig, call_hash) {
+				ensure!(
+					T::BlockNumberProvider::current_block_number() <= expiry,
+					Error::<T>::Expired
+				);
+			}
+
+			let maybe_pos = m.approvals.binary_search(&who).err();
+			let mut approved = config.approval_weight(&m.approvals);
+			if maybe_pos.is_some() {
+				approved = approved.saturating_add(who_weight);
+			}
+
+			match maybe_call.filter(|call| threshold_reached(call, approved)) {
+				Some(call) => {
+					Self::ensure_max_weight(&call, max_weight)?;
+
+					// Clean up storage before executing call to avoid an possibility of
+					// reentrancy attack.
+					<Multisigs<T>>::remove(&multisig, call_hash);
+					<MultisigExpiry<T>>::remove(&multisig, call_hash);
+					T::Currency::unreserve(&m.depositor, m.deposit);
+					(timepoint, call)
+				},
+				None => {
+					let pos = maybe_pos.ok_or(Error::<T>::AlreadyApproved)?;
+					m.approvals
+						.try_insert(pos, who.clone())
+						.map_err(|_| Error::<T>::TooManySignatories)?;
+					<Multisigs<T>>::insert(&multisig, call_hash, m);
+					Self::deposit_event(Event::MultisigApproval {
+						approving: who,
+						timepoint,
+						multisig,
+						call_hash,
+					});
+
+					let final_weight = T::WeightInfo::as_multi_approve(members_len, call_len)
+						.saturating_add(overhead);
+					// Call is not made, so the actual weight does not include call
+					return Ok(Some(final_weight).into())
+				},
+			}
+		} else {
+			// Not yet started; there should be no timepoint given.
+			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
+
+			match maybe_call.filter(|call| threshold_reached(call, who_weight)) {
+				// The approval of the sender is enough, no need to record the operation.
+				Some(call) => {
+					Self::ensure_max_weight(&call, max_weight)?;
+					(Self::timepoint(), call)
+				},
+				None => {
+					let deposit = Self::stateful_deposit(members_len);
+					T::Currency::reserve(&who, deposit)?;
+
+					let initial_approvals =
+						vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+					<Multisigs<T>>::insert(
+						&multisig,
+						call_hash,
+						Multisig {
+							when: Self::timepoint(),
+							deposit,
+							depositor: who.clone(),
+							approvals: initial_approvals,
+						},
+					);
+					if let Some(lifetime) = config.proposal_lifetime {
+						let expiry =
+							T::BlockNumberProvider::current_block_number().saturating_add(lifetime);
+						<MultisigExpiry<T>>::insert(&multisig, call_hash, expiry);
+					}
+					Self::deposit_event(Event::NewMultisig { approving: who, multisig, call_hash });
+
+					let final_weight = T::WeightInfo::as_multi_create(members_len, call_len)
+						.saturating_add(overhead);
+					// Call is not made, so the actual weight does not include call
+					return Ok(Some(final_weight).into())
+				},
+			}
+		};
+
+		let result = call.dispatch(RawOrigin::Signed(multisig.clone()).into());
+		Self::deposit_event(Event::MultisigExecuted {
+			approving: who,
+			timepoint,
+			multisig,
+			call_hash,
+			result: result.map(|_| ()).map_err(|e| e.error),
+		});
+		Ok(get_result_weight(result)
+			.map(|actual_weight| {
+				T::WeightInfo::as_multi_complete(members_len, call_len)
+					.saturating_add(overhead)
+					.saturating_add(actual_weight)
+			})
+			.into())
+	}
+
+	/// Check that the weight of `call` does not exceed `max_weight`.
+	fn ensure_max_weight(call: &<T as Config>::RuntimeCall, max_weight: Weight) -> DispatchResult {
+		ensure!(
+			call.get_dispatch_info().call_weight.all_lte(max_weight),
+			Error::<T>::MaxWeightTooLow
+		);
+		Ok(())
+	}
+
+	/// Check that the members are sorted, not empty and not too many, that their weights are
+	/// not zero and that `threshold` can be reached.
+	fn ensure_valid_members(
+		members: Vec<(T::AccountId, u32)>,
+		threshold: u32,
+	) -> Result<BoundedVec<(T::AccountId, u32), T::MaxSignatories>, DispatchError> {
+		ensure!(!members.is_empty(), Error::<T>::TooFewSignatories);
+		ensure!(
+			members.windows(2).all(|pair| pair[0].0 < pair[1].0),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		ensure!(members.iter().all(|(_, weight)| *weight > 0), Error::<T>::ZeroWeight);
+
+		let members: BoundedVec<_, T::MaxSignatories> =
+			members.try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+		let total = members.iter().fold(0u32, |acc, (_, weight)| acc.saturating_add(*weight));
+		ensure!(threshold > 0 && threshold <= total, Error::<T>::UnreachableThreshold);
+
+		Ok(members)
+	}
+
+	/// Hold the deposit the configuration of a stateful multisig requires after it changed it.
+	///
+	/// The multisig pays for the changes to its own configuration: the first time it changes it,
+	/// it takes over the deposit from its creator, who gets their deposit back. Afterwards, only
+	/// the difference to its current deposit is reserved or unreserved.
+	fn update_stateful_deposit(
+		multisig: &T::AccountId,
+		config: &mut StatefulMultisig<
+			BlockNumberFor<T>,
+			BalanceOf<T>,
+			T::AccountId,
+			T::MaxSignatories,
+		>,
+	) -> DispatchResult {
+		let items = config.members.len().saturating_add(config.call_thresholds.len());
+		let new_deposit = Self::stateful_deposit(items as u32);
+		let old_deposit = config.deposit;
+
+		if &config.depositor != multisig {
+			T::Currency::reserve(multisig, new_deposit)?;
+			let err_amount = T::Currency::unreserve(&config.depositor, old_deposit);
+			debug_assert!(err_amount.is_zero());
+			config.depositor = multisig.clone();
+		} else if new_deposit > old_deposit {
+			T::Currency::reserve(multisig, new_deposit.saturating_sub(old_deposit))?;
+		} else if new_deposit < old_deposit {
+			let excess = old_deposit.saturating_sub(new_deposit);
+			let err_amount = T::Currency::unreserve(multisig, excess);
+			debug_assert!(err_amount.is_zero());
+		}
+
+		config.deposit = new_deposit;
+		Ok(())
+	}
+
+	/// Calculate the deposit for the configuration of a stateful multisig or for one of its
+	/// operations.
+	///
+	/// The deposit is calculated as `DepositBase + DepositFactor * items`, where `items` is
+	/// the number of members, plus the number of call thresholds for the configuration.
+	pub fn stateful_deposit(items: u32) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * items.into()
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -30,6 +30,7 @@ construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Multisig: pallet_multisig,
+		Utility: pallet_utility,
 	}
 );
 
@@ -52,8 +53,13 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
 		match *c {
 			RuntimeCall::Balances(_) => true,
+			RuntimeCall::Utility(_) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+			// Needed for stateful multisigs to manage themselves
+			RuntimeCall::Multisig(
+				Call::reconfigure_stateful { .. } | Call::set_call_threshold { .. },
+			) => true,
 			_ => false,
 		}
 	}
@@ -73,9 +79,39 @@ impl Config for Test {
 	type MaxSignatories = ConstU32<3>;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CallClassifier = TestCallClassifier;
+}
+
+impl pallet_utility::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+pub struct TestCallClassifier;
+impl ClassifyCall<RuntimeCall> for TestCallClassifier {
+	fn call_indices(call: &RuntimeCall) -> Option<Vec<CallIndex>> {
+		let mut call_indices = vec![call_index_of(call)?];
+		match call {
+			RuntimeCall::Utility(
+				UtilityCall::batch { calls } |
+				UtilityCall::batch_all { calls } |
+				UtilityCall::force_batch { calls },
+			) =>
+				for call in calls {
+					call_indices.extend(Self::call_indices(call)?);
+				},
+			// The other calls of the utility pallet are not classified.
+			RuntimeCall::Utility(_) => return None,
+			_ => (),
+		}
+		Some(call_indices)
+	}
 }
 
 use pallet_balances::{Call as BalancesCall, Error as BalancesError};
+use pallet_utility::Call as UtilityCall;
 
 pub fn new_test_ext() -> TestState {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -1015,3 +1051,472 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+fn create_stateful(members: Vec<(u64, u32)>, threshold: u32, lifetime: Option<u32>) -> u64 {
+	let multi = Multisig::stateful_account_id(&1, 0, &now());
+	assert_ok!(Multisig::create_stateful(
+		RuntimeOrigin::signed(1),
+		members,
+		threshold,
+		lifetime,
+		0
+	));
+	multi
+}
+
+#[test]
+fn stateful_multisig_dispatches_with_weighted_approvals() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1), (3, 2)], 2, None);
+		// DepositBase + DepositFactor * 3 members.
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 8);
+
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				Some(now()),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::AlreadyApproved,
+		);
+
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+
+		// The approval of a member with enough weight is enough on its own.
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			Event::MultisigExecuted {
+				approving: 3,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn stateful_multisig_can_be_reconfigured() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1)], 2, None);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		// An operation approved by 1 before it is removed from the members.
+		let transfer = call_transfer(6, 5);
+		let transfer_weight = transfer.get_dispatch_info().call_weight;
+		let transfer_hash = blake2_256(&transfer.encode());
+		assert_ok!(Multisig::approve_as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			transfer_hash,
+			Weight::zero()
+		));
+
+		let call = Box::new(RuntimeCall::Multisig(Call::reconfigure_stateful {
+			members: vec![(2, 1), (3, 1), (4, 1)],
+			threshold: 2,
+			proposal_lifetime: None,
+		}));
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call.clone(),
+			call_weight
+		));
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		System::assert_has_event(Event::StatefulMultisigReconfigured { multisig: multi }.into());
+
+		// Same account, new members, and the multisig took over the deposit, which follows the
+		// number of members. The deposit of 1 for the open transfer is still reserved.
+		let config = StatefulMultisigs::<Test>::get(multi).unwrap();
+		assert_eq!(config.members.into_inner(), vec![(2, 1), (3, 1), (4, 1)]);
+		assert_eq!(config.depositor, multi);
+		assert_eq!(Balances::reserved_balance(multi), 4);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				None,
+				[0; 32],
+				Weight::zero()
+			),
+			Error::<Test>::NotMember,
+		);
+
+		// The approval of 1 no longer counts.
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			transfer.clone(),
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			transfer,
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Dissolving the multisig returns its own deposit.
+		assert_ok!(Multisig::dissolve_stateful(RuntimeOrigin::signed(multi)));
+		assert_eq!(Balances::reserved_balance(multi), 0);
+	});
+}
+
+#[test]
+fn stateful_multisig_cannot_grow_without_paying_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1)], 1, None);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// The creator does not pay for the configuration the multisig chooses.
+		assert_noop!(
+			Multisig::reconfigure_stateful(
+				RuntimeOrigin::signed(multi),
+				vec![(2, 1), (3, 1), (4, 1)],
+				1,
+				None
+			),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Multisig::reconfigure_stateful(
+			RuntimeOrigin::signed(multi),
+			vec![(2, 1), (3, 1), (4, 1)],
+			1,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(multi), 4);
+
+		// From then on, only the difference is reserved or returned.
+		assert_ok!(Multisig::reconfigure_stateful(
+			RuntimeOrigin::signed(multi),
+			vec![(2, 1)],
+			1,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(multi), 2);
+		assert_eq!(Balances::free_balance(multi), 3);
+	});
+}
+
+#[test]
+fn stateful_multisig_call_thresholds_work() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1), (3, 1)], 1, None);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 2));
+
+		let transfer = call_transfer(6, 5);
+		let transfer_weight = transfer.get_dispatch_info().call_weight;
+		let call_index = {
+			let encoded = transfer.encode();
+			(encoded[0], encoded[1])
+		};
+
+		// Any member can change the call thresholds, as they are not themselves overridden.
+		let call = Box::new(RuntimeCall::Multisig(Call::set_call_threshold {
+			call_index,
+			threshold: Some(2),
+		}));
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			call,
+			call_weight
+		));
+		System::assert_has_event(
+			Event::CallThresholdSet { multisig: multi, call_index, threshold: Some(2) }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(multi), 5);
+
+		assert_noop!(
+			Multisig::set_call_threshold(RuntimeOrigin::signed(multi), call_index, Some(4)),
+			Error::<Test>::UnreachableThreshold,
+		);
+
+		// Transfers now need two approvals.
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			transfer.clone(),
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			transfer,
+			transfer_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+
+		assert_ok!(Multisig::set_call_threshold(RuntimeOrigin::signed(multi), call_index, None));
+		assert!(StatefulMultisigs::<Test>::get(multi).unwrap().call_thresholds.is_empty());
+		assert_eq!(Balances::reserved_balance(multi), 4);
+	});
+}
+
+#[test]
+fn stateful_multisig_call_thresholds_apply_to_nested_calls() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1), (3, 1)], 1, None);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+
+		let transfer = call_transfer(6, 5);
+		let call_index = call_index_of(&transfer).unwrap();
+		assert_ok!(Multisig::set_call_threshold(RuntimeOrigin::signed(multi), call_index, Some(2)));
+
+		// Wrapping the transfer into a batch doesn't lower the threshold.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch { calls: vec![*transfer] }));
+		let batch_weight = batch.get_dispatch_info().call_weight;
+		let batch_hash = blake2_256(&batch.encode());
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			batch.clone(),
+			batch_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert!(Multisigs::<Test>::contains_key(multi, batch_hash));
+
+		// Neither does wrapping it into a call which isn't classified.
+		let derivative = Box::new(RuntimeCall::Utility(UtilityCall::as_derivative {
+			index: 0,
+			call: call_transfer(6, 5),
+		}));
+		let derivative_weight = derivative.get_dispatch_info().call_weight;
+		let derivative_hash = blake2_256(&derivative.encode());
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			derivative,
+			derivative_weight
+		));
+		assert!(Multisigs::<Test>::contains_key(multi, derivative_hash));
+
+		// Batches of calls without a threshold of their own only need the default threshold.
+		let remarks = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![RuntimeCall::System(frame_system::Call::remark { remark: vec![] })],
+		}));
+		let remarks_weight = remarks.get_dispatch_info().call_weight;
+		let remarks_hash = blake2_256(&remarks.encode());
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			remarks,
+			remarks_weight
+		));
+		assert!(!Multisigs::<Test>::contains_key(multi, remarks_hash));
+		System::assert_has_event(pallet_utility::Event::<Test>::BatchCompleted.into());
+
+		// The second approval dispatches the batched transfer.
+		assert_ok!(Multisig::as_stateful_multi(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			batch,
+			batch_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+	});
+}
+
+#[test]
+fn stateful_multisig_operations_expire() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1)], 2, Some(5));
+		let hash = blake2_256(&call_transfer(6, 5).encode());
+		let timepoint = now();
+		assert_ok!(Multisig::approve_as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(MultisigExpiry::<Test>::get(multi, hash), Some(6));
+		assert_eq!(Balances::reserved_balance(1), 6);
+
+		System::set_block_number(6);
+		assert_noop!(
+			Multisig::remove_expired_multisig(RuntimeOrigin::signed(5), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(7);
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(2),
+				multi,
+				Some(timepoint),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::Expired,
+		);
+
+		assert_ok!(Multisig::remove_expired_multisig(RuntimeOrigin::signed(5), multi, hash));
+		System::assert_last_event(
+			Event::MultisigExpired { multisig: multi, call_hash: hash }.into(),
+		);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!MultisigExpiry::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 3);
+	});
+}
+
+#[test]
+fn stateful_multisig_checks_its_configuration() {
+	new_test_ext().execute_with(|| {
+		let create = |members: Vec<(u64, u32)>, threshold| {
+			Multisig::create_stateful(RuntimeOrigin::signed(1), members, threshold, None, 0)
+		};
+		assert_noop!(create(vec![], 1), Error::<Test>::TooFewSignatories);
+		assert_noop!(create(vec![(2, 1), (1, 1)], 1), Error::<Test>::SignatoriesOutOfOrder);
+		assert_noop!(create(vec![(1, 1), (1, 1)], 1), Error::<Test>::SignatoriesOutOfOrder);
+		assert_noop!(create(vec![(1, 0), (2, 1)], 1), Error::<Test>::ZeroWeight);
+		assert_noop!(create(vec![(1, 1), (2, 1)], 0), Error::<Test>::UnreachableThreshold);
+		assert_noop!(create(vec![(1, 1), (2, 1)], 3), Error::<Test>::UnreachableThreshold);
+		assert_noop!(
+			create(vec![(1, 1), (2, 1), (3, 1), (4, 1)], 2),
+			Error::<Test>::TooManySignatories
+		);
+
+		let multi = create_stateful(vec![(1, 1), (2, 1)], 2, None);
+		assert_noop!(create(vec![(1, 1), (2, 1)], 2), Error::<Test>::AlreadyStored);
+		assert_ok!(Multisig::create_stateful(
+			RuntimeOrigin::signed(1),
+			vec![(1, 1), (2, 1)],
+			2,
+			None,
+			1
+		));
+
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(3),
+				multi,
+				None,
+				[0; 32],
+				Weight::zero()
+			),
+			Error::<Test>::NotMember,
+		);
+		assert_noop!(
+			Multisig::reconfigure_stateful(RuntimeOrigin::signed(1), vec![(1, 1)], 1, None),
+			Error::<Test>::NotStateful,
+		);
+
+		assert_ok!(Multisig::dissolve_stateful(RuntimeOrigin::signed(multi)));
+		System::assert_last_event(Event::StatefulMultisigDissolved { multisig: multi }.into());
+		// Only the deposit of the second multisig is left.
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Multisig::approve_as_stateful_multi(
+				RuntimeOrigin::signed(1),
+				multi,
+				None,
+				[0; 32],
+				Weight::zero()
+			),
+			Error::<Test>::NotStateful,
+		);
+	});
+}
+
+#[test]
+fn stateful_multisig_cannot_be_dissolved_with_open_operations() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stateful(vec![(1, 1), (2, 1)], 2, Some(10));
+		let hash = blake2_256(&call_transfer(6, 5).encode());
+		assert_ok!(Multisig::approve_as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+
+		assert_noop!(
+			Multisig::dissolve_stateful(RuntimeOrigin::signed(multi)),
+			Error::<Test>::OperationsOpen,
+		);
+
+		assert_ok!(Multisig::cancel_as_stateful_multi(
+			RuntimeOrigin::signed(1),
+			multi,
+			timepoint,
+			hash
+		));
+		assert_ok!(Multisig::dissolve_stateful(RuntimeOrigin::signed(multi)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(MultisigExpiry::<Test>::iter_prefix(multi).next().is_none());
+	});
+}
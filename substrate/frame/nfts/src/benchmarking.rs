@@ -98,6 +98,29 @@ fn mint_item<T: Config<I>, I: 'static>(
 	(item, caller, caller_lookup)
 }
 
+fn royalties<T: Config<I>, I: 'static>(n: u32) -> RoyaltiesOf<T, I> {
+	(0..n)
+		.map(|i| {
+			let recipient: T::AccountId = account("recipient", i, SEED);
+			T::Currency::make_free_balance_be(&recipient, T::Currency::minimum_balance());
+			let basis_points = (u32::from(MAX_ROYALTY_BASIS_POINTS) / n) as u16;
+			RoyaltyShare { recipient, basis_points }
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn add_collection_royalties<T: Config<I>, I: 'static>(n: u32) {
+	let collection = T::Helper::collection(0);
+	let caller = Collection::<T, I>::get(collection).unwrap().owner;
+	assert_ok!(Nfts::<T, I>::set_collection_royalty(
+		SystemOrigin::Signed(caller).into(),
+		collection,
+		royalties::<T, I>(n),
+	));
+}
+
 fn lock_item<T: Config<I>, I: 'static>(
 	index: u16,
 ) -> (T::ItemId, T::AccountId, AccountIdLookupOf<T>) {
@@ -631,6 +654,23 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let royalties = royalties::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, royalties.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet { collection, royalties }.into());
+	}
+
+	set_item_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let royalties = royalties::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, royalties.clone())
+	verify {
+		assert_last_event::<T, I>(Event::ItemRoyaltySet { collection, item, royalties }.into());
+	}
+
 	update_mint_settings {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let mint_settings = MintSettings {
@@ -662,11 +702,14 @@ benchmarks_instance_pallet! {
 	}
 
 	buy_item {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, seller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = ItemPrice::<T, I>::from(1_000_000u32);
+		add_collection_royalties::<T, I>(r);
+		T::Currency::make_free_balance_be(&seller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
 		let origin = SystemOrigin::Signed(seller.clone()).into();
 		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
@@ -750,16 +793,19 @@ benchmarks_instance_pallet! {
 	}
 
 	claim_swap {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = ItemPrice::<T, I>::from(1_000_000u32);
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		add_collection_royalties::<T, I>(r);
 		let origin = SystemOrigin::Signed(caller.clone());
 		T::BlockNumberProvider::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
@@ -146,7 +146,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `send_item_id`, `receive_collection_id`, and `receive_item_id`. The `caller` account must be
 	/// the owner of the item specified by `send_collection_id` and `send_item_id`. If the claimed
 	/// swap has an associated `price`, it will be transferred between the owners of the two items
-	/// based on the `price.direction`. After the swap is completed, the function emits the
+	/// based on the `price.direction`, and the royalties of the item sold for it are paid out of
+	/// it. After the swap is completed, the function emits the
 	/// `SwapClaimed` event.
 	///
 	/// - `caller`: The account claiming the swap offer, which must be the owner of the sent item.
@@ -192,18 +193,34 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
-					&receive_item.owner,
-					&send_item.owner,
-					price.amount,
-					KeepAlive,
-				)?,
-				PriceDirection::Receive => T::Currency::transfer(
-					&send_item.owner,
-					&receive_item.owner,
-					price.amount,
-					KeepAlive,
-				)?,
+				PriceDirection::Send => {
+					let royalties = Self::do_pay_royalties(
+						send_collection_id,
+						send_item_id,
+						&receive_item.owner,
+						price.amount,
+					)?;
+					T::Currency::transfer(
+						&receive_item.owner,
+						&send_item.owner,
+						price.amount.saturating_sub(royalties),
+						KeepAlive,
+					)?
+				},
+				PriceDirection::Receive => {
+					let royalties = Self::do_pay_royalties(
+						receive_collection_id,
+						receive_item_id,
+						&send_item.owner,
+						price.amount,
+					)?;
+					T::Currency::transfer(
+						&send_item.owner,
+						&receive_item.owner,
+						price.amount.saturating_sub(royalties),
+						KeepAlive,
+					)?
+				},
 			};
 		}
 
@@ -116,7 +116,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///
 	/// This function is used to buy an item from the specified `collection`. The `buyer` account
 	/// will attempt to buy the item with the provided `bid_price`. The item's current owner will
-	/// receive the bid price if it is equal to or higher than the item's set price. If
+	/// receive the item's set price, less the royalties of the item, if the bid price is equal to
+	/// or higher than it. If
 	/// `whitelisted_buyer` is specified in the item's price information, only that account is
 	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
 	/// function will return an error.
@@ -148,10 +149,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
+		let royalties = Self::do_pay_royalties(collection, item, &buyer, price_info.0)?;
 		T::Currency::transfer(
 			&buyer,
 			&details.owner,
-			price_info.0,
+			price_info.0.saturating_sub(royalties),
 			ExistenceRequirement::KeepAlive,
 		)?;
 
@@ -0,0 +1,172 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to configure and pay the royalties of collections and
+//! items for the NFTs pallet.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+use sp_runtime::Permill;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the royalties of a collection.
+	///
+	/// - `maybe_check_owner`: An optional account that must be the owner of the collection. If
+	///   `None`, the royalties can be set even if they are locked.
+	/// - `collection`: The identifier of the collection.
+	/// - `royalties`: The new royalties of the collection. If empty, the royalties are cleared.
+	///
+	/// This function checks that the `UnlockedRoyalties` setting of the collection is enabled and
+	/// that the royalties do not exceed the price of an item. It then stores the royalties and
+	/// emits the `CollectionRoyaltySet` event.
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalties: RoyaltiesOf<T, I>,
+	) -> DispatchResult {
+		Self::ensure_royalties_unlocked(maybe_check_owner, &collection)?;
+		Self::ensure_valid_royalties(&royalties)?;
+
+		if royalties.is_empty() {
+			CollectionRoyaltyOf::<T, I>::remove(&collection);
+		} else {
+			CollectionRoyaltyOf::<T, I>::insert(&collection, &royalties);
+		}
+
+		Self::deposit_event(Event::CollectionRoyaltySet { collection, royalties });
+		Ok(())
+	}
+
+	/// Sets the royalties of an item, which take precedence over those of its collection.
+	///
+	/// - `maybe_check_owner`: An optional account that must be the owner of the collection. If
+	///   `None`, the royalties can be set even if they are locked.
+	/// - `collection`: The identifier of the collection of the item.
+	/// - `item`: The identifier of the item.
+	/// - `royalties`: The new royalties of the item. If empty, the royalties of the item are
+	///   cleared and those of the collection apply.
+	///
+	/// The royalties of items are locked together with those of their collection.
+	pub(crate) fn do_set_item_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		royalties: RoyaltiesOf<T, I>,
+	) -> DispatchResult {
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		Self::ensure_royalties_unlocked(maybe_check_owner, &collection)?;
+		Self::ensure_valid_royalties(&royalties)?;
+
+		if royalties.is_empty() {
+			ItemRoyaltyOf::<T, I>::remove(&collection, &item);
+		} else {
+			ItemRoyaltyOf::<T, I>::insert(&collection, &item, &royalties);
+		}
+
+		Self::deposit_event(Event::ItemRoyaltySet { collection, item, royalties });
+		Ok(())
+	}
+
+	/// Returns the royalties owed on trades of an item: those of the item if set, otherwise those
+	/// of its collection.
+	pub fn royalties(collection: &T::CollectionId, item: &T::ItemId) -> RoyaltiesOf<T, I> {
+		ItemRoyaltyOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltyOf::<T, I>::get(collection))
+			.unwrap_or_default()
+	}
+
+	/// Returns the amount owed to each royalty recipient of an item traded for `price`.
+	pub fn royalty_amounts(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		price: ItemPrice<T, I>,
+	) -> Vec<(T::AccountId, ItemPrice<T, I>)> {
+		Self::royalties(collection, item)
+			.into_iter()
+			.map(|share| {
+				let amount =
+					Permill::from_parts(u32::from(share.basis_points) * 100).mul_floor(price);
+				(share.recipient, amount)
+			})
+			.collect()
+	}
+
+	/// Pays the royalties owed on a trade of an item for `price`.
+	///
+	/// - `collection`: The identifier of the collection of the traded item.
+	/// - `item`: The identifier of the traded item.
+	/// - `payer`: The account paying the price of the item.
+	/// - `price`: The price the item is traded for.
+	///
+	/// Returns the total amount of royalties paid, which the seller does not receive. Emits a
+	/// `RoyaltyPaid` event for each recipient paid.
+	///
+	/// A royalty below the existential deposit owed to an account that does not exist cannot be
+	/// paid without failing the trade, so it is skipped and left to the seller.
+	pub(crate) fn do_pay_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		payer: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> Result<ItemPrice<T, I>, DispatchError> {
+		let mut paid: ItemPrice<T, I> = Zero::zero();
+		for (recipient, amount) in Self::royalty_amounts(&collection, &item, price) {
+			if amount.is_zero() ||
+				(amount < T::Currency::minimum_balance() &&
+					T::Currency::total_balance(&recipient).is_zero())
+			{
+				continue
+			}
+			T::Currency::transfer(payer, &recipient, amount, KeepAlive)?;
+			paid.saturating_accrue(amount);
+			Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+		}
+		Ok(paid)
+	}
+
+	fn ensure_royalties_unlocked(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: &T::CollectionId,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			Self::ensure_royalties_not_locked(collection)?;
+		}
+		Ok(())
+	}
+
+	/// Ensures the `UnlockedRoyalties` setting of `collection` is enabled.
+	pub(crate) fn ensure_royalties_not_locked(collection: &T::CollectionId) -> DispatchResult {
+		let config = Self::get_collection_config(collection)?;
+		ensure!(
+			config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedCollectionRoyalties
+		);
+		Ok(())
+	}
+
+	fn ensure_valid_royalties(royalties: &RoyaltiesOf<T, I>) -> DispatchResult {
+		let total = royalties.iter().map(|share| u32::from(share.basis_points)).sum::<u32>();
+		ensure!(total <= u32::from(MAX_ROYALTY_BASIS_POINTS), Error::<T, I>::RoyaltyTooHigh);
+		Ok(())
+	}
+}
@@ -466,6 +466,58 @@ impl<T: Config<I>, I: 'static> Trading<T::AccountId, ItemPrice<T, I>> for Pallet
 	}
 }
 
+impl<T: Config<I>, I: 'static> InspectRoyalties<T::AccountId, ItemPrice<T, I>> for Pallet<T, I> {
+	fn royalties(collection: &Self::CollectionId, item: &Self::ItemId) -> Vec<(T::AccountId, u16)> {
+		Pallet::<T, I>::royalties(collection, item)
+			.into_iter()
+			.map(|share| (share.recipient, share.basis_points))
+			.collect()
+	}
+
+	fn royalty_amounts(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		price: &ItemPrice<T, I>,
+	) -> Vec<(T::AccountId, ItemPrice<T, I>)> {
+		Pallet::<T, I>::royalty_amounts(collection, item, *price)
+	}
+}
+
+/// Royalties set through this trait are subject to the `UnlockedRoyalties` setting of the
+/// collection, like those set by its owner. Only the force origin can change locked royalties.
+impl<T: Config<I>, I: 'static> MutateRoyalties<T::AccountId> for Pallet<T, I> {
+	fn set_collection_royalties(
+		collection: &Self::CollectionId,
+		royalties: &[(T::AccountId, u16)],
+	) -> DispatchResult {
+		Self::ensure_royalties_not_locked(collection)?;
+		Self::do_set_collection_royalty(None, *collection, Self::to_royalties(royalties)?)
+	}
+
+	fn set_item_royalties(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		royalties: &[(T::AccountId, u16)],
+	) -> DispatchResult {
+		Self::ensure_royalties_not_locked(collection)?;
+		Self::do_set_item_royalty(None, *collection, *item, Self::to_royalties(royalties)?)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	fn to_royalties(royalties: &[(T::AccountId, u16)]) -> Result<RoyaltiesOf<T, I>, DispatchError> {
+		royalties
+			.iter()
+			.map(|(recipient, basis_points)| RoyaltyShare {
+				recipient: recipient.clone(),
+				basis_points: *basis_points,
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| Error::<T, I>::TooManyRoyaltyRecipients.into())
+	}
+}
+
 impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I> {
 	type CollectionsIterator = KeyPrefixIterator<<T as Config<I>>::CollectionId>;
 	type ItemsIterator = KeyPrefixIterator<<T as Config<I>>::ItemId>;
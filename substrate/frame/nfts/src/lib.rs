@@ -79,7 +79,7 @@ pub mod pallet {
 	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -217,6 +217,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of royalty recipients of a collection or an item.
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -414,6 +418,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Royalties paid on trades of the items of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltiesOf<T, I>, OptionQuery>;
+
+	/// Royalties paid on trades of an item, overriding the royalties of its collection.
+	#[pallet::storage]
+	pub type ItemRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltiesOf<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -594,6 +615,21 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// The royalties of a `collection` were set, or cleared if `royalties` is empty.
+		CollectionRoyaltySet { collection: T::CollectionId, royalties: RoyaltiesOf<T, I> },
+		/// The royalties of an `item` were set, or cleared if `royalties` is empty.
+		ItemRoyaltySet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalties: RoyaltiesOf<T, I>,
+		},
+		/// A royalty was paid on a trade of an `item`.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -688,6 +724,12 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// Collection's royalties are locked.
+		LockedCollectionRoyalties,
+		/// The royalties add up to more than the price of an item.
+		RoyaltyTooHigh,
+		/// Too many royalty recipients were provided.
+		TooManyRoyaltyRecipients,
 	}
 
 	#[pallet::call]
@@ -1759,16 +1801,20 @@ pub mod pallet {
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
 		/// Emits `ItemBought` on success.
+		///
+		/// Weight: `O(R)` where `R` is the number of royalty recipients of the `item`.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(T::WeightInfo::buy_item(T::MaxRoyaltyRecipients::get()))]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
 			item: T::ItemId,
 			bid_price: ItemPrice<T, I>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
-			Self::do_buy_item(collection, item, origin, bid_price)
+			let recipients = Self::royalties(&collection, &item).len() as u32;
+			Self::do_buy_item(collection, item, origin, bid_price)?;
+			Ok(Some(T::WeightInfo::buy_item(recipients)).into())
 		}
 
 		/// Allows to pay the tips.
@@ -1859,8 +1905,11 @@ pub mod pallet {
 		/// - `witness_price`: A price that was previously agreed on.
 		///
 		/// Emits `SwapClaimed` on success.
+		///
+		/// Weight: `O(R)` where `R` is the number of royalty recipients of the item sold for the
+		/// price of the swap.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap(T::MaxRoyaltyRecipients::get()))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1868,8 +1917,13 @@ pub mod pallet {
 			receive_collection: T::CollectionId,
 			receive_item: T::ItemId,
 			witness_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
+			// Only the royalties of one of the items are paid, depending on the price direction.
+			let recipients = Self::royalties(&send_collection, &send_item)
+				.len()
+				.max(Self::royalties(&receive_collection, &receive_item).len())
+				as u32;
 			Self::do_claim_swap(
 				origin,
 				send_collection,
@@ -1877,7 +1931,8 @@ pub mod pallet {
 				receive_collection,
 				receive_item,
 				witness_price,
-			)
+			)?;
+			Ok(Some(T::WeightInfo::claim_swap(recipients)).into())
 		}
 
 		/// Mint an item by providing the pre-signed approval.
@@ -1931,6 +1986,63 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set the royalties paid on trades of the items of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// Royalties are paid out of the price of an item whenever it is bought or swapped for a
+		/// price, unless the item has royalties of its own.
+		///
+		/// - `collection`: The collection whose royalties to set.
+		/// - `royalties`: The recipients of the royalties and their shares of the price, in basis
+		///   points. An empty list clears the royalties.
+		///
+		/// Emits `CollectionRoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalties: RoyaltiesOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_royalty(maybe_check_owner, collection, royalties)
+		}
+
+		/// Set the royalties paid on trades of an item, overriding the royalties of its
+		/// collection.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose royalties to set.
+		/// - `royalties`: The recipients of the royalties and their shares of the price, in basis
+		///   points. An empty list clears the royalties of the item, so that those of the
+		///   collection apply again.
+		///
+		/// Emits `ItemRoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_item_royalty())]
+		pub fn set_item_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalties: RoyaltiesOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_item_royalty(maybe_check_owner, collection, item, royalties)
+		}
 	}
 }
 
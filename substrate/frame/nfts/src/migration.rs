@@ -64,7 +64,7 @@ pub mod v1 {
 				on_chain_version
 			);
 
-			if on_chain_version == 0 && in_code_version == 1 {
+			if on_chain_version == 0 && in_code_version >= 1 {
 				let mut translated = 0u64;
 				let mut configs_iterated = 0u64;
 				Collection::<T>::translate::<
@@ -77,13 +77,12 @@ pub mod v1 {
 					Some(old_value.migrate_to_v1(item_configs))
 				});
 
-				in_code_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 
 				log::info!(
 					target: LOG_TARGET,
-					"Upgraded {} records, storage to version {:?}",
-					translated,
-					in_code_version
+					"Upgraded {} records, storage to version 1",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + configs_iterated + 1, translated + 1)
 			} else {
@@ -118,3 +117,90 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// A migration utility to update the storage version from v1 to v2 for the pallet.
+	///
+	/// The settings of a collection are stored as the set of its disabled settings, so the
+	/// royalties of the collections created before the `UnlockedRoyalties` setting existed are
+	/// unlocked. This locks the royalties of the collections whose metadata is locked, as their
+	/// owners expect the terms of the collection to be final.
+	pub struct MigrateToV2<T, I = ()>(core::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let in_code_version = Pallet::<T, I>::in_code_storage_version();
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with in-code storage version {:?} / onchain {:?}",
+				in_code_version,
+				on_chain_version
+			);
+
+			if on_chain_version == 1 && in_code_version == 2 {
+				let mut iterated = 0u64;
+				let mut locked = 0u64;
+				CollectionConfigOf::<T, I>::translate_values::<CollectionConfigFor<T, I>, _>(
+					|mut config| {
+						iterated.saturating_inc();
+						if !config.is_setting_enabled(CollectionSetting::UnlockedMetadata) {
+							config.disable_setting(CollectionSetting::UnlockedRoyalties);
+							locked.saturating_inc();
+						}
+						Some(config)
+					},
+				);
+
+				in_code_version.put::<Pallet<T, I>>();
+
+				log::info!(
+					target: LOG_TARGET,
+					"Locked the royalties of {} collections, storage to version {:?}",
+					locked,
+					in_code_version
+				);
+				T::DbWeight::get().reads_writes(iterated + 1, iterated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let prev_count = CollectionConfigOf::<T, I>::iter().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CollectionConfigOf::<T, I>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the records count before and after the migration should be the same"
+			);
+			ensure!(
+				CollectionConfigOf::<T, I>::iter_values().all(|config| {
+					config.is_setting_enabled(CollectionSetting::UnlockedMetadata) ||
+						!config.is_setting_enabled(CollectionSetting::UnlockedRoyalties)
+				}),
+				"the royalties of collections with locked metadata should be locked"
+			);
+
+			ensure!(Pallet::<T, I>::on_chain_storage_version() >= 2, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
@@ -22,8 +22,10 @@ use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{
-		tokens::nonfungibles_v2::{Create, Destroy, Inspect, Mutate},
-		Currency, Get,
+		tokens::nonfungibles_v2::{
+			Create, Destroy, Inspect, InspectRoyalties, Mutate, MutateRoyalties,
+		},
+		Currency, Get, OnRuntimeUpgrade, StorageVersion,
 	},
 };
 use pallet_balances::Error as BalancesError;
@@ -3875,3 +3877,378 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+#[test]
+fn buy_item_pays_royalties() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+
+		for user in [&user_1, &user_2, &user_3, &user_4] {
+			Balances::make_free_balance_be(user, initial_balance);
+		}
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+
+		let share = |recipient: &AccountIdOf<Test>, basis_points| RoyaltyShare {
+			recipient: recipient.clone(),
+			basis_points,
+		};
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				bvec![share(&user_3, 1000)]
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				bvec![share(&user_3, 6000), share(&user_4, 5000)]
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+
+		let royalties: RoyaltiesOf<Test> = bvec![share(&user_3, 1000), share(&user_4, 500)];
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			royalties.clone()
+		));
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			royalties
+		}));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(200),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			200
+		));
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			recipient: user_3.clone(),
+			amount: 20,
+		}));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 170);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 200);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 20);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance + 10);
+
+		// The royalties of an item override those of its collection.
+		assert_ok!(Nfts::set_item_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			bvec![share(&user_4, 2000)]
+		));
+		assert_eq!(
+			<Nfts as InspectRoyalties<_, _>>::royalties(&collection_id, &item_id),
+			vec![(user_4.clone(), 2000)]
+		);
+		assert_eq!(
+			<Nfts as InspectRoyalties<_, _>>::royalty_amounts(&collection_id, &item_id, &100),
+			vec![(user_4.clone(), 20)]
+		);
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(100),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			100
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 70);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 120);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 20);
+		assert_eq!(Balances::total_balance(&user_4), initial_balance + 30);
+
+		// Clearing the royalties of the item makes those of the collection apply again.
+		assert_ok!(Nfts::set_item_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			bvec![]
+		));
+		assert_eq!(
+			<Nfts as InspectRoyalties<_, _>>::royalties(&collection_id, &item_id),
+			vec![(user_3.clone(), 1000), (user_4.clone(), 500)]
+		);
+	});
+}
+
+#[test]
+fn royalties_below_existential_deposit_to_missing_accounts_are_skipped() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let user_4 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1000;
+
+		ExistentialDeposit::set(5);
+		for user in [&user_1, &user_2, &user_3] {
+			Balances::make_free_balance_be(user, initial_balance);
+		}
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			bvec![
+				RoyaltyShare { recipient: user_3.clone(), basis_points: 100 },
+				RoyaltyShare { recipient: user_4.clone(), basis_points: 100 },
+			]
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(200),
+			None,
+		));
+
+		// `user_4` does not exist and 2 is below the existential deposit, so it is not paid.
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			200
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 198);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 200);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 2);
+		assert_eq!(Balances::total_balance(&user_4), 0);
+		assert!(!events().iter().any(|e| matches!(
+			e,
+			Event::<Test>::RoyaltyPaid { recipient, .. } if recipient == &user_4
+		)));
+	});
+}
+
+#[test]
+fn migration_to_v2_locks_royalties_of_collections_with_locked_metadata() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Nfts>();
+		for _ in 0..2 {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				account(1),
+				default_collection_config()
+			));
+		}
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(account(1)),
+			1,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata.into())
+		));
+		// Like collections created before the setting existed, both have unlocked royalties.
+		assert!(CollectionConfigOf::<Test>::get(1)
+			.unwrap()
+			.is_setting_enabled(CollectionSetting::UnlockedRoyalties));
+
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nfts::on_chain_storage_version(), StorageVersion::new(2));
+		let royalties_unlocked = |collection| {
+			CollectionConfigOf::<Test>::get(collection)
+				.unwrap()
+				.is_setting_enabled(CollectionSetting::UnlockedRoyalties)
+		};
+		assert!(royalties_unlocked(0));
+		assert!(!royalties_unlocked(1));
+	});
+}
+
+#[test]
+fn claim_swap_pays_royalties() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let initial_balance = 1000;
+		let price = PriceWithDirection { amount: 100, direction: PriceDirection::Receive };
+
+		for user in [&user_1, &user_2, &user_3] {
+			Balances::make_free_balance_be(user, initial_balance);
+		}
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_2.clone(),
+			default_item_config(),
+		));
+		assert_ok!(<Nfts as MutateRoyalties<_>>::set_item_royalties(
+			&collection_id,
+			&item_1,
+			&[(user_3.clone(), 1000)]
+		));
+
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(price.clone()),
+			2,
+		));
+		// The owner of `item_2` pays for `item_1`, so the royalties of `item_1` are due.
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(price),
+		));
+
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 90);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 100);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 10);
+	});
+}
+
+#[test]
+fn royalties_can_be_locked() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 1;
+		let royalties: RoyaltiesOf<Test> =
+			bvec![RoyaltyShare { recipient: user_2.clone(), basis_points: 100 }];
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_item_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			royalties.clone()
+		));
+
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalties.clone()
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+		assert_noop!(
+			Nfts::set_item_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				bvec![]
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+
+		// So can't other pallets.
+		assert_noop!(
+			<Nfts as MutateRoyalties<_>>::set_collection_royalties(
+				&collection_id,
+				&[(user_2.clone(), 200)]
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+		assert_noop!(
+			<Nfts as MutateRoyalties<_>>::set_item_royalties(&collection_id, &item_id, &[]),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+		assert_eq!(ItemRoyaltyOf::<Test>::get(collection_id, item_id), Some(royalties.clone()));
+
+		// The force origin can still change locked royalties.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::root(),
+			collection_id,
+			royalties.clone()
+		));
+		assert_eq!(CollectionRoyaltyOf::<Test>::get(collection_id), Some(royalties));
+
+		// Burning an item removes its royalties.
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id));
+		assert_eq!(ItemRoyaltyOf::<Test>::get(collection_id, item_id), None);
+	});
+}
@@ -88,6 +88,14 @@ pub type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
 	<T as SystemConfig>::AccountId,
 	BlockNumberFor<T, I>,
 >;
+/// A type alias for the royalties of a collection or an item.
+pub type RoyaltiesOf<T, I = ()> = BoundedVec<
+	RoyaltyShare<<T as SystemConfig>::AccountId>,
+	<T as Config<I>>::MaxRoyaltyRecipients,
+>;
+
+/// Basis points making up the full price of an item.
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
 
 /// Information about a collection.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -276,6 +284,25 @@ pub enum PriceDirection {
 	Receive,
 }
 
+/// A share of the price of an item paid to a recipient whenever the item is traded.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RoyaltyShare<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The share of the price, in basis points.
+	pub basis_points: u16,
+}
+
 /// Holds the details about the price.
 #[derive(
 	Clone,
@@ -310,6 +337,11 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection and its items can be modified.
+	///
+	/// Collections created before royalties were introduced have this setting enabled, unless
+	/// their metadata was locked, see [`crate::migration::v2::MigrateToV2`].
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
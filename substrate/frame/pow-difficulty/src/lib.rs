@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # PoW Difficulty Pallet
+//!
+//! Adjusts the difficulty of a Proof-of-Work chain so that blocks are produced every
+//! [`Config::TargetBlockTime`] on average.
+//!
+//! ## Overview
+//!
+//! The pallet has to be set as (or be part of) the [`pallet_timestamp::Config::OnTimestampSet`]
+//! handler of the runtime. On every new timestamp it records the time it took to mine the block,
+//! together with the difficulty the block was mined at, into a ring buffer of the last
+//! [`Config::AdjustmentWindow`] blocks. The difficulty of the next block is then
+//!
+//! ```text
+//! sum(difficulties) * TargetBlockTime / sum(solve times)
+//! ```
+//!
+//! where the sum of the solve times is clamped to at most [`Config::MaxAdjustmentFactor`] times
+//! away from the target, and the result is never lower than [`Config::MinimumDifficulty`].
+//!
+//! Updating the difficulty is `O(1)`, as required from an `OnTimestampSet` handler. Its weight is
+//! registered as mandatory weight of the block.
+//!
+//! Changing [`Config::AdjustmentWindow`] discards the recorded samples, either on the next runtime
+//! upgrade or the next timestamp, whichever comes first. This is `O(1)` as well: the samples are
+//! overwritten before they are used again, and the ones outside of the new window are removed
+//! in [`Hooks::on_idle`](frame_support::traits::Hooks::on_idle). The difficulty itself is kept.
+//!
+//! The difficulty is meant to be returned by the `DifficultyApi` runtime api of
+//! `sp-consensus-pow`, see [`Pallet::difficulty`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+	dispatch::DispatchClass,
+	traits::OnTimestampSet,
+	weights::{Weight, WeightMeter},
+};
+use sp_core::U256;
+use sp_runtime::traits::UniqueSaturatedInto;
+
+pub use pallet::*;
+
+const LOG_TARGET: &str = "runtime::pow-difficulty";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
+		/// The average time between two blocks the difficulty is adjusted for, in milliseconds.
+		#[pallet::constant]
+		type TargetBlockTime: Get<u64>;
+
+		/// The number of blocks the solve times are averaged over.
+		#[pallet::constant]
+		type AdjustmentWindow: Get<u32>;
+
+		/// The maximum factor the average solve time of the window is taken into account with.
+		///
+		/// Average solve times more than this factor away from [`Config::TargetBlockTime`] are
+		/// clamped, limiting how fast the difficulty can change.
+		#[pallet::constant]
+		type MaxAdjustmentFactor: Get<u32>;
+
+		/// The lowest difficulty the chain can reach.
+		#[pallet::constant]
+		type MinimumDifficulty: Get<U256>;
+	}
+
+	/// The difficulty of the next block.
+	#[pallet::storage]
+	pub type CurrentDifficulty<T: Config> = StorageValue<_, U256, ValueQuery>;
+
+	/// The timestamp of the last block, in milliseconds.
+	#[pallet::storage]
+	pub type LastTimestamp<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// Ring buffer of the solve time and difficulty of the last [`Config::AdjustmentWindow`]
+	/// blocks.
+	#[pallet::storage]
+	pub type Samples<T: Config> = StorageMap<_, Twox64Concat, u32, (u64, U256), OptionQuery>;
+
+	/// The index in [`Samples`] the next sample is written to, always lower than the window.
+	#[pallet::storage]
+	pub type NextSample<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of samples in [`Samples`], at most the window.
+	#[pallet::storage]
+	pub type SampleCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The [`Config::AdjustmentWindow`] the samples in [`Samples`] were recorded with.
+	#[pallet::storage]
+	pub type SampleWindow<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// The end of the range of [`Samples`] left over from a larger window, starting at the
+	/// current window.
+	#[pallet::storage]
+	pub type StaleSamples<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// The sum of the solve times and of the difficulties currently in [`Samples`].
+	#[pallet::storage]
+	pub type WindowTotals<T: Config> = StorageValue<_, (u64, U256), ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The difficulty of the first block.
+		pub initial_difficulty: U256,
+		#[serde(skip)]
+		pub _config: core::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			CurrentDifficulty::<T>::put(self.initial_difficulty.max(T::MinimumDifficulty::get()));
+			SampleWindow::<T>::put(T::AdjustmentWindow::get());
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Pallet::<T>::ensure_sample_window()
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Pallet::<T>::remove_stale_samples(remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(T::TargetBlockTime::get() > 0, "`TargetBlockTime` must not be zero");
+			assert!(T::AdjustmentWindow::get() > 0, "`AdjustmentWindow` must not be zero");
+			assert!(T::MaxAdjustmentFactor::get() > 0, "`MaxAdjustmentFactor` must not be zero");
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The difficulty the next block has to be mined at.
+	pub fn difficulty() -> U256 {
+		CurrentDifficulty::<T>::get().max(T::MinimumDifficulty::get())
+	}
+
+	/// Discard the recorded samples if they were recorded with a different
+	/// [`Config::AdjustmentWindow`].
+	///
+	/// Only the bookkeeping is reset, the samples themselves are overwritten before they are read
+	/// again. Samples outside of the new window are left to [`Self::remove_stale_samples`].
+	///
+	/// Returns the weight consumed.
+	fn ensure_sample_window() -> Weight {
+		let window = T::AdjustmentWindow::get();
+		let db = T::DbWeight::get();
+
+		match SampleWindow::<T>::get() {
+			Some(old) if old == window => db.reads(1),
+			Some(old) => {
+				log::info!(
+					target: LOG_TARGET,
+					"Adjustment window changed from {} to {}, discarding the recorded samples",
+					old,
+					window,
+				);
+
+				let stale = StaleSamples::<T>::get().unwrap_or_default().max(old);
+				if stale > window {
+					StaleSamples::<T>::put(stale);
+				} else {
+					StaleSamples::<T>::kill();
+				}
+				NextSample::<T>::kill();
+				SampleCount::<T>::kill();
+				WindowTotals::<T>::kill();
+				SampleWindow::<T>::put(window);
+				db.reads_writes(2, 5)
+			},
+			// Nothing was recorded yet.
+			None => {
+				SampleWindow::<T>::put(window);
+				db.reads_writes(1, 1)
+			},
+		}
+	}
+
+	/// Remove the samples left over from a larger window, as far as `limit` allows.
+	///
+	/// Returns the weight consumed.
+	fn remove_stale_samples(limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut meter = WeightMeter::with_limit(limit);
+		if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+
+		let Some(mut end) = StaleSamples::<T>::get() else { return meter.consumed() };
+		let window = T::AdjustmentWindow::get();
+		while end > window && meter.try_consume(db.writes(1)).is_ok() {
+			end -= 1;
+			Samples::<T>::remove(end);
+		}
+
+		if end > window {
+			StaleSamples::<T>::put(end);
+		} else {
+			StaleSamples::<T>::kill();
+		}
+		meter.consumed()
+	}
+
+	/// Record that the current block was mined in `solve_time` milliseconds and adjust the
+	/// difficulty of the next block.
+	///
+	/// Returns the weight consumed.
+	fn note_solve_time(solve_time: u64) -> Weight {
+		// Normally done by `on_runtime_upgrade`, this only has to do anything if the window is
+		// changed without a runtime upgrade.
+		let mut weight = Self::ensure_sample_window();
+
+		let window = T::AdjustmentWindow::get();
+		let index = NextSample::<T>::get();
+		let count = SampleCount::<T>::get();
+		let (mut total_time, mut total_difficulty) = WindowTotals::<T>::get();
+
+		// Until the window is full the ring buffer is filled in order, so `index` can only hold a
+		// sample of the current window once it is full.
+		if count == window {
+			if let Some((time, difficulty)) = Samples::<T>::get(index) {
+				total_time = total_time.saturating_sub(time);
+				total_difficulty = total_difficulty.saturating_sub(difficulty);
+			}
+		}
+
+		let difficulty = Self::difficulty();
+		total_time = total_time.saturating_add(solve_time);
+		total_difficulty = total_difficulty.saturating_add(difficulty);
+
+		let count = count.saturating_add(1).min(window);
+		Samples::<T>::insert(index, (solve_time, difficulty));
+		NextSample::<T>::put((index + 1) % window);
+		SampleCount::<T>::put(count);
+		WindowTotals::<T>::put((total_time, total_difficulty));
+
+		let samples = u64::from(count);
+		let target = T::TargetBlockTime::get().saturating_mul(samples);
+		let factor = u64::from(T::MaxAdjustmentFactor::get());
+		let actual = total_time.clamp(target / factor, target.saturating_mul(factor)).max(1);
+
+		let next = total_difficulty.saturating_mul(U256::from(T::TargetBlockTime::get())) /
+			U256::from(actual);
+		CurrentDifficulty::<T>::put(next.max(T::MinimumDifficulty::get()));
+
+		// `NextSample`, `SampleCount`, `WindowTotals`, `Samples` and `CurrentDifficulty`.
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 5));
+		weight
+	}
+}
+
+impl<T: Config> OnTimestampSet<T::Moment> for Pallet<T> {
+	fn on_timestamp_set(moment: T::Moment) {
+		let now: u64 = moment.unique_saturated_into();
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		// The first block has nothing to compare with.
+		if let Some(last) = LastTimestamp::<T>::get() {
+			weight.saturating_accrue(Self::note_solve_time(now.saturating_sub(last)));
+		}
+
+		LastTimestamp::<T>::put(now);
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			weight,
+			DispatchClass::Mandatory,
+		);
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for the PoW difficulty pallet.
+
+use crate as pallet_pow_difficulty;
+use frame_support::{
+	derive_impl, parameter_types, traits::ConstU64, weights::constants::RocksDbWeight,
+};
+use sp_core::U256;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Target block time used by the tests, in milliseconds.
+pub const TARGET_BLOCK_TIME: u64 = 6000;
+
+/// Adjustment window used by the tests.
+pub const WINDOW: u32 = 4;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		PowDifficulty: pallet_pow_difficulty,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type DbWeight = RocksDbWeight;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = PowDifficulty;
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumDifficulty: U256 = U256([100, 0, 0, 0]);
+	pub static AdjustmentWindow: u32 = WINDOW;
+}
+
+impl pallet_pow_difficulty::Config for Test {
+	type TargetBlockTime = ConstU64<TARGET_BLOCK_TIME>;
+	type AdjustmentWindow = AdjustmentWindow;
+	type MaxAdjustmentFactor = ConstU32<4>;
+	type MinimumDifficulty = MinimumDifficulty;
+}
+
+pub fn new_test_ext(initial_difficulty: u64) -> sp_io::TestExternalities {
+	let t = RuntimeGenesisConfig {
+		system: Default::default(),
+		pow_difficulty: pallet_pow_difficulty::GenesisConfig {
+			initial_difficulty: initial_difficulty.into(),
+			..Default::default()
+		},
+	}
+	.build_storage()
+	.unwrap();
+	t.into()
+}
+
+/// Produce blocks with the given solve times, starting after the current timestamp.
+pub fn produce_blocks(solve_times: &[u64]) {
+	for solve_time in solve_times {
+		let now = Timestamp::get() + solve_time;
+		System::set_block_number(System::block_number() + 1);
+		Timestamp::set_timestamp(now);
+	}
+}
@@ -0,0 +1,225 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the PoW difficulty pallet.
+
+use crate::{
+	mock::*, CurrentDifficulty, LastTimestamp, NextSample, SampleCount, SampleWindow, Samples,
+	StaleSamples, WindowTotals,
+};
+use frame_support::{dispatch::DispatchClass, traits::Hooks, weights::Weight};
+use sp_core::U256;
+
+#[test]
+fn genesis_respects_minimum_difficulty() {
+	new_test_ext(10).execute_with(|| {
+		assert_eq!(PowDifficulty::difficulty(), U256::from(100));
+	});
+	new_test_ext(1000).execute_with(|| {
+		assert_eq!(PowDifficulty::difficulty(), U256::from(1000));
+	});
+}
+
+#[test]
+fn first_timestamp_does_not_adjust() {
+	new_test_ext(1000).execute_with(|| {
+		Timestamp::set_timestamp(42);
+
+		assert_eq!(LastTimestamp::<Test>::get(), Some(42));
+		assert_eq!(SampleCount::<Test>::get(), 0);
+		assert_eq!(PowDifficulty::difficulty(), U256::from(1000));
+	});
+}
+
+#[test]
+fn on_target_blocks_keep_difficulty() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[TARGET_BLOCK_TIME; 10]);
+
+		assert_eq!(PowDifficulty::difficulty(), U256::from(1000));
+	});
+}
+
+#[test]
+fn fast_blocks_increase_difficulty() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME / 2]);
+
+		assert_eq!(PowDifficulty::difficulty(), U256::from(2000));
+	});
+}
+
+#[test]
+fn slow_blocks_decrease_difficulty() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME * 2]);
+
+		assert_eq!(PowDifficulty::difficulty(), U256::from(500));
+	});
+}
+
+#[test]
+fn adjustment_is_clamped() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[1]);
+		assert_eq!(PowDifficulty::difficulty(), U256::from(4000));
+	});
+
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME * 100]);
+		assert_eq!(PowDifficulty::difficulty(), U256::from(250));
+	});
+}
+
+#[test]
+fn difficulty_never_drops_below_minimum() {
+	new_test_ext(200).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME * 4; 5]);
+
+		assert_eq!(PowDifficulty::difficulty(), U256::from(100));
+		assert_eq!(CurrentDifficulty::<Test>::get(), U256::from(100));
+	});
+}
+
+#[test]
+fn window_only_keeps_recent_samples() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME * 2; WINDOW as usize]);
+		produce_blocks(&[TARGET_BLOCK_TIME; WINDOW as usize]);
+
+		assert_eq!(SampleCount::<Test>::get(), WINDOW);
+		assert_eq!(NextSample::<Test>::get(), 0);
+		assert_eq!(Samples::<Test>::iter().count(), WINDOW as usize);
+
+		let (time, difficulty) = WindowTotals::<Test>::get();
+		assert_eq!(time, TARGET_BLOCK_TIME * WINDOW as u64);
+		assert_eq!(
+			difficulty,
+			Samples::<Test>::iter_values().fold(U256::zero(), |acc, (_, d)| acc + d)
+		);
+		// After a full window of on target blocks the difficulty is the average of the window.
+		assert_eq!(PowDifficulty::difficulty(), difficulty / WINDOW);
+	});
+}
+
+#[test]
+fn ring_index_wraps_around() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME; 3 * WINDOW as usize + 1]);
+
+		assert_eq!(NextSample::<Test>::get(), 1);
+		assert_eq!(SampleCount::<Test>::get(), WINDOW);
+		assert_eq!(Samples::<Test>::iter().count(), WINDOW as usize);
+
+		// Every new sample replaces the oldest one.
+		produce_blocks(&[TARGET_BLOCK_TIME * 2]);
+		assert_eq!(NextSample::<Test>::get(), 2);
+		assert_eq!(Samples::<Test>::get(1).unwrap().0, TARGET_BLOCK_TIME * 2);
+		assert_eq!(WindowTotals::<Test>::get().0, TARGET_BLOCK_TIME * (WINDOW as u64 + 1));
+	});
+}
+
+#[test]
+fn changing_window_discards_samples_on_upgrade() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME / 2; WINDOW as usize]);
+		let difficulty = PowDifficulty::difficulty();
+		assert!(difficulty > U256::from(1000));
+
+		AdjustmentWindow::set(2);
+		<PowDifficulty as Hooks<u64>>::on_runtime_upgrade();
+
+		assert_eq!(SampleWindow::<Test>::get(), Some(2));
+		assert_eq!(NextSample::<Test>::get(), 0);
+		assert_eq!(SampleCount::<Test>::get(), 0);
+		assert_eq!(WindowTotals::<Test>::get(), Default::default());
+		// The difficulty is kept.
+		assert_eq!(PowDifficulty::difficulty(), difficulty);
+
+		// The samples outside of the new window are removed when there is weight left.
+		assert_eq!(StaleSamples::<Test>::get(), Some(WINDOW));
+		assert_eq!(Samples::<Test>::iter().count(), WINDOW as usize);
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let one = db.reads_writes(1, 1) + db.writes(1);
+		assert_eq!(<PowDifficulty as Hooks<u64>>::on_idle(1, one), one);
+		assert_eq!(StaleSamples::<Test>::get(), Some(WINDOW - 1));
+		<PowDifficulty as Hooks<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(StaleSamples::<Test>::get(), None);
+		assert_eq!(Samples::<Test>::iter().count(), 2);
+
+		// The next samples only take the new window into account.
+		produce_blocks(&[TARGET_BLOCK_TIME; 3]);
+		assert_eq!(Samples::<Test>::iter().count(), 2);
+		assert_eq!(WindowTotals::<Test>::get(), (2 * TARGET_BLOCK_TIME, difficulty * 2));
+		assert_eq!(PowDifficulty::difficulty(), difficulty);
+	});
+}
+
+#[test]
+fn changing_window_without_upgrade_discards_samples() {
+	new_test_ext(1000).execute_with(|| {
+		produce_blocks(&[0]);
+		produce_blocks(&[TARGET_BLOCK_TIME; WINDOW as usize]);
+
+		AdjustmentWindow::set(WINDOW + 2);
+		produce_blocks(&[TARGET_BLOCK_TIME * 2]);
+
+		assert_eq!(SampleWindow::<Test>::get(), Some(WINDOW + 2));
+		assert_eq!(StaleSamples::<Test>::get(), None);
+		assert_eq!(SampleCount::<Test>::get(), 1);
+		assert_eq!(WindowTotals::<Test>::get(), (TARGET_BLOCK_TIME * 2, U256::from(1000)));
+		assert_eq!(PowDifficulty::difficulty(), U256::from(500));
+
+		// The samples of the old window are never taken into account.
+		produce_blocks(&[TARGET_BLOCK_TIME; WINDOW as usize + 1]);
+		assert_eq!(SampleCount::<Test>::get(), WINDOW + 2);
+		assert_eq!(WindowTotals::<Test>::get().0, TARGET_BLOCK_TIME * (WINDOW as u64 + 3));
+		produce_blocks(&[TARGET_BLOCK_TIME]);
+		assert_eq!(WindowTotals::<Test>::get().0, TARGET_BLOCK_TIME * (WINDOW as u64 + 2));
+	});
+}
+
+#[test]
+fn on_timestamp_set_registers_weight() {
+	new_test_ext(1000).execute_with(|| {
+		let mandatory = || *System::block_weight().get(DispatchClass::Mandatory);
+
+		produce_blocks(&[0]);
+		let first = mandatory();
+		assert!(first.any_gt(Weight::zero()));
+
+		frame_system::BlockWeight::<Test>::kill();
+		produce_blocks(&[TARGET_BLOCK_TIME]);
+		// Recording a sample is more expensive than just noting the timestamp.
+		assert!(mandatory().any_gt(first));
+	});
+}
+
+#[test]
+fn integrity_test_works() {
+	new_test_ext(1000).execute_with(|| {
+		<PowDifficulty as Hooks<u64>>::integrity_test();
+	});
+}
@@ -52,6 +52,21 @@ fn add_proxies<T: Config>(n: u32, maybe_who: Option<T::AccountId>) -> Result<(),
 	Ok(())
 }
 
+/// Set limits on all the proxies of `who`, so that removing them must also remove their limits.
+fn add_proxy_limits<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+	for def in Proxies::<T>::get(who).0 {
+		Proxy::<T>::set_proxy_limits(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(def.delegate),
+			def.proxy_type,
+			def.delay,
+			Some(10u32.into()),
+			None,
+		)?;
+	}
+	Ok(())
+}
+
 fn add_announcements<T: Config>(
 	n: u32,
 	maybe_who: Option<T::AccountId>,
@@ -275,12 +290,43 @@ mod benchmarks {
 	fn remove_proxies(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
+		add_proxy_limits::<T>(&caller)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
-		let (proxies, _) = Proxies::<T>::get(caller);
+		let (proxies, _) = Proxies::<T>::get(&caller);
 		assert_eq!(proxies.len() as u32, 0);
+		assert!(Limits::<T>::iter_prefix(caller).next().is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proxy_limits(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		// Allowances can only be set if the runtime can value calls.
+		let allowance = <T::CallValue as CallValue<_, _>>::SUPPORTED
+			.then(|| SpendingAllowance { amount: 100u32.into(), period: 10u32.into() });
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			Some(10u32.into()),
+			allowance,
+		);
+
+		let def = ProxyDefinition {
+			delegate,
+			proxy_type: T::ProxyType::default(),
+			delay: BlockNumberFor::<T>::zero(),
+		};
+		assert!(Limits::<T>::contains_key(caller, def));
 
 		Ok(())
 	}
@@ -331,6 +377,7 @@ mod benchmarks {
 
 		add_proxies::<T>(p, Some(pure_account.clone()))?;
 		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
+		add_proxy_limits::<T>(&pure_account)?;
 
 		#[extrinsic_call]
 		_(
@@ -343,6 +390,7 @@ mod benchmarks {
 		);
 
 		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert!(Limits::<T>::iter_prefix(pure_account).next().is_none());
 
 		Ok(())
 	}
@@ -23,6 +23,12 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! A proxy can further be limited with `set_proxy_limits`, making it expire after a given block
+//! and capping the funds it may spend per period. The funds moved by a call are reported by the
+//! runtime through [`Config::CallValue`]. How often a proxy was used and how much it spent can be
+//! queried with the `proxy_limits` view function. A proxy with limits cannot manage the proxies
+//! of the account, as it could otherwise lift its own limits.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -34,7 +40,7 @@ mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
 	traits::{Currency, InstanceFilter, ReservableCurrency},
@@ -100,6 +106,66 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// Extracts the amount of funds a call moves out of the account it is dispatched from.
+///
+/// Used to enforce the spending allowance of proxies. Only the call dispatched through the proxy
+/// is given, so implementations must account for the calls nested in it, e.g. in
+/// `utility::batch`.
+pub trait CallValue<Call, Balance> {
+	/// Whether calls can be valued at all. Spending allowances can only be set if they can.
+	const SUPPORTED: bool = true;
+
+	/// The amount of funds moved by `call`, or `None` if it does not move any.
+	fn value(call: &Call) -> Option<Balance>;
+}
+
+impl<Call, Balance> CallValue<Call, Balance> for () {
+	const SUPPORTED: bool = false;
+
+	fn value(_: &Call) -> Option<Balance> {
+		None
+	}
+}
+
+/// An amount of funds a proxy may spend per period.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct SpendingAllowance<Balance, BlockNumber> {
+	/// The funds that may be spent per period.
+	pub amount: Balance,
+	/// The length of a period, in blocks.
+	pub period: BlockNumber,
+}
+
+/// The limits of a proxy, together with how much of them it used.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ProxyLimits<Balance, BlockNumber> {
+	/// The block after which the proxy can no longer be used.
+	pub expiry: Option<BlockNumber>,
+	/// The funds the proxy may spend per period.
+	pub allowance: Option<SpendingAllowance<Balance, BlockNumber>>,
+	/// The first block of the current spending period.
+	pub period_start: BlockNumber,
+	/// The funds spent in the current period.
+	pub spent: Balance,
+	/// The number of calls dispatched through the proxy since its limits were first set.
+	pub uses: u32,
+	/// The funds spent through the proxy since its limits were first set.
+	pub total_spent: Balance,
+	/// The amount held in reserve of the delegator for storing the limits.
+	pub deposit: Balance,
+}
+
 /// The type of deposit
 #[derive(
 	Encode,
@@ -157,6 +223,12 @@ pub mod pallet {
 			+ Default
 			+ MaxEncodedLen;
 
+		/// The amount of funds moved by a call, used to enforce the spending allowance of
+		/// proxies.
+		///
+		/// With `()`, calls can't be valued and spending allowances can't be set.
+		type CallValue: CallValue<<Self as Config>::RuntimeCall, BalanceOf<Self>>;
+
 		/// The base amount of currency needed to reserve for creating a proxy.
 		///
 		/// This is held for an additional storage item whose value size is
@@ -242,6 +314,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// Limits of the proxy.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -255,8 +329,12 @@ pub mod pallet {
 			let real = T::Lookup::lookup(real)?;
 			let def = Self::find_proxy(&real, &who, force_proxy_type)?;
 			ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
+			let value = Self::check_proxy_use(&real, &def, &call)?;
 
-			Self::do_proxy(def, real, *call);
+			let succeeded = Self::do_proxy(def.clone(), real.clone(), *call, value.is_some());
+			if let Some(value) = value {
+				Self::note_proxy_use(&real, &def, value, succeeded);
+			}
 
 			Ok(())
 		}
@@ -291,7 +369,10 @@ pub mod pallet {
 		/// - `proxy`: The account that the `caller` would like to remove as a proxy.
 		/// - `proxy_type`: The permissions currently enabled for the removed proxy account.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get()))]
+		#[pallet::weight(
+			T::WeightInfo::remove_proxy(T::MaxProxies::get())
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
 		pub fn remove_proxy(
 			origin: OriginFor<T>,
 			delegate: AccountIdLookupOf<T>,
@@ -405,6 +486,7 @@ pub mod pallet {
 
 			let (_, deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&spawner, deposit);
+			Self::remove_all_limits(&who);
 
 			Self::deposit_event(Event::PureKilled {
 				pure: who,
@@ -544,6 +626,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// Limits of the proxy.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -567,8 +651,12 @@ pub mod pallet {
 					now.saturating_sub(ann.height) < def.delay
 			})
 			.map_err(|_| Error::<T>::Unannounced)?;
+			let value = Self::check_proxy_use(&real, &def, &call)?;
 
-			Self::do_proxy(def, real, *call);
+			let succeeded = Self::do_proxy(def.clone(), real.clone(), *call, value.is_some());
+			if let Some(value) = value {
+				Self::note_proxy_use(&real, &def, value, succeeded);
+			}
 
 			Ok(())
 		}
@@ -671,6 +759,84 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Set or clear the limits of a proxy of the sender.
+		///
+		/// Changing the limits of a proxy keeps its usage counters. A deposit of
+		/// `ProxyDepositFactor` is held while a proxy has limits. A proxy with limits can't
+		/// dispatch any call managing proxies, including this one.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account registered as proxy.
+		/// - `proxy_type`: The permissions of the proxy.
+		/// - `delay`: The announcement period of the proxy.
+		/// - `expiry`: The block after which the proxy can no longer be used, if any.
+		/// - `allowance`: The funds the proxy may spend per period, if limited.
+		///
+		/// If both `expiry` and `allowance` are `None`, then the limits are removed together with
+		/// their usage counters, and the deposit is returned.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_proxy_limits(T::MaxProxies::get()))]
+		pub fn set_proxy_limits(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: Option<BlockNumberFor<T>>,
+			allowance: Option<SpendingAllowance<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			if let Some(allowance) = &allowance {
+				ensure!(T::CallValue::SUPPORTED, Error::<T>::AllowanceUnsupported);
+				ensure!(!allowance.period.is_zero(), Error::<T>::ZeroPeriod);
+			}
+
+			let def = ProxyDefinition { delegate, proxy_type, delay };
+			ensure!(Proxies::<T>::get(&who).0.binary_search(&def).is_ok(), Error::<T>::NotFound);
+
+			Limits::<T>::try_mutate_exists(&who, &def, |maybe_limits| -> DispatchResult {
+				let old_deposit = maybe_limits.as_ref().map_or_else(Zero::zero, |l| l.deposit);
+				if expiry.is_none() && allowance.is_none() {
+					T::Currency::unreserve(&who, old_deposit);
+					*maybe_limits = None;
+					return Ok(())
+				}
+
+				let new_deposit = T::ProxyDepositFactor::get();
+				if new_deposit > old_deposit {
+					T::Currency::reserve(&who, new_deposit - old_deposit)?;
+				} else if new_deposit < old_deposit {
+					T::Currency::unreserve(&who, old_deposit - new_deposit);
+				}
+
+				let limits = maybe_limits.get_or_insert_with(|| ProxyLimits {
+					expiry: None,
+					allowance: None,
+					period_start: T::BlockNumberProvider::current_block_number(),
+					spent: Zero::zero(),
+					uses: 0,
+					total_spent: Zero::zero(),
+					deposit: Zero::zero(),
+				});
+				limits.expiry = expiry;
+				limits.allowance = allowance;
+				limits.deposit = new_deposit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProxyLimitsSet {
+				delegator: who,
+				delegatee: def.delegate,
+				proxy_type: def.proxy_type,
+				delay: def.delay,
+				expiry,
+				allowance,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -722,6 +888,16 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// The limits of a proxy were set, or removed if both `expiry` and `allowance` are
+		/// `None`.
+		ProxyLimitsSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: Option<BlockNumberFor<T>>,
+			allowance: Option<SpendingAllowance<BalanceOf<T>, BlockNumberFor<T>>>,
+		},
 	}
 
 	#[pallet::error]
@@ -742,6 +918,14 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy expired.
+		Expired,
+		/// The call would exceed the spending allowance of the proxy for the current period.
+		AllowanceExceeded,
+		/// The period of a spending allowance cannot be zero.
+		ZeroPeriod,
+		/// The runtime can't value calls, so spending allowances can't be set.
+		AllowanceUnsupported,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -774,6 +958,17 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The limits of proxies, keyed by the delegator and the definition of the proxy.
+	#[pallet::storage]
+	pub type Limits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		ProxyLimits<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
 	#[pallet::view_functions]
 	impl<T: Config> Pallet<T> {
 		/// Check if a `RuntimeCall` is allowed for a given `ProxyType`.
@@ -788,6 +983,16 @@ pub mod pallet {
 		pub fn is_superset(to_check: T::ProxyType, against: T::ProxyType) -> bool {
 			to_check.is_superset(&against)
 		}
+
+		/// The limits of the proxies of `real` and how much of them they used.
+		pub fn proxy_limits(
+			real: T::AccountId,
+		) -> Vec<(
+			ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+			ProxyLimits<BalanceOf<T>, BlockNumberFor<T>>,
+		)> {
+			Limits::<T>::iter_prefix(real).collect()
+		}
 	}
 }
 
@@ -905,6 +1110,9 @@ impl<T: Config> Pallet<T> {
 			};
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
+			if let Some(limits) = Limits::<T>::take(delegator, &proxy_def) {
+				T::Currency::unreserve(delegator, limits.deposit);
+			}
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -991,11 +1199,83 @@ impl<T: Config> Pallet<T> {
 		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
 	}
 
+	/// Check that the proxy `def` of `real` did not expire and that its spending allowance covers
+	/// `call`.
+	///
+	/// Returns the value of `call` if the proxy has limits, to be recorded with
+	/// [`Self::note_proxy_use`] once the call was dispatched.
+	fn check_proxy_use(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		call: &<T as Config>::RuntimeCall,
+	) -> Result<Option<BalanceOf<T>>, DispatchError> {
+		let Some(mut limits) = Limits::<T>::get(real, def) else { return Ok(None) };
+		let now = T::BlockNumberProvider::current_block_number();
+		if let Some(expiry) = limits.expiry {
+			ensure!(now <= expiry, Error::<T>::Expired);
+		}
+
+		let value = T::CallValue::value(call).unwrap_or_else(Zero::zero);
+		if let Some(allowance) = limits.allowance {
+			Self::roll_over_period(&mut limits, &allowance, now);
+			let spent = limits.spent.saturating_add(value);
+			ensure!(spent <= allowance.amount, Error::<T>::AllowanceExceeded);
+		}
+		Ok(Some(value))
+	}
+
+	/// Record a use of the proxy `def` of `real` for a call of the given `value`.
+	///
+	/// The value is only charged against the allowance if the call succeeded.
+	fn note_proxy_use(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		value: BalanceOf<T>,
+		succeeded: bool,
+	) {
+		// The limits may have been changed or removed by the call itself.
+		Limits::<T>::mutate(real, def, |maybe_limits| {
+			let Some(limits) = maybe_limits.as_mut() else { return };
+			limits.uses.saturating_inc();
+			if !succeeded {
+				return
+			}
+
+			if let Some(allowance) = limits.allowance {
+				let now = T::BlockNumberProvider::current_block_number();
+				Self::roll_over_period(limits, &allowance, now);
+				limits.spent = limits.spent.saturating_add(value);
+			}
+			limits.total_spent = limits.total_spent.saturating_add(value);
+		})
+	}
+
+	/// Start a new spending period if the current one is over, aligned with the previous ones.
+	fn roll_over_period(
+		limits: &mut ProxyLimits<BalanceOf<T>, BlockNumberFor<T>>,
+		allowance: &SpendingAllowance<BalanceOf<T>, BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) {
+		let elapsed = now.saturating_sub(limits.period_start);
+		if elapsed >= allowance.period {
+			limits.period_start = now.saturating_sub(elapsed % allowance.period);
+			limits.spent = Zero::zero();
+		}
+	}
+
+	/// Remove the limits of all the proxies of `delegator`, returning their deposits.
+	fn remove_all_limits(delegator: &T::AccountId) {
+		for (_, limits) in Limits::<T>::drain_prefix(delegator) {
+			T::Currency::unreserve(delegator, limits.deposit);
+		}
+	}
+
 	fn do_proxy(
 		def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
 		real: T::AccountId,
 		call: <T as Config>::RuntimeCall,
-	) {
+		limited: bool,
+	) -> bool {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
 		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
@@ -1003,6 +1283,16 @@ impl<T: Config> Pallet<T> {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
+				// A proxy with limits cannot manage proxies, as that would allow it to lift its
+				// own limits, e.g. by adding itself again without them.
+				Some(
+					Call::add_proxy { .. } |
+					Call::remove_proxy { .. } |
+					Call::remove_proxies { .. } |
+					Call::create_pure { .. } |
+					Call::kill_pure { .. } |
+					Call::set_proxy_limits { .. },
+				) if limited => false,
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
@@ -1018,7 +1308,9 @@ impl<T: Config> Pallet<T> {
 			}
 		});
 		let e = call.dispatch(origin);
+		let succeeded = e.is_ok();
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		succeeded
 	}
 
 	/// Removes all proxy delegates for a given delegator.
@@ -1028,5 +1320,6 @@ impl<T: Config> Pallet<T> {
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
+		Self::remove_all_limits(delegator);
 	}
 }
@@ -108,6 +108,18 @@ impl Contains<RuntimeCall> for BaseFilter {
 	}
 }
 
+pub struct TransferValue;
+impl CallValue<RuntimeCall, u64> for TransferValue {
+	fn value(call: &RuntimeCall) -> Option<u64> {
+		match call {
+			RuntimeCall::Balances(BalancesCall::transfer_allow_death { value, .. }) => Some(*value),
+			RuntimeCall::Utility(UtilityCall::batch { calls }) =>
+				Some(calls.iter().filter_map(Self::value).sum()),
+			_ => None,
+		}
+	}
+}
+
 parameter_types! {
 	pub static ProxyDepositBase: u64 = 1;
 	pub static ProxyDepositFactor: u64 = 1;
@@ -120,6 +132,7 @@ impl Config for Test {
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
 	type ProxyType = ProxyType;
+	type CallValue = TransferValue;
 	type ProxyDepositBase = ProxyDepositBase;
 	type ProxyDepositFactor = ProxyDepositFactor;
 	type MaxProxies = ConstU32<4>;
@@ -838,3 +851,222 @@ fn poke_deposit_fails_for_unsigned_origin() {
 		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::none()), DispatchError::BadOrigin,);
 	});
 }
+
+#[test]
+fn proxy_limits_expire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyLimitsSet {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				expiry: Some(3),
+				allowance: None,
+			}
+			.into(),
+		);
+		// The deposit of the proxy, plus `ProxyDepositFactor` for its limits.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::set_block_number(3);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+
+		System::set_block_number(4);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()),
+			Error::<Test>::Expired
+		);
+
+		// Removing the limits makes the proxy usable again.
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert!(Proxy::proxy_limits(1).is_empty());
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+	});
+}
+
+#[test]
+fn proxy_spending_allowance_rolls_over() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let allowance = SpendingAllowance { amount: 3, period: 10 };
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some(allowance)
+		));
+
+		let transfer = |value| Box::new(call_transfer(6, value));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, transfer(2)));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, transfer(2)),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, transfer(1)));
+		// Calls not moving funds are not limited.
+		let remark = Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, remark));
+		assert_eq!(Balances::free_balance(6), 3);
+
+		// A new period starts every 10 blocks.
+		System::set_block_number(15);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, transfer(2)));
+		// The value of batched calls is accounted for.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 1)],
+		}));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch),
+			Error::<Test>::AllowanceExceeded
+		);
+
+		let def = ProxyDefinition { delegate: 2, proxy_type: ProxyType::Any, delay: 0 };
+		let limits = ProxyLimits {
+			expiry: None,
+			allowance: Some(allowance),
+			period_start: 11,
+			spent: 2,
+			uses: 4,
+			total_spent: 5,
+			deposit: 1,
+		};
+		assert_eq!(Proxy::proxy_limits(1), vec![(def, limits)]);
+	});
+}
+
+#[test]
+fn proxy_spending_allowance_only_charges_successful_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let allowance = SpendingAllowance { amount: 20, period: 10 };
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some(allowance)
+		));
+
+		// Within the allowance, but more than the free balance of the delegator.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 8))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(TokenError::FundsUnavailable.into()) }.into(),
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+
+		let (_, limits) = Proxy::proxy_limits(1).pop().unwrap();
+		assert_eq!((limits.uses, limits.spent, limits.total_spent), (2, 3, 3));
+	});
+}
+
+#[test]
+fn proxy_limits_are_removed_with_the_proxy() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::set_proxy_limits(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, Some(3), None),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert_noop!(
+			Proxy::set_proxy_limits(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				0,
+				None,
+				Some(SpendingAllowance { amount: 3, period: 0 })
+			),
+			Error::<Test>::ZeroPeriod
+		);
+
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::JustTransfer,
+			0,
+			Some(3),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Proxy::proxy_limits(1).len(), 1);
+
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Proxy::proxy_limits(1).is_empty());
+	});
+}
+
+#[test]
+fn limited_proxy_cannot_manage_proxies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let allowance = SpendingAllowance { amount: 3, period: 10 };
+		assert_ok!(Proxy::set_proxy_limits(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some(allowance)
+		));
+
+		let filtered = ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) };
+		let lift_limits = RuntimeCall::Proxy(ProxyCall::set_proxy_limits {
+			delegate: 2,
+			proxy_type: ProxyType::Any,
+			delay: 0,
+			expiry: None,
+			allowance: None,
+		});
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(lift_limits)));
+		System::assert_last_event(filtered.clone().into());
+
+		let add_unlimited = RuntimeCall::Proxy(ProxyCall::add_proxy {
+			delegate: 3,
+			proxy_type: ProxyType::Any,
+			delay: 0,
+		});
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(add_unlimited)));
+		System::assert_last_event(filtered.into());
+
+		// The limits are kept and no other proxy was added.
+		assert_eq!(Proxy::proxy_limits(1).pop().unwrap().1.allowance, Some(allowance));
+		assert_eq!(Proxies::<Test>::get(1).0.len(), 1);
+	});
+}
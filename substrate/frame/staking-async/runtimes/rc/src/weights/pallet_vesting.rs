@@ -17,7 +17,7 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `3a2e9ae8a8f5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
@@ -52,7 +52,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -61,20 +61,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 38_225_000 picoseconds.
-		Weight::from_parts(37_860_470, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_479
-			.saturating_add(Weight::from_parts(41_149, 0).saturating_mul(l.into()))
-			// Standard Error: 2_631
-			.saturating_add(Weight::from_parts(76_064, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 39_090_000 picoseconds.
+		Weight::from_parts(38_717_617, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_485
+			.saturating_add(Weight::from_parts(41_053, 0).saturating_mul(l.into()))
+			// Standard Error: 2_704
+			.saturating_add(Weight::from_parts(84_791, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -83,20 +83,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_682_000 picoseconds.
-		Weight::from_parts(40_558_815, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_473
-			.saturating_add(Weight::from_parts(35_138, 0).saturating_mul(l.into()))
-			// Standard Error: 2_620
-			.saturating_add(Weight::from_parts(72_425, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 42_093_000 picoseconds.
+		Weight::from_parts(41_965_948, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(33_960, 0).saturating_mul(l.into()))
+			// Standard Error: 2_517
+			.saturating_add(Weight::from_parts(85_031, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -107,20 +107,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 40_813_000 picoseconds.
-		Weight::from_parts(40_248_990, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_925
-			.saturating_add(Weight::from_parts(47_778, 0).saturating_mul(l.into()))
-			// Standard Error: 3_425
-			.saturating_add(Weight::from_parts(88_421, 0).saturating_mul(s.into()))
+		//  Measured:  `448 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 43_667_000 picoseconds.
+		Weight::from_parts(43_063_542, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_906
+			.saturating_add(Weight::from_parts(45_869, 0).saturating_mul(l.into()))
+			// Standard Error: 3_607
+			.saturating_add(Weight::from_parts(97_200, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -131,20 +131,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `448 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 43_330_000 picoseconds.
-		Weight::from_parts(43_588_745, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 2_075
-			.saturating_add(Weight::from_parts(35_838, 0).saturating_mul(l.into()))
-			// Standard Error: 3_693
-			.saturating_add(Weight::from_parts(73_951, 0).saturating_mul(s.into()))
+		//  Measured:  `448 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 45_525_000 picoseconds.
+		Weight::from_parts(45_797_131, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 2_176
+			.saturating_add(Weight::from_parts(34_146, 0).saturating_mul(l.into()))
+			// Standard Error: 3_443
+			.saturating_add(Weight::from_parts(82_310, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -155,20 +155,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 80_026_000 picoseconds.
-		Weight::from_parts(82_148_674, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 3_243
-			.saturating_add(Weight::from_parts(30_866, 0).saturating_mul(l.into()))
-			// Standard Error: 5_770
-			.saturating_add(Weight::from_parts(99_755, 0).saturating_mul(s.into()))
+		//  Measured:  `519 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 82_423_000 picoseconds.
+		Weight::from_parts(84_609_147, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 3_052
+			.saturating_add(Weight::from_parts(30_464, 0).saturating_mul(l.into()))
+			// Standard Error: 5_811
+			.saturating_add(Weight::from_parts(113_048, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -179,20 +179,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `622 + l * (25 ±0) + s * (36 ±0)`
+		//  Measured:  `622 + l * (25 ±0) + s * (38 ±0)`
 		//  Estimated: `6196`
-		// Minimum execution time: 81_979_000 picoseconds.
-		Weight::from_parts(83_373_383, 0)
+		// Minimum execution time: 86_994_000 picoseconds.
+		Weight::from_parts(88_473_747, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			// Standard Error: 3_069
-			.saturating_add(Weight::from_parts(49_002, 0).saturating_mul(l.into()))
-			// Standard Error: 5_460
-			.saturating_add(Weight::from_parts(105_265, 0).saturating_mul(s.into()))
+			// Standard Error: 3_125
+			.saturating_add(Weight::from_parts(47_227, 0).saturating_mul(l.into()))
+			// Standard Error: 5_314
+			.saturating_add(Weight::from_parts(114_541, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -201,20 +201,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 39_190_000 picoseconds.
-		Weight::from_parts(38_673_517, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_789
-			.saturating_add(Weight::from_parts(38_146, 0).saturating_mul(l.into()))
-			// Standard Error: 3_305
-			.saturating_add(Weight::from_parts(97_870, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 40_209_000 picoseconds.
+		Weight::from_parts(39_679_273, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_688
+			.saturating_add(Weight::from_parts(39_555, 0).saturating_mul(l.into()))
+			// Standard Error: 3_078
+			.saturating_add(Weight::from_parts(112_316, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -223,20 +223,20 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `345 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_229_000 picoseconds.
-		Weight::from_parts(42_040_081, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_659
-			.saturating_add(Weight::from_parts(38_531, 0).saturating_mul(l.into()))
-			// Standard Error: 3_065
-			.saturating_add(Weight::from_parts(76_527, 0).saturating_mul(s.into()))
+		//  Measured:  `345 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 45_069_000 picoseconds.
+		Weight::from_parts(44_867_268, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_705
+			.saturating_add(Weight::from_parts(39_157, 0).saturating_mul(l.into()))
+			// Standard Error: 2_968
+			.saturating_add(Weight::from_parts(84_420, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -247,16 +247,40 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 46_474_000 picoseconds.
-		Weight::from_parts(46_105_020, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
-			// Standard Error: 1_706
-			.saturating_add(Weight::from_parts(39_879, 0).saturating_mul(l.into()))
-			// Standard Error: 3_151
-			.saturating_add(Weight::from_parts(87_824, 0).saturating_mul(s.into()))
+		//  Measured:  `519 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 49_040_000 picoseconds.
+		Weight::from_parts(48_650_688, 0)
+			.saturating_add(Weight::from_parts(0, 5586))
+			// Standard Error: 1_753
+			.saturating_add(Weight::from_parts(39_648, 0).saturating_mul(l.into()))
+			// Standard Error: 3_398
+			.saturating_add(Weight::from_parts(98_344, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `484 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_110_000 picoseconds.
+		Weight::from_parts(56_304_629, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_846
+			.saturating_add(Weight::from_parts(41_333, 0).saturating_mul(l.into()))
+			// Standard Error: 3_600
+			.saturating_add(Weight::from_parts(99_933, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
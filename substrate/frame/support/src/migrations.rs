@@ -17,19 +17,25 @@
 
 use crate::{
 	defensive,
-	storage::{storage_prefix, transactional::with_transaction_opaque_err},
+	hash::{ReversibleStorageHasher, StorageHasher},
+	storage::{
+		storage_prefix,
+		transactional::with_transaction_opaque_err,
+		types::{QueryKindTrait, StorageDoubleMap, StorageMap},
+		unhashed,
+	},
 	traits::{
 		Defensive, GetStorageVersion, NoStorageVersionSet, PalletInfoAccess, SafeMode,
-		StorageVersion,
+		StorageInstance, StorageVersion,
 	},
 	weights::{RuntimeDbWeight, Weight, WeightMeter},
 };
 use alloc::vec::Vec;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use core::marker::PhantomData;
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_arithmetic::traits::Bounded;
-use sp_core::Get;
+use sp_core::{Get, TypedGet};
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
 use sp_runtime::traits::Zero;
 
@@ -1027,6 +1033,232 @@ impl SteppedMigrations for Tuple {
 	}
 }
 
+/// A storage map whose entries can be translated one by one by [`SteppedTranslate`].
+///
+/// Implemented for [`StorageMap`] and [`StorageDoubleMap`] with reversible hashers, since the
+/// keys of the map are used as cursor.
+pub trait SteppedTranslatableMap {
+	/// The full key of an entry of the map.
+	type Key: FullCodec + MaxEncodedLen;
+
+	/// The value type of the map, i.e. the type of the values after the translation.
+	type Value: FullCodec;
+
+	/// The key following `key` in the map, or the first key of the map if `key` is `None`.
+	///
+	/// `key` does not need to be present in the map.
+	fn next_key(key: Option<&Self::Key>) -> Option<Self::Key>;
+
+	/// The raw storage key of the entry under `key`.
+	fn raw_key(key: &Self::Key) -> Vec<u8>;
+
+	/// The number of entries in the map.
+	///
+	/// This iterates over the whole map and should only be used in tests and try-runtime checks.
+	fn count() -> u32;
+}
+
+impl<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues> SteppedTranslatableMap
+	for StorageMap<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	Hasher: StorageHasher + ReversibleStorageHasher,
+	Key: FullCodec + MaxEncodedLen,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	type Key = Key;
+	type Value = Value;
+
+	fn next_key(key: Option<&Key>) -> Option<Key> {
+		match key {
+			Some(key) => Self::iter_keys_from(Self::hashed_key_for(key)).next(),
+			None => Self::iter_keys().next(),
+		}
+	}
+
+	fn raw_key(key: &Key) -> Vec<u8> {
+		Self::hashed_key_for(key)
+	}
+
+	fn count() -> u32 {
+		Self::iter_keys().count() as u32
+	}
+}
+
+impl<Prefix, Hasher1, Key1, Hasher2, Key2, Value, QueryKind, OnEmpty, MaxValues>
+	SteppedTranslatableMap
+	for StorageDoubleMap<Prefix, Hasher1, Key1, Hasher2, Key2, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	Hasher1: StorageHasher + ReversibleStorageHasher,
+	Hasher2: StorageHasher + ReversibleStorageHasher,
+	Key1: FullCodec + MaxEncodedLen,
+	Key2: FullCodec + MaxEncodedLen,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	type Key = (Key1, Key2);
+	type Value = Value;
+
+	fn next_key(key: Option<&(Key1, Key2)>) -> Option<(Key1, Key2)> {
+		match key {
+			Some((k1, k2)) => Self::iter_keys_from(Self::hashed_key_for(k1, k2)).next(),
+			None => Self::iter_keys().next(),
+		}
+	}
+
+	fn raw_key((k1, k2): &(Key1, Key2)) -> Vec<u8> {
+		Self::hashed_key_for(k1, k2)
+	}
+
+	fn count() -> u32 {
+		Self::iter_keys().count() as u32
+	}
+}
+
+/// The translation of a single value applied by [`SteppedTranslate`].
+pub trait TranslateValue<Key, Old, New> {
+	/// Translate the `old` value stored under `key`.
+	///
+	/// Returning `None` removes the entry from the map. This should be deterministic, since the
+	/// try-runtime checks of [`SteppedTranslate`] rely on it.
+	fn translate(key: &Key, old: Old) -> Option<New>;
+}
+
+/// A [`SteppedMigration`] translating every value of a storage map from `Old` to the value type
+/// of the map.
+///
+/// This is the multi-block equivalent of `StorageMap::translate`: the entries are translated
+/// one by one in no particular order, using the key of the last translated entry as cursor, and
+/// as many entries are translated per step as the weight meter allows. Values which fail to
+/// decode as `Old` are logged and left untouched.
+///
+/// # Parameters
+///
+/// - `Id`: The unique identifier of the migration.
+/// - `Map`: The storage map to translate, see [`SteppedTranslatableMap`].
+/// - `Old`: The type of the values before the migration.
+/// - `Translate`: The translation of a single value, see [`TranslateValue`].
+/// - `ItemWeight`: The weight of translating a single entry, including reading the next key and
+///   reading and writing the value. `pallet_migrations` provides a benchmarked default.
+///
+/// # Example
+///
+/// ```ignore
+/// parameter_types! {
+/// 	pub const MigrationId: [u8; 16] = *b"MyPalletValuesV1";
+/// }
+///
+/// pub struct WidenValue;
+/// impl TranslateValue<u32, u32, u64> for WidenValue {
+/// 	fn translate(_key: &u32, old: u32) -> Option<u64> {
+/// 		Some(old.into())
+/// 	}
+/// }
+///
+/// pub type MigrateValues<T> = SteppedTranslate<
+/// 	MigrationId,
+/// 	my_pallet::Values<T>,
+/// 	u32,
+/// 	WidenValue,
+/// 	pallet_migrations::migrations::TranslateMapItemWeight<T>,
+/// >;
+/// ```
+pub struct SteppedTranslate<Id, Map, Old, Translate, ItemWeight>(
+	PhantomData<(Id, Map, Old, Translate, ItemWeight)>,
+);
+
+impl<Id, Map, Old, Translate, ItemWeight> SteppedMigration
+	for SteppedTranslate<Id, Map, Old, Translate, ItemWeight>
+where
+	Id: TypedGet,
+	Id::Type: FullCodec + MaxEncodedLen,
+	Map: SteppedTranslatableMap,
+	Old: Decode,
+	Translate: TranslateValue<Map::Key, Old, Map::Value>,
+	ItemWeight: Get<Weight>,
+{
+	type Cursor = Map::Key;
+	type Identifier = Id::Type;
+
+	fn id() -> Self::Identifier {
+		Id::get()
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = ItemWeight::get();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required })
+		}
+
+		while meter.try_consume(required).is_ok() {
+			let Some(key) = Map::next_key(cursor.as_ref()) else { return Ok(None) };
+			let raw_key = Map::raw_key(&key);
+
+			match unhashed::get::<Old>(&raw_key) {
+				Some(old) => match Translate::translate(&key, old) {
+					Some(new) => unhashed::put(&raw_key, &new),
+					None => unhashed::kill(&raw_key),
+				},
+				None => log::error!(
+					target: "runtime::migrations",
+					"SteppedTranslate: failed to decode value under key {:?}, skipping",
+					sp_core::hexdisplay::HexDisplay::from(&raw_key)
+				),
+			}
+
+			cursor = Some(key);
+		}
+
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		// Entries which fail to decode are kept, the others only if they translate to a value.
+		let mut expected = 0u32;
+		let mut key = Map::next_key(None);
+		while let Some(k) = key {
+			let keep = match unhashed::get::<Old>(&Map::raw_key(&k)) {
+				Some(old) => Translate::translate(&k, old).is_some(),
+				None => true,
+			};
+			if keep {
+				expected += 1;
+			}
+			key = Map::next_key(Some(&k));
+		}
+		log::info!(
+			target: "runtime::migrations",
+			"SteppedTranslate: expecting {expected} of {} entries to remain.",
+			Map::count()
+		);
+		Ok(expected.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let expected = u32::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+		let count = Map::count();
+		if count != expected {
+			log::error!(
+				target: "runtime::migrations",
+				"SteppedTranslate: expected {expected} entries, found {count}."
+			);
+			return Err("SteppedTranslate: unexpected number of entries".into())
+		}
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1164,6 +1396,142 @@ mod tests {
 		});
 	}
 
+	#[crate::storage_alias]
+	type TranslateMap = StorageMap<Prefix, crate::Twox64Concat, u32, u64>;
+	#[crate::storage_alias]
+	type TranslateDoubleMap =
+		StorageDoubleMap<Prefix, crate::Twox64Concat, u32, crate::Twox64Concat, u32, u64>;
+
+	crate::parameter_types! {
+		const TranslateId: [u8; 4] = *b"test";
+		const TranslateItemWeight: Weight = Weight::from_parts(10, 0);
+	}
+
+	/// Doubles the values under odd keys and removes the others.
+	pub struct DoubleOdd;
+	impl TranslateValue<u32, u32, u64> for DoubleOdd {
+		fn translate(key: &u32, old: u32) -> Option<u64> {
+			(key % 2 == 1).then(|| old as u64 * 2)
+		}
+	}
+	impl TranslateValue<(u32, u32), u32, u64> for DoubleOdd {
+		fn translate((_, k2): &(u32, u32), old: u32) -> Option<u64> {
+			Self::translate(k2, old)
+		}
+	}
+
+	type TranslateMapMigration =
+		SteppedTranslate<TranslateId, TranslateMap, u32, DoubleOdd, TranslateItemWeight>;
+	type TranslateDoubleMapMigration =
+		SteppedTranslate<TranslateId, TranslateDoubleMap, u32, DoubleOdd, TranslateItemWeight>;
+
+	/// Run `M` to completion with `limit` weight per step, returning the number of steps.
+	fn run_to_completion<M: SteppedMigration>(limit: Weight) -> u32 {
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			steps += 1;
+			cursor = match M::step(cursor, &mut WeightMeter::with_limit(limit)) {
+				Ok(Some(cursor)) => Some(cursor),
+				Ok(None) => return steps,
+				Err(err) => panic!("Migration failed: {:?}", err),
+			};
+		}
+	}
+
+	#[test]
+	fn stepped_translate_map_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for k in 0..10u32 {
+				unhashed::put(&TranslateMap::hashed_key_for(k), &k);
+			}
+
+			// Three entries per step.
+			assert_eq!(run_to_completion::<TranslateMapMigration>(Weight::from_parts(35, 0)), 4);
+
+			assert_eq!(<TranslateMap as SteppedTranslatableMap>::count(), 5);
+			for k in 0..10u32 {
+				let expected = (k % 2 == 1).then(|| k as u64 * 2);
+				assert_eq!(TranslateMap::get(k), expected);
+			}
+		});
+	}
+
+	#[test]
+	fn stepped_translate_double_map_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for k1 in 0..3u32 {
+				for k2 in 0..4u32 {
+					unhashed::put(&TranslateDoubleMap::hashed_key_for(k1, k2), &k2);
+				}
+			}
+
+			assert_eq!(
+				run_to_completion::<TranslateDoubleMapMigration>(Weight::from_parts(50, 0)),
+				3
+			);
+
+			assert_eq!(<TranslateDoubleMap as SteppedTranslatableMap>::count(), 6);
+			for k1 in 0..3u32 {
+				assert_eq!(TranslateDoubleMap::get(k1, 1), Some(2));
+				assert_eq!(TranslateDoubleMap::get(k1, 2), None);
+				assert_eq!(TranslateDoubleMap::get(k1, 3), Some(6));
+			}
+		});
+	}
+
+	#[test]
+	fn stepped_translate_skips_undecodable_values() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			unhashed::put(&TranslateMap::hashed_key_for(1), &1u32);
+			unhashed::put_raw(&TranslateMap::hashed_key_for(3), &[1u8]);
+
+			assert_eq!(run_to_completion::<TranslateMapMigration>(Weight::MAX), 1);
+
+			assert_eq!(TranslateMap::get(1), Some(2));
+			assert_eq!(unhashed::get_raw(&TranslateMap::hashed_key_for(3)), Some(vec![1u8]));
+		});
+	}
+
+	#[test]
+	fn stepped_translate_insufficient_weight() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			unhashed::put(&TranslateMap::hashed_key_for(1), &1u32);
+
+			let mut meter = WeightMeter::with_limit(Weight::from_parts(5, 0));
+			assert!(matches!(
+				TranslateMapMigration::step(None, &mut meter),
+				Err(SteppedMigrationError::InsufficientWeight { required })
+					if required == TranslateItemWeight::get()
+			));
+			assert_eq!(unhashed::get::<u32>(&TranslateMap::hashed_key_for(1)), Some(1));
+
+			// An empty map completes immediately.
+			unhashed::kill(&TranslateMap::hashed_key_for(1));
+			assert!(matches!(TranslateMapMigration::step(None, &mut WeightMeter::new()), Ok(None)));
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn stepped_translate_try_runtime_checks_work() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for k in 0..4u32 {
+				unhashed::put(&TranslateMap::hashed_key_for(k), &k);
+			}
+			unhashed::put_raw(&TranslateMap::hashed_key_for(4), &[1u8]);
+
+			let state = TranslateMapMigration::pre_upgrade().unwrap();
+			assert_eq!(u32::decode(&mut &state[..]).unwrap(), 3);
+			run_to_completion::<TranslateMapMigration>(Weight::MAX);
+			assert_ok!(TranslateMapMigration::post_upgrade(state.clone()));
+
+			// An entry that should have been removed makes the check fail.
+			TranslateMap::insert(0, 0);
+			assert!(TranslateMapMigration::post_upgrade(state).is_err());
+		});
+	}
+
 	#[test]
 	fn integrity_test_works() {
 		sp_io::TestExternalities::default().execute_with(|| {
@@ -441,3 +441,36 @@ pub trait Trading<AccountId, ItemPrice>: Inspect<AccountId> {
 	/// Returns the item price of `item` or `None` if the item is not for sale.
 	fn item_price(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<ItemPrice>;
 }
+
+/// Trait for inspecting the royalties paid on trades of non-fungible items.
+pub trait InspectRoyalties<AccountId, Balance>: Inspect<AccountId> {
+	/// Returns the recipients of the royalties of `item` of `collection`, each with their share
+	/// of the price in basis points.
+	fn royalties(collection: &Self::CollectionId, item: &Self::ItemId) -> Vec<(AccountId, u16)>;
+
+	/// Returns the amount owed to each royalty recipient when `item` of `collection` is traded
+	/// for `price`.
+	fn royalty_amounts(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		price: &Balance,
+	) -> Vec<(AccountId, Balance)>;
+}
+
+/// Trait for setting the royalties paid on trades of non-fungible items.
+pub trait MutateRoyalties<AccountId>: Inspect<AccountId> {
+	/// Sets the royalties of the items of `collection`, each recipient with their share of the
+	/// price in basis points. An empty list clears the royalties.
+	fn set_collection_royalties(
+		collection: &Self::CollectionId,
+		royalties: &[(AccountId, u16)],
+	) -> DispatchResult;
+
+	/// Sets the royalties of `item` of `collection`, overriding those of the collection. An empty
+	/// list clears the royalties of the item.
+	fn set_item_royalties(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		royalties: &[(AccountId, u16)],
+	) -> DispatchResult;
+}
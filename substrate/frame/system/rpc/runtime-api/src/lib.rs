@@ -23,6 +23,12 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+use sp_runtime::traits::Block as BlockT;
+use sp_weights::Weight;
+
 #[docify::export(AccountNonceApi)]
 sp_api::decl_runtime_apis! {
 	/// The API to query account nonce.
@@ -34,3 +40,19 @@ sp_api::decl_runtime_apis! {
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API to discover the tasks of the runtime that can be executed.
+	///
+	/// Used by the node to submit the tasks through `frame_system::Call::do_task`, see
+	/// `frame_system::Pallet::valid_tasks`.
+	pub trait TasksApi<RuntimeTask> where
+		RuntimeTask: codec::Codec,
+	{
+		/// Get at most `max` tasks that are currently valid, together with their weight.
+		fn valid_tasks(max: u32) -> Vec<(RuntimeTask, Weight)>;
+
+		/// Create the unsigned extrinsic executing `task`.
+		fn task_extrinsic(task: RuntimeTask) -> <Block as BlockT>::Extrinsic;
+	}
+}
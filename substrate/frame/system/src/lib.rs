@@ -2226,6 +2226,41 @@ impl<T: Config> Pallet<T> {
 		Account::<T>::mutate(who, |a| a.nonce += T::Nonce::one());
 	}
 
+	/// Enumerate at most `max` tasks of the runtime that are currently valid, together with their
+	/// weight.
+	///
+	/// The enumeration of the tasks is not bounded, so it is stopped once `max` valid tasks are
+	/// found.
+	///
+	/// Meant to be exposed to the node through `TasksApi`, so that it can submit the tasks with
+	/// [`Pallet::do_task`].
+	#[cfg(feature = "experimental")]
+	pub fn valid_tasks(max: u32) -> Vec<(T::RuntimeTask, Weight)>
+	where
+		<T::RuntimeTask as frame_support::traits::Task>::Enumeration:
+			Iterator<Item = T::RuntimeTask>,
+	{
+		use frame_support::traits::Task;
+
+		T::RuntimeTask::iter()
+			.filter(|task| task.is_valid())
+			.take(max as usize)
+			.map(|task| {
+				let weight = task.weight();
+				(task, weight)
+			})
+			.collect()
+	}
+
+	/// Create the unsigned extrinsic executing `task` through [`Pallet::do_task`].
+	#[cfg(feature = "experimental")]
+	pub fn task_extrinsic(task: T::RuntimeTask) -> T::Extrinsic
+	where
+		T: offchain::CreateBare<Call<T>>,
+	{
+		T::create_bare(Call::<T>::do_task { task }.into())
+	}
+
 	/// Note what the extrinsic data of the current extrinsic index is.
 	///
 	/// This is required to be called before applying an extrinsic. The data will used
@@ -110,6 +110,29 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Approve a recurring spend of two instalments under index `0`, returning whether the spend
+// origin was able to do so.
+fn create_recurring_spend<T: Config<I>, I: 'static>(
+	asset_kind: T::AssetKind,
+	amount: AssetBalanceOf<T, I>,
+	beneficiary_lookup: BeneficiaryLookupOf<T, I>,
+) -> Result<bool, BenchmarkError> {
+	if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+		Treasury::<T, I>::spend_in_instalments(
+			origin,
+			Box::new(asset_kind),
+			amount,
+			2,
+			1u32.into(),
+			Box::new(beneficiary_lookup),
+			None,
+		)?;
+		Ok(true)
+	} else {
+		Ok(false)
+	}
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -350,6 +373,201 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_in_instalments` is un-callable and can use
+	/// weight=0.
+	#[benchmark]
+	fn spend_in_instalments() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let period: BlockNumberFor<T, I> = 1u32.into();
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			amount,
+			2,
+			period,
+			Box::new(beneficiary_lookup),
+			None,
+		);
+
+		let valid_from = T::BlockNumberProvider::current_block_number();
+		assert_last_event::<T, I>(
+			Event::RecurringSpendApproved {
+				index: 0,
+				asset_kind,
+				amount_per_instalment: amount,
+				instalments: 2,
+				period,
+				beneficiary,
+				valid_from,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn payout_instalment() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists =
+			create_recurring_spend::<T, _>(asset_kind.clone(), amount, beneficiary_lookup)?;
+
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind, amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[block]
+		{
+			let res =
+				Treasury::<T, _>::payout_instalment(RawOrigin::Signed(caller.clone()).into(), 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			let id = match RecurringSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure);
+					id
+				},
+				_ => panic!("No payout attempt made"),
+			};
+			assert_last_event::<T, I>(Event::Paid { index: 0, payment_id: id }.into());
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn check_instalment_status() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), amount);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		let spend_exists = create_recurring_spend::<T, _>(asset_kind, amount, beneficiary_lookup)?;
+		if spend_exists {
+			Treasury::<T, _>::payout_instalment(RawOrigin::Signed(caller.clone()).into(), 0u32)?;
+			match RecurringSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					T::Paymaster::ensure_concluded(id);
+				},
+				_ => panic!("No payout attempt made"),
+			};
+		}
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::check_instalment_status(
+				RawOrigin::Signed(caller.clone()).into(),
+				0u32,
+			);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if let Some(s) = RecurringSpends::<T, I>::get(0) {
+			assert!(!matches!(s.status, PaymentState::Attempted { .. }));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause_recurring_spend() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = create_recurring_spend::<T, _>(asset_kind, amount, beneficiary_lookup)?;
+
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::pause_recurring_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			assert!(RecurringSpends::<T, I>::get(0).unwrap().paused);
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume_recurring_spend() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = create_recurring_spend::<T, _>(asset_kind, amount, beneficiary_lookup)?;
+
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		if spend_exists {
+			Treasury::<T, _>::pause_recurring_spend(origin.clone(), 0u32)?;
+		}
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::resume_recurring_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			assert!(!RecurringSpends::<T, I>::get(0).unwrap().paused);
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_recurring_spend() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = create_recurring_spend::<T, _>(asset_kind, amount, beneficiary_lookup)?;
+
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::cancel_recurring_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(RecurringSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
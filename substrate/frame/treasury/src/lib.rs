@@ -69,6 +69,13 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! Recurring payments, such as grants paid out monthly, can be approved at once using the
+//! `spend_in_instalments` dispatchable. The approved total is split into a number of equal
+//! instalments, each becoming claimable one period after the previous one and paid through the
+//! same [`pallet::Config::Paymaster`] using the `payout_instalment` and `check_instalment_status`
+//! dispatchables. Unlike one-shot spends, a due instalment does not expire. The
+//! [`pallet::Config::RejectOrigin`] can pause, resume or cancel such a spend at any time.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -90,8 +97,8 @@ use scale_info::TypeInfo;
 use alloc::{boxed::Box, collections::btree_map::BTreeMap};
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, BlockNumberProvider, CheckedAdd, CheckedMul, One, Saturating,
+		StaticLookup, UniqueSaturatedInto, Zero,
 	},
 	PerThing, Permill, RuntimeDebug,
 };
@@ -223,6 +230,40 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 	pub status: PaymentState<PaymentId>,
 }
 
+/// Info regarding an approved treasury spend paid out in equal instalments.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId> {
+	/// The kind of asset to be spent.
+	pub asset_kind: AssetKind,
+	/// The asset amount paid out by each instalment.
+	pub amount_per_instalment: AssetBalance,
+	/// The beneficiary of the spend.
+	pub beneficiary: Beneficiary,
+	/// The block number from which the next instalment can be claimed.
+	pub next_payout_at: BlockNumber,
+	/// The number of blocks between two successive instalments.
+	pub period: BlockNumber,
+	/// The number of instalments which have not been paid out yet.
+	pub instalments_remaining: u32,
+	/// The asset amount which has not been paid out yet.
+	pub remaining_budget: AssetBalance,
+	/// Whether the payouts have been paused by the reject origin.
+	pub paused: bool,
+	/// The status of the payout/claim of the current instalment.
+	pub status: PaymentState<PaymentId>,
+}
+
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
@@ -382,6 +423,25 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Spends paid out in instalments that have been approved and being processed.
+	///
+	/// Indices are taken from [`SpendCount`] and hence never collide with [`Spends`].
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type RecurringSpends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		RecurringSpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+			<T::Paymaster as Pay>::Id,
+		>,
+		OptionQuery,
+	>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
@@ -444,6 +504,30 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new asset spend paid out in instalments has been approved.
+		RecurringSpendApproved {
+			index: SpendIndex,
+			asset_kind: T::AssetKind,
+			amount_per_instalment: AssetBalanceOf<T, I>,
+			instalments: u32,
+			period: BlockNumberFor<T, I>,
+			beneficiary: T::Beneficiary,
+			valid_from: BlockNumberFor<T, I>,
+		},
+		/// An instalment of a recurring spend was processed. The next one can be claimed from
+		/// `next_payout_at`.
+		InstalmentProcessed {
+			index: SpendIndex,
+			instalments_remaining: u32,
+			remaining_budget: AssetBalanceOf<T, I>,
+			next_payout_at: BlockNumberFor<T, I>,
+		},
+		/// The payouts of a recurring spend have been paused.
+		RecurringSpendPaused { index: SpendIndex },
+		/// The payouts of a recurring spend have been resumed.
+		RecurringSpendResumed { index: SpendIndex, next_payout_at: BlockNumberFor<T, I> },
+		/// A recurring spend was cancelled and its remaining budget released.
+		RecurringSpendCancelled { index: SpendIndex, remaining_budget: AssetBalanceOf<T, I> },
 	}
 
 	/// Error for the treasury pallet.
@@ -472,6 +556,13 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The number of instalments or the period between them is zero, or the total amount
+		/// overflows.
+		InvalidSchedule,
+		/// The recurring spend is paused.
+		SpendPaused,
+		/// The recurring spend is not paused.
+		SpendNotPaused,
 	}
 
 	#[pallet::hooks]
@@ -695,23 +786,7 @@ pub mod pallet {
 
 			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
 
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(native_amount);
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::ensure_spend_in_context(max_amount, native_amount)?;
 
 			let index = SpendCount::<T, I>::get();
 			Spends::<T, I>::insert(
@@ -869,6 +944,340 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds paid out in equal instalments.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of all instalments of `asset_kind` in the native asset. The amount of
+		/// `asset_kind` is converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend for transferring `amount_per_instalment` of `asset_kind` to a
+		/// designated beneficiary `instalments` times. The first instalment can be claimed from
+		/// `valid_from` and every following one `period` blocks after the previous one. Each
+		/// instalment must be claimed using the `payout_instalment` dispatchable. A due
+		/// instalment does not expire.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount_per_instalment`: The amount to be transferred to the `beneficiary` by each
+		///   instalment.
+		/// - `instalments`: The number of instalments. Must not be zero.
+		/// - `period`: The number of blocks between two successive instalments. Must not be zero.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the first instalment can be claimed. If
+		///   `None`, it can be claimed immediately after approval.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_in_instalments())]
+		pub fn spend_in_instalments(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount_per_instalment: AssetBalanceOf<T, I>,
+			instalments: u32,
+			period: BlockNumberFor<T, I>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+
+			ensure!(instalments > 0 && !period.is_zero(), Error::<T, I>::InvalidSchedule);
+			let total = amount_per_instalment
+				.checked_mul(&instalments.into())
+				.ok_or(Error::<T, I>::InvalidSchedule)?;
+
+			let native_amount = T::BalanceConverter::from_asset_balance(total, *asset_kind.clone())
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			Self::ensure_spend_in_context(max_amount, native_amount)?;
+
+			let valid_from = valid_from.unwrap_or(T::BlockNumberProvider::current_block_number());
+			let index = SpendCount::<T, I>::get();
+			RecurringSpends::<T, I>::insert(
+				index,
+				RecurringSpendStatus {
+					asset_kind: *asset_kind.clone(),
+					amount_per_instalment,
+					beneficiary: beneficiary.clone(),
+					next_payout_at: valid_from,
+					period,
+					instalments_remaining: instalments,
+					remaining_budget: total,
+					paused: false,
+					status: PaymentState::Pending,
+				},
+			);
+			SpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::RecurringSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount_per_instalment,
+				instalments,
+				period,
+				beneficiary,
+				valid_from,
+			});
+			Ok(())
+		}
+
+		/// Claim the current instalment of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The instalment can be claimed once its payout block has been reached and as long as the
+		/// spend is not paused. In case of a payout failure, the spend status must be updated with
+		/// the `check_instalment_status` dispatchable before retrying with the current function.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::Paid`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::payout_instalment())]
+		pub fn payout_instalment(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut spend =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(!spend.paused, Error::<T, I>::SpendPaused);
+			ensure!(now >= spend.next_payout_at, Error::<T, I>::EarlyPayout);
+			ensure!(
+				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			let id = T::Paymaster::pay(
+				&spend.beneficiary,
+				spend.asset_kind.clone(),
+				spend.amount_per_instalment,
+			)
+			.map_err(|_| Error::<T, I>::PayoutError)?;
+
+			spend.status = PaymentState::Attempted { id };
+			RecurringSpends::<T, I>::insert(index, spend);
+
+			Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
+
+			Ok(())
+		}
+
+		/// Check the payment status of the current instalment of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The status check is a prerequisite for retrying a failed payout and for claiming the
+		/// next instalment. Once an instalment has succeeded, the remaining budget is reduced and
+		/// the next instalment scheduled one period later. The spend is removed from the storage
+		/// once its last instalment has succeeded. In such instances, transaction fees are
+		/// refunded.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::PaymentFailed`] if the instalment payout has failed.
+		/// Emits [`Event::InstalmentProcessed`] if the instalment payout has succeed.
+		/// Emits [`Event::SpendProcessed`] if the last instalment payout has succeed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::check_instalment_status())]
+		pub fn check_instalment_status(
+			origin: OriginFor<T>,
+			index: SpendIndex,
+		) -> DispatchResultWithPostInfo {
+			use PaymentState as State;
+			use PaymentStatus as Status;
+
+			ensure_signed(origin)?;
+			let mut spend =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			let payment_id = match spend.status {
+				State::Attempted { id } => id,
+				_ => return Err(Error::<T, I>::NotAttempted.into()),
+			};
+
+			match T::Paymaster::check_payment(payment_id) {
+				Status::Failure => {
+					spend.status = PaymentState::Failed;
+					RecurringSpends::<T, I>::insert(index, spend);
+					Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
+					Ok(Pays::Yes.into())
+				},
+				Status::Success | Status::Unknown => {
+					spend.instalments_remaining.saturating_dec();
+					spend.remaining_budget.saturating_reduce(spend.amount_per_instalment);
+					if spend.instalments_remaining.is_zero() {
+						RecurringSpends::<T, I>::remove(index);
+						Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+					} else {
+						spend.status = PaymentState::Pending;
+						spend.next_payout_at.saturating_accrue(spend.period);
+						Self::deposit_event(Event::<T, I>::InstalmentProcessed {
+							index,
+							instalments_remaining: spend.instalments_remaining,
+							remaining_budget: spend.remaining_budget,
+							next_payout_at: spend.next_payout_at,
+						});
+						RecurringSpends::<T, I>::insert(index, spend);
+					}
+					Ok(Pays::No.into())
+				},
+				Status::InProgress => Err(Error::<T, I>::Inconclusive.into()),
+			}
+		}
+
+		/// Pause the payouts of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// No instalment can be claimed while the spend is paused. A payout already attempted can
+		/// still be checked with the `check_instalment_status` dispatchable.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendPaused`] if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::pause_recurring_spend())]
+		pub fn pause_recurring_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			RecurringSpends::<T, I>::try_mutate(index, |maybe_spend| -> DispatchResult {
+				let spend = maybe_spend.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+				ensure!(!spend.paused, Error::<T, I>::SpendPaused);
+				spend.paused = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::RecurringSpendPaused { index });
+			Ok(())
+		}
+
+		/// Resume the payouts of a paused recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// Instalments which became due while the spend was paused are not paid out at once: the
+		/// next instalment becomes claimable at the latest from the current block and the
+		/// following ones keep their period from there.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendResumed`] if successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::resume_recurring_spend())]
+		pub fn resume_recurring_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			let next_payout_at = RecurringSpends::<T, I>::try_mutate(
+				index,
+				|maybe_spend| -> Result<_, DispatchError> {
+					let spend = maybe_spend.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
+					ensure!(spend.paused, Error::<T, I>::SpendNotPaused);
+					spend.paused = false;
+					spend.next_payout_at = spend.next_payout_at.max(now);
+					Ok(spend.next_payout_at)
+				},
+			)?;
+
+			Self::deposit_event(Event::<T, I>::RecurringSpendResumed { index, next_payout_at });
+			Ok(())
+		}
+
+		/// Cancel a recurring spend, releasing its remaining budget.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// A cancellation is only possible if the payout of the current instalment is not being
+		/// attempted. Instalments already paid out are not affected.
+		///
+		/// ### Parameters
+		/// - `index`: The spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendCancelled`] if successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::cancel_recurring_spend())]
+		pub fn cancel_recurring_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let spend = RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(
+				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				Error::<T, I>::AlreadyAttempted
+			);
+
+			RecurringSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::RecurringSpendCancelled {
+				index,
+				remaining_budget: spend.remaining_budget,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Account `native_amount` against the `max_amount` of the spend origin within the current
+		/// dispatch context, so a batch of spends cannot exceed the limit of a single one.
+		fn ensure_spend_in_context(
+			max_amount: BalanceOf<T, I>,
+			native_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+			Ok(())
+		}
 	}
 }
 
@@ -1075,31 +1484,46 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// ## Invariants of spend storage items
 	///
-	/// 1. [`SpendCount`] >= Number of elements in [`Spends`].
-	/// 2. Each entry in [`Spends`] should be saved under a key strictly less than current
-	/// [`SpendCount`].
+	/// 1. [`SpendCount`] >= Number of elements in [`Spends`] and [`RecurringSpends`].
+	/// 2. Each entry in [`Spends`] and [`RecurringSpends`] should be saved under a key strictly
+	/// less than current [`SpendCount`].
 	/// 3. For each spend entry contained in [`Spends`] we should have spend.expire_at
 	/// > spend.valid_from.
+	/// 4. For each spend entry contained in [`RecurringSpends`] the remaining budget should
+	/// cover exactly the remaining, non-zero number of instalments.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_spends() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_spend_count = SpendCount::<T, I>::get();
 		ensure!(
-			current_spend_count as usize >= Spends::<T, I>::iter().count(),
+			current_spend_count as usize >=
+				Spends::<T, I>::iter().count() + RecurringSpends::<T, I>::iter().count(),
 			"Actual number of spends exceeds `SpendCount`."
 		);
 
-		Spends::<T, I>::iter_keys().try_for_each(|spend_index| -> DispatchResult {
+		Spends::<T, I>::iter_keys()
+			.chain(RecurringSpends::<T, I>::iter_keys())
+			.try_for_each(|spend_index| -> DispatchResult {
+				ensure!(
+					current_spend_count > spend_index,
+					"`SpendCount` should by strictly greater than any SpendIndex used as a key for `Spends`."
+				);
+				Ok(())
+			})?;
+
+		Spends::<T, I>::iter().try_for_each(|(_index, spend)| -> DispatchResult {
 			ensure!(
-				current_spend_count > spend_index,
-				"`SpendCount` should by strictly greater than any SpendIndex used as a key for `Spends`."
+				spend.valid_from < spend.expire_at,
+				"Spend cannot expire before it becomes valid."
 			);
 			Ok(())
 		})?;
 
-		Spends::<T, I>::iter().try_for_each(|(_index, spend)| -> DispatchResult {
+		RecurringSpends::<T, I>::iter().try_for_each(|(_index, spend)| -> DispatchResult {
 			ensure!(
-				spend.valid_from < spend.expire_at,
-				"Spend cannot expire before it becomes valid."
+				!spend.instalments_remaining.is_zero() &&
+					spend.amount_per_instalment.checked_mul(&spend.instalments_remaining.into()) ==
+						Some(spend.remaining_budget),
+				"Remaining budget must match the remaining instalments of a recurring spend."
 			);
 			Ok(())
 		})?;
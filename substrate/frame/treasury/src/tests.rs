@@ -242,6 +242,14 @@ fn get_payment_id(i: SpendIndex) -> Option<u64> {
 	}
 }
 
+fn get_recurring_payment_id(i: SpendIndex) -> Option<u64> {
+	let spend = RecurringSpends::<Test, _>::get(i).expect("no recurring spend");
+	match spend.status {
+		PaymentState::Attempted { id } => Some(id),
+		_ => None,
+	}
+}
+
 #[test]
 fn genesis_config_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -849,6 +857,313 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn spend_in_instalments_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// the origin is checked against the total amount of all instalments.
+		assert_noop!(
+			Treasury::spend_in_instalments(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				1,
+				3,
+				5,
+				Box::new(6),
+				None
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend_in_instalments(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				1,
+				0,
+				5,
+				Box::new(6),
+				None
+			),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			Treasury::spend_in_instalments(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				1,
+				2,
+				0,
+				Box::new(6),
+				None
+			),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			Treasury::spend_in_instalments(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				u64::MAX,
+				2,
+				5,
+				Box::new(6),
+				None
+			),
+			Error::<Test, _>::InvalidSchedule
+		);
+
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			1,
+			2,
+			5,
+			Box::new(6),
+			Some(3)
+		));
+
+		assert_eq!(SpendCount::<Test, _>::get(), 1);
+		assert_eq!(
+			RecurringSpends::<Test, _>::get(0).unwrap(),
+			RecurringSpendStatus {
+				asset_kind: 1,
+				amount_per_instalment: 1,
+				beneficiary: 6,
+				next_payout_at: 3,
+				period: 5,
+				instalments_remaining: 2,
+				remaining_budget: 2,
+				paused: false,
+				status: PaymentState::Pending,
+			}
+		);
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount_per_instalment: 1,
+				instalments: 2,
+				period: 5,
+				beneficiary: 6,
+				valid_from: 3,
+			}
+			.into(),
+		);
+
+		// recurring and one-shot spends share the same indices.
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		assert_eq!(SpendCount::<Test, _>::get(), 2);
+		assert!(Spends::<Test, _>::get(1).is_some());
+		assert_ok!(Treasury::do_try_state());
+	});
+}
+
+#[test]
+fn instalment_payout_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// approve three instalments of `10` coins of asset `1`, one every `5` blocks.
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			3,
+			5,
+			Box::new(6),
+			None
+		));
+
+		// first instalment.
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 10);
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+		System::assert_last_event(Event::<Test, _>::Paid { index: 0, payment_id }.into());
+		assert_noop!(
+			Treasury::payout_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+		set_status(payment_id, PaymentStatus::Success);
+		let info = Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::<Test, _>::InstalmentProcessed {
+				index: 0,
+				instalments_remaining: 2,
+				remaining_budget: 20,
+				next_payout_at: 6,
+			}
+			.into(),
+		);
+
+		// second instalment is only due one period later.
+		assert_noop!(
+			Treasury::payout_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::EarlyPayout
+		);
+		System::set_block_number(6);
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 20);
+		set_status(get_recurring_payment_id(0).unwrap(), PaymentStatus::Success);
+		assert_ok!(Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0));
+		assert_eq!(RecurringSpends::<Test, _>::get(0).unwrap().next_payout_at, 11);
+
+		// a due instalment does not expire.
+		System::set_block_number(30);
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 30);
+		set_status(get_recurring_payment_id(0).unwrap(), PaymentStatus::Success);
+		let info = Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+
+		// the spend is removed after the last instalment.
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+		assert_noop!(
+			Treasury::payout_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
+#[test]
+fn instalment_payout_retry_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			3,
+			5,
+			Box::new(6),
+			None
+		));
+		assert_noop!(
+			Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::NotAttempted
+		);
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		let payment_id = get_recurring_payment_id(0).expect("no payment attempt");
+
+		// payment in progress.
+		set_status(payment_id, PaymentStatus::InProgress);
+		assert_noop!(
+			Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::Inconclusive
+		);
+
+		// payment failed, the budget is untouched.
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 10);
+		let info = Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+		System::assert_last_event(Event::<Test, _>::PaymentFailed { index: 0, payment_id }.into());
+		let spend = RecurringSpends::<Test, _>::get(0).unwrap();
+		assert_eq!(spend.status, PaymentState::Failed);
+		assert_eq!((spend.instalments_remaining, spend.remaining_budget), (3, 30));
+
+		// the payout can be retried now.
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 10);
+	});
+}
+
+#[test]
+fn pause_and_resume_recurring_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			3,
+			5,
+			Box::new(6),
+			None
+		));
+
+		assert_noop!(Treasury::pause_recurring_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_noop!(
+			Treasury::pause_recurring_spend(RuntimeOrigin::root(), 1),
+			Error::<Test, _>::InvalidIndex
+		);
+		assert_noop!(
+			Treasury::resume_recurring_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::SpendNotPaused
+		);
+		assert_ok!(Treasury::pause_recurring_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::RecurringSpendPaused { index: 0 }.into());
+		assert_noop!(
+			Treasury::pause_recurring_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::SpendPaused
+		);
+
+		// no instalment can be claimed while paused.
+		assert_noop!(
+			Treasury::payout_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::SpendPaused
+		);
+
+		// instalments due while paused are not paid out at once.
+		System::set_block_number(20);
+		assert_noop!(Treasury::resume_recurring_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_ok!(Treasury::resume_recurring_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendResumed { index: 0, next_payout_at: 20 }.into(),
+		);
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+		set_status(get_recurring_payment_id(0).unwrap(), PaymentStatus::Success);
+
+		// an attempted payout can still be concluded while paused.
+		assert_ok!(Treasury::pause_recurring_spend(RuntimeOrigin::root(), 0));
+		assert_ok!(Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0));
+		let spend = RecurringSpends::<Test, _>::get(0).unwrap();
+		assert_eq!((spend.next_payout_at, spend.remaining_budget), (25, 20));
+
+		// resuming before the next instalment is due keeps the schedule.
+		assert_ok!(Treasury::resume_recurring_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendResumed { index: 0, next_payout_at: 25 }.into(),
+		);
+	});
+}
+
+#[test]
+fn cancel_recurring_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			3,
+			5,
+			Box::new(6),
+			None
+		));
+		assert_ok!(Treasury::payout_instalment(RuntimeOrigin::signed(1), 0));
+
+		// spend cannot be cancelled while a payout is attempted.
+		assert_noop!(Treasury::cancel_recurring_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_noop!(
+			Treasury::cancel_recurring_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+		set_status(get_recurring_payment_id(0).unwrap(), PaymentStatus::Success);
+		assert_ok!(Treasury::check_instalment_status(RuntimeOrigin::signed(1), 0));
+
+		assert_ok!(Treasury::cancel_recurring_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendCancelled { index: 0, remaining_budget: 20 }.into(),
+		);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+		assert_eq!(paid(6, 1), 10);
+		assert_noop!(
+			Treasury::cancel_recurring_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1012,6 +1327,34 @@ fn try_state_spends_invariant_3_works() {
 	});
 }
 
+#[test]
+fn try_state_spends_invariant_4_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::pallet_prelude::DispatchError::Other;
+		// Propose and approve a recurring spend
+		assert_ok!(Treasury::spend_in_instalments(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			1,
+			2,
+			5,
+			Box::new(6),
+			None
+		));
+		// Check invariant 4 holds
+		assert_ok!(Treasury::do_try_state());
+		// Break invariant 4 by increasing the remaining budget
+		RecurringSpends::<Test>::mutate(0, |s| s.as_mut().unwrap().remaining_budget += 1);
+		// Invariant 4 should be violated
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other(
+				"Remaining budget must match the remaining instalments of a recurring spend."
+			))
+		);
+	});
+}
+
 #[test]
 fn multiple_spend_periods_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -435,6 +435,46 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn revoke_vesting_schedule(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, { T::MAX_VESTING_SCHEDULES }>,
+	) -> Result<(), BenchmarkError> {
+		let revoker: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&revoker, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+
+		// Give target existing locks and schedules, the last of which is revocable.
+		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s - 1)?;
+
+		let min_transfer = T::MinVestedTransfer::get();
+		let locked = min_transfer.checked_mul(&20_u32.into()).unwrap();
+		let schedule =
+			VestingInfo::new(locked, min_transfer, 1_u32.into()).with_revoker(revoker.clone());
+		assert_ok!(Pallet::<T>::do_vested_transfer(&revoker, &target, schedule));
+
+		// The revocable vesting schedule.
+		let schedule_index = s - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(revoker.clone()), target_lookup, schedule_index);
+
+		assert_eq!(
+			Vesting::<T>::get(&target).map_or(0, |schedules| schedules.len()),
+			schedule_index as usize,
+			"Schedule count should reduce by 1"
+		);
+		let event: <T as Config>::RuntimeEvent =
+			Event::<T>::VestingRevoked { account: target, revoker, amount: locked }.into();
+		frame_system::Pallet::<T>::assert_last_event(event.into());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
@@ -27,6 +27,10 @@
 //! amount for any reason other than the ones specified in `UnvestedFundsAllowedWithdrawReasons`
 //! configuration value.
 //!
+//! Besides unlocking linearly, a schedule may unlock nothing before a cliff, or unlock in steps
+//! every given number of blocks. A schedule may also name a revoker, who can claim back the funds
+//! which are not yet vested.
+//!
 //! As the amount vested increases over time, the amount unvested reduces. However, locks remain in
 //! place and explicit action is needed on behalf of the user to ensure that the amount locked is
 //! equivalent to the amount remaining to be vested. This is done through a dispatchable function,
@@ -42,6 +46,8 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `revoke_vesting_schedule` - Revoke a vesting schedule, returning the funds which are not yet
+//!   vested to its revoker.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -66,6 +72,8 @@ use frame_support::{
 	ensure,
 	storage::bounded_vec::BoundedVec,
 	traits::{
+		fungible,
+		tokens::{Fortitude, Preservation},
 		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, VestedTransfer,
 		VestingSchedule, WithdrawReasons,
 	},
@@ -78,7 +86,7 @@ use sp_runtime::{
 		AtLeast32BitUnsigned, BlockNumberProvider, Bounded, Convert, MaybeSerializeDeserialize,
 		One, Saturating, StaticLookup, Zero,
 	},
-	DispatchError, RuntimeDebug,
+	DispatchError, RuntimeDebug, SaturatedConversion,
 };
 
 pub use pallet::*;
@@ -90,6 +98,8 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingInfoOf<T> =
+	VestingInfo<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -99,6 +109,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 pub enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -131,8 +142,8 @@ impl VestingAction {
 	/// Pick the schedules that this action dictates should continue vesting undisturbed.
 	fn pick_schedules<T: Config>(
 		&self,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
-	) -> impl Iterator<Item = VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> + '_ {
+		schedules: Vec<VestingInfoOf<T>>,
+	) -> impl Iterator<Item = VestingInfoOf<T>> + '_ {
 		schedules.into_iter().enumerate().filter_map(move |(index, schedule)| {
 			if self.should_remove(index) {
 				None
@@ -164,7 +175,7 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The currency trait.
-		type Currency: LockableCurrency<Self::AccountId>;
+		type Currency: LockableCurrency<Self::AccountId> + fungible::Inspect<Self::AccountId>;
 
 		/// Convert the block number into a balance.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
@@ -229,7 +240,7 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+		BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
 	>;
 
 	/// Storage version of the pallet.
@@ -253,7 +264,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -267,7 +278,7 @@ pub mod pallet {
 				let locked = balance.saturating_sub(liquid);
 				let length_as_balance = T::BlockNumberToBalance::convert(length);
 				let per_block = locked / length_as_balance.max(sp_runtime::traits::One::one());
-				let vesting_info = VestingInfo::new(locked, per_block, begin);
+				let vesting_info: VestingInfoOf<T> = VestingInfo::new(locked, per_block, begin);
 				if !vesting_info.is_valid() {
 					panic!("Invalid VestingInfo params at genesis")
 				};
@@ -293,6 +304,9 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A vesting schedule has been revoked and the funds which were not yet vested have been
+		/// returned to the revoker.
+		VestingRevoked { account: T::AccountId, revoker: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -309,6 +323,11 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The sender is not allowed to revoke the vesting schedule.
+		NotRevoker,
+		/// The vesting schedules cannot be merged because they have different revokers or
+		/// unlock in incompatible ways.
+		IncompatibleSchedules,
 	}
 
 	#[pallet::call]
@@ -357,7 +376,8 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account receiving the vested funds.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. If it is revocable, the
+		///   revoker must be the sender.
 		///
 		/// Emits `VestingCreated`.
 		///
@@ -372,10 +392,14 @@ pub mod pallet {
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
-			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
+			ensure!(
+				schedule.revoker().map_or(true, |revoker| revoker == &transactor),
+				Error::<T>::InvalidScheduleParams
+			);
 			Self::do_vested_transfer(&transactor, &target, schedule)
 		}
 
@@ -401,7 +425,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			source: AccountIdLookupOf<T>,
 			target: AccountIdLookupOf<T>,
-			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 			let target = T::Lookup::lookup(target)?;
@@ -425,6 +449,11 @@ pub mod pallet {
 		///   current_block)`.
 		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
 		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
+		/// - `kind`: `Cliff` at the latest cliff of the two schedules if it is after
+		///   `starting_block`, `Stepped` with the longest period of the two schedules, or `Linear`
+		///   otherwise. A `Cliff` and a `Stepped` schedule cannot be merged.
+		/// - `revoker`: The revoker of both schedules. Schedules with different revokers cannot be
+		///   merged.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
@@ -488,6 +517,40 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Revoke a vesting schedule, returning the funds which are not yet vested to the revoker.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the revoker of
+		/// the schedule.
+		///
+		/// - `target`: An account that has a revocable vesting schedule.
+		/// - `schedule_index`: The index of the vesting schedule to revoke.
+		///
+		/// Emits `VestingRevoked`.
+		///
+		/// NOTE: This will unlock all schedules through the current block. Unvested funds frozen by
+		/// other locks, e.g. for staking, or already spent, are not returned.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_vesting_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_vesting_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+		) -> DispatchResultWithPostInfo {
+			let revoker = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+
+			let schedules_count = Vesting::<T>::decode_len(&who).unwrap_or_default();
+			Self::do_revoke_vesting_schedule(&revoker, &who, schedule_index)?;
+
+			Ok(Some(T::WeightInfo::revoke_vesting_schedule(
+				MaxLocksOf::<T>::get(),
+				schedules_count as u32,
+			))
+			.into())
+		}
 	}
 }
 
@@ -495,8 +558,7 @@ impl<T: Config> Pallet<T> {
 	// Public function for accessing vesting storage
 	pub fn vesting(
 		account: T::AccountId,
-	) -> Option<BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>>
-	{
+	) -> Option<BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>> {
 		Vesting::<T>::get(account)
 	}
 
@@ -504,9 +566,9 @@ impl<T: Config> Pallet<T> {
 	// NOTE: We assume both schedules have had funds unlocked up through the current block.
 	fn merge_vesting_info(
 		now: BlockNumberFor<T>,
-		schedule1: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-		schedule2: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-	) -> Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+		schedule1: VestingInfoOf<T>,
+		schedule2: VestingInfoOf<T>,
+	) -> Result<Option<VestingInfoOf<T>>, DispatchError> {
 		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let now_as_balance = T::BlockNumberToBalance::convert(now);
@@ -514,15 +576,18 @@ impl<T: Config> Pallet<T> {
 		// Check if one or both schedules have ended.
 		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
 			// If both schedules have ended, we don't merge and exit early.
-			(true, true) => return None,
+			(true, true) => return Ok(None),
 			// If one schedule has ended, we treat the one that has not ended as the new
 			// merged schedule.
-			(true, false) => return Some(schedule2),
-			(false, true) => return Some(schedule1),
+			(true, false) => return Ok(Some(schedule2)),
+			(false, true) => return Ok(Some(schedule1)),
 			// If neither schedule has ended don't exit early.
 			_ => {},
 		}
 
+		// Revocable funds must remain revocable by the same account.
+		ensure!(schedule1.revoker() == schedule2.revoker(), Error::<T>::IncompatibleSchedules);
+
 		let locked = schedule1
 			.locked_at::<T::BlockNumberToBalance>(now)
 			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
@@ -543,17 +608,43 @@ impl<T: Config> Pallet<T> {
 			(locked / duration).max(One::one())
 		};
 
-		let schedule = VestingInfo::new(locked, per_block, starting_block);
+		let kind = match (schedule1.kind(), schedule2.kind()) {
+			(VestingKind::Linear, VestingKind::Linear) => VestingKind::Linear,
+			// Funds behind a cliff must not become available before it.
+			(VestingKind::Cliff { cliff }, VestingKind::Linear) |
+			(VestingKind::Linear, VestingKind::Cliff { cliff }) => VestingKind::Cliff { cliff },
+			(VestingKind::Cliff { cliff: cliff1 }, VestingKind::Cliff { cliff: cliff2 }) =>
+				VestingKind::Cliff { cliff: cliff1.max(cliff2) },
+			(VestingKind::Stepped { period }, VestingKind::Linear) |
+			(VestingKind::Linear, VestingKind::Stepped { period }) => VestingKind::Stepped { period },
+			(
+				VestingKind::Stepped { period: period1 },
+				VestingKind::Stepped { period: period2 },
+			) => VestingKind::Stepped { period: period1.max(period2) },
+			(VestingKind::Cliff { .. }, VestingKind::Stepped { .. }) |
+			(VestingKind::Stepped { .. }, VestingKind::Cliff { .. }) =>
+				return Err(Error::<T>::IncompatibleSchedules.into()),
+		};
+		// A cliff which is not after the start of the merged schedule has no effect.
+		let kind = match kind {
+			VestingKind::Cliff { cliff } if cliff <= starting_block => VestingKind::Linear,
+			kind => kind,
+		};
+
+		let mut schedule = VestingInfo::new(locked, per_block, starting_block).with_kind(kind);
+		if let Some(revoker) = schedule1.revoker() {
+			schedule = schedule.with_revoker(revoker.clone());
+		}
 		debug_assert!(schedule.is_valid(), "merge_vesting_info schedule validation check failed");
 
-		Some(schedule)
+		Ok(Some(schedule))
 	}
 
 	// Execute a vested transfer from `source` to `target` with the given `schedule`.
 	fn do_vested_transfer(
 		source: &T::AccountId,
 		target: &T::AccountId,
-		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		schedule: VestingInfoOf<T>,
 	) -> DispatchResult {
 		// Validate user inputs.
 		ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
@@ -562,24 +653,14 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		)?;
+		Self::ensure_can_add_vesting_schedule(target, &schedule)?;
 
 		T::Currency::transfer(source, target, schedule.locked(), ExistenceRequirement::AllowDeath)?;
 
 		// We can't let this fail because the currency transfer has already happened.
 		// Must be successful as it has been checked before.
 		// Better to return error on failure anyway.
-		let res = Self::add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::do_add_vesting_schedule(target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
@@ -596,9 +677,9 @@ impl<T: Config> Pallet<T> {
 	///
 	/// NOTE: the amount locked does not include any schedules that are filtered out via `action`.
 	fn report_schedule_updates(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 		action: VestingAction,
-	) -> (Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>) {
+	) -> (Vec<VestingInfoOf<T>>, BalanceOf<T>) {
 		let now = T::BlockNumberProvider::current_block_number();
 
 		let mut total_locked_now: BalanceOf<T> = Zero::zero();
@@ -635,12 +716,10 @@ impl<T: Config> Pallet<T> {
 	/// Write an accounts updated vesting schedules to storage.
 	fn write_vesting(
 		who: &T::AccountId,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 	) -> Result<(), DispatchError> {
-		let schedules: BoundedVec<
-			VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-			MaxVestingSchedulesGet<T>,
-		> = schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+		let schedules: BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>> =
+			schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
 
 		if schedules.len() == 0 {
 			Vesting::<T>::remove(&who);
@@ -664,18 +743,116 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Revoke the vesting schedule of `who` at `schedule_index` on behalf of `revoker`, returning
+	/// the funds which are not yet vested to `revoker`.
+	fn do_revoke_vesting_schedule(
+		revoker: &T::AccountId,
+		who: &T::AccountId,
+		schedule_index: u32,
+	) -> DispatchResult {
+		let schedules = Vesting::<T>::get(who).ok_or(Error::<T>::NotVesting)?;
+		let schedule = schedules
+			.get(schedule_index as usize)
+			.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+		ensure!(schedule.revoker() == Some(revoker), Error::<T>::NotRevoker);
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let unvested = schedule.locked_at::<T::BlockNumberToBalance>(now);
+
+		let remove_action = VestingAction::Remove { index: schedule_index as usize };
+		let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		// Some of the unvested funds may have been used for the withdraw reasons the vesting lock
+		// allows, or be frozen by other locks overlapping with it, e.g. for staking. Only what is
+		// left of them and usable can be returned.
+		let usable = <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(
+			who,
+			Preservation::Expendable,
+			Fortitude::Polite,
+		);
+		let amount = unvested.min(usable.saturated_into::<u128>().saturated_into());
+		if !amount.is_zero() {
+			T::Currency::transfer(who, revoker, amount, ExistenceRequirement::AllowDeath)?;
+		}
+
+		Self::deposit_event(Event::<T>::VestingRevoked {
+			account: who.clone(),
+			revoker: revoker.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Add `schedule` to the vesting schedules of `who`.
+	///
+	/// It is a no-op if the amount to be vested is zero.
+	fn do_add_vesting_schedule(who: &T::AccountId, schedule: VestingInfoOf<T>) -> DispatchResult {
+		if schedule.locked().is_zero() {
+			return Ok(())
+		}
+
+		// Check for `per_block` or `locked` of 0.
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		};
+
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		debug_assert!(schedules.len() > 0, "schedules cannot be empty after insertion");
+		let schedule_index = schedules.len() - 1;
+		Self::deposit_event(Event::<T>::VestingCreated {
+			account: who.clone(),
+			schedule_index: schedule_index as u32,
+		});
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
+	/// Ensure we can call `do_add_vesting_schedule` with `schedule` without error.
+	fn ensure_can_add_vesting_schedule(
+		who: &T::AccountId,
+		schedule: &VestingInfoOf<T>,
+	) -> DispatchResult {
+		// Check for `per_block` or `locked` of 0.
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		}
+
+		ensure!(
+			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
+
+		Ok(())
+	}
+
 	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
 	/// and locked amount.
 	fn exec_action(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 		action: VestingAction,
-	) -> Result<(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>), DispatchError> {
+	) -> Result<(Vec<VestingInfoOf<T>>, BalanceOf<T>), DispatchError> {
 		let (schedules, locked_now) = match action {
 			VestingAction::Merge { index1: idx1, index2: idx2 } => {
 				// The schedule index is based off of the schedule ordering prior to filtering out
 				// any schedules that may be ending at this block.
-				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
-				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule1 =
+					schedules.get(idx1).cloned().ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule2 =
+					schedules.get(idx2).cloned().ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
 
 				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
 				// Thus we know below that we can push the new merged schedule without error
@@ -684,15 +861,15 @@ impl<T: Config> Pallet<T> {
 					Self::report_schedule_updates(schedules.to_vec(), action);
 
 				let now = T::BlockNumberProvider::current_block_number();
-				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
+				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2)? {
 					// Merging created a new schedule so we:
-					// 1) need to add it to the accounts vesting schedule collection,
-					schedules.push(new_schedule);
+					// 1) update the locked amount to reflect the schedule we are about to add,
 					// (we use `locked_at` in case this is a schedule that started in the past)
 					let new_schedule_locked =
 						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
-					// and 2) update the locked amount to reflect the schedule we just added.
 					locked_now = locked_now.saturating_add(new_schedule_locked);
+					// and 2) need to add it to the accounts vesting schedule collection.
+					schedules.push(new_schedule);
 				} // In the None case there was no new schedule to account for.
 
 				(schedules, locked_now)
@@ -747,36 +924,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(())
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		};
-
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		debug_assert!(schedules.len() > 0, "schedules cannot be empty after insertion");
-		let schedule_index = schedules.len() - 1;
-		Self::deposit_event(Event::<T>::VestingCreated {
-			account: who.clone(),
-			schedule_index: schedule_index as u32,
-		});
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::do_add_vesting_schedule(who, VestingInfo::new(locked, per_block, starting_block))
 	}
 
 	/// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -787,17 +935,10 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		// Check for `per_block` or `locked` of 0.
-		if !VestingInfo::new(locked, per_block, starting_block).is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		}
-
-		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
-			Error::<T>::AtMaxVestingSchedules
-		);
-
-		Ok(())
+		Self::ensure_can_add_vesting_schedule(
+			who,
+			&VestingInfo::new(locked, per_block, starting_block),
+		)
 	}
 
 	/// Remove a vesting schedule for a given account.
@@ -19,11 +19,42 @@
 
 use super::*;
 use alloc::vec;
+use frame_support::{
+	pallet_prelude::{OptionQuery, StorageMap},
+	storage_alias,
+	traits::OnRuntimeUpgrade,
+	Blake2_128Concat,
+};
+
+/// The layout of `VestingInfo` prior to `Releases::V2`, which only supported linear,
+/// non-revocable schedules.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LinearVestingInfo<Balance, BlockNumber> {
+	/// Locked amount at genesis.
+	pub locked: Balance,
+	/// Amount that gets unlocked every block after `starting_block`.
+	pub per_block: Balance,
+	/// Starting block for unlocking(vesting).
+	pub starting_block: BlockNumber,
+}
+
+/// The `LinearVestingInfo` of a runtime.
+pub type LinearVestingInfoOf<T> = LinearVestingInfo<BalanceOf<T>, BlockNumberFor<T>>;
 
 // Migration from single schedule to multiple schedules.
 pub mod v1 {
 	use super::*;
 
+	/// The `Vesting` storage with the linear schedules of `Releases::V1`.
+	#[storage_alias]
+	pub type Vesting<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<LinearVestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
+		OptionQuery,
+	>;
+
 	#[cfg(feature = "try-runtime")]
 	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
 		assert!(StorageVersion::<T>::get() == Releases::V0, "Storage version too high.");
@@ -41,26 +72,21 @@ pub mod v1 {
 	pub fn migrate<T: Config>() -> Weight {
 		let mut reads_writes = 0;
 
-		Vesting::<T>::translate::<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
-			|_key, vesting_info| {
-				reads_writes += 1;
-				let v: Option<
-					BoundedVec<
-						VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-						MaxVestingSchedulesGet<T>,
-					>,
-				> = vec![vesting_info].try_into().ok();
-
-				if v.is_none() {
-					log::warn!(
-						target: "runtime::vesting",
-						"migration: Failed to move a vesting schedule into a BoundedVec"
-					);
-				}
+		// Prior to `Releases::V1` every account had a single schedule of the linear layout.
+		Vesting::<T>::translate::<LinearVestingInfoOf<T>, _>(|_key, vesting_info| {
+			reads_writes += 1;
+			let v: Option<BoundedVec<LinearVestingInfoOf<T>, MaxVestingSchedulesGet<T>>> =
+				vec![vesting_info].try_into().ok();
+
+			if v.is_none() {
+				log::warn!(
+					target: "runtime::vesting",
+					"migration: Failed to move a vesting schedule into a BoundedVec"
+				);
+			}
 
-				v
-			},
-		);
+			v
+		});
 
 		T::DbWeight::get().reads_writes(reads_writes, reads_writes)
 	}
@@ -78,6 +104,7 @@ pub mod v1 {
 			for s in schedules {
 				// It is ok if this does not pass, but ideally pre-existing schedules would pass
 				// this validation logic so we can be more confident about edge cases.
+				let s: VestingInfoOf<T> = VestingInfo::new(s.locked, s.per_block, s.starting_block);
 				if !s.is_valid() {
 					log::warn!(
 						target: "runtime::vesting",
@@ -94,3 +121,74 @@ pub mod v1 {
 		Ok(())
 	}
 }
+
+// Migration from linear schedules to schedules with an unlock kind and an optional revoker.
+pub mod v2 {
+	use super::*;
+
+	/// Migrate `Vesting` storage to the `VestingInfo` layout with an unlock kind and an optional
+	/// revoker. Existing schedules become linear and non-revocable.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				log::info!(
+					target: "runtime::vesting",
+					"migration: Vesting storage version v2 migration should be removed",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads_writes = 0;
+			crate::Vesting::<T>::translate::<
+				BoundedVec<LinearVestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
+				_,
+			>(|_key, schedules| {
+				reads_writes += 1;
+				let schedules: Vec<VestingInfoOf<T>> = schedules
+					.into_iter()
+					.map(|s| VestingInfo::new(s.locked, s.per_block, s.starting_block))
+					.collect();
+				// The number of schedules is unchanged, so this cannot fail.
+				schedules.try_into().ok()
+			});
+			StorageVersion::<T>::put(Releases::V2);
+
+			log::info!(
+				target: "runtime::vesting",
+				"migration: Vesting storage version v2 migrated {} accounts",
+				reads_writes,
+			);
+			T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let schedules = v1::Vesting::<T>::iter_values()
+				.map(|schedules| schedules.len() as u32)
+				.sum::<u32>();
+			Ok(schedules.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let expected = u32::decode(&mut &state[..])
+				.map_err(|_| "migration: failed to decode the pre-upgrade state")?;
+			ensure!(StorageVersion::<T>::get() == Releases::V2, "migration: wrong storage version");
+
+			let mut schedules = 0u32;
+			for (_key, account_schedules) in crate::Vesting::<T>::iter() {
+				for s in account_schedules {
+					ensure!(
+						s.kind() == VestingKind::Linear && s.revoker().is_none(),
+						"migration: a migrated schedule is not linear and non-revocable",
+					);
+					schedules += 1;
+				}
+			}
+			ensure!(schedules == expected, "migration: the number of schedules changed");
+			Ok(())
+		}
+	}
+}
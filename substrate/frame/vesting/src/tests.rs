@@ -16,7 +16,10 @@
 // limitations under the License.
 
 use codec::EncodeLike;
-use frame_support::{assert_noop, assert_ok, assert_storage_noop};
+use frame_support::{
+	assert_noop, assert_ok, assert_storage_noop,
+	traits::{LockableCurrency, OnRuntimeUpgrade, WithdrawReasons},
+};
 use frame_system::RawOrigin;
 use sp_runtime::{
 	traits::{BadOrigin, Identity},
@@ -1066,9 +1069,9 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
 	});
 }
 
@@ -1088,7 +1091,7 @@ fn merge_vesting_handles_per_block_0() {
 		assert_eq!(sched1.ending_block_as_balance::<Identity>(), 512u64 + 10);
 
 		let merged = VestingInfo::new(764, 1, 10);
-		assert_eq!(Vesting::merge_vesting_info(5, sched0, sched1), Some(merged));
+		assert_eq!(Vesting::merge_vesting_info(5, sched0, sched1), Ok(Some(merged)));
 	});
 }
 
@@ -1096,38 +1099,38 @@ fn merge_vesting_handles_per_block_0() {
 fn vesting_info_validate_works() {
 	let min_transfer = <Test as Config>::MinVestedTransfer::get();
 	// Does not check for min transfer.
-	assert_eq!(VestingInfo::new(min_transfer - 1, 1u64, 10u64).is_valid(), true);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer - 1, 1u64, 10u64).is_valid(), true);
 
 	// `locked` cannot be 0.
-	assert_eq!(VestingInfo::new(0, 1u64, 10u64).is_valid(), false);
+	assert_eq!(VestingInfo::<_, _, u64>::new(0, 1u64, 10u64).is_valid(), false);
 
 	// `per_block` cannot be 0.
-	assert_eq!(VestingInfo::new(min_transfer + 1, 0u64, 10u64).is_valid(), false);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer + 1, 0u64, 10u64).is_valid(), false);
 
 	// With valid inputs it does not error.
-	assert_eq!(VestingInfo::new(min_transfer, 1u64, 10u64).is_valid(), true);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer, 1u64, 10u64).is_valid(), true);
 }
 
 #[test]
 fn vesting_info_ending_block_as_balance_works() {
 	// Treats `per_block` 0 as 1.
-	let per_block_0 = VestingInfo::new(256u32, 0u32, 10u32);
+	let per_block_0 = VestingInfo::<_, _, u64>::new(256u32, 0u32, 10u32);
 	assert_eq!(per_block_0.ending_block_as_balance::<Identity>(), 256 + 10);
 
 	// `per_block >= locked` always results in a schedule ending the block after it starts
-	let per_block_gt_locked = VestingInfo::new(256u32, 256 * 2u32, 10u32);
+	let per_block_gt_locked = VestingInfo::<_, _, u64>::new(256u32, 256 * 2u32, 10u32);
 	assert_eq!(
 		per_block_gt_locked.ending_block_as_balance::<Identity>(),
 		1 + per_block_gt_locked.starting_block()
 	);
-	let per_block_eq_locked = VestingInfo::new(256u32, 256u32, 10u32);
+	let per_block_eq_locked = VestingInfo::<_, _, u64>::new(256u32, 256u32, 10u32);
 	assert_eq!(
 		per_block_gt_locked.ending_block_as_balance::<Identity>(),
 		per_block_eq_locked.ending_block_as_balance::<Identity>()
 	);
 
 	// Correctly calcs end if `locked % per_block != 0`. (We need a block to unlock the remainder).
-	let imperfect_per_block = VestingInfo::new(256u32, 250u32, 10u32);
+	let imperfect_per_block = VestingInfo::<_, _, u64>::new(256u32, 250u32, 10u32);
 	assert_eq!(
 		imperfect_per_block.ending_block_as_balance::<Identity>(),
 		imperfect_per_block.starting_block() + 2u32,
@@ -1141,11 +1144,11 @@ fn vesting_info_ending_block_as_balance_works() {
 
 #[test]
 fn per_block_works() {
-	let per_block_0 = VestingInfo::new(256u32, 0u32, 10u32);
+	let per_block_0 = VestingInfo::<_, _, u64>::new(256u32, 0u32, 10u32);
 	assert_eq!(per_block_0.per_block(), 1u32);
 	assert_eq!(per_block_0.raw_per_block(), 0u32);
 
-	let per_block_1 = VestingInfo::new(256u32, 1u32, 10u32);
+	let per_block_1 = VestingInfo::<_, _, u64>::new(256u32, 1u32, 10u32);
 	assert_eq!(per_block_1.per_block(), 1u32);
 	assert_eq!(per_block_1.raw_per_block(), 1u32);
 }
@@ -1262,3 +1265,247 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn cliff_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched = VestingInfo::new(ED * 10, ED, 1).with_kind(VestingKind::Cliff { cliff: 5 });
+		assert_eq!(sched.ending_block_as_balance::<Identity>(), 11);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![sched]);
+
+		// Nothing is unlocked before the cliff.
+		System::set_block_number(4);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		// Everything accrued since the start is unlocked at the cliff.
+		System::set_block_number(5);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 6));
+		System::set_block_number(11);
+		assert_eq!(Vesting::vesting_balance(&4), Some(0));
+		vest_and_assert_no_vesting::<Test>(4);
+
+		// The cliff must be after the start of the schedule.
+		let sched = VestingInfo::new(ED * 10, ED, 11).with_kind(VestingKind::Cliff { cliff: 11 });
+		assert_noop!(
+			Vesting::vested_transfer(Some(3).into(), 4, sched),
+			Error::<Test>::InvalidScheduleParams
+		);
+	});
+}
+
+#[test]
+fn stepped_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched = VestingInfo::new(ED * 10, ED, 1).with_kind(VestingKind::Stepped { period: 4 });
+		// 10 blocks of linear vesting are rounded up to 3 periods.
+		assert_eq!(sched.ending_block_as_balance::<Identity>(), 13);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![sched]);
+
+		// Funds are only unlocked at the end of each period.
+		System::set_block_number(4);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		System::set_block_number(5);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 6));
+		System::set_block_number(8);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 6));
+		System::set_block_number(9);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 2));
+		System::set_block_number(12);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 2));
+		System::set_block_number(13);
+		assert_eq!(Vesting::vesting_balance(&4), Some(0));
+		vest_and_assert_no_vesting::<Test>(4);
+
+		// The period cannot be 0.
+		let sched = VestingInfo::new(ED * 10, ED, 13).with_kind(VestingKind::Stepped { period: 0 });
+		assert_noop!(
+			Vesting::vested_transfer(Some(3).into(), 4, sched),
+			Error::<Test>::InvalidScheduleParams
+		);
+	});
+}
+
+#[test]
+fn revoke_vesting_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Only the sender may be the revoker of a vested transfer.
+		let sched = VestingInfo::new(ED * 10, ED, 1).with_revoker(3);
+		assert_noop!(
+			Vesting::vested_transfer(Some(13).into(), 4, sched),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(Balances::free_balance(&3), ED * 20);
+
+		System::set_block_number(4);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 7));
+
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(Some(2).into(), 4, 0),
+			Error::<Test>::NotRevoker
+		);
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(Some(3).into(), 4, 1),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+		// Schedules without a revoker cannot be revoked.
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(Some(3).into(), 1, 0),
+			Error::<Test>::NotRevoker
+		);
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(Some(3).into(), 3, 0),
+			Error::<Test>::NotVesting
+		);
+
+		// The funds not yet vested are returned to the revoker.
+		assert_ok!(Vesting::revoke_vesting_schedule(Some(3).into(), 4, 0));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, revoker: 3, amount: ED * 7 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 27);
+		assert_eq!(Balances::free_balance(&4), ED * 43);
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+		assert_eq!(Vesting::vesting_balance(&4), None);
+		// The vested funds are free to use.
+		assert_ok!(Balances::transfer_allow_death(Some(4).into(), 3, ED * 43));
+
+		// Root may create a schedule with any revoker.
+		let sched = VestingInfo::new(ED * 10, ED, 4).with_revoker(2);
+		assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 13, 4, sched));
+		assert_ok!(Vesting::revoke_vesting_schedule(Some(2).into(), 4, 0));
+		assert_eq!(Balances::free_balance(&2), ED * 30);
+	});
+}
+
+#[test]
+fn revoke_vesting_schedule_with_overlapping_lock() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched = VestingInfo::new(ED * 10, ED, 1).with_revoker(3);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(Balances::free_balance(&4), ED * 50);
+
+		// The beneficiary stakes most of its funds, including the unvested ones.
+		Balances::set_lock(*b"staking ", &4, ED * 45, WithdrawReasons::all());
+
+		System::set_block_number(4);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 7));
+
+		// Only the unvested funds not frozen by the staking lock are returned.
+		assert_ok!(Vesting::revoke_vesting_schedule(Some(3).into(), 4, 0));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, revoker: 3, amount: ED * 5 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 25);
+		assert_eq!(Balances::free_balance(&4), ED * 45);
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+
+		// Nothing can be returned while everything is frozen by the staking lock.
+		let sched = VestingInfo::new(ED * 10, ED, 4).with_revoker(3);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		Balances::set_lock(*b"staking ", &4, ED * 55, WithdrawReasons::all());
+		assert_ok!(Vesting::revoke_vesting_schedule(Some(3).into(), 4, 0));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, revoker: 3, amount: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&4), ED * 55);
+	});
+}
+
+#[test]
+fn merge_schedules_with_kinds_and_revokers() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 has a linear schedule of 20 * ED over 20 blocks from block 10.
+		let sched0 = VestingInfo::new(ED * 20, ED, 10);
+		let sched1 = VestingInfo::new(ED * 10, ED, 10).with_kind(VestingKind::Cliff { cliff: 15 });
+		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched1));
+		let sched2 =
+			VestingInfo::new(ED * 10, ED, 10).with_kind(VestingKind::Stepped { period: 5 });
+		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched2));
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1, sched2]);
+
+		// A cliff and a stepped schedule cannot be merged.
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 1, 2),
+			Error::<Test>::IncompatibleSchedules
+		);
+
+		// The merged schedule keeps the cliff.
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		let merged =
+			VestingInfo::new(ED * 30, ED * 3 / 2, 10).with_kind(VestingKind::Cliff { cliff: 15 });
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2, merged]);
+
+		System::set_block_number(14);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 40));
+		System::set_block_number(15);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 30 - ED * 15 / 2 + ED * 5));
+
+		// Schedules with different revokers cannot be merged.
+		let sched3 = VestingInfo::new(ED * 10, ED, 15).with_revoker(13);
+		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched3));
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 1, 2),
+			Error::<Test>::IncompatibleSchedules
+		);
+	});
+}
+
+#[test]
+fn merge_vesting_info_handles_kinds() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let linear = VestingInfo::new(ED * 10, ED, 10);
+		let stepped =
+			VestingInfo::new(ED * 10, ED, 10).with_kind(VestingKind::Stepped { period: 5 });
+		let cliff = VestingInfo::new(ED * 10, ED, 10).with_kind(VestingKind::Cliff { cliff: 15 });
+
+		// The merged schedule keeps the period.
+		let merged =
+			VestingInfo::new(ED * 20, ED * 2, 10).with_kind(VestingKind::Stepped { period: 5 });
+		assert_eq!(Vesting::merge_vesting_info(1, linear, stepped), Ok(Some(merged)));
+
+		// A cliff which has passed is dropped.
+		let merged = VestingInfo::new(ED * 8, ED * 2, 16);
+		assert_eq!(Vesting::merge_vesting_info(16, linear, cliff), Ok(Some(merged)));
+
+		// The revoker is kept.
+		let merged = VestingInfo::new(ED * 8, ED * 2, 16).with_revoker(3);
+		assert_eq!(
+			Vesting::merge_vesting_info(16, linear.with_revoker(3), cliff.with_revoker(3)),
+			Ok(Some(merged))
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v2_works() {
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.vesting_genesis_config(vec![])
+		.build()
+		.execute_with(|| {
+			type OldSchedules =
+				BoundedVec<migrations::LinearVestingInfo<u64, u64>, MaxVestingSchedulesGet<Test>>;
+			let old1 =
+				migrations::LinearVestingInfo { locked: ED * 5, per_block: 128, starting_block: 0 };
+			let old2 = migrations::LinearVestingInfo {
+				locked: ED * 20,
+				per_block: ED,
+				starting_block: 10,
+			};
+			let schedules: OldSchedules = vec![old1].try_into().unwrap();
+			migrations::v1::Vesting::<Test>::insert(1, schedules);
+			let schedules: OldSchedules = vec![old1, old2].try_into().unwrap();
+			migrations::v1::Vesting::<Test>::insert(2, schedules);
+			StorageVersion::<Test>::put(Releases::V1);
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+			let new1 = VestingInfo::new(ED * 5, 128, 0);
+			let new2 = VestingInfo::new(ED * 20, ED, 10);
+			assert_eq!(VestingStorage::<Test>::get(&1).unwrap(), vec![new1]);
+			assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![new1, new2]);
+		});
+}
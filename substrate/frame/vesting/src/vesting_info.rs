@@ -19,6 +19,35 @@
 
 use super::*;
 
+/// The way in which a vesting schedule unlocks its funds over time.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum VestingKind<BlockNumber> {
+	/// `per_block` gets unlocked every block after `starting_block`.
+	Linear,
+	/// Nothing gets unlocked before `cliff`. From `cliff` onwards the schedule unlocks as if it
+	/// was linear, so everything that accrued since `starting_block` gets unlocked at once.
+	Cliff {
+		/// Block before which nothing gets unlocked.
+		cliff: BlockNumber,
+	},
+	/// `per_block * period` gets unlocked at once every `period` blocks after `starting_block`.
+	Stepped {
+		/// Number of blocks between two unlocks.
+		period: BlockNumber,
+	},
+}
+
 /// Struct to encode the vesting schedule of an individual account.
 #[derive(
 	Encode,
@@ -32,33 +61,54 @@ use super::*;
 	MaxEncodedLen,
 	TypeInfo,
 )]
-pub struct VestingInfo<Balance, BlockNumber> {
+pub struct VestingInfo<Balance, BlockNumber, AccountId> {
 	/// Locked amount at genesis.
 	locked: Balance,
 	/// Amount that gets unlocked every block after `starting_block`.
 	per_block: Balance,
 	/// Starting block for unlocking(vesting).
 	starting_block: BlockNumber,
+	/// How the funds get unlocked over time.
+	kind: VestingKind<BlockNumber>,
+	/// Account which may revoke the schedule, claiming back the funds not yet vested.
+	revoker: Option<AccountId>,
 }
 
-impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
+impl<Balance, BlockNumber, AccountId> VestingInfo<Balance, BlockNumber, AccountId>
 where
 	Balance: AtLeast32BitUnsigned + Copy,
 	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
 {
-	/// Instantiate a new `VestingInfo`.
+	/// Instantiate a new linear, non-revocable `VestingInfo`.
 	pub fn new(
 		locked: Balance,
 		per_block: Balance,
 		starting_block: BlockNumber,
-	) -> VestingInfo<Balance, BlockNumber> {
-		VestingInfo { locked, per_block, starting_block }
+	) -> VestingInfo<Balance, BlockNumber, AccountId> {
+		VestingInfo { locked, per_block, starting_block, kind: VestingKind::Linear, revoker: None }
+	}
+
+	/// Set how the funds of the schedule get unlocked over time.
+	pub fn with_kind(mut self, kind: VestingKind<BlockNumber>) -> Self {
+		self.kind = kind;
+		self
+	}
+
+	/// Set the account which may revoke the schedule.
+	pub fn with_revoker(mut self, revoker: AccountId) -> Self {
+		self.revoker = Some(revoker);
+		self
 	}
 
 	/// Validate parameters for `VestingInfo`. Note that this does not check
 	/// against `MinVestedTransfer`.
 	pub fn is_valid(&self) -> bool {
-		!self.locked.is_zero() && !self.raw_per_block().is_zero()
+		let kind_is_valid = match self.kind {
+			VestingKind::Linear => true,
+			VestingKind::Cliff { cliff } => cliff > self.starting_block,
+			VestingKind::Stepped { period } => !period.is_zero(),
+		};
+		kind_is_valid && !self.locked.is_zero() && !self.raw_per_block().is_zero()
 	}
 
 	/// Locked amount at schedule creation.
@@ -84,6 +134,16 @@ where
 		self.starting_block
 	}
 
+	/// How the funds of the schedule get unlocked over time.
+	pub fn kind(&self) -> VestingKind<BlockNumber> {
+		self.kind
+	}
+
+	/// Account which may revoke the schedule, if any.
+	pub fn revoker(&self) -> Option<&AccountId> {
+		self.revoker.as_ref()
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
 		&self,
@@ -92,6 +152,14 @@ where
 		// Number of blocks that count toward vesting;
 		// saturating to 0 when n < starting_block.
 		let vested_block_count = n.saturating_sub(self.starting_block);
+		let vested_block_count = match self.kind {
+			VestingKind::Linear => vested_block_count,
+			VestingKind::Cliff { cliff } if n < cliff => Zero::zero(),
+			VestingKind::Cliff { .. } => vested_block_count,
+			// Only whole periods count toward vesting.
+			VestingKind::Stepped { period } =>
+				vested_block_count / period.max(One::one()) * period.max(One::one()),
+		};
 		let vested_block_count = BlockNumberToBalance::convert(vested_block_count);
 		// Return amount that is still locked in vesting.
 		vested_block_count
@@ -120,6 +188,19 @@ where
 				}
 		};
 
-		starting_block.saturating_add(duration)
+		match self.kind {
+			VestingKind::Linear => starting_block.saturating_add(duration),
+			// Nothing is unlocked before the cliff, after which the schedule is linear.
+			VestingKind::Cliff { cliff } => starting_block
+				.saturating_add(duration)
+				.max(BlockNumberToBalance::convert(cliff)),
+			// The last unlock happens at the end of the period in which the linear schedule ends.
+			VestingKind::Stepped { period } => {
+				let period = BlockNumberToBalance::convert(period).max(One::one());
+				let periods = duration / period +
+					if (duration % period).is_zero() { Zero::zero() } else { One::one() };
+				starting_block.saturating_add(periods.saturating_mul(period))
+			},
+		}
 	}
 }
@@ -35,7 +35,7 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
@@ -81,13 +81,14 @@ pub trait WeightInfo {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -96,19 +97,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_108_000 picoseconds.
-		Weight::from_parts(28_662_441, 4764)
-			// Standard Error: 954
-			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
-			// Standard Error: 1_698
-			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 30_368_000 picoseconds.
+		Weight::from_parts(29_903_563, 5586)
+			// Standard Error: 895
+			.saturating_add(Weight::from_parts(29_798, 0).saturating_mul(l.into()))
+			// Standard Error: 1_617
+			.saturating_add(Weight::from_parts(68_395, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -117,19 +118,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 30_994_000 picoseconds.
-		Weight::from_parts(30_464_494, 4764)
-			// Standard Error: 1_481
-			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
-			// Standard Error: 2_636
-			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 32_497_000 picoseconds.
+		Weight::from_parts(31_941_997, 5586)
+			// Standard Error: 1_504
+			.saturating_add(Weight::from_parts(27_402, 0).saturating_mul(l.into()))
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(74_667, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -140,19 +141,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 31_696_000 picoseconds.
-		Weight::from_parts(31_191_832, 4764)
-			// Standard Error: 1_199
-			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
-			// Standard Error: 2_133
-			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 32_980_000 picoseconds.
+		Weight::from_parts(32_455_841, 5586)
+			// Standard Error: 1_263
+			.saturating_add(Weight::from_parts(29_513, 0).saturating_mul(l.into()))
+			// Standard Error: 2_154
+			.saturating_add(Weight::from_parts(75_482, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -163,19 +164,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 33_388_000 picoseconds.
-		Weight::from_parts(32_877_344, 4764)
-			// Standard Error: 1_245
-			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
-			// Standard Error: 2_216
-			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 34_183_000 picoseconds.
+		Weight::from_parts(33_660_092, 5586)
+			// Standard Error: 1_226
+			.saturating_add(Weight::from_parts(34_958, 0).saturating_mul(l.into()))
+			// Standard Error: 2_019
+			.saturating_add(Weight::from_parts(64_331, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -186,19 +187,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 67_470_000 picoseconds.
-		Weight::from_parts(68_202_160, 4764)
-			// Standard Error: 2_799
-			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
-			// Standard Error: 4_979
-			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 71_992_000 picoseconds.
+		Weight::from_parts(72_773_236, 5586)
+			// Standard Error: 2_567
+			.saturating_add(Weight::from_parts(48_375, 0).saturating_mul(l.into()))
+			// Standard Error: 5_289
+			.saturating_add(Weight::from_parts(111_741, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -209,19 +210,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
+		//  Measured:  `328 + l * (25 ±0) + s * (38 ±0)`
 		//  Estimated: `6196`
-		// Minimum execution time: 69_510_000 picoseconds.
-		Weight::from_parts(69_862_164, 6196)
-			// Standard Error: 2_523
-			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
-			// Standard Error: 4_489
-			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
+		// Minimum execution time: 73_863_000 picoseconds.
+		Weight::from_parts(74_236_856, 6196)
+			// Standard Error: 2_667
+			.saturating_add(Weight::from_parts(50_309, 0).saturating_mul(l.into()))
+			// Standard Error: 4_195
+			.saturating_add(Weight::from_parts(134_969, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -230,19 +231,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_865_000 picoseconds.
-		Weight::from_parts(29_471_812, 4764)
-			// Standard Error: 1_177
-			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
-			// Standard Error: 2_174
-			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 31_326_000 picoseconds.
+		Weight::from_parts(30_913_886, 5586)
+			// Standard Error: 1_174
+			.saturating_add(Weight::from_parts(30_598, 0).saturating_mul(l.into()))
+			// Standard Error: 2_286
+			.saturating_add(Weight::from_parts(72_693, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -251,19 +252,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 32_137_000 picoseconds.
-		Weight::from_parts(31_237_719, 4764)
-			// Standard Error: 1_135
-			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
-			// Standard Error: 2_097
-			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 34_059_000 picoseconds.
+		Weight::from_parts(33_106_290, 5586)
+			// Standard Error: 1_220
+			.saturating_add(Weight::from_parts(34_288, 0).saturating_mul(l.into()))
+			// Standard Error: 2_009
+			.saturating_add(Weight::from_parts(90_794, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -274,23 +275,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 35_493_000 picoseconds.
-		Weight::from_parts(34_502_101, 4764)
-			// Standard Error: 1_833
-			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
-			// Standard Error: 3_385
-			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 37_016_000 picoseconds.
+		Weight::from_parts(35_982_432, 5586)
+			// Standard Error: 1_796
+			.saturating_add(Weight::from_parts(39_415, 0).saturating_mul(l.into()))
+			// Standard Error: 3_161
+			.saturating_add(Weight::from_parts(89_585, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `159 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_818_000 picoseconds.
+		Weight::from_parts(54_045_049, 6196)
+			// Standard Error: 1_764
+			.saturating_add(Weight::from_parts(39_895, 0).saturating_mul(l.into()))
+			// Standard Error: 3_693
+			.saturating_add(Weight::from_parts(95_785, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -299,19 +323,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_108_000 picoseconds.
-		Weight::from_parts(28_662_441, 4764)
-			// Standard Error: 954
-			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
-			// Standard Error: 1_698
-			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 30_368_000 picoseconds.
+		Weight::from_parts(29_903_563, 5586)
+			// Standard Error: 895
+			.saturating_add(Weight::from_parts(29_798, 0).saturating_mul(l.into()))
+			// Standard Error: 1_617
+			.saturating_add(Weight::from_parts(68_395, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -320,19 +344,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 30_994_000 picoseconds.
-		Weight::from_parts(30_464_494, 4764)
-			// Standard Error: 1_481
-			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
-			// Standard Error: 2_636
-			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 32_497_000 picoseconds.
+		Weight::from_parts(31_941_997, 5586)
+			// Standard Error: 1_504
+			.saturating_add(Weight::from_parts(27_402, 0).saturating_mul(l.into()))
+			// Standard Error: 2_413
+			.saturating_add(Weight::from_parts(74_667, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -343,19 +367,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 31_696_000 picoseconds.
-		Weight::from_parts(31_191_832, 4764)
-			// Standard Error: 1_199
-			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
-			// Standard Error: 2_133
-			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 32_980_000 picoseconds.
+		Weight::from_parts(32_455_841, 5586)
+			// Standard Error: 1_263
+			.saturating_add(Weight::from_parts(29_513, 0).saturating_mul(l.into()))
+			// Standard Error: 2_154
+			.saturating_add(Weight::from_parts(75_482, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -366,19 +390,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[1, 28]`.
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 33_388_000 picoseconds.
-		Weight::from_parts(32_877_344, 4764)
-			// Standard Error: 1_245
-			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
-			// Standard Error: 2_216
-			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 34_183_000 picoseconds.
+		Weight::from_parts(33_660_092, 5586)
+			// Standard Error: 1_226
+			.saturating_add(Weight::from_parts(34_958, 0).saturating_mul(l.into()))
+			// Standard Error: 2_019
+			.saturating_add(Weight::from_parts(64_331, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -389,19 +413,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[0, 27]`.
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 67_470_000 picoseconds.
-		Weight::from_parts(68_202_160, 4764)
-			// Standard Error: 2_799
-			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
-			// Standard Error: 4_979
-			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 71_992_000 picoseconds.
+		Weight::from_parts(72_773_236, 5586)
+			// Standard Error: 2_567
+			.saturating_add(Weight::from_parts(48_375, 0).saturating_mul(l.into()))
+			// Standard Error: 5_289
+			.saturating_add(Weight::from_parts(111_741, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -412,19 +436,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[0, 27]`.
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
+		//  Measured:  `328 + l * (25 ±0) + s * (38 ±0)`
 		//  Estimated: `6196`
-		// Minimum execution time: 69_510_000 picoseconds.
-		Weight::from_parts(69_862_164, 6196)
-			// Standard Error: 2_523
-			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
-			// Standard Error: 4_489
-			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
+		// Minimum execution time: 73_863_000 picoseconds.
+		Weight::from_parts(74_236_856, 6196)
+			// Standard Error: 2_667
+			.saturating_add(Weight::from_parts(50_309, 0).saturating_mul(l.into()))
+			// Standard Error: 4_195
+			.saturating_add(Weight::from_parts(134_969, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -433,19 +457,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 28]`.
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_865_000 picoseconds.
-		Weight::from_parts(29_471_812, 4764)
-			// Standard Error: 1_177
-			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
-			// Standard Error: 2_174
-			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 31_326_000 picoseconds.
+		Weight::from_parts(30_913_886, 5586)
+			// Standard Error: 1_174
+			.saturating_add(Weight::from_parts(30_598, 0).saturating_mul(l.into()))
+			// Standard Error: 2_286
+			.saturating_add(Weight::from_parts(72_693, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -454,19 +478,19 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 28]`.
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 32_137_000 picoseconds.
-		Weight::from_parts(31_237_719, 4764)
-			// Standard Error: 1_135
-			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
-			// Standard Error: 2_097
-			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
+		//  Measured:  `123 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 34_059_000 picoseconds.
+		Weight::from_parts(33_106_290, 5586)
+			// Standard Error: 1_220
+			.saturating_add(Weight::from_parts(34_288, 0).saturating_mul(l.into()))
+			// Standard Error: 2_009
+			.saturating_add(Weight::from_parts(90_794, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -477,15 +501,38 @@ impl WeightInfo for () {
 	/// The range of component `s` is `[2, 28]`.
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 35_493_000 picoseconds.
-		Weight::from_parts(34_502_101, 4764)
-			// Standard Error: 1_833
-			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
-			// Standard Error: 3_385
-			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
+		//  Measured:  `225 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `5586`
+		// Minimum execution time: 37_016_000 picoseconds.
+		Weight::from_parts(35_982_432, 5586)
+			// Standard Error: 1_796
+			.saturating_add(Weight::from_parts(39_415, 0).saturating_mul(l.into()))
+			// Standard Error: 3_161
+			.saturating_add(Weight::from_parts(89_585, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(2121), added: 4596, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `159 + l * (25 ±0) + s * (38 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 54_818_000 picoseconds.
+		Weight::from_parts(54_045_049, 6196)
+			// Standard Error: 1_764
+			.saturating_add(Weight::from_parts(39_895, 0).saturating_mul(l.into()))
+			// Standard Error: 3_693
+			.saturating_add(Weight::from_parts(95_785, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -0,0 +1,269 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recording of the storage operations done while executing the runtime.
+//!
+//! Registering an [`ExecutionTraceExt`] in the extensions passed to the [`Ext`](crate::Ext)
+//! makes it record every storage operation, in order, into an [`ExecutionTrace`]. Two traces of
+//! the same block, e.g. executed by different runtime versions, can be compared with
+//! [`ExecutionTrace::first_divergence`].
+//!
+//! The recorder doesn't know which extrinsic the runtime is applying, so whoever drives the
+//! execution must tell it with [`ExecutionTraceRecorder::set_extrinsic`].
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use sp_core::Bytes;
+
+/// A storage operation done through the externalities.
+///
+/// The variants are named after the `method` of the `state` traces emitted by the
+/// [`Ext`](crate::Ext), which correspond to the host functions called by the runtime.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase", tag = "method"))]
+pub enum TraceOp {
+	/// Read the value of `key`.
+	Get {
+		/// The key.
+		key: Bytes,
+		/// The value.
+		value: Option<Bytes>,
+	},
+	/// Read the hash of the value of `key`.
+	Hash {
+		/// The key.
+		key: Bytes,
+		/// The hash of the value.
+		hash: Option<Bytes>,
+	},
+	/// Check whether `key` exists.
+	Exists {
+		/// The key.
+		key: Bytes,
+		/// Whether the key exists.
+		exists: bool,
+	},
+	/// Get the key following `key`.
+	NextKey {
+		/// The key.
+		key: Bytes,
+		/// The next key.
+		next: Option<Bytes>,
+	},
+	/// Set or clear (`value` is `None`) the value of `key`.
+	Put {
+		/// The key.
+		key: Bytes,
+		/// The value.
+		value: Option<Bytes>,
+	},
+	/// Append `value` to the value of `key`.
+	Append {
+		/// The key.
+		key: Bytes,
+		/// The value.
+		value: Bytes,
+	},
+	/// Remove up to `limit` keys starting with `prefix`.
+	ClearPrefix {
+		/// The prefix of the removed keys.
+		prefix: Bytes,
+		/// The maximum number of keys to remove.
+		limit: Option<u32>,
+		/// The number of keys removed from the backend.
+		removed: u32,
+	},
+	/// Compute the storage root.
+	StorageRoot {
+		/// The resulting root.
+		root: Bytes,
+	},
+	/// Read the value of `key` in the child trie `child`.
+	ChildGet {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The key.
+		key: Bytes,
+		/// The value.
+		value: Option<Bytes>,
+	},
+	/// Read the hash of the value of `key` in the child trie `child`.
+	ChildHash {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The key.
+		key: Bytes,
+		/// The hash of the value.
+		hash: Option<Bytes>,
+	},
+	/// Check whether `key` exists in the child trie `child`.
+	ChildExists {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The key.
+		key: Bytes,
+		/// Whether the key exists.
+		exists: bool,
+	},
+	/// Get the key following `key` in the child trie `child`.
+	ChildNextKey {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The key.
+		key: Bytes,
+		/// The next key.
+		next: Option<Bytes>,
+	},
+	/// Set or clear (`value` is `None`) the value of `key` in the child trie `child`.
+	ChildPut {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The key.
+		key: Bytes,
+		/// The value.
+		value: Option<Bytes>,
+	},
+	/// Remove up to `limit` keys of the child trie `child`.
+	ChildKill {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The maximum number of keys to remove.
+		limit: Option<u32>,
+		/// The number of keys removed from the backend.
+		removed: u32,
+	},
+	/// Remove up to `limit` keys starting with `prefix` from the child trie `child`.
+	ChildClearPrefix {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The prefix of the removed keys.
+		prefix: Bytes,
+		/// The maximum number of keys to remove.
+		limit: Option<u32>,
+		/// The number of keys removed from the backend.
+		removed: u32,
+	},
+	/// Compute the root of the child trie `child`.
+	ChildStorageRoot {
+		/// The storage key of the child trie.
+		child: Bytes,
+		/// The resulting root.
+		root: Bytes,
+	},
+	/// Start a storage transaction.
+	StartTransaction,
+	/// Roll back the last storage transaction.
+	RollbackTransaction,
+	/// Commit the last storage transaction.
+	CommitTransaction,
+}
+
+/// One entry of an [`ExecutionTrace`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TraceEntry {
+	/// The extrinsic being applied when the operation was done.
+	///
+	/// `None` outside of the extrinsics, i.e. while initializing or finalizing the block.
+	pub extrinsic: Option<u32>,
+	/// The operation.
+	#[cfg_attr(feature = "std", serde(flatten))]
+	pub op: TraceOp,
+}
+
+/// The ordered list of storage operations done while executing the runtime.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ExecutionTrace {
+	/// The recorded operations.
+	pub entries: Vec<TraceEntry>,
+}
+
+impl ExecutionTrace {
+	/// Returns the entries recorded while executing the given `extrinsic`.
+	pub fn extrinsic(&self, extrinsic: u32) -> impl Iterator<Item = &TraceEntry> {
+		self.entries.iter().filter(move |e| e.extrinsic == Some(extrinsic))
+	}
+
+	/// Returns the index of the first entry that differs between `self` and `other`.
+	///
+	/// Returns `None` if both traces are equal.
+	pub fn first_divergence(&self, other: &Self) -> Option<usize> {
+		self.entries.iter().zip(&other.entries).position(|(a, b)| a != b).or_else(|| {
+			(self.entries.len() != other.entries.len())
+				.then(|| self.entries.len().min(other.entries.len()))
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+pub use recorder::{ExecutionTraceExt, ExecutionTraceRecorder};
+
+#[cfg(feature = "std")]
+mod recorder {
+	use super::*;
+	use parking_lot::Mutex;
+	use std::sync::Arc;
+
+	#[derive(Default)]
+	struct Inner {
+		trace: ExecutionTrace,
+		extrinsic: Option<u32>,
+	}
+
+	/// Collects the [`TraceEntry`]s of an execution.
+	///
+	/// Cloning the recorder is cheap and all the clones record into the same trace.
+	#[derive(Clone, Default)]
+	pub struct ExecutionTraceRecorder(Arc<Mutex<Inner>>);
+
+	impl ExecutionTraceRecorder {
+		/// Take the trace recorded so far, leaving an empty trace behind.
+		pub fn take(&self) -> ExecutionTrace {
+			core::mem::take(&mut self.0.lock().trace)
+		}
+
+		/// Attribute the operations recorded from now on to `extrinsic`, or to none if `None`.
+		///
+		/// Operations are attributed to no extrinsic until this is first called.
+		pub fn set_extrinsic(&self, extrinsic: Option<u32>) {
+			self.0.lock().extrinsic = extrinsic;
+		}
+
+		/// Record the given operation.
+		pub fn record(&self, op: TraceOp) {
+			let mut inner = self.0.lock();
+			let extrinsic = inner.extrinsic;
+			inner.trace.entries.push(TraceEntry { extrinsic, op });
+		}
+	}
+
+	sp_externalities::decl_extension! {
+		/// Extension enabling the recording of an [`ExecutionTrace`].
+		pub struct ExecutionTraceExt(ExecutionTraceRecorder);
+	}
+
+	impl ExecutionTraceExt {
+		/// Create a new instance recording into `recorder`.
+		pub fn new(recorder: ExecutionTraceRecorder) -> Self {
+			Self(recorder)
+		}
+	}
+}
@@ -17,11 +17,12 @@
 
 //! Concrete externalities implementation.
 
-#[cfg(feature = "std")]
-use crate::overlayed_changes::OverlayedExtensions;
 use crate::{
-	backend::Backend, IndexOperation, IterArgs, OverlayedChanges, StorageKey, StorageValue,
+	backend::Backend, execution_trace::TraceOp, IndexOperation, IterArgs, OverlayedChanges,
+	StorageKey, StorageValue,
 };
+#[cfg(feature = "std")]
+use crate::{execution_trace::ExecutionTraceRecorder, overlayed_changes::OverlayedExtensions};
 use codec::{Compact, CompactLen, Decode, Encode};
 use hash_db::Hasher;
 #[cfg(feature = "std")]
@@ -103,6 +104,10 @@ where
 	/// Extensions registered with this instance.
 	#[cfg(feature = "std")]
 	extensions: Option<OverlayedExtensions<'a>>,
+	/// Records the storage operations, if an
+	/// [`ExecutionTraceExt`](crate::ExecutionTraceExt) is registered.
+	#[cfg(feature = "std")]
+	execution_trace: Option<ExecutionTraceRecorder>,
 }
 
 impl<'a, H, B> Ext<'a, H, B>
@@ -121,15 +126,39 @@ where
 	pub fn new(
 		overlay: &'a mut OverlayedChanges<H>,
 		backend: &'a B,
-		extensions: Option<&'a mut sp_externalities::Extensions>,
+		mut extensions: Option<&'a mut sp_externalities::Extensions>,
 	) -> Self {
+		let execution_trace = extensions
+			.as_deref_mut()
+			.and_then(|e| e.get_mut(TypeId::of::<crate::ExecutionTraceExt>()))
+			.and_then(|e| e.downcast_ref::<crate::ExecutionTraceExt>())
+			.map(|e| e.0.clone());
+
 		Self {
 			overlay,
 			backend,
 			id: rand::random(),
 			extensions: extensions.map(OverlayedExtensions::new),
+			execution_trace,
 		}
 	}
+
+	/// Record the operation built by `op` if the execution is traced.
+	#[cfg(feature = "std")]
+	fn record(&self, op: impl FnOnce() -> TraceOp) {
+		if let Some(ref recorder) = self.execution_trace {
+			recorder.record(op())
+		}
+	}
+}
+
+#[cfg(not(feature = "std"))]
+impl<'a, H, B> Ext<'a, H, B>
+where
+	H: Hasher,
+	B: Backend<H>,
+{
+	fn record(&self, _: impl FnOnce() -> TraceOp) {}
 }
 
 #[cfg(test)]
@@ -189,6 +218,11 @@ where
 			),
 		);
 
+		self.record(|| TraceOp::Get {
+			key: key.to_vec().into(),
+			value: result.clone().map(Into::into),
+		});
+
 		result
 	}
 
@@ -207,7 +241,14 @@ where
 			key = %HexDisplay::from(&key),
 			?result,
 		);
-		result.map(|r| r.encode())
+
+		let result = result.map(|r| r.encode());
+		self.record(|| TraceOp::Hash {
+			key: key.to_vec().into(),
+			hash: result.clone().map(Into::into),
+		});
+
+		result
 	}
 
 	fn child_storage(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageValue> {
@@ -229,6 +270,12 @@ where
 			result = ?result.as_ref().map(HexDisplay::from)
 		);
 
+		self.record(|| TraceOp::ChildGet {
+			child: child_info.storage_key().to_vec().into(),
+			key: key.to_vec().into(),
+			value: result.clone().map(Into::into),
+		});
+
 		result
 	}
 
@@ -251,7 +298,14 @@ where
 			?result,
 		);
 
-		result.map(|r| r.encode())
+		let result = result.map(|r| r.encode());
+		self.record(|| TraceOp::ChildHash {
+			child: child_info.storage_key().to_vec().into(),
+			key: key.to_vec().into(),
+			hash: result.clone().map(Into::into),
+		});
+
+		result
 	}
 
 	fn exists_storage(&mut self, key: &[u8]) -> bool {
@@ -269,6 +323,8 @@ where
 			%result,
 		);
 
+		self.record(|| TraceOp::Exists { key: key.to_vec().into(), exists: result });
+
 		result
 	}
 
@@ -291,86 +347,37 @@ where
 			key = %HexDisplay::from(&key),
 			%result,
 		);
+
+		self.record(|| TraceOp::ChildExists {
+			child: child_info.storage_key().to_vec().into(),
+			key: key.to_vec().into(),
+			exists: result,
+		});
+
 		result
 	}
 
 	fn next_storage_key(&mut self, key: &[u8]) -> Option<StorageKey> {
-		let mut next_backend_key =
-			self.backend.next_storage_key(key).expect(EXT_NOT_ALLOWED_TO_FAIL);
-		let mut overlay_changes = self.overlay.iter_after(key).peekable();
-
-		match (&next_backend_key, overlay_changes.peek()) {
-			(_, None) => next_backend_key,
-			(Some(_), Some(_)) => {
-				for overlay_key in overlay_changes {
-					let cmp = next_backend_key.as_deref().map(|v| v.cmp(overlay_key.0));
+		let next = self.next_key(key);
 
-					// If `backend_key` is less than the `overlay_key`, we found out next key.
-					if cmp == Some(Ordering::Less) {
-						return next_backend_key
-					} else if overlay_key.1.value().is_some() {
-						// If there exists a value for the `overlay_key` in the overlay
-						// (aka the key is still valid), it means we have found our next key.
-						return Some(overlay_key.0.to_vec())
-					} else if cmp == Some(Ordering::Equal) {
-						// If the `backend_key` and `overlay_key` are equal, it means that we need
-						// to search for the next backend key, because the overlay has overwritten
-						// this key.
-						next_backend_key = self
-							.backend
-							.next_storage_key(overlay_key.0)
-							.expect(EXT_NOT_ALLOWED_TO_FAIL);
-					}
-				}
+		self.record(|| TraceOp::NextKey {
+			key: key.to_vec().into(),
+			next: next.clone().map(Into::into),
+		});
 
-				next_backend_key
-			},
-			(None, Some(_)) => {
-				// Find the next overlay key that has a value attached.
-				overlay_changes.find_map(|k| k.1.value().as_ref().map(|_| k.0.to_vec()))
-			},
-		}
+		next
 	}
 
 	fn next_child_storage_key(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageKey> {
-		let mut next_backend_key = self
-			.backend
-			.next_child_storage_key(child_info, key)
-			.expect(EXT_NOT_ALLOWED_TO_FAIL);
-		let mut overlay_changes =
-			self.overlay.child_iter_after(child_info.storage_key(), key).peekable();
+		let next = self.next_child_key(child_info, key);
 
-		match (&next_backend_key, overlay_changes.peek()) {
-			(_, None) => next_backend_key,
-			(Some(_), Some(_)) => {
-				for overlay_key in overlay_changes {
-					let cmp = next_backend_key.as_deref().map(|v| v.cmp(overlay_key.0));
-
-					// If `backend_key` is less than the `overlay_key`, we found out next key.
-					if cmp == Some(Ordering::Less) {
-						return next_backend_key
-					} else if overlay_key.1.value().is_some() {
-						// If there exists a value for the `overlay_key` in the overlay
-						// (aka the key is still valid), it means we have found our next key.
-						return Some(overlay_key.0.to_vec())
-					} else if cmp == Some(Ordering::Equal) {
-						// If the `backend_key` and `overlay_key` are equal, it means that we need
-						// to search for the next backend key, because the overlay has overwritten
-						// this key.
-						next_backend_key = self
-							.backend
-							.next_child_storage_key(child_info, overlay_key.0)
-							.expect(EXT_NOT_ALLOWED_TO_FAIL);
-					}
-				}
+		self.record(|| TraceOp::ChildNextKey {
+			child: child_info.storage_key().to_vec().into(),
+			key: key.to_vec().into(),
+			next: next.clone().map(Into::into),
+		});
 
-				next_backend_key
-			},
-			(None, Some(_)) => {
-				// Find the next overlay key that has a value attached.
-				overlay_changes.find_map(|k| k.1.value().as_ref().map(|_| k.0.to_vec()))
-			},
-		}
+		next
 	}
 
 	fn place_storage(&mut self, key: StorageKey, value: Option<StorageValue>) {
@@ -395,6 +402,11 @@ where
 			),
 		);
 
+		self.record(|| TraceOp::Put {
+			key: key.clone().into(),
+			value: value.clone().map(Into::into),
+		});
+
 		self.overlay.set_storage(key, value);
 	}
 
@@ -414,6 +426,12 @@ where
 		);
 		let _guard = guard();
 
+		self.record(|| TraceOp::ChildPut {
+			child: child_info.storage_key().to_vec().into(),
+			key: key.clone().into(),
+			value: value.clone().map(Into::into),
+		});
+
 		self.overlay.set_child_storage(child_info, key, value);
 	}
 
@@ -433,6 +451,11 @@ where
 		let overlay = self.overlay.clear_child_storage(child_info);
 		let (maybe_cursor, backend, loops) =
 			self.limit_remove_from_backend(Some(child_info), None, maybe_limit, maybe_cursor);
+		self.record(|| TraceOp::ChildKill {
+			child: child_info.storage_key().to_vec().into(),
+			limit: maybe_limit,
+			removed: backend,
+		});
 		MultiRemovalResults { maybe_cursor, backend, unique: overlay + backend, loops }
 	}
 
@@ -461,6 +484,11 @@ where
 		let overlay = self.overlay.clear_prefix(prefix);
 		let (maybe_cursor, backend, loops) =
 			self.limit_remove_from_backend(None, Some(prefix), maybe_limit, maybe_cursor);
+		self.record(|| TraceOp::ClearPrefix {
+			prefix: prefix.to_vec().into(),
+			limit: maybe_limit,
+			removed: backend,
+		});
 		MultiRemovalResults { maybe_cursor, backend, unique: overlay + backend, loops }
 	}
 
@@ -487,6 +515,12 @@ where
 			maybe_limit,
 			maybe_cursor,
 		);
+		self.record(|| TraceOp::ChildClearPrefix {
+			child: child_info.storage_key().to_vec().into(),
+			prefix: prefix.to_vec().into(),
+			limit: maybe_limit,
+			removed: backend,
+		});
 		MultiRemovalResults { maybe_cursor, backend, unique: overlay + backend, loops }
 	}
 
@@ -501,6 +535,8 @@ where
 
 		let _guard = guard();
 
+		self.record(|| TraceOp::Append { key: key.clone().into(), value: value.clone().into() });
+
 		let backend = &mut self.backend;
 		self.overlay.append_storage(key.clone(), value, || {
 			backend.storage(&key).expect(EXT_NOT_ALLOWED_TO_FAIL).unwrap_or_default()
@@ -520,7 +556,10 @@ where
 			cached = %_cached,
 		);
 
-		root.encode()
+		let root = root.encode();
+		self.record(|| TraceOp::StorageRoot { root: root.clone().into() });
+
+		root
 	}
 
 	fn child_storage_root(
@@ -544,7 +583,13 @@ where
 			cached = %_cached,
 		);
 
-		root.encode()
+		let root = root.encode();
+		self.record(|| TraceOp::ChildStorageRoot {
+			child: child_info.storage_key().to_vec().into(),
+			root: root.clone().into(),
+		});
+
+		root
 	}
 
 	fn storage_index_transaction(&mut self, index: u32, hash: &[u8], size: u32) {
@@ -579,14 +624,17 @@ where
 	}
 
 	fn storage_start_transaction(&mut self) {
+		self.record(|| TraceOp::StartTransaction);
 		self.overlay.start_transaction()
 	}
 
 	fn storage_rollback_transaction(&mut self) -> Result<(), ()> {
+		self.record(|| TraceOp::RollbackTransaction);
 		self.overlay.rollback_transaction().map_err(|_| ())
 	}
 
 	fn storage_commit_transaction(&mut self) -> Result<(), ()> {
+		self.record(|| TraceOp::CommitTransaction);
 		self.overlay.commit_transaction().map_err(|_| ())
 	}
 
@@ -657,6 +705,87 @@ where
 	H::Out: Ord + 'static + codec::Codec,
 	B: Backend<H>,
 {
+	/// The key following `key`, taking the overlay into account.
+	fn next_key(&mut self, key: &[u8]) -> Option<StorageKey> {
+		let mut next_backend_key =
+			self.backend.next_storage_key(key).expect(EXT_NOT_ALLOWED_TO_FAIL);
+		let mut overlay_changes = self.overlay.iter_after(key).peekable();
+
+		match (&next_backend_key, overlay_changes.peek()) {
+			(_, None) => next_backend_key,
+			(Some(_), Some(_)) => {
+				for overlay_key in overlay_changes {
+					let cmp = next_backend_key.as_deref().map(|v| v.cmp(overlay_key.0));
+
+					// If `backend_key` is less than the `overlay_key`, we found out next key.
+					if cmp == Some(Ordering::Less) {
+						return next_backend_key
+					} else if overlay_key.1.value().is_some() {
+						// If there exists a value for the `overlay_key` in the overlay
+						// (aka the key is still valid), it means we have found our next key.
+						return Some(overlay_key.0.to_vec())
+					} else if cmp == Some(Ordering::Equal) {
+						// If the `backend_key` and `overlay_key` are equal, it means that we need
+						// to search for the next backend key, because the overlay has overwritten
+						// this key.
+						next_backend_key = self
+							.backend
+							.next_storage_key(overlay_key.0)
+							.expect(EXT_NOT_ALLOWED_TO_FAIL);
+					}
+				}
+
+				next_backend_key
+			},
+			(None, Some(_)) => {
+				// Find the next overlay key that has a value attached.
+				overlay_changes.find_map(|k| k.1.value().as_ref().map(|_| k.0.to_vec()))
+			},
+		}
+	}
+
+	/// The key following `key` in the given child trie, taking the overlay into account.
+	fn next_child_key(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageKey> {
+		let mut next_backend_key = self
+			.backend
+			.next_child_storage_key(child_info, key)
+			.expect(EXT_NOT_ALLOWED_TO_FAIL);
+		let mut overlay_changes =
+			self.overlay.child_iter_after(child_info.storage_key(), key).peekable();
+
+		match (&next_backend_key, overlay_changes.peek()) {
+			(_, None) => next_backend_key,
+			(Some(_), Some(_)) => {
+				for overlay_key in overlay_changes {
+					let cmp = next_backend_key.as_deref().map(|v| v.cmp(overlay_key.0));
+
+					// If `backend_key` is less than the `overlay_key`, we found out next key.
+					if cmp == Some(Ordering::Less) {
+						return next_backend_key
+					} else if overlay_key.1.value().is_some() {
+						// If there exists a value for the `overlay_key` in the overlay
+						// (aka the key is still valid), it means we have found our next key.
+						return Some(overlay_key.0.to_vec())
+					} else if cmp == Some(Ordering::Equal) {
+						// If the `backend_key` and `overlay_key` are equal, it means that we need
+						// to search for the next backend key, because the overlay has overwritten
+						// this key.
+						next_backend_key = self
+							.backend
+							.next_child_storage_key(child_info, overlay_key.0)
+							.expect(EXT_NOT_ALLOWED_TO_FAIL);
+					}
+				}
+
+				next_backend_key
+			},
+			(None, Some(_)) => {
+				// Find the next overlay key that has a value attached.
+				overlay_changes.find_map(|k| k.1.value().as_ref().map(|_| k.0.to_vec()))
+			},
+		}
+	}
+
 	fn limit_remove_from_backend(
 		&mut self,
 		child_info: Option<&ChildInfo>,
@@ -1078,4 +1207,80 @@ mod tests {
 
 		assert_eq!(Vec::<u32>::decode(&mut &data[..]).unwrap(), vec![1, 2]);
 	}
+
+	#[test]
+	fn execution_trace_is_recorded() {
+		use crate::{ExecutionTraceExt, ExecutionTraceRecorder, TraceEntry};
+		use sp_core::storage::well_known_keys::EXTRINSIC_INDEX;
+
+		let mut overlay = OverlayedChanges::default();
+		let backend = (
+			Storage { top: map![vec![10] => vec![10]], children_default: map![] },
+			StateVersion::default(),
+		)
+			.into();
+		let recorder = ExecutionTraceRecorder::default();
+		let mut extensions = sp_externalities::Extensions::new();
+		extensions.register(ExecutionTraceExt::new(recorder.clone()));
+
+		let mut ext = TestExt::new(&mut overlay, &backend, Some(&mut extensions));
+		assert_eq!(ext.storage(&[10]), Some(vec![10]));
+		// Written while initializing the block, so before the first extrinsic is applied.
+		ext.set_storage(EXTRINSIC_INDEX.to_vec(), 0u32.encode());
+		recorder.set_extrinsic(Some(0));
+		ext.storage_start_transaction();
+		ext.set_storage(vec![20], vec![20]);
+		ext.storage_commit_transaction().unwrap();
+		ext.set_storage(EXTRINSIC_INDEX.to_vec(), 1u32.encode());
+		recorder.set_extrinsic(None);
+		ext.clear_storage(EXTRINSIC_INDEX);
+		assert!(!ext.exists_storage(&[20, 0]));
+
+		let entry = |extrinsic, op| TraceEntry { extrinsic, op };
+		let trace = recorder.take();
+		assert_eq!(
+			trace.entries,
+			vec![
+				entry(None, TraceOp::Get { key: vec![10].into(), value: Some(vec![10].into()) }),
+				entry(
+					None,
+					TraceOp::Put {
+						key: EXTRINSIC_INDEX.to_vec().into(),
+						value: Some(0u32.encode().into()),
+					}
+				),
+				entry(Some(0), TraceOp::StartTransaction),
+				entry(Some(0), TraceOp::Put { key: vec![20].into(), value: Some(vec![20].into()) }),
+				entry(Some(0), TraceOp::CommitTransaction),
+				entry(
+					Some(0),
+					TraceOp::Put {
+						key: EXTRINSIC_INDEX.to_vec().into(),
+						value: Some(1u32.encode().into()),
+					}
+				),
+				entry(None, TraceOp::Put { key: EXTRINSIC_INDEX.to_vec().into(), value: None }),
+				entry(None, TraceOp::Exists { key: vec![20, 0].into(), exists: false }),
+			]
+		);
+		assert_eq!(trace.extrinsic(0).count(), 4);
+		assert!(recorder.take().entries.is_empty());
+
+		let mut other = trace.clone();
+		assert_eq!(trace.first_divergence(&other), None);
+		other.entries[3].op = TraceOp::Put { key: vec![20].into(), value: Some(vec![21].into()) };
+		assert_eq!(trace.first_divergence(&other), Some(3));
+		other.entries.truncate(3);
+		assert_eq!(trace.first_divergence(&other), Some(3));
+	}
+
+	#[test]
+	fn execution_trace_is_opt_in() {
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut extensions = sp_externalities::Extensions::new();
+
+		let ext = TestExt::new(&mut overlay, &backend, Some(&mut extensions));
+		assert!(ext.execution_trace.is_none());
+	}
 }
@@ -26,6 +26,7 @@ pub mod backend;
 #[cfg(not(substrate_runtime))]
 mod basic;
 mod error;
+mod execution_trace;
 mod ext;
 #[cfg(feature = "fuzzing")]
 pub mod fuzzing;
@@ -131,6 +132,7 @@ impl core::fmt::Display for DefaultError {
 pub use crate::{
 	backend::{Backend, BackendTransaction, IterArgs, KeysIter, PairsIter, StorageIterator},
 	error::{Error, ExecutionError},
+	execution_trace::{ExecutionTrace, TraceEntry, TraceOp},
 	ext::Ext,
 	overlayed_changes::{
 		ChildStorageCollection, IndexOperation, OffchainChangesCollection,
@@ -151,10 +153,15 @@ pub use crate::{
 
 #[cfg(feature = "std")]
 mod std_reexport {
-	pub use crate::{testing::TestExternalities, trie_backend::create_proof_check_backend};
+	pub use crate::{
+		execution_trace::{ExecutionTraceExt, ExecutionTraceRecorder},
+		testing::TestExternalities,
+		trie_backend::create_proof_check_backend,
+	};
 	pub use sp_trie::{
 		trie_types::{TrieDBMutV0, TrieDBMutV1},
-		CompactProof, DBValue, LayoutV0, LayoutV1, MemoryDB, StorageProof, TrieMut,
+		CompactProof, DBValue, LayoutV0, LayoutV1, MemoryDB, MultiRootCompactProof, StorageProof,
+		TrieMut,
 	};
 }
 
@@ -824,6 +831,35 @@ mod execution {
 			.expect("A recorder was set and thus, a storage proof can be extracted; qed"))
 	}
 
+	/// Generate a read proof of the same `keys` at several states.
+	///
+	/// The nodes the states have in common are only included once, which makes this much smaller
+	/// than separate proofs when proving e.g. consecutive blocks.
+	pub fn prove_read_multi_root<B, H, I>(
+		backends: impl IntoIterator<Item = B>,
+		keys: I,
+	) -> Result<MultiRootCompactProof, Box<dyn Error>>
+	where
+		B: AsTrieBackend<H>,
+		H: Hasher,
+		H::Out: Ord + Codec,
+		I: IntoIterator + Clone,
+		I::Item: AsRef<[u8]>,
+	{
+		let proofs = backends
+			.into_iter()
+			.map(|backend| {
+				let trie_backend = backend.as_trie_backend();
+				let proof = prove_read_on_trie_backend(trie_backend, keys.clone())?;
+				proof
+					.into_compact_proof::<H>(*trie_backend.root())
+					.map_err(|e| Box::new(format!("{:?}", e)) as Box<dyn Error>)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(MultiRootCompactProof::new(proofs))
+	}
+
 	/// Check storage read proof, generated by `prove_read` call.
 	pub fn read_proof_check<H, I>(
 		root: H::Out,
@@ -845,6 +881,29 @@ mod execution {
 		Ok(result)
 	}
 
+	/// Check multi root read proof, generated by `prove_read_multi_root` call.
+	///
+	/// Returns the values of `keys` at every one of the `roots`, in order.
+	pub fn read_multi_root_proof_check<H, I>(
+		roots: &[H::Out],
+		proof: MultiRootCompactProof,
+		keys: I,
+	) -> Result<Vec<HashMap<Vec<u8>, Option<Vec<u8>>>>, Box<dyn Error>>
+	where
+		H: Hasher + 'static,
+		H::Out: Ord + Codec,
+		I: IntoIterator + Clone,
+		I::Item: AsRef<[u8]>,
+	{
+		proof
+			.to_storage_proofs::<H>(roots)
+			.map_err(|e| Box::new(format!("{:?}", e)) as Box<dyn Error>)?
+			.into_iter()
+			.zip(roots)
+			.map(|(proof, root)| read_proof_check::<H, _>(*root, proof, keys.clone()))
+			.collect()
+	}
+
 	/// Check storage range proof with child trie included, generated by
 	/// `prove_range_read_with_child_with_size` call.
 	///
@@ -1648,6 +1707,41 @@ mod tests {
 		assert_eq!(local_result3.into_iter().collect::<Vec<_>>(), vec![(b"dummy".to_vec(), None)]);
 	}
 
+	#[test]
+	fn prove_read_multi_root_and_proof_check_works() {
+		let state_version = StateVersion::V1;
+		let block1 = trie_backend::tests::test_trie(state_version, None, None);
+		let block2 =
+			block1.update(vec![(None, vec![(b"value2".to_vec(), Some(vec![25]))])], state_version);
+		let roots = vec![*block1.root(), *block2.root()];
+		let keys = [&b"value1"[..], &b"value2"[..], &b"key"[..]];
+
+		let separate = [&block1, &block2]
+			.into_iter()
+			.map(|backend| {
+				prove_read(backend.clone(), keys)
+					.unwrap()
+					.into_compact_proof::<BlakeTwo256>(*backend.root())
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+		let proof = prove_read_multi_root([block1.clone(), block2.clone()], keys).unwrap();
+		assert!(proof.encoded_size() < separate.encoded_size());
+
+		let results =
+			read_multi_root_proof_check::<BlakeTwo256, _>(&roots, proof.clone(), keys).unwrap();
+		assert_eq!(results[0][&b"value2"[..]], Some(vec![24]));
+		assert_eq!(results[1][&b"value2"[..]], Some(vec![25]));
+		for result in &results {
+			assert_eq!(result[&b"value1"[..]], Some(vec![42]));
+			assert_eq!(result[&b"key"[..]], Some(b"value".to_vec()));
+		}
+
+		// The proofs are bound to their roots.
+		assert!(read_multi_root_proof_check::<BlakeTwo256, _>(&[roots[1], roots[0]], proof, keys)
+			.is_err());
+	}
+
 	#[test]
 	fn child_read_compact_stress_test() {
 		use rand::{rngs::SmallRng, RngCore, SeedableRng};
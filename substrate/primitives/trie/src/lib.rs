@@ -27,6 +27,7 @@ pub mod cache;
 mod error;
 #[cfg(any(not(feature = "std"), test))]
 mod hasher_random_state;
+mod multi_root_proof;
 mod node_codec;
 mod node_header;
 #[cfg(feature = "std")]
@@ -54,6 +55,7 @@ pub use hash_db::{HashDB as HashDBT, EMPTY_PREFIX};
 use hash_db::{Hasher, Prefix};
 /// Various re-exports from the `memory-db` crate.
 pub use memory_db::{prefixed_key, HashKey, KeyFunction, PrefixedKey};
+pub use multi_root_proof::{MultiRootCompactProof, MultiRootProofError, MultiRootProofErrorFor};
 /// The Substrate format implementation of `NodeCodec`.
 pub use node_codec::NodeCodec;
 pub use storage_proof::{CompactProof, StorageProof, StorageProofError};
@@ -0,0 +1,276 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proofs covering several state roots at once.
+//!
+//! Light clients and bridges often need to prove the same keys at consecutive blocks. The
+//! [`CompactProof`]s of such blocks mostly consist of the same nodes, as only the parts of the
+//! trie that changed in between differ. A [`MultiRootCompactProof`] stores every distinct
+//! compact encoded node once and, for every root, the indices of the nodes its compact proof is
+//! made of.
+
+use crate::{CompactProof, StorageProof};
+use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
+use codec::{Compact, Decode, Encode};
+use hash_db::Hasher;
+use scale_info::TypeInfo;
+
+// Note that `LayoutV1` usage here (proof compaction) is compatible
+// with `LayoutV0`.
+use crate::LayoutV1 as Layout;
+
+/// Error returned when decoding a [`MultiRootCompactProof`].
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum MultiRootProofError<H, CodecError> {
+	#[cfg_attr(feature = "std", error("The proof covers {0} roots, expected {1}"))]
+	RootCountMismatch(u32, u32),
+	#[cfg_attr(feature = "std", error("Node index {0} is out of bounds"))]
+	InvalidNodeIndex(u32),
+	#[cfg_attr(feature = "std", error("Node {0} is not used by any root"))]
+	UnusedNode(u32),
+	#[cfg_attr(feature = "std", error("Invalid proof for root {0}: {1:?}"))]
+	InvalidProof(u32, crate::CompactProofError<H, CodecError>),
+}
+
+/// Error type of [`MultiRootCompactProof`] using the default layout.
+pub type MultiRootProofErrorFor<H> =
+	MultiRootProofError<<H as Hasher>::Out, crate::Error<<H as Hasher>::Out>>;
+
+/// Compact proofs for several state roots sharing their common nodes.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+pub struct MultiRootCompactProof {
+	/// The distinct compact encoded nodes of all the proofs.
+	pub encoded_nodes: Vec<Vec<u8>>,
+	/// For every root, the indices into `encoded_nodes` of its compact proof, in order.
+	pub proofs: Vec<Vec<Compact<u32>>>,
+}
+
+impl MultiRootCompactProof {
+	/// Bundle the given compact proofs, one per root, deduplicating their nodes.
+	pub fn new(proofs: impl IntoIterator<Item = CompactProof>) -> Self {
+		let mut encoded_nodes = Vec::new();
+		let mut indices = BTreeMap::new();
+
+		let proofs = proofs
+			.into_iter()
+			.map(|proof| {
+				proof
+					.encoded_nodes
+					.into_iter()
+					.map(|node| {
+						let index = *indices.entry(node).or_insert_with_key(|node| {
+							encoded_nodes.push(node.clone());
+							encoded_nodes.len() as u32 - 1
+						});
+						Compact(index)
+					})
+					.collect()
+			})
+			.collect();
+
+		Self { encoded_nodes, proofs }
+	}
+
+	/// Bundle the given storage proofs, compacting each of them against its root.
+	pub fn from_storage_proofs<H: Hasher>(
+		proofs: impl IntoIterator<Item = (H::Out, StorageProof)>,
+	) -> Result<Self, crate::CompactProofError<H::Out, crate::Error<H::Out>>> {
+		let proofs = proofs
+			.into_iter()
+			.map(|(root, proof)| proof.into_compact_proof::<H>(root))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self::new(proofs))
+	}
+
+	/// Returns the number of roots covered by the proof.
+	pub fn len(&self) -> usize {
+		self.proofs.len()
+	}
+
+	/// Returns whether the proof covers no root at all.
+	pub fn is_empty(&self) -> bool {
+		self.proofs.is_empty()
+	}
+
+	/// Returns the compact proof of the `index`th root.
+	///
+	/// Returns `None` if there is no such root or if the proof references unknown nodes.
+	pub fn compact_proof(&self, index: usize) -> Option<CompactProof> {
+		let encoded_nodes = self
+			.proofs
+			.get(index)?
+			.iter()
+			.map(|i| self.encoded_nodes.get(i.0 as usize).cloned())
+			.collect::<Option<_>>()?;
+
+		Some(CompactProof { encoded_nodes })
+	}
+
+	/// Convert into one [`MemoryDB`](crate::MemoryDB) per root.
+	///
+	/// `expected_roots` are the roots of the proofs, in order. Every database only contains the
+	/// nodes proven against its own root.
+	///
+	/// Fails if the number of roots does not match, if any of the proofs is invalid or if the
+	/// proof contains nodes that are not used by any root.
+	pub fn to_memory_dbs<H: Hasher>(
+		&self,
+		expected_roots: &[H::Out],
+	) -> Result<Vec<crate::MemoryDB<H>>, MultiRootProofErrorFor<H>> {
+		if self.proofs.len() != expected_roots.len() {
+			return Err(MultiRootProofError::RootCountMismatch(
+				self.proofs.len() as u32,
+				expected_roots.len() as u32,
+			))
+		}
+
+		let mut used = vec![false; self.encoded_nodes.len()];
+		let mut dbs = Vec::with_capacity(expected_roots.len());
+
+		for (index, (proof, root)) in self.proofs.iter().zip(expected_roots).enumerate() {
+			let encoded = proof
+				.iter()
+				.map(|i| {
+					let node = self
+						.encoded_nodes
+						.get(i.0 as usize)
+						.ok_or(MultiRootProofError::InvalidNodeIndex(i.0))?;
+					used[i.0 as usize] = true;
+					Ok(node.as_slice())
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let mut db = crate::MemoryDB::<H>::new(&[]);
+			crate::decode_compact::<Layout<H>, _, _>(&mut db, encoded, Some(root))
+				.map_err(|e| MultiRootProofError::InvalidProof(index as u32, e))?;
+			dbs.push(db);
+		}
+
+		if let Some(unused) = used.iter().position(|used| !used) {
+			return Err(MultiRootProofError::UnusedNode(unused as u32))
+		}
+
+		Ok(dbs)
+	}
+
+	/// Decode into one full [`StorageProof`] per root.
+	///
+	/// See [`Self::to_memory_dbs`] for the checks done.
+	pub fn to_storage_proofs<H: Hasher>(
+		&self,
+		expected_roots: &[H::Out],
+	) -> Result<Vec<StorageProof>, MultiRootProofErrorFor<H>> {
+		Ok(self
+			.to_memory_dbs::<H>(expected_roots)?
+			.into_iter()
+			.map(|mut db| {
+				StorageProof::new(
+					db.drain().into_iter().filter_map(|(_, (node, rc))| (rc > 0).then_some(node)),
+				)
+			})
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{read_trie_value, tests::create_storage_proof};
+
+	type Hasher = sp_core::Blake2Hasher;
+	type Layout = crate::LayoutV1<Hasher>;
+
+	const BLOCK_1: &[(&[u8], &[u8])] =
+		&[(b"key1", &[1; 64]), (b"key2", &[2; 64]), (b"key3", &[3; 64]), (b"key11", &[4; 64])];
+	const BLOCK_2: &[(&[u8], &[u8])] =
+		&[(b"key1", &[1; 64]), (b"key2", &[2; 64]), (b"key3", &[5; 64]), (b"key11", &[4; 64])];
+
+	fn proofs() -> Vec<(<Hasher as hash_db::Hasher>::Out, StorageProof)> {
+		[BLOCK_1, BLOCK_2]
+			.into_iter()
+			.map(|data| {
+				let (proof, root) = create_storage_proof::<Layout>(data);
+				(root, StorageProof::new(proof))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn multi_root_proof_deduplicates_nodes() {
+		let proofs = proofs();
+		let roots = proofs.iter().map(|(root, _)| *root).collect::<Vec<_>>();
+		let separate = proofs
+			.iter()
+			.map(|(root, proof)| proof.to_compact_proof::<Hasher>(*root).unwrap())
+			.collect::<Vec<_>>();
+
+		let multi = MultiRootCompactProof::from_storage_proofs::<Hasher>(proofs).unwrap();
+
+		assert_eq!(multi.len(), 2);
+		assert!(
+			multi.encoded_nodes.len() <
+				separate.iter().map(|p| p.encoded_nodes.len()).sum::<usize>()
+		);
+		assert!(multi.encoded_size() < separate.encoded_size());
+		for (index, proof) in separate.into_iter().enumerate() {
+			assert_eq!(multi.compact_proof(index), Some(proof));
+		}
+
+		let decoded = MultiRootCompactProof::decode(&mut &multi.encode()[..]).unwrap();
+		let dbs = decoded.to_memory_dbs::<Hasher>(&roots).unwrap();
+		for ((db, root), data) in dbs.iter().zip(&roots).zip([BLOCK_1, BLOCK_2]) {
+			for (key, value) in data {
+				assert_eq!(
+					read_trie_value::<Layout, _>(db, root, key, None, None).unwrap(),
+					Some(value.to_vec()),
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn multi_root_proof_is_checked_against_roots() {
+		let proofs = proofs();
+		let roots = proofs.iter().map(|(root, _)| *root).collect::<Vec<_>>();
+		let multi = MultiRootCompactProof::from_storage_proofs::<Hasher>(proofs).unwrap();
+
+		assert!(matches!(
+			multi.to_memory_dbs::<Hasher>(&roots[..1]),
+			Err(MultiRootProofError::RootCountMismatch(2, 1))
+		));
+		assert!(matches!(
+			multi.to_memory_dbs::<Hasher>(&[roots[1], roots[0]]),
+			Err(MultiRootProofError::InvalidProof(0, _))
+		));
+
+		let mut padded = multi.clone();
+		padded.encoded_nodes.push(vec![1, 2, 3]);
+		assert!(matches!(
+			padded.to_memory_dbs::<Hasher>(&roots),
+			Err(MultiRootProofError::UnusedNode(_))
+		));
+
+		let mut invalid = multi;
+		invalid.proofs[1].push(Compact(u32::MAX));
+		assert!(matches!(
+			invalid.to_memory_dbs::<Hasher>(&roots),
+			Err(MultiRootProofError::InvalidNodeIndex(u32::MAX))
+		));
+	}
+}
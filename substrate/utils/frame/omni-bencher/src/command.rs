@@ -15,6 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::upgrade_check::CheckUpgradeCmd;
 use clap::Parser;
 use frame_benchmarking_cli::{BenchmarkCmd, OpaqueBlock};
 use sc_cli::Result;
@@ -76,6 +77,18 @@ use sp_runtime::traits::BlakeTwo256;
 ///
 /// For the exact arguments of the `pallet` command, please refer to the `pallet` sub-module.
 ///
+/// ## Upgrade Checks
+///
+/// Before enacting a runtime upgrade, the new runtime can be checked against the one on chain for
+/// changes that break transactions or storage:
+///
+/// ```sh
+/// frame-omni-bencher check-upgrade --old old-runtime.compact.compressed.wasm \
+///     --new target/release/wbuild/westend-runtime/westend-runtime.compact.compressed.wasm
+/// ```
+///
+/// The command fails on breaking changes, unless `--report-only` is passed.
+///
 /// ## Backwards Compatibility
 ///
 /// The exposed pallet sub-command is identical as the node-integrated CLI. The only difference is
@@ -92,6 +105,8 @@ pub struct Command {
 pub enum SubCommand {
 	/// Compatibility syntax with the old benchmark runner.
 	V1(V1Command),
+	/// Check a runtime upgrade for breaking changes, based on the metadata of both runtimes.
+	CheckUpgrade(CheckUpgradeCmd),
 	// NOTE: Here we can add new commands in a forward-compatible way. For example when
 	// transforming the CLI from a monolithic design to a data driven pipeline, there could be
 	// commands like `measure`, `analyze` and `render`.
@@ -126,6 +141,7 @@ impl Command {
 	pub fn run(self) -> Result<()> {
 		match self.sub {
 			SubCommand::V1(V1Command { sub }) => sub.run(),
+			SubCommand::CheckUpgrade(cmd) => cmd.run::<HostFunctions>(),
 		}
 	}
 }
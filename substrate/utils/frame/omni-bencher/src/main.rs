@@ -16,6 +16,7 @@
 // limitations under the License.
 
 mod command;
+mod upgrade_check;
 
 use clap::Parser;
 use sc_cli::Result;
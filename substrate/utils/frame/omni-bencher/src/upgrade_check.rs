@@ -0,0 +1,874 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility checks of a runtime upgrade, based on the metadata of both runtimes.
+
+use codec::Decode;
+use frame_metadata::{
+	v15::{PalletMetadata, RuntimeMetadataV15, StorageEntryMetadata, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use sc_cli::Result;
+use sc_executor::WasmExecutor;
+use sc_runtime_utilities::{fetch_latest_metadata_from_code_blob, RuntimeCaller};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef};
+use sp_version::RuntimeVersion;
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap},
+	fmt,
+	path::{Path, PathBuf},
+};
+
+/// Storage key under which FRAME stores the storage version of a pallet, after the pallet prefix.
+const STORAGE_VERSION_STORAGE_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
+
+/// Check a runtime upgrade for breaking changes.
+///
+/// Compares the metadata and version of the runtime currently on chain with the one to upgrade to
+/// and reports:
+/// - pallets and calls whose index changed, removed calls and calls with changed arguments,
+/// - events whose index or fields changed,
+/// - storage items whose layout changed while the storage version of the pallet was not bumped,
+/// - changes of the transaction extension pipeline,
+/// - a missing `spec_version` bump, or a missing `transaction_version` bump while any of the above
+///   invalidates transactions signed against the old runtime.
+///
+/// The in-code storage versions are taken from the genesis state of the default preset of each
+/// runtime. If that cannot be built, every storage layout change is reported as breaking.
+#[derive(Debug, clap::Parser)]
+pub struct CheckUpgradeCmd {
+	/// Path to the WASM blob of the runtime currently on chain.
+	#[arg(long, value_name = "PATH")]
+	pub old: PathBuf,
+
+	/// Path to the WASM blob of the runtime to upgrade to.
+	#[arg(long, value_name = "PATH")]
+	pub new: PathBuf,
+
+	/// Only report breaking changes instead of failing on them.
+	#[arg(long)]
+	pub report_only: bool,
+}
+
+impl CheckUpgradeCmd {
+	pub fn run<HF: sp_wasm_interface::HostFunctions>(&self) -> Result<()> {
+		let old = RuntimeInfo::load::<HF>(&self.old)?;
+		let new = RuntimeInfo::load::<HF>(&self.new)?;
+
+		let report = check_upgrade(&old, &new);
+		println!(
+			"Upgrade from {} {} (transaction version {}) to {} {} (transaction version {}):",
+			old.version.spec_name,
+			old.version.spec_version,
+			old.version.transaction_version,
+			new.version.spec_name,
+			new.version.spec_version,
+			new.version.transaction_version,
+		);
+		for finding in &report {
+			println!("  {finding}");
+		}
+
+		let breaking = report.iter().filter(|f| f.severity == Severity::Breaking).count();
+		println!("Found {breaking} breaking change(s) and {} warning(s).", report.len() - breaking);
+
+		if breaking > 0 && !self.report_only {
+			return Err(format!("The upgrade contains {breaking} breaking change(s)").into())
+		}
+		Ok(())
+	}
+}
+
+/// How severe a change between two runtimes is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+	/// The change needs attention, but is fine on its own.
+	Warning,
+	/// The change breaks the chain or its users.
+	Breaking,
+}
+
+/// A change between two runtimes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+	pub severity: Severity,
+	pub message: String,
+}
+
+impl Finding {
+	fn warning(message: impl Into<String>) -> Self {
+		Self { severity: Severity::Warning, message: message.into() }
+	}
+
+	fn breaking(message: impl Into<String>) -> Self {
+		Self { severity: Severity::Breaking, message: message.into() }
+	}
+}
+
+impl fmt::Display for Finding {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.severity {
+			Severity::Warning => write!(f, "[warning] {}", self.message),
+			Severity::Breaking => write!(f, "[breaking] {}", self.message),
+		}
+	}
+}
+
+/// Everything about a runtime needed to check an upgrade.
+pub struct RuntimeInfo {
+	pub version: RuntimeVersion,
+	pub metadata: RuntimeMetadataV15,
+	/// The in-code storage versions of the pallets by name, if the genesis state could be built.
+	pub storage_versions: Option<BTreeMap<String, u16>>,
+}
+
+impl RuntimeInfo {
+	/// Load the runtime from the WASM blob at `path`.
+	pub fn load<HF: sp_wasm_interface::HostFunctions>(path: &Path) -> Result<Self> {
+		let code = std::fs::read(path)
+			.map_err(|e| format!("Unable to read runtime {}: {e}", path.display()))?;
+		let executor = WasmExecutor::<(sp_io::SubstrateHostFunctions, HF)>::builder()
+			.with_allow_missing_host_functions(true)
+			.build();
+
+		let version = RuntimeCaller::new(&executor, Cow::Borrowed(&code))
+			.call("Core_version", ())
+			.map_err(|e| format!("Unable to call `Core_version`: {e}"))?;
+		let version = RuntimeVersion::decode(&mut &version[..])
+			.map_err(|e| format!("Unable to decode the runtime version: {e}"))?;
+
+		let metadata = fetch_latest_metadata_from_code_blob(&executor, Cow::Borrowed(&code))
+			.map_err(|e| format!("Unable to fetch the metadata: {e}"))?;
+		let metadata = match RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+			.map_err(|e| format!("Unable to decode the metadata: {e}"))?
+			.1
+		{
+			RuntimeMetadata::V15(metadata) => metadata,
+			_ => return Err("Only metadata V15 is supported".into()),
+		};
+
+		let storage_versions =
+			match sc_chain_spec::GenesisConfigBuilderRuntimeCaller::<HF>::new(&code)
+				.get_storage_for_named_preset(None)
+			{
+				Ok(storage) => Some(
+					metadata
+						.pallets
+						.iter()
+						.filter_map(|p| {
+							let key = [
+								sp_crypto_hashing::twox_128(p.name.as_bytes()),
+								sp_crypto_hashing::twox_128(STORAGE_VERSION_STORAGE_KEY_POSTFIX),
+							]
+							.concat();
+							let version = storage.top.get(&key)?;
+							Some((p.name.clone(), u16::decode(&mut &version[..]).ok()?))
+						})
+						.collect(),
+				),
+				Err(e) => {
+					log::warn!(
+						"Unable to build the genesis state of {}, storage versions are unknown: {e}",
+						path.display()
+					);
+					None
+				},
+			};
+
+		Ok(Self { version, metadata, storage_versions })
+	}
+}
+
+/// Compare two runtimes and return all findings.
+pub fn check_upgrade(old: &RuntimeInfo, new: &RuntimeInfo) -> Vec<Finding> {
+	let mut old_shapes = ShapeCache::new(&old.metadata.types);
+	let mut new_shapes = ShapeCache::new(&new.metadata.types);
+	let mut findings = Vec::new();
+	// Whether transactions signed against the old runtime become invalid.
+	let mut invalidates_transactions = false;
+
+	let new_pallets: HashMap<_, _> =
+		new.metadata.pallets.iter().map(|p| (p.name.as_str(), p)).collect();
+	for old_pallet in &old.metadata.pallets {
+		let Some(new_pallet) = new_pallets.get(old_pallet.name.as_str()) else {
+			findings.push(Finding::warning(format!(
+				"Pallet `{}` was removed, make sure its storage is cleaned up",
+				old_pallet.name
+			)));
+			continue
+		};
+
+		if old_pallet.index != new_pallet.index {
+			invalidates_transactions = true;
+			findings.push(Finding::warning(format!(
+				"Pallet `{}` index changed from {} to {}",
+				old_pallet.name, old_pallet.index, new_pallet.index
+			)));
+		}
+
+		let calls = compare_variants(
+			&mut old_shapes,
+			old_pallet.calls.as_ref().map(|c| c.ty.id),
+			&mut new_shapes,
+			new_pallet.calls.as_ref().map(|c| c.ty.id),
+		);
+		for change in calls {
+			invalidates_transactions = true;
+			findings.push(Finding::warning(format!("Call `{}::{change}", old_pallet.name)));
+		}
+
+		let events = compare_variants(
+			&mut old_shapes,
+			old_pallet.event.as_ref().map(|e| e.ty.id),
+			&mut new_shapes,
+			new_pallet.event.as_ref().map(|e| e.ty.id),
+		);
+		for change in events {
+			findings.push(Finding::warning(format!("Event `{}::{change}", old_pallet.name)));
+		}
+
+		findings.extend(check_storage(
+			old_pallet,
+			&mut old_shapes,
+			old.storage_versions.as_ref(),
+			new_pallet,
+			&mut new_shapes,
+			new.storage_versions.as_ref(),
+		));
+	}
+
+	let old_extensions = old
+		.metadata
+		.extrinsic
+		.signed_extensions
+		.iter()
+		.map(|e| {
+			(
+				e.identifier.as_str(),
+				old_shapes.shape(e.ty.id),
+				old_shapes.shape(e.additional_signed.id),
+			)
+		})
+		.collect::<Vec<_>>();
+	let new_extensions = new
+		.metadata
+		.extrinsic
+		.signed_extensions
+		.iter()
+		.map(|e| {
+			(
+				e.identifier.as_str(),
+				new_shapes.shape(e.ty.id),
+				new_shapes.shape(e.additional_signed.id),
+			)
+		})
+		.collect::<Vec<_>>();
+	if old_extensions != new_extensions {
+		invalidates_transactions = true;
+		let names = |exts: &[(&str, String, String)]| {
+			exts.iter().map(|(name, ..)| *name).collect::<Vec<_>>().join(", ")
+		};
+		findings.push(Finding::warning(format!(
+			"Transaction extensions changed from [{}] to [{}]",
+			names(&old_extensions),
+			names(&new_extensions)
+		)));
+	}
+	if old.metadata.extrinsic.version != new.metadata.extrinsic.version {
+		invalidates_transactions = true;
+		findings.push(Finding::warning(format!(
+			"Extrinsic format version changed from {} to {}",
+			old.metadata.extrinsic.version, new.metadata.extrinsic.version
+		)));
+	}
+
+	findings.extend(check_version(&old.version, &new.version, invalidates_transactions));
+	findings
+}
+
+/// Check the version bumps of an upgrade.
+fn check_version(
+	old: &RuntimeVersion,
+	new: &RuntimeVersion,
+	invalidates_transactions: bool,
+) -> Vec<Finding> {
+	let mut findings = Vec::new();
+	if old.spec_name != new.spec_name {
+		findings.push(Finding::breaking(format!(
+			"`spec_name` changed from `{}` to `{}`",
+			old.spec_name, new.spec_name
+		)));
+	}
+	if new.spec_version <= old.spec_version {
+		findings.push(Finding::breaking(format!(
+			"`spec_version` must be increased, but went from {} to {}",
+			old.spec_version, new.spec_version
+		)));
+	}
+	if invalidates_transactions && new.transaction_version <= old.transaction_version {
+		findings.push(Finding::breaking(format!(
+			"`transaction_version` must be increased since calls or transaction extensions \
+			 changed, but went from {} to {}",
+			old.transaction_version, new.transaction_version
+		)));
+	}
+	findings
+}
+
+/// Compare the storage items of a pallet.
+fn check_storage(
+	old_pallet: &PalletMetadata<PortableForm>,
+	old_shapes: &mut ShapeCache,
+	old_versions: Option<&BTreeMap<String, u16>>,
+	new_pallet: &PalletMetadata<PortableForm>,
+	new_shapes: &mut ShapeCache,
+	new_versions: Option<&BTreeMap<String, u16>>,
+) -> Vec<Finding> {
+	let mut findings = Vec::new();
+	let Some(old_storage) = &old_pallet.storage else { return findings };
+	let new_entries: HashMap<_, _> = new_pallet
+		.storage
+		.iter()
+		.flat_map(|s| s.entries.iter().map(|e| (e.name.as_str(), e)))
+		.collect();
+
+	let version = |versions: Option<&BTreeMap<String, u16>>| {
+		versions.map(|v| v.get(&old_pallet.name).copied().unwrap_or_default())
+	};
+	let (old_version, new_version) = (version(old_versions), version(new_versions));
+
+	for old_entry in &old_storage.entries {
+		let name = format!("{}::{}", old_pallet.name, old_entry.name);
+		let Some(new_entry) = new_entries.get(old_entry.name.as_str()) else {
+			findings.push(Finding::warning(format!(
+				"Storage `{name}` was removed, make sure it is cleaned up"
+			)));
+			continue
+		};
+
+		if entry_layout(old_shapes, old_entry) == entry_layout(new_shapes, new_entry) {
+			continue
+		}
+
+		match (old_version, new_version) {
+			(Some(old_version), Some(new_version)) if new_version > old_version =>
+				findings.push(Finding::warning(format!(
+					"Storage `{name}` layout changed with a storage version bump from \
+					 {old_version} to {new_version}, make sure the migration covers it"
+				))),
+			(Some(old_version), Some(new_version)) => findings.push(Finding::breaking(format!(
+				"Storage `{name}` layout changed without a storage version bump \
+				 ({old_version} to {new_version}), a migration is likely missing"
+			))),
+			_ => findings.push(Finding::breaking(format!(
+				"Storage `{name}` layout changed and the storage versions are unknown, a migration \
+				 is likely needed"
+			))),
+		}
+	}
+	findings
+}
+
+/// The encoding relevant layout of a storage entry.
+fn entry_layout(shapes: &mut ShapeCache, entry: &StorageEntryMetadata<PortableForm>) -> String {
+	match &entry.ty {
+		StorageEntryType::Plain(value) => shapes.shape(value.id),
+		StorageEntryType::Map { hashers, key, value } =>
+			format!("{hashers:?} {} => {}", shapes.shape(key.id), shapes.shape(value.id)),
+	}
+}
+
+/// Compare the variants of two enums, such as the calls or events of a pallet, by name.
+///
+/// Returns a description of every removed variant and of every variant whose index or fields
+/// changed. Variants added in the new enum are fine.
+fn compare_variants(
+	old_shapes: &mut ShapeCache,
+	old_ty: Option<u32>,
+	new_shapes: &mut ShapeCache,
+	new_ty: Option<u32>,
+) -> Vec<String> {
+	fn variants(shapes: &mut ShapeCache, ty: Option<u32>) -> BTreeMap<String, (u8, String)> {
+		let Some(TypeDef::Variant(def)) =
+			ty.and_then(|ty| shapes.registry.resolve(ty)).map(|t| t.type_def.clone())
+		else {
+			return BTreeMap::new()
+		};
+		def.variants
+			.iter()
+			.map(|v| (v.name.clone(), (v.index, shapes.fields(&v.fields))))
+			.collect()
+	}
+	let old_variants = variants(old_shapes, old_ty);
+	let new_variants = variants(new_shapes, new_ty);
+
+	let mut changes = Vec::new();
+	for (name, (old_index, old_fields)) in old_variants {
+		match new_variants.get(&name) {
+			None => changes.push(format!("{name}` was removed")),
+			Some((new_index, _)) if *new_index != old_index =>
+				changes.push(format!("{name}` index changed from {old_index} to {new_index}")),
+			Some((_, new_fields)) if *new_fields != old_fields =>
+				changes.push(format!("{name}` fields changed")),
+			Some(_) => {},
+		}
+	}
+	changes
+}
+
+/// Renders the encoding relevant structure of types, so that types of different registries can be
+/// compared.
+///
+/// Type paths and field names are ignored, since they do not affect the encoding.
+struct ShapeCache<'a> {
+	registry: &'a PortableRegistry,
+	cache: HashMap<u32, String>,
+	visiting: Vec<u32>,
+}
+
+impl<'a> ShapeCache<'a> {
+	fn new(registry: &'a PortableRegistry) -> Self {
+		Self { registry, cache: HashMap::new(), visiting: Vec::new() }
+	}
+
+	fn shape(&mut self, id: u32) -> String {
+		if let Some(shape) = self.cache.get(&id) {
+			return shape.clone()
+		}
+		let Some(ty) = self.registry.resolve(id) else { return format!("<unknown type {id}>") };
+		if self.visiting.contains(&id) {
+			// Recursive types are referred to by their path.
+			return format!("<{}>", ty.path.segments.join("::"))
+		}
+
+		self.visiting.push(id);
+		let shape = match &ty.type_def {
+			TypeDef::Composite(def) => format!("{{{}}}", self.fields(&def.fields)),
+			TypeDef::Variant(def) => def
+				.variants
+				.iter()
+				.map(|v| format!("{}({})", v.index, self.fields(&v.fields)))
+				.collect::<Vec<_>>()
+				.join("|"),
+			TypeDef::Sequence(def) => format!("[{}]", self.shape(def.type_param.id)),
+			TypeDef::Array(def) => format!("[{}; {}]", self.shape(def.type_param.id), def.len),
+			TypeDef::Tuple(def) => format!(
+				"({})",
+				def.fields.iter().map(|f| self.shape(f.id)).collect::<Vec<_>>().join(", ")
+			),
+			TypeDef::Primitive(def) => format!("{def:?}").to_lowercase(),
+			TypeDef::Compact(def) => format!("Compact<{}>", self.shape(def.type_param.id)),
+			TypeDef::BitSequence(def) => format!(
+				"BitVec<{}, {}>",
+				self.shape(def.bit_store_type.id),
+				self.shape(def.bit_order_type.id)
+			),
+		};
+		self.visiting.pop();
+
+		self.cache.insert(id, shape.clone());
+		shape
+	}
+
+	fn fields(&mut self, fields: &[Field<PortableForm>]) -> String {
+		fields.iter().map(|f| self.shape(f.ty.id)).collect::<Vec<_>>().join(", ")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_metadata::v15::{
+		CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletCallMetadata, PalletStorageMetadata,
+		SignedExtensionMetadata, StorageEntryModifier,
+	};
+	use scale_info::{form::MetaForm, meta_type, IntoPortable, MetaType, Registry, TypeInfo};
+
+	mod v1 {
+		use scale_info::TypeInfo;
+
+		#[allow(dead_code)]
+		#[derive(TypeInfo)]
+		pub enum Call {
+			#[codec(index = 0)]
+			Transfer { dest: u64, amount: u128 },
+			#[codec(index = 1)]
+			Remark { data: Vec<u8> },
+			#[codec(index = 2)]
+			Kill,
+			#[codec(index = 3)]
+			SetCode { code: Vec<u8> },
+		}
+	}
+
+	mod v2 {
+		use scale_info::TypeInfo;
+
+		#[allow(dead_code)]
+		#[derive(TypeInfo)]
+		pub enum Call {
+			#[codec(index = 0)]
+			Transfer { to: u64, value: u128 },
+			#[codec(index = 1)]
+			Burn { amount: u32 },
+			#[codec(index = 2)]
+			Remark { data: Vec<u8> },
+			#[codec(index = 3)]
+			SetCode { code: Vec<u8>, checked: bool },
+		}
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	struct Account {
+		nonce: u32,
+		data: Vec<u8>,
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	struct RenamedAccount {
+		counter: u32,
+		payload: Vec<u8>,
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	struct CompactAccount {
+		#[codec(compact)]
+		nonce: u32,
+		data: Vec<u8>,
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	struct Node {
+		children: Vec<Node>,
+	}
+
+	fn pallet(
+		name: &'static str,
+		index: u8,
+		calls: Option<MetaType>,
+		storage: Vec<(&'static str, MetaType)>,
+	) -> PalletMetadata<MetaForm> {
+		PalletMetadata {
+			name,
+			storage: Some(PalletStorageMetadata {
+				prefix: name,
+				entries: storage
+					.into_iter()
+					.map(|(name, ty)| StorageEntryMetadata {
+						name,
+						modifier: StorageEntryModifier::Default,
+						ty: StorageEntryType::Plain(ty),
+						default: Vec::new(),
+						docs: Vec::new(),
+					})
+					.collect(),
+			}),
+			calls: calls.map(|ty| PalletCallMetadata { ty }),
+			event: None,
+			constants: Vec::new(),
+			error: None,
+			index,
+			docs: Vec::new(),
+		}
+	}
+
+	fn extension(identifier: &'static str, ty: MetaType) -> SignedExtensionMetadata<MetaForm> {
+		SignedExtensionMetadata { identifier, ty, additional_signed: meta_type::<()>() }
+	}
+
+	fn runtime(
+		spec_version: u32,
+		transaction_version: u32,
+		pallets: Vec<PalletMetadata<MetaForm>>,
+		signed_extensions: Vec<SignedExtensionMetadata<MetaForm>>,
+		storage_versions: Option<BTreeMap<String, u16>>,
+	) -> RuntimeInfo {
+		let mut registry = Registry::new();
+		let pallets = registry.map_into_portable(pallets);
+		let extrinsic = ExtrinsicMetadata {
+			version: 4,
+			address_ty: meta_type::<()>(),
+			call_ty: meta_type::<()>(),
+			signature_ty: meta_type::<()>(),
+			extra_ty: meta_type::<()>(),
+			signed_extensions,
+		}
+		.into_portable(&mut registry);
+		let ty = registry.register_type(&meta_type::<()>());
+		let outer_enums = OuterEnums {
+			call_enum_ty: meta_type::<()>(),
+			event_enum_ty: meta_type::<()>(),
+			error_enum_ty: meta_type::<()>(),
+		}
+		.into_portable(&mut registry);
+
+		RuntimeInfo {
+			version: RuntimeVersion {
+				spec_name: "test".into(),
+				spec_version,
+				transaction_version,
+				..Default::default()
+			},
+			metadata: RuntimeMetadataV15 {
+				types: registry.into(),
+				pallets,
+				extrinsic,
+				ty,
+				apis: Vec::new(),
+				outer_enums,
+				custom: CustomMetadata { map: Default::default() },
+			},
+			storage_versions,
+		}
+	}
+
+	fn versions(version: u16) -> Option<BTreeMap<String, u16>> {
+		Some(BTreeMap::from([("Accounts".to_string(), version)]))
+	}
+
+	fn storage_runtime(
+		spec_version: u32,
+		ty: MetaType,
+		storage_versions: Option<BTreeMap<String, u16>>,
+	) -> RuntimeInfo {
+		runtime(
+			spec_version,
+			1,
+			vec![pallet("Accounts", 0, None, vec![("Account", ty)])],
+			Vec::new(),
+			storage_versions,
+		)
+	}
+
+	#[test]
+	fn identical_runtimes_only_need_a_spec_version_bump() {
+		let old = storage_runtime(1, meta_type::<Account>(), versions(1));
+
+		assert_eq!(
+			check_upgrade(&old, &old),
+			vec![Finding::breaking("`spec_version` must be increased, but went from 1 to 1")]
+		);
+		assert!(check_upgrade(&old, &storage_runtime(2, meta_type::<Account>(), versions(1)))
+			.is_empty());
+	}
+
+	#[test]
+	fn compare_variants_works() {
+		let old = runtime(
+			1,
+			1,
+			vec![pallet("Balances", 5, Some(meta_type::<v1::Call>()), vec![])],
+			vec![],
+			None,
+		);
+		let new = runtime(
+			2,
+			1,
+			vec![pallet("Balances", 5, Some(meta_type::<v2::Call>()), vec![])],
+			vec![],
+			None,
+		);
+		let ty = |info: &RuntimeInfo| info.metadata.pallets[0].calls.as_ref().map(|c| c.ty.id);
+
+		// Renamed fields are fine, added variants too.
+		assert_eq!(
+			compare_variants(
+				&mut ShapeCache::new(&old.metadata.types),
+				ty(&old),
+				&mut ShapeCache::new(&new.metadata.types),
+				ty(&new),
+			),
+			vec![
+				"Kill` was removed".to_string(),
+				"Remark` index changed from 1 to 2".to_string(),
+				"SetCode` fields changed".to_string(),
+			]
+		);
+
+		// Removing all calls removes every variant, adding them is fine.
+		let without_calls = runtime(2, 1, vec![pallet("Balances", 5, None, vec![])], vec![], None);
+		assert_eq!(
+			compare_variants(
+				&mut ShapeCache::new(&old.metadata.types),
+				ty(&old),
+				&mut ShapeCache::new(&without_calls.metadata.types),
+				None,
+			)
+			.len(),
+			4
+		);
+		assert!(compare_variants(
+			&mut ShapeCache::new(&without_calls.metadata.types),
+			None,
+			&mut ShapeCache::new(&new.metadata.types),
+			ty(&new),
+		)
+		.is_empty());
+
+		// Changed calls invalidate transactions signed against the old runtime.
+		let findings = check_upgrade(&old, &new);
+		assert!(findings.contains(&Finding::warning("Call `Balances::Kill` was removed")));
+		assert!(findings.contains(&Finding::breaking(
+			"`transaction_version` must be increased since calls or transaction extensions \
+			 changed, but went from 1 to 1"
+		)));
+	}
+
+	#[test]
+	fn shape_cache_ignores_names() {
+		let mut registry = Registry::new();
+		let account = registry.register_type(&meta_type::<Account>()).id;
+		let renamed = registry.register_type(&meta_type::<RenamedAccount>()).id;
+		let compact = registry.register_type(&meta_type::<CompactAccount>()).id;
+		let registry = PortableRegistry::from(registry);
+		let mut shapes = ShapeCache::new(&registry);
+
+		assert_eq!(shapes.shape(account), "{u32, [u8]}");
+		assert_eq!(shapes.shape(account), shapes.shape(renamed));
+		assert_eq!(shapes.shape(compact), "{Compact<u32>, [u8]}");
+		assert_eq!(shapes.shape(u32::MAX), format!("<unknown type {}>", u32::MAX));
+	}
+
+	#[test]
+	fn shape_cache_handles_recursive_types() {
+		let mut registry = Registry::new();
+		let node = registry.register_type(&meta_type::<Node>()).id;
+		let registry = PortableRegistry::from(registry);
+		let path = registry.resolve(node).unwrap().path.segments.join("::");
+
+		assert_eq!(ShapeCache::new(&registry).shape(node), format!("{{[<{path}>]}}"));
+	}
+
+	#[test]
+	fn storage_layout_change_needs_a_storage_version_bump() {
+		let old = storage_runtime(1, meta_type::<Account>(), versions(1));
+		let check = |new: RuntimeInfo| {
+			check_storage(
+				&old.metadata.pallets[0],
+				&mut ShapeCache::new(&old.metadata.types),
+				old.storage_versions.as_ref(),
+				&new.metadata.pallets[0],
+				&mut ShapeCache::new(&new.metadata.types),
+				new.storage_versions.as_ref(),
+			)
+		};
+
+		// Same layout with different names.
+		assert!(check(storage_runtime(2, meta_type::<RenamedAccount>(), versions(1))).is_empty());
+
+		assert_eq!(
+			check(storage_runtime(2, meta_type::<CompactAccount>(), versions(1))),
+			vec![Finding::breaking(
+				"Storage `Accounts::Account` layout changed without a storage version bump (1 to \
+				 1), a migration is likely missing"
+			)]
+		);
+		assert_eq!(
+			check(storage_runtime(2, meta_type::<CompactAccount>(), versions(2))),
+			vec![Finding::warning(
+				"Storage `Accounts::Account` layout changed with a storage version bump from 1 to \
+				 2, make sure the migration covers it"
+			)]
+		);
+		assert_eq!(
+			check(storage_runtime(2, meta_type::<CompactAccount>(), None)),
+			vec![Finding::breaking(
+				"Storage `Accounts::Account` layout changed and the storage versions are unknown, \
+				 a migration is likely needed"
+			)]
+		);
+
+		let removed = runtime(2, 1, vec![pallet("Accounts", 0, None, vec![])], vec![], versions(1));
+		assert_eq!(
+			check(removed),
+			vec![Finding::warning(
+				"Storage `Accounts::Account` was removed, make sure it is cleaned up"
+			)]
+		);
+	}
+
+	#[test]
+	fn pallet_index_change_needs_a_transaction_version_bump() {
+		let old = runtime(1, 1, vec![pallet("Balances", 5, None, vec![])], vec![], None);
+		let moved = |transaction_version| {
+			runtime(2, transaction_version, vec![pallet("Balances", 6, None, vec![])], vec![], None)
+		};
+		let index_changed = Finding::warning("Pallet `Balances` index changed from 5 to 6");
+
+		assert_eq!(
+			check_upgrade(&old, &moved(1)),
+			vec![
+				index_changed.clone(),
+				Finding::breaking(
+					"`transaction_version` must be increased since calls or transaction \
+					 extensions changed, but went from 1 to 1"
+				),
+			]
+		);
+		assert_eq!(check_upgrade(&old, &moved(2)), vec![index_changed]);
+
+		let removed = runtime(2, 1, vec![], vec![], None);
+		assert_eq!(
+			check_upgrade(&old, &removed),
+			vec![Finding::warning(
+				"Pallet `Balances` was removed, make sure its storage is cleaned up"
+			)]
+		);
+	}
+
+	#[test]
+	fn transaction_extension_changes_are_reported() {
+		let old = runtime(1, 1, vec![], vec![extension("CheckNonce", meta_type::<u32>())], None);
+		let added = runtime(
+			2,
+			2,
+			vec![],
+			vec![
+				extension("CheckNonce", meta_type::<u32>()),
+				extension("CheckWeight", meta_type::<()>()),
+			],
+			None,
+		);
+		assert_eq!(
+			check_upgrade(&old, &added),
+			vec![Finding::warning(
+				"Transaction extensions changed from [CheckNonce] to [CheckNonce, CheckWeight]"
+			)]
+		);
+
+		// Changing the type of an extension is a change as well.
+		let changed =
+			runtime(2, 1, vec![], vec![extension("CheckNonce", meta_type::<u64>())], None);
+		assert_eq!(
+			check_upgrade(&old, &changed),
+			vec![
+				Finding::warning(
+					"Transaction extensions changed from [CheckNonce] to [CheckNonce]"
+				),
+				Finding::breaking(
+					"`transaction_version` must be increased since calls or transaction \
+					 extensions changed, but went from 1 to 1"
+				),
+			]
+		);
+	}
+}
@@ -0,0 +1,65 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use assert_cmd::cargo::cargo_bin;
+use std::{fs, path::Path, process::Command};
+
+/// Writes the test runtime into `base_path` and returns a `check-upgrade` command comparing it
+/// with itself.
+fn check_upgrade_same_runtime_command(base_path: &Path) -> Result<Command, String> {
+	let wasm = cumulus_test_runtime::WASM_BINARY.ok_or("WASM binary not available".to_string())?;
+	let runtime_path = base_path.join("runtime.wasm");
+	fs::write(&runtime_path, wasm).map_err(|e| format!("Unable to write runtime file: {}", e))?;
+
+	let mut cmd = Command::new(cargo_bin("frame-omni-bencher"));
+	cmd.args(["check-upgrade", "--old", runtime_path.to_str().unwrap()])
+		.args(["--new", runtime_path.to_str().unwrap()]);
+	Ok(cmd)
+}
+
+#[test]
+fn check_upgrade_fails_without_spec_version_bump() -> std::result::Result<(), String> {
+	let tmp_dir = tempfile::tempdir().expect("Should be able to create tmp dir.");
+
+	let output = check_upgrade_same_runtime_command(tmp_dir.path())?
+		.output()
+		.map_err(|e| format!("command failed: {:?}", e))?;
+
+	if output.status.success() {
+		return Err("Command should have failed!".into())
+	}
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(stdout.contains("[breaking] `spec_version` must be increased"));
+	// Nothing but the version changed.
+	assert!(stdout.contains("Found 1 breaking change(s) and 0 warning(s)."));
+	Ok(())
+}
+
+#[test]
+fn check_upgrade_report_only_works() -> std::result::Result<(), String> {
+	let tmp_dir = tempfile::tempdir().expect("Should be able to create tmp dir.");
+
+	let status = check_upgrade_same_runtime_command(tmp_dir.path())?
+		.arg("--report-only")
+		.status()
+		.map_err(|e| format!("command failed: {:?}", e))?;
+
+	if !status.success() {
+		return Err("Command failed".into())
+	}
+	Ok(())
+}
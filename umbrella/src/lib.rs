@@ -591,6 +591,10 @@ pub use pallet_parameters;
 #[cfg(feature = "pallet-people")]
 pub use pallet_people;
 
+/// FRAME pallet adjusting the Proof-of-Work difficulty based on block timestamps.
+#[cfg(feature = "pallet-pow-difficulty")]
+pub use pallet_pow_difficulty;
+
 /// FRAME pallet for storing preimages of hashes.
 #[cfg(feature = "pallet-preimage")]
 pub use pallet_preimage;
@@ -1052,6 +1056,10 @@ pub use sc_consensus;
 #[cfg(feature = "sc-consensus-aura")]
 pub use sc_consensus_aura;
 
+/// RPC extensions for the Aura consensus algorithm.
+#[cfg(feature = "sc-consensus-aura-rpc")]
+pub use sc_consensus_aura_rpc;
+
 /// BABE consensus algorithm for substrate.
 #[cfg(feature = "sc-consensus-babe")]
 pub use sc_consensus_babe;
@@ -1088,6 +1096,10 @@ pub use sc_consensus_manual_seal;
 #[cfg(feature = "sc-consensus-pow")]
 pub use sc_consensus_pow;
 
+/// RPC extensions for the PoW consensus algorithm.
+#[cfg(feature = "sc-consensus-pow-rpc")]
+pub use sc_consensus_pow_rpc;
+
 /// Generic slots-based utilities for consensus.
 #[cfg(feature = "sc-consensus-slots")]
 pub use sc_consensus_slots;
@@ -1176,6 +1188,10 @@ pub use sc_rpc_server;
 #[cfg(feature = "sc-rpc-spec-v2")]
 pub use sc_rpc_spec_v2;
 
+/// Discovers the FRAME tasks of the runtime and submits them.
+#[cfg(feature = "sc-runtime-tasks")]
+pub use sc_runtime_tasks;
+
 /// Substrate client utilities for frame runtime functions calls.
 #[cfg(feature = "sc-runtime-utilities")]
 pub use sc_runtime_utilities;